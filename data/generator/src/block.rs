@@ -1,4 +1,4 @@
-use std::{fs, io::Result};
+use std::{collections::HashMap, fs, io::Result};
 
 use convert_case::{Case, Casing};
 use proc_macro2::Literal;
@@ -8,23 +8,46 @@ use serde::Deserialize;
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
-struct BlockData {
+pub(crate) struct BlockData {
     id: u64,
     display_name: String,
-    name: String,
+    pub(crate) name: String,
     hardness: Option<f32>,
     min_state_id: u64,
     max_state_id: u64,
-    // "states": [],
+    pub(crate) states: Vec<StateData>,
     drops: Vec<u64>,
     diggable: bool,
     transparent: bool,
     filter_light: u64,
     emit_light: u64,
-    // "boundingBox": "empty",
+    bounding_box: String,
     stack_size: u64,
     default_state: u64,
     resistance: f32,
+    material: Option<String>,
+    harvest_tools: Option<HashMap<String, bool>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub(crate) struct StateData {
+    pub(crate) name: String,
+    #[serde(rename = "type")]
+    pub(crate) state_type: String,
+    #[serde(rename = "num_values")]
+    num_values: u64,
+    values: Option<Vec<String>>,
+}
+
+impl StateData {
+    pub(crate) fn values(&self) -> Vec<String> {
+        match &self.values {
+            Some(values) => values.clone(),
+            None => vec!["true".to_string(), "false".to_string()],
+        }
+    }
 }
 
 pub fn generate_block(version: &str) -> Result<String> {
@@ -146,6 +169,261 @@ pub fn generate_block(version: &str) -> Result<String> {
         }
     });
 
+    let block_to_min_state_ids = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let min_state_id = Literal::u64_unsuffixed(block.min_state_id);
+
+        quote! {
+            Block::#enum_name => #min_state_id
+        }
+    });
+    fns.push(quote! {
+        pub fn min_state_id(&self) -> u32 {
+            match self {
+                #(#block_to_min_state_ids,)*
+            }
+        }
+    });
+
+    let block_to_max_state_ids = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let max_state_id = Literal::u64_unsuffixed(block.max_state_id);
+
+        quote! {
+            Block::#enum_name => #max_state_id
+        }
+    });
+    fns.push(quote! {
+        pub fn max_state_id(&self) -> u32 {
+            match self {
+                #(#block_to_max_state_ids,)*
+            }
+        }
+    });
+
+    let state_id_from_blocks = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let min_state_id = Literal::u64_unsuffixed(block.min_state_id);
+        let max_state_id = Literal::u64_unsuffixed(block.max_state_id);
+
+        quote! {
+            #min_state_id..=#max_state_id => Some(Block::#enum_name)
+        }
+    });
+    fns.push(quote! {
+        pub fn from_state_id(state_id: u32) -> Option<Self> {
+            match state_id {
+                #(#state_id_from_blocks,)*
+                _ => None,
+            }
+        }
+    });
+
+    let block_to_hardnesses = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+
+        match block.hardness {
+            Some(hardness) => {
+                let hardness = Literal::f32_unsuffixed(hardness);
+                quote! {
+                    Block::#enum_name => Some(#hardness)
+                }
+            }
+            None => quote! {
+                Block::#enum_name => None
+            },
+        }
+    });
+    fns.push(quote! {
+        pub fn hardness(&self) -> Option<f32> {
+            match self {
+                #(#block_to_hardnesses,)*
+            }
+        }
+    });
+
+    let block_to_diggables = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let diggable = block.diggable;
+
+        quote! {
+            Block::#enum_name => #diggable
+        }
+    });
+    fns.push(quote! {
+        pub fn diggable(&self) -> bool {
+            match self {
+                #(#block_to_diggables,)*
+            }
+        }
+    });
+
+    let block_to_drops = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let drops = block.drops.iter().map(|drop| Literal::u64_unsuffixed(*drop));
+
+        quote! {
+            Block::#enum_name => &[#(#drops),*]
+        }
+    });
+    fns.push(quote! {
+        pub fn drops(&self) -> &'static [u32] {
+            match self {
+                #(#block_to_drops,)*
+            }
+        }
+    });
+
+    let block_to_solids = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let solid = block.bounding_box == "block";
+
+        quote! {
+            Block::#enum_name => #solid
+        }
+    });
+    fns.push(quote! {
+        pub fn solid(&self) -> bool {
+            match self {
+                #(#block_to_solids,)*
+            }
+        }
+    });
+
+    let block_to_filter_lights = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let filter_light = Literal::u8_unsuffixed(block.filter_light as u8);
+
+        quote! {
+            Block::#enum_name => #filter_light
+        }
+    });
+    fns.push(quote! {
+        pub fn filter_light(&self) -> u8 {
+            match self {
+                #(#block_to_filter_lights,)*
+            }
+        }
+    });
+
+    let block_to_emit_lights = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let emit_light = Literal::u8_unsuffixed(block.emit_light as u8);
+
+        quote! {
+            Block::#enum_name => #emit_light
+        }
+    });
+    fns.push(quote! {
+        pub fn emit_light(&self) -> u8 {
+            match self {
+                #(#block_to_emit_lights,)*
+            }
+        }
+    });
+
+    let block_to_materials = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let material = Literal::string(block.material.as_deref().unwrap_or("default"));
+
+        quote! {
+            Block::#enum_name => #material
+        }
+    });
+    fns.push(quote! {
+        pub fn material(&self) -> &'static str {
+            match self {
+                #(#block_to_materials,)*
+            }
+        }
+    });
+
+    // item ids that drop the block when breaking it, None when anything does
+    let block_to_harvest_tools = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let mut tools = block
+            .harvest_tools
+            .iter()
+            .flat_map(|tools| tools.keys())
+            .map(|id| id.parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+        tools.sort_unstable();
+        let tools = tools.into_iter().map(Literal::u64_unsuffixed);
+
+        if block.harvest_tools.is_some() {
+            quote! {
+                Block::#enum_name => Some(&[#(#tools),*])
+            }
+        } else {
+            quote! {
+                Block::#enum_name => None
+            }
+        }
+    });
+    fns.push(quote! {
+        pub fn harvest_tools(&self) -> Option<&'static [u32]> {
+            match self {
+                #(#block_to_harvest_tools,)*
+            }
+        }
+    });
+
+    let block_to_properties = blocks.iter().map(|block| {
+        let enum_name = format_ident!("{}", block.name.to_case(Case::Pascal));
+        let properties = block.states.iter().map(|state| {
+            let name = Literal::string(&state.name);
+            let values = state.values();
+            let values = values.iter().map(|value| Literal::string(value));
+
+            quote! {
+                (#name, &[#(#values),*])
+            }
+        });
+
+        quote! {
+            Block::#enum_name => &[#(#properties),*]
+        }
+    });
+    fns.push(quote! {
+        pub fn properties(&self) -> &'static [(&'static str, &'static [&'static str])] {
+            match self {
+                #(#block_to_properties,)*
+            }
+        }
+    });
+
+    fns.push(quote! {
+        pub fn state_properties(state_id: u32) -> Option<Vec<(&'static str, &'static str)>> {
+            let block = Self::from_state_id(state_id)?;
+            let mut offset = (state_id - block.min_state_id()) as usize;
+            let mut properties = vec![];
+            for (name, values) in block.properties().iter().rev() {
+                properties.push((*name, values[offset % values.len()]));
+                offset /= values.len();
+            }
+            properties.reverse();
+            Some(properties)
+        }
+    });
+
+    fns.push(quote! {
+        pub fn state_from_properties(&self, properties: &[(&str, &str)]) -> u32 {
+            let defaults = Self::state_properties(self.default_state()).unwrap_or_default();
+            let mut offset = 0;
+            for (index, (name, values)) in self.properties().iter().enumerate() {
+                let value = properties
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| *v)
+                    .filter(|v| values.contains(v))
+                    .unwrap_or(defaults[index].1);
+                let value_index = values.iter().position(|v| *v == value).unwrap_or(0);
+                offset = offset * values.len() + value_index;
+            }
+            self.min_state_id() + offset as u32
+        }
+    });
+
     let block_fns = quote! {
         impl Block {
             #(#fns)*
@@ -0,0 +1,230 @@
+use std::{fs, io::Result};
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::block::BlockData;
+
+enum PropertyKind {
+    Bool,
+    Int,
+    Enum(Vec<String>),
+}
+
+struct Property {
+    name: String,
+    kind: PropertyKind,
+}
+
+impl Property {
+    // `type` is a keyword
+    fn field_name(&self) -> String {
+        match self.name.as_str() {
+            "type" => "kind".to_string(),
+            name => name.to_string(),
+        }
+    }
+
+    fn getter(&self) -> Ident {
+        format_ident!("{}", self.field_name())
+    }
+
+    fn setter(&self) -> Ident {
+        format_ident!("set_{}", self.field_name())
+    }
+
+    fn enum_name(&self) -> Ident {
+        format_ident!("{}", self.field_name().to_case(Case::Pascal))
+    }
+}
+
+// every property name used by any block. a name shared by bool and enum properties
+// (the sides of fences and redstone wire) is typed as bool, the enum one stays
+// reachable through BlockState::property
+fn collect_properties(blocks: &[BlockData]) -> Vec<Property> {
+    let mut properties: Vec<Property> = vec![];
+    for state in blocks.iter().flat_map(|block| block.states.iter()) {
+        let index = match properties.iter().position(|p| p.name == state.name) {
+            Some(index) => index,
+            None => {
+                let kind = match state.state_type.as_str() {
+                    "bool" => PropertyKind::Bool,
+                    "int" => PropertyKind::Int,
+                    _ => PropertyKind::Enum(vec![]),
+                };
+                properties.push(Property {
+                    name: state.name.clone(),
+                    kind,
+                });
+                properties.len() - 1
+            }
+        };
+        let property = &mut properties[index];
+        match (&mut property.kind, state.state_type.as_str()) {
+            (PropertyKind::Enum(_), "bool") => property.kind = PropertyKind::Bool,
+            (PropertyKind::Enum(values), "enum") => {
+                for value in state.values() {
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    properties.sort_by(|a, b| a.name.cmp(&b.name));
+    properties
+}
+
+fn generate_accessors(property: &Property) -> TokenStream {
+    let name = Literal::string(&property.name);
+    let getter = property.getter();
+    let setter = property.setter();
+    match &property.kind {
+        PropertyKind::Bool => quote! {
+            pub fn #getter(&self) -> Option<bool> {
+                self.property(#name).map(|value| value == "true")
+            }
+
+            pub fn #setter(&mut self, value: bool) -> bool {
+                self.set_property(#name, if value { "true" } else { "false" })
+            }
+        },
+        PropertyKind::Int => quote! {
+            pub fn #getter(&self) -> Option<u8> {
+                self.property(#name).and_then(|value| value.parse().ok())
+            }
+
+            pub fn #setter(&mut self, value: u8) -> bool {
+                self.set_property(#name, &value.to_string())
+            }
+        },
+        PropertyKind::Enum(_) => {
+            let enum_name = property.enum_name();
+            quote! {
+                pub fn #getter(&self) -> Option<#enum_name> {
+                    self.property(#name).and_then(#enum_name::from_name)
+                }
+
+                pub fn #setter(&mut self, value: #enum_name) -> bool {
+                    self.set_property(#name, value.name())
+                }
+            }
+        }
+    }
+}
+
+fn generate_enum(property: &Property) -> Option<TokenStream> {
+    let values = match &property.kind {
+        PropertyKind::Enum(values) => values,
+        _ => return None,
+    };
+    let enum_name = property.enum_name();
+    let variants = values
+        .iter()
+        .map(|value| format_ident!("{}", value.to_case(Case::Pascal)))
+        .collect::<Vec<_>>();
+    let names = values
+        .iter()
+        .map(|value| Literal::string(value))
+        .collect::<Vec<_>>();
+
+    Some(quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            pub fn name(&self) -> &'static str {
+                match self {
+                    #(#enum_name::#variants => #names,)*
+                }
+            }
+
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#names => Some(#enum_name::#variants),)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
+
+pub fn generate_block_state(version: &str) -> Result<String> {
+    let blocks_json =
+        fs::read_to_string(format!("minecraft-data/data/pc/{}/blocks.json", version))?;
+    let blocks: Vec<BlockData> = serde_json::from_str(&blocks_json)?;
+    let properties = collect_properties(&blocks);
+    println!("block state properties: {}", properties.len());
+
+    let accessors = properties.iter().map(generate_accessors);
+    let enums = properties.iter().filter_map(generate_enum);
+
+    let block_state = quote! {
+        use crate::block::Block;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct BlockState(u32);
+
+        impl BlockState {
+            pub fn new(block: Block) -> Self {
+                Self(block.default_state())
+            }
+
+            pub fn from_id(id: u32) -> Option<Self> {
+                Block::from_state_id(id).map(|_| Self(id))
+            }
+
+            pub fn id(&self) -> u32 {
+                self.0
+            }
+
+            pub fn block(&self) -> Block {
+                Block::from_state_id(self.0).unwrap()
+            }
+
+            pub fn property(&self, name: &str) -> Option<&'static str> {
+                Block::state_properties(self.0)?
+                    .into_iter()
+                    .find(|(property, _)| *property == name)
+                    .map(|(_, value)| value)
+            }
+
+            pub fn set_property(&mut self, name: &str, value: &str) -> bool {
+                let block = self.block();
+                let value = block
+                    .properties()
+                    .iter()
+                    .find(|(property, _)| *property == name)
+                    .and_then(|(_, values)| values.iter().find(|v| **v == value));
+                let value = match value {
+                    Some(value) => *value,
+                    None => return false,
+                };
+                let mut properties = Block::state_properties(self.0).unwrap_or_default();
+                for property in properties.iter_mut() {
+                    if property.0 == name {
+                        property.1 = value;
+                    }
+                }
+                self.0 = block.state_from_properties(&properties);
+                true
+            }
+
+            #(#accessors)*
+        }
+
+        impl From<Block> for BlockState {
+            fn from(block: Block) -> Self {
+                Self::new(block)
+            }
+        }
+
+        #(#enums)*
+    };
+
+    Ok(block_state.to_string())
+}
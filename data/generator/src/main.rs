@@ -5,10 +5,14 @@ use std::{
 };
 
 use block::generate_block;
+use block_state::generate_block_state;
 use item::generate_item;
+use recipe::generate_recipe;
 
 mod block;
+mod block_state;
 mod item;
+mod recipe;
 
 fn main() -> Result<()> {
     let version = "1.15.2";
@@ -21,6 +25,14 @@ fn main() -> Result<()> {
     let mut block_rs_file = File::create("../src/block.rs")?;
     block_rs_file.write_all(block_rs.as_bytes())?;
 
+    let block_state_rs = generate_block_state(version)?;
+    let mut block_state_rs_file = File::create("../src/block_state.rs")?;
+    block_state_rs_file.write_all(block_state_rs.as_bytes())?;
+
+    let recipe_rs = generate_recipe(version)?;
+    let mut recipe_rs_file = File::create("../src/recipe.rs")?;
+    recipe_rs_file.write_all(recipe_rs.as_bytes())?;
+
     Command::new("cargo")
         .current_dir("../")
         .args(&["fmt"])
@@ -0,0 +1,177 @@
+use std::{collections::HashMap, fs, io::Result};
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ItemData {
+    id: u64,
+    name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum IngredientData {
+    Item(u64),
+    Items(Vec<u64>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ResultData {
+    id: u64,
+    count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecipeData {
+    in_shape: Option<Vec<Vec<Option<IngredientData>>>>,
+    ingredients: Option<Vec<Option<IngredientData>>>,
+    result: ResultData,
+}
+
+// minecraft-data has no furnace recipes, these are kept next to the generator
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmeltingData {
+    ingredient: Vec<String>,
+    result: String,
+    experience: f32,
+    cookingtime: u64,
+}
+
+pub fn generate_recipe(version: &str) -> Result<String> {
+    let items_json = fs::read_to_string(format!("minecraft-data/data/pc/{}/items.json", version))?;
+    let items: Vec<ItemData> = serde_json::from_str(&items_json)?;
+    let names = items
+        .into_iter()
+        .map(|item| (item.id, item.name))
+        .collect::<HashMap<_, _>>();
+
+    let recipes_json =
+        fs::read_to_string(format!("minecraft-data/data/pc/{}/recipes.json", version))?;
+    let recipes: HashMap<String, Vec<RecipeData>> = serde_json::from_str(&recipes_json)?;
+    let mut recipes = recipes
+        .into_iter()
+        .map(|(id, recipes)| (id.parse::<u64>().unwrap(), recipes))
+        .collect::<Vec<_>>();
+    recipes.sort_by_key(|(id, _)| *id);
+
+    let smelting_json = fs::read_to_string("smelting.json")?;
+    let smelting: Vec<SmeltingData> = serde_json::from_str(&smelting_json)?;
+
+    let item = |id: u64| {
+        let enum_name = format_ident!("{}", names[&id].to_case(Case::Pascal));
+        quote! { Item::#enum_name }
+    };
+    let ingredient = |data: &Option<IngredientData>| {
+        let ids = match data {
+            Some(IngredientData::Item(id)) => vec![*id],
+            Some(IngredientData::Items(ids)) => ids.clone(),
+            None => vec![],
+        };
+        let items = ids.into_iter().map(item);
+        quote! { &[#(#items),*] }
+    };
+
+    let mut entries: Vec<TokenStream> = vec![];
+    let mut used_names = HashMap::new();
+    let mut recipe_name = |result: &str, suffix: &str| {
+        let base = format!("{}{}", result, suffix);
+        let count = used_names.entry(base.clone()).or_insert(0);
+        *count += 1;
+        if *count == 1 {
+            format!("minecraft:{}", base)
+        } else {
+            format!("minecraft:{}_{}", base, count)
+        }
+    };
+
+    for (id, variants) in recipes.iter() {
+        for recipe in variants {
+            let name = Literal::string(&recipe_name(&names[id], ""));
+            let result = item(recipe.result.id);
+            let count = Literal::u64_unsuffixed(recipe.result.count);
+            let (kind, ingredients) = match (&recipe.in_shape, &recipe.ingredients) {
+                (Some(shape), _) => {
+                    let width = Literal::u64_unsuffixed(
+                        shape.iter().map(|row| row.len()).max().unwrap_or(0) as u64,
+                    );
+                    let height = Literal::u64_unsuffixed(shape.len() as u64);
+                    let ingredients = shape.iter().flatten().map(ingredient).collect::<Vec<_>>();
+                    (
+                        quote! { RecipeKind::Shaped { width: #width, height: #height } },
+                        ingredients,
+                    )
+                }
+                (None, Some(ingredients)) => (
+                    quote! { RecipeKind::Shapeless },
+                    ingredients.iter().map(ingredient).collect(),
+                ),
+                (None, None) => continue,
+            };
+            entries.push(quote! {
+                Recipe {
+                    name: #name,
+                    kind: #kind,
+                    ingredients: &[#(#ingredients),*],
+                    result: #result,
+                    count: #count,
+                }
+            });
+        }
+    }
+
+    for recipe in smelting.iter() {
+        let name = Literal::string(&recipe_name(&recipe.result, "_from_smelting"));
+        let result_name = format_ident!("{}", recipe.result.to_case(Case::Pascal));
+        let ingredient = recipe
+            .ingredient
+            .iter()
+            .map(|name| format_ident!("{}", name.to_case(Case::Pascal)));
+        let experience = Literal::f32_unsuffixed(recipe.experience);
+        let cooking_time = Literal::u64_unsuffixed(recipe.cookingtime);
+        entries.push(quote! {
+            Recipe {
+                name: #name,
+                kind: RecipeKind::Smelting { experience: #experience, cooking_time: #cooking_time },
+                ingredients: &[&[#(Item::#ingredient),*]],
+                result: Item::#result_name,
+                count: 1,
+            }
+        });
+    }
+    println!("recipe sizes: {}", entries.len());
+
+    let recipe_types = quote! {
+        use crate::item::Item;
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum RecipeKind {
+            Shaped { width: u8, height: u8 },
+            Shapeless,
+            Smelting { experience: f32, cooking_time: u32 },
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Recipe {
+            pub name: &'static str,
+            pub kind: RecipeKind,
+            pub ingredients: &'static [&'static [Item]],
+            pub result: Item,
+            pub count: u8,
+        }
+    };
+    let recipe_list = quote! {
+        pub const RECIPES: &[Recipe] = &[#(#entries,)*];
+    };
+
+    Ok(format!(
+        "{}\n\n{}",
+        recipe_types.to_string(),
+        recipe_list.to_string()
+    ))
+}
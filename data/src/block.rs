@@ -4789,4 +4789,9843 @@ impl Block {
             Block::HoneycombBlock => 11336,
         }
     }
+    pub fn min_state_id(&self) -> u32 {
+        match self {
+            Block::Air => 0,
+            Block::Stone => 1,
+            Block::Granite => 2,
+            Block::PolishedGranite => 3,
+            Block::Diorite => 4,
+            Block::PolishedDiorite => 5,
+            Block::Andesite => 6,
+            Block::PolishedAndesite => 7,
+            Block::GrassBlock => 8,
+            Block::Dirt => 10,
+            Block::CoarseDirt => 11,
+            Block::Podzol => 12,
+            Block::Cobblestone => 14,
+            Block::OakPlanks => 15,
+            Block::SprucePlanks => 16,
+            Block::BirchPlanks => 17,
+            Block::JunglePlanks => 18,
+            Block::AcaciaPlanks => 19,
+            Block::DarkOakPlanks => 20,
+            Block::OakSapling => 21,
+            Block::SpruceSapling => 23,
+            Block::BirchSapling => 25,
+            Block::JungleSapling => 27,
+            Block::AcaciaSapling => 29,
+            Block::DarkOakSapling => 31,
+            Block::Bedrock => 33,
+            Block::Water => 34,
+            Block::Lava => 50,
+            Block::Sand => 66,
+            Block::RedSand => 67,
+            Block::Gravel => 68,
+            Block::GoldOre => 69,
+            Block::IronOre => 70,
+            Block::CoalOre => 71,
+            Block::OakLog => 72,
+            Block::SpruceLog => 75,
+            Block::BirchLog => 78,
+            Block::JungleLog => 81,
+            Block::AcaciaLog => 84,
+            Block::DarkOakLog => 87,
+            Block::StrippedSpruceLog => 90,
+            Block::StrippedBirchLog => 93,
+            Block::StrippedJungleLog => 96,
+            Block::StrippedAcaciaLog => 99,
+            Block::StrippedDarkOakLog => 102,
+            Block::StrippedOakLog => 105,
+            Block::OakWood => 108,
+            Block::SpruceWood => 111,
+            Block::BirchWood => 114,
+            Block::JungleWood => 117,
+            Block::AcaciaWood => 120,
+            Block::DarkOakWood => 123,
+            Block::StrippedOakWood => 126,
+            Block::StrippedSpruceWood => 129,
+            Block::StrippedBirchWood => 132,
+            Block::StrippedJungleWood => 135,
+            Block::StrippedAcaciaWood => 138,
+            Block::StrippedDarkOakWood => 141,
+            Block::OakLeaves => 144,
+            Block::SpruceLeaves => 158,
+            Block::BirchLeaves => 172,
+            Block::JungleLeaves => 186,
+            Block::AcaciaLeaves => 200,
+            Block::DarkOakLeaves => 214,
+            Block::Sponge => 228,
+            Block::WetSponge => 229,
+            Block::Glass => 230,
+            Block::LapisOre => 231,
+            Block::LapisBlock => 232,
+            Block::Dispenser => 233,
+            Block::Sandstone => 245,
+            Block::ChiseledSandstone => 246,
+            Block::CutSandstone => 247,
+            Block::NoteBlock => 248,
+            Block::WhiteBed => 1048,
+            Block::OrangeBed => 1064,
+            Block::MagentaBed => 1080,
+            Block::LightBlueBed => 1096,
+            Block::YellowBed => 1112,
+            Block::LimeBed => 1128,
+            Block::PinkBed => 1144,
+            Block::GrayBed => 1160,
+            Block::LightGrayBed => 1176,
+            Block::CyanBed => 1192,
+            Block::PurpleBed => 1208,
+            Block::BlueBed => 1224,
+            Block::BrownBed => 1240,
+            Block::GreenBed => 1256,
+            Block::RedBed => 1272,
+            Block::BlackBed => 1288,
+            Block::PoweredRail => 1304,
+            Block::DetectorRail => 1316,
+            Block::StickyPiston => 1328,
+            Block::Cobweb => 1340,
+            Block::Grass => 1341,
+            Block::Fern => 1342,
+            Block::DeadBush => 1343,
+            Block::Seagrass => 1344,
+            Block::TallSeagrass => 1345,
+            Block::Piston => 1347,
+            Block::PistonHead => 1359,
+            Block::WhiteWool => 1383,
+            Block::OrangeWool => 1384,
+            Block::MagentaWool => 1385,
+            Block::LightBlueWool => 1386,
+            Block::YellowWool => 1387,
+            Block::LimeWool => 1388,
+            Block::PinkWool => 1389,
+            Block::GrayWool => 1390,
+            Block::LightGrayWool => 1391,
+            Block::CyanWool => 1392,
+            Block::PurpleWool => 1393,
+            Block::BlueWool => 1394,
+            Block::BrownWool => 1395,
+            Block::GreenWool => 1396,
+            Block::RedWool => 1397,
+            Block::BlackWool => 1398,
+            Block::MovingPiston => 1399,
+            Block::Dandelion => 1411,
+            Block::Poppy => 1412,
+            Block::BlueOrchid => 1413,
+            Block::Allium => 1414,
+            Block::AzureBluet => 1415,
+            Block::RedTulip => 1416,
+            Block::OrangeTulip => 1417,
+            Block::WhiteTulip => 1418,
+            Block::PinkTulip => 1419,
+            Block::OxeyeDaisy => 1420,
+            Block::Cornflower => 1421,
+            Block::WitherRose => 1422,
+            Block::LilyOfTheValley => 1423,
+            Block::BrownMushroom => 1424,
+            Block::RedMushroom => 1425,
+            Block::GoldBlock => 1426,
+            Block::IronBlock => 1427,
+            Block::Bricks => 1428,
+            Block::Tnt => 1429,
+            Block::Bookshelf => 1431,
+            Block::MossyCobblestone => 1432,
+            Block::Obsidian => 1433,
+            Block::Torch => 1434,
+            Block::WallTorch => 1435,
+            Block::Fire => 1439,
+            Block::Spawner => 1951,
+            Block::OakStairs => 1952,
+            Block::Chest => 2032,
+            Block::RedstoneWire => 2056,
+            Block::DiamondOre => 3352,
+            Block::DiamondBlock => 3353,
+            Block::CraftingTable => 3354,
+            Block::Wheat => 3355,
+            Block::Farmland => 3363,
+            Block::Furnace => 3371,
+            Block::OakSign => 3379,
+            Block::SpruceSign => 3411,
+            Block::BirchSign => 3443,
+            Block::AcaciaSign => 3475,
+            Block::JungleSign => 3507,
+            Block::DarkOakSign => 3539,
+            Block::OakDoor => 3571,
+            Block::Ladder => 3635,
+            Block::Rail => 3643,
+            Block::CobblestoneStairs => 3653,
+            Block::OakWallSign => 3733,
+            Block::SpruceWallSign => 3741,
+            Block::BirchWallSign => 3749,
+            Block::AcaciaWallSign => 3757,
+            Block::JungleWallSign => 3765,
+            Block::DarkOakWallSign => 3773,
+            Block::Lever => 3781,
+            Block::StonePressurePlate => 3805,
+            Block::IronDoor => 3807,
+            Block::OakPressurePlate => 3871,
+            Block::SprucePressurePlate => 3873,
+            Block::BirchPressurePlate => 3875,
+            Block::JunglePressurePlate => 3877,
+            Block::AcaciaPressurePlate => 3879,
+            Block::DarkOakPressurePlate => 3881,
+            Block::RedstoneOre => 3883,
+            Block::RedstoneTorch => 3885,
+            Block::RedstoneWallTorch => 3887,
+            Block::StoneButton => 3895,
+            Block::Snow => 3919,
+            Block::Ice => 3927,
+            Block::SnowBlock => 3928,
+            Block::Cactus => 3929,
+            Block::Clay => 3945,
+            Block::SugarCane => 3946,
+            Block::Jukebox => 3962,
+            Block::OakFence => 3964,
+            Block::Pumpkin => 3996,
+            Block::Netherrack => 3997,
+            Block::SoulSand => 3998,
+            Block::Glowstone => 3999,
+            Block::NetherPortal => 4000,
+            Block::CarvedPumpkin => 4002,
+            Block::JackOLantern => 4006,
+            Block::Cake => 4010,
+            Block::Repeater => 4017,
+            Block::WhiteStainedGlass => 4081,
+            Block::OrangeStainedGlass => 4082,
+            Block::MagentaStainedGlass => 4083,
+            Block::LightBlueStainedGlass => 4084,
+            Block::YellowStainedGlass => 4085,
+            Block::LimeStainedGlass => 4086,
+            Block::PinkStainedGlass => 4087,
+            Block::GrayStainedGlass => 4088,
+            Block::LightGrayStainedGlass => 4089,
+            Block::CyanStainedGlass => 4090,
+            Block::PurpleStainedGlass => 4091,
+            Block::BlueStainedGlass => 4092,
+            Block::BrownStainedGlass => 4093,
+            Block::GreenStainedGlass => 4094,
+            Block::RedStainedGlass => 4095,
+            Block::BlackStainedGlass => 4096,
+            Block::OakTrapdoor => 4097,
+            Block::SpruceTrapdoor => 4161,
+            Block::BirchTrapdoor => 4225,
+            Block::JungleTrapdoor => 4289,
+            Block::AcaciaTrapdoor => 4353,
+            Block::DarkOakTrapdoor => 4417,
+            Block::StoneBricks => 4481,
+            Block::MossyStoneBricks => 4482,
+            Block::CrackedStoneBricks => 4483,
+            Block::ChiseledStoneBricks => 4484,
+            Block::InfestedStone => 4485,
+            Block::InfestedCobblestone => 4486,
+            Block::InfestedStoneBricks => 4487,
+            Block::InfestedMossyStoneBricks => 4488,
+            Block::InfestedCrackedStoneBricks => 4489,
+            Block::InfestedChiseledStoneBricks => 4490,
+            Block::BrownMushroomBlock => 4491,
+            Block::RedMushroomBlock => 4555,
+            Block::MushroomStem => 4619,
+            Block::IronBars => 4683,
+            Block::GlassPane => 4715,
+            Block::Melon => 4747,
+            Block::AttachedPumpkinStem => 4748,
+            Block::AttachedMelonStem => 4752,
+            Block::PumpkinStem => 4756,
+            Block::MelonStem => 4764,
+            Block::Vine => 4772,
+            Block::OakFenceGate => 4804,
+            Block::BrickStairs => 4836,
+            Block::StoneBrickStairs => 4916,
+            Block::Mycelium => 4996,
+            Block::LilyPad => 4998,
+            Block::NetherBricks => 4999,
+            Block::NetherBrickFence => 5000,
+            Block::NetherBrickStairs => 5032,
+            Block::NetherWart => 5112,
+            Block::EnchantingTable => 5116,
+            Block::BrewingStand => 5117,
+            Block::Cauldron => 5125,
+            Block::EndPortal => 5129,
+            Block::EndPortalFrame => 5130,
+            Block::EndStone => 5138,
+            Block::DragonEgg => 5139,
+            Block::RedstoneLamp => 5140,
+            Block::Cocoa => 5142,
+            Block::SandstoneStairs => 5154,
+            Block::EmeraldOre => 5234,
+            Block::EnderChest => 5235,
+            Block::TripwireHook => 5243,
+            Block::Tripwire => 5259,
+            Block::EmeraldBlock => 5387,
+            Block::SpruceStairs => 5388,
+            Block::BirchStairs => 5468,
+            Block::JungleStairs => 5548,
+            Block::CommandBlock => 5628,
+            Block::Beacon => 5640,
+            Block::CobblestoneWall => 5641,
+            Block::MossyCobblestoneWall => 5705,
+            Block::FlowerPot => 5769,
+            Block::PottedOakSapling => 5770,
+            Block::PottedSpruceSapling => 5771,
+            Block::PottedBirchSapling => 5772,
+            Block::PottedJungleSapling => 5773,
+            Block::PottedAcaciaSapling => 5774,
+            Block::PottedDarkOakSapling => 5775,
+            Block::PottedFern => 5776,
+            Block::PottedDandelion => 5777,
+            Block::PottedPoppy => 5778,
+            Block::PottedBlueOrchid => 5779,
+            Block::PottedAllium => 5780,
+            Block::PottedAzureBluet => 5781,
+            Block::PottedRedTulip => 5782,
+            Block::PottedOrangeTulip => 5783,
+            Block::PottedWhiteTulip => 5784,
+            Block::PottedPinkTulip => 5785,
+            Block::PottedOxeyeDaisy => 5786,
+            Block::PottedCornflower => 5787,
+            Block::PottedLilyOfTheValley => 5788,
+            Block::PottedWitherRose => 5789,
+            Block::PottedRedMushroom => 5790,
+            Block::PottedBrownMushroom => 5791,
+            Block::PottedDeadBush => 5792,
+            Block::PottedCactus => 5793,
+            Block::Carrots => 5794,
+            Block::Potatoes => 5802,
+            Block::OakButton => 5810,
+            Block::SpruceButton => 5834,
+            Block::BirchButton => 5858,
+            Block::JungleButton => 5882,
+            Block::AcaciaButton => 5906,
+            Block::DarkOakButton => 5930,
+            Block::SkeletonSkull => 5954,
+            Block::SkeletonWallSkull => 5970,
+            Block::WitherSkeletonSkull => 5974,
+            Block::WitherSkeletonWallSkull => 5990,
+            Block::ZombieHead => 5994,
+            Block::ZombieWallHead => 6010,
+            Block::PlayerHead => 6014,
+            Block::PlayerWallHead => 6030,
+            Block::CreeperHead => 6034,
+            Block::CreeperWallHead => 6050,
+            Block::DragonHead => 6054,
+            Block::DragonWallHead => 6070,
+            Block::Anvil => 6074,
+            Block::ChippedAnvil => 6078,
+            Block::DamagedAnvil => 6082,
+            Block::TrappedChest => 6086,
+            Block::LightWeightedPressurePlate => 6110,
+            Block::HeavyWeightedPressurePlate => 6126,
+            Block::Comparator => 6142,
+            Block::DaylightDetector => 6158,
+            Block::RedstoneBlock => 6190,
+            Block::NetherQuartzOre => 6191,
+            Block::Hopper => 6192,
+            Block::QuartzBlock => 6202,
+            Block::ChiseledQuartzBlock => 6203,
+            Block::QuartzPillar => 6204,
+            Block::QuartzStairs => 6207,
+            Block::ActivatorRail => 6287,
+            Block::Dropper => 6299,
+            Block::WhiteTerracotta => 6311,
+            Block::OrangeTerracotta => 6312,
+            Block::MagentaTerracotta => 6313,
+            Block::LightBlueTerracotta => 6314,
+            Block::YellowTerracotta => 6315,
+            Block::LimeTerracotta => 6316,
+            Block::PinkTerracotta => 6317,
+            Block::GrayTerracotta => 6318,
+            Block::LightGrayTerracotta => 6319,
+            Block::CyanTerracotta => 6320,
+            Block::PurpleTerracotta => 6321,
+            Block::BlueTerracotta => 6322,
+            Block::BrownTerracotta => 6323,
+            Block::GreenTerracotta => 6324,
+            Block::RedTerracotta => 6325,
+            Block::BlackTerracotta => 6326,
+            Block::WhiteStainedGlassPane => 6327,
+            Block::OrangeStainedGlassPane => 6359,
+            Block::MagentaStainedGlassPane => 6391,
+            Block::LightBlueStainedGlassPane => 6423,
+            Block::YellowStainedGlassPane => 6455,
+            Block::LimeStainedGlassPane => 6487,
+            Block::PinkStainedGlassPane => 6519,
+            Block::GrayStainedGlassPane => 6551,
+            Block::LightGrayStainedGlassPane => 6583,
+            Block::CyanStainedGlassPane => 6615,
+            Block::PurpleStainedGlassPane => 6647,
+            Block::BlueStainedGlassPane => 6679,
+            Block::BrownStainedGlassPane => 6711,
+            Block::GreenStainedGlassPane => 6743,
+            Block::RedStainedGlassPane => 6775,
+            Block::BlackStainedGlassPane => 6807,
+            Block::AcaciaStairs => 6839,
+            Block::DarkOakStairs => 6919,
+            Block::SlimeBlock => 6999,
+            Block::Barrier => 7000,
+            Block::IronTrapdoor => 7001,
+            Block::Prismarine => 7065,
+            Block::PrismarineBricks => 7066,
+            Block::DarkPrismarine => 7067,
+            Block::PrismarineStairs => 7068,
+            Block::PrismarineBrickStairs => 7148,
+            Block::DarkPrismarineStairs => 7228,
+            Block::PrismarineSlab => 7308,
+            Block::PrismarineBrickSlab => 7314,
+            Block::DarkPrismarineSlab => 7320,
+            Block::SeaLantern => 7326,
+            Block::HayBlock => 7327,
+            Block::WhiteCarpet => 7330,
+            Block::OrangeCarpet => 7331,
+            Block::MagentaCarpet => 7332,
+            Block::LightBlueCarpet => 7333,
+            Block::YellowCarpet => 7334,
+            Block::LimeCarpet => 7335,
+            Block::PinkCarpet => 7336,
+            Block::GrayCarpet => 7337,
+            Block::LightGrayCarpet => 7338,
+            Block::CyanCarpet => 7339,
+            Block::PurpleCarpet => 7340,
+            Block::BlueCarpet => 7341,
+            Block::BrownCarpet => 7342,
+            Block::GreenCarpet => 7343,
+            Block::RedCarpet => 7344,
+            Block::BlackCarpet => 7345,
+            Block::Terracotta => 7346,
+            Block::CoalBlock => 7347,
+            Block::PackedIce => 7348,
+            Block::Sunflower => 7349,
+            Block::Lilac => 7351,
+            Block::RoseBush => 7353,
+            Block::Peony => 7355,
+            Block::TallGrass => 7357,
+            Block::LargeFern => 7359,
+            Block::WhiteBanner => 7361,
+            Block::OrangeBanner => 7377,
+            Block::MagentaBanner => 7393,
+            Block::LightBlueBanner => 7409,
+            Block::YellowBanner => 7425,
+            Block::LimeBanner => 7441,
+            Block::PinkBanner => 7457,
+            Block::GrayBanner => 7473,
+            Block::LightGrayBanner => 7489,
+            Block::CyanBanner => 7505,
+            Block::PurpleBanner => 7521,
+            Block::BlueBanner => 7537,
+            Block::BrownBanner => 7553,
+            Block::GreenBanner => 7569,
+            Block::RedBanner => 7585,
+            Block::BlackBanner => 7601,
+            Block::WhiteWallBanner => 7617,
+            Block::OrangeWallBanner => 7621,
+            Block::MagentaWallBanner => 7625,
+            Block::LightBlueWallBanner => 7629,
+            Block::YellowWallBanner => 7633,
+            Block::LimeWallBanner => 7637,
+            Block::PinkWallBanner => 7641,
+            Block::GrayWallBanner => 7645,
+            Block::LightGrayWallBanner => 7649,
+            Block::CyanWallBanner => 7653,
+            Block::PurpleWallBanner => 7657,
+            Block::BlueWallBanner => 7661,
+            Block::BrownWallBanner => 7665,
+            Block::GreenWallBanner => 7669,
+            Block::RedWallBanner => 7673,
+            Block::BlackWallBanner => 7677,
+            Block::RedSandstone => 7681,
+            Block::ChiseledRedSandstone => 7682,
+            Block::CutRedSandstone => 7683,
+            Block::RedSandstoneStairs => 7684,
+            Block::OakSlab => 7764,
+            Block::SpruceSlab => 7770,
+            Block::BirchSlab => 7776,
+            Block::JungleSlab => 7782,
+            Block::AcaciaSlab => 7788,
+            Block::DarkOakSlab => 7794,
+            Block::StoneSlab => 7800,
+            Block::SmoothStoneSlab => 7806,
+            Block::SandstoneSlab => 7812,
+            Block::CutSandstoneSlab => 7818,
+            Block::PetrifiedOakSlab => 7824,
+            Block::CobblestoneSlab => 7830,
+            Block::BrickSlab => 7836,
+            Block::StoneBrickSlab => 7842,
+            Block::NetherBrickSlab => 7848,
+            Block::QuartzSlab => 7854,
+            Block::RedSandstoneSlab => 7860,
+            Block::CutRedSandstoneSlab => 7866,
+            Block::PurpurSlab => 7872,
+            Block::SmoothStone => 7878,
+            Block::SmoothSandstone => 7879,
+            Block::SmoothQuartz => 7880,
+            Block::SmoothRedSandstone => 7881,
+            Block::SpruceFenceGate => 7882,
+            Block::BirchFenceGate => 7914,
+            Block::JungleFenceGate => 7946,
+            Block::AcaciaFenceGate => 7978,
+            Block::DarkOakFenceGate => 8010,
+            Block::SpruceFence => 8042,
+            Block::BirchFence => 8074,
+            Block::JungleFence => 8106,
+            Block::AcaciaFence => 8138,
+            Block::DarkOakFence => 8170,
+            Block::SpruceDoor => 8202,
+            Block::BirchDoor => 8266,
+            Block::JungleDoor => 8330,
+            Block::AcaciaDoor => 8394,
+            Block::DarkOakDoor => 8458,
+            Block::EndRod => 8522,
+            Block::ChorusPlant => 8528,
+            Block::ChorusFlower => 8592,
+            Block::PurpurBlock => 8598,
+            Block::PurpurPillar => 8599,
+            Block::PurpurStairs => 8602,
+            Block::EndStoneBricks => 8682,
+            Block::Beetroots => 8683,
+            Block::GrassPath => 8687,
+            Block::EndGateway => 8688,
+            Block::RepeatingCommandBlock => 8689,
+            Block::ChainCommandBlock => 8701,
+            Block::FrostedIce => 8713,
+            Block::MagmaBlock => 8717,
+            Block::NetherWartBlock => 8718,
+            Block::RedNetherBricks => 8719,
+            Block::BoneBlock => 8720,
+            Block::StructureVoid => 8723,
+            Block::Observer => 8724,
+            Block::ShulkerBox => 8736,
+            Block::WhiteShulkerBox => 8742,
+            Block::OrangeShulkerBox => 8748,
+            Block::MagentaShulkerBox => 8754,
+            Block::LightBlueShulkerBox => 8760,
+            Block::YellowShulkerBox => 8766,
+            Block::LimeShulkerBox => 8772,
+            Block::PinkShulkerBox => 8778,
+            Block::GrayShulkerBox => 8784,
+            Block::LightGrayShulkerBox => 8790,
+            Block::CyanShulkerBox => 8796,
+            Block::PurpleShulkerBox => 8802,
+            Block::BlueShulkerBox => 8808,
+            Block::BrownShulkerBox => 8814,
+            Block::GreenShulkerBox => 8820,
+            Block::RedShulkerBox => 8826,
+            Block::BlackShulkerBox => 8832,
+            Block::WhiteGlazedTerracotta => 8838,
+            Block::OrangeGlazedTerracotta => 8842,
+            Block::MagentaGlazedTerracotta => 8846,
+            Block::LightBlueGlazedTerracotta => 8850,
+            Block::YellowGlazedTerracotta => 8854,
+            Block::LimeGlazedTerracotta => 8858,
+            Block::PinkGlazedTerracotta => 8862,
+            Block::GrayGlazedTerracotta => 8866,
+            Block::LightGrayGlazedTerracotta => 8870,
+            Block::CyanGlazedTerracotta => 8874,
+            Block::PurpleGlazedTerracotta => 8878,
+            Block::BlueGlazedTerracotta => 8882,
+            Block::BrownGlazedTerracotta => 8886,
+            Block::GreenGlazedTerracotta => 8890,
+            Block::RedGlazedTerracotta => 8894,
+            Block::BlackGlazedTerracotta => 8898,
+            Block::WhiteConcrete => 8902,
+            Block::OrangeConcrete => 8903,
+            Block::MagentaConcrete => 8904,
+            Block::LightBlueConcrete => 8905,
+            Block::YellowConcrete => 8906,
+            Block::LimeConcrete => 8907,
+            Block::PinkConcrete => 8908,
+            Block::GrayConcrete => 8909,
+            Block::LightGrayConcrete => 8910,
+            Block::CyanConcrete => 8911,
+            Block::PurpleConcrete => 8912,
+            Block::BlueConcrete => 8913,
+            Block::BrownConcrete => 8914,
+            Block::GreenConcrete => 8915,
+            Block::RedConcrete => 8916,
+            Block::BlackConcrete => 8917,
+            Block::WhiteConcretePowder => 8918,
+            Block::OrangeConcretePowder => 8919,
+            Block::MagentaConcretePowder => 8920,
+            Block::LightBlueConcretePowder => 8921,
+            Block::YellowConcretePowder => 8922,
+            Block::LimeConcretePowder => 8923,
+            Block::PinkConcretePowder => 8924,
+            Block::GrayConcretePowder => 8925,
+            Block::LightGrayConcretePowder => 8926,
+            Block::CyanConcretePowder => 8927,
+            Block::PurpleConcretePowder => 8928,
+            Block::BlueConcretePowder => 8929,
+            Block::BrownConcretePowder => 8930,
+            Block::GreenConcretePowder => 8931,
+            Block::RedConcretePowder => 8932,
+            Block::BlackConcretePowder => 8933,
+            Block::Kelp => 8934,
+            Block::KelpPlant => 8960,
+            Block::DriedKelpBlock => 8961,
+            Block::TurtleEgg => 8962,
+            Block::DeadTubeCoralBlock => 8974,
+            Block::DeadBrainCoralBlock => 8975,
+            Block::DeadBubbleCoralBlock => 8976,
+            Block::DeadFireCoralBlock => 8977,
+            Block::DeadHornCoralBlock => 8978,
+            Block::TubeCoralBlock => 8979,
+            Block::BrainCoralBlock => 8980,
+            Block::BubbleCoralBlock => 8981,
+            Block::FireCoralBlock => 8982,
+            Block::HornCoralBlock => 8983,
+            Block::DeadTubeCoral => 8984,
+            Block::DeadBrainCoral => 8986,
+            Block::DeadBubbleCoral => 8988,
+            Block::DeadFireCoral => 8990,
+            Block::DeadHornCoral => 8992,
+            Block::TubeCoral => 8994,
+            Block::BrainCoral => 8996,
+            Block::BubbleCoral => 8998,
+            Block::FireCoral => 9000,
+            Block::HornCoral => 9002,
+            Block::DeadTubeCoralFan => 9004,
+            Block::DeadBrainCoralFan => 9006,
+            Block::DeadBubbleCoralFan => 9008,
+            Block::DeadFireCoralFan => 9010,
+            Block::DeadHornCoralFan => 9012,
+            Block::TubeCoralFan => 9014,
+            Block::BrainCoralFan => 9016,
+            Block::BubbleCoralFan => 9018,
+            Block::FireCoralFan => 9020,
+            Block::HornCoralFan => 9022,
+            Block::DeadTubeCoralWallFan => 9024,
+            Block::DeadBrainCoralWallFan => 9032,
+            Block::DeadBubbleCoralWallFan => 9040,
+            Block::DeadFireCoralWallFan => 9048,
+            Block::DeadHornCoralWallFan => 9056,
+            Block::TubeCoralWallFan => 9064,
+            Block::BrainCoralWallFan => 9072,
+            Block::BubbleCoralWallFan => 9080,
+            Block::FireCoralWallFan => 9088,
+            Block::HornCoralWallFan => 9096,
+            Block::SeaPickle => 9104,
+            Block::BlueIce => 9112,
+            Block::Conduit => 9113,
+            Block::BambooSapling => 9115,
+            Block::Bamboo => 9116,
+            Block::PottedBamboo => 9128,
+            Block::VoidAir => 9129,
+            Block::CaveAir => 9130,
+            Block::BubbleColumn => 9131,
+            Block::PolishedGraniteStairs => 9133,
+            Block::SmoothRedSandstoneStairs => 9213,
+            Block::MossyStoneBrickStairs => 9293,
+            Block::PolishedDioriteStairs => 9373,
+            Block::MossyCobblestoneStairs => 9453,
+            Block::EndStoneBrickStairs => 9533,
+            Block::StoneStairs => 9613,
+            Block::SmoothSandstoneStairs => 9693,
+            Block::SmoothQuartzStairs => 9773,
+            Block::GraniteStairs => 9853,
+            Block::AndesiteStairs => 9933,
+            Block::RedNetherBrickStairs => 10013,
+            Block::PolishedAndesiteStairs => 10093,
+            Block::DioriteStairs => 10173,
+            Block::PolishedGraniteSlab => 10253,
+            Block::SmoothRedSandstoneSlab => 10259,
+            Block::MossyStoneBrickSlab => 10265,
+            Block::PolishedDioriteSlab => 10271,
+            Block::MossyCobblestoneSlab => 10277,
+            Block::EndStoneBrickSlab => 10283,
+            Block::SmoothSandstoneSlab => 10289,
+            Block::SmoothQuartzSlab => 10295,
+            Block::GraniteSlab => 10301,
+            Block::AndesiteSlab => 10307,
+            Block::RedNetherBrickSlab => 10313,
+            Block::PolishedAndesiteSlab => 10319,
+            Block::DioriteSlab => 10325,
+            Block::BrickWall => 10331,
+            Block::PrismarineWall => 10395,
+            Block::RedSandstoneWall => 10459,
+            Block::MossyStoneBrickWall => 10523,
+            Block::GraniteWall => 10587,
+            Block::StoneBrickWall => 10651,
+            Block::NetherBrickWall => 10715,
+            Block::AndesiteWall => 10779,
+            Block::RedNetherBrickWall => 10843,
+            Block::SandstoneWall => 10907,
+            Block::EndStoneBrickWall => 10971,
+            Block::DioriteWall => 11035,
+            Block::Scaffolding => 11099,
+            Block::Loom => 11131,
+            Block::Barrel => 11135,
+            Block::Smoker => 11147,
+            Block::BlastFurnace => 11155,
+            Block::CartographyTable => 11163,
+            Block::FletchingTable => 11164,
+            Block::Grindstone => 11165,
+            Block::Lectern => 11177,
+            Block::SmithingTable => 11193,
+            Block::Stonecutter => 11194,
+            Block::Bell => 11198,
+            Block::Lantern => 11230,
+            Block::Campfire => 11232,
+            Block::SweetBerryBush => 11264,
+            Block::StructureBlock => 11268,
+            Block::Jigsaw => 11272,
+            Block::Composter => 11278,
+            Block::BeeNest => 11287,
+            Block::Beehive => 11311,
+            Block::HoneyBlock => 11335,
+            Block::HoneycombBlock => 11336,
+        }
+    }
+    pub fn max_state_id(&self) -> u32 {
+        match self {
+            Block::Air => 0,
+            Block::Stone => 1,
+            Block::Granite => 2,
+            Block::PolishedGranite => 3,
+            Block::Diorite => 4,
+            Block::PolishedDiorite => 5,
+            Block::Andesite => 6,
+            Block::PolishedAndesite => 7,
+            Block::GrassBlock => 9,
+            Block::Dirt => 10,
+            Block::CoarseDirt => 11,
+            Block::Podzol => 13,
+            Block::Cobblestone => 14,
+            Block::OakPlanks => 15,
+            Block::SprucePlanks => 16,
+            Block::BirchPlanks => 17,
+            Block::JunglePlanks => 18,
+            Block::AcaciaPlanks => 19,
+            Block::DarkOakPlanks => 20,
+            Block::OakSapling => 22,
+            Block::SpruceSapling => 24,
+            Block::BirchSapling => 26,
+            Block::JungleSapling => 28,
+            Block::AcaciaSapling => 30,
+            Block::DarkOakSapling => 32,
+            Block::Bedrock => 33,
+            Block::Water => 49,
+            Block::Lava => 65,
+            Block::Sand => 66,
+            Block::RedSand => 67,
+            Block::Gravel => 68,
+            Block::GoldOre => 69,
+            Block::IronOre => 70,
+            Block::CoalOre => 71,
+            Block::OakLog => 74,
+            Block::SpruceLog => 77,
+            Block::BirchLog => 80,
+            Block::JungleLog => 83,
+            Block::AcaciaLog => 86,
+            Block::DarkOakLog => 89,
+            Block::StrippedSpruceLog => 92,
+            Block::StrippedBirchLog => 95,
+            Block::StrippedJungleLog => 98,
+            Block::StrippedAcaciaLog => 101,
+            Block::StrippedDarkOakLog => 104,
+            Block::StrippedOakLog => 107,
+            Block::OakWood => 110,
+            Block::SpruceWood => 113,
+            Block::BirchWood => 116,
+            Block::JungleWood => 119,
+            Block::AcaciaWood => 122,
+            Block::DarkOakWood => 125,
+            Block::StrippedOakWood => 128,
+            Block::StrippedSpruceWood => 131,
+            Block::StrippedBirchWood => 134,
+            Block::StrippedJungleWood => 137,
+            Block::StrippedAcaciaWood => 140,
+            Block::StrippedDarkOakWood => 143,
+            Block::OakLeaves => 157,
+            Block::SpruceLeaves => 171,
+            Block::BirchLeaves => 185,
+            Block::JungleLeaves => 199,
+            Block::AcaciaLeaves => 213,
+            Block::DarkOakLeaves => 227,
+            Block::Sponge => 228,
+            Block::WetSponge => 229,
+            Block::Glass => 230,
+            Block::LapisOre => 231,
+            Block::LapisBlock => 232,
+            Block::Dispenser => 244,
+            Block::Sandstone => 245,
+            Block::ChiseledSandstone => 246,
+            Block::CutSandstone => 247,
+            Block::NoteBlock => 1047,
+            Block::WhiteBed => 1063,
+            Block::OrangeBed => 1079,
+            Block::MagentaBed => 1095,
+            Block::LightBlueBed => 1111,
+            Block::YellowBed => 1127,
+            Block::LimeBed => 1143,
+            Block::PinkBed => 1159,
+            Block::GrayBed => 1175,
+            Block::LightGrayBed => 1191,
+            Block::CyanBed => 1207,
+            Block::PurpleBed => 1223,
+            Block::BlueBed => 1239,
+            Block::BrownBed => 1255,
+            Block::GreenBed => 1271,
+            Block::RedBed => 1287,
+            Block::BlackBed => 1303,
+            Block::PoweredRail => 1315,
+            Block::DetectorRail => 1327,
+            Block::StickyPiston => 1339,
+            Block::Cobweb => 1340,
+            Block::Grass => 1341,
+            Block::Fern => 1342,
+            Block::DeadBush => 1343,
+            Block::Seagrass => 1344,
+            Block::TallSeagrass => 1346,
+            Block::Piston => 1358,
+            Block::PistonHead => 1382,
+            Block::WhiteWool => 1383,
+            Block::OrangeWool => 1384,
+            Block::MagentaWool => 1385,
+            Block::LightBlueWool => 1386,
+            Block::YellowWool => 1387,
+            Block::LimeWool => 1388,
+            Block::PinkWool => 1389,
+            Block::GrayWool => 1390,
+            Block::LightGrayWool => 1391,
+            Block::CyanWool => 1392,
+            Block::PurpleWool => 1393,
+            Block::BlueWool => 1394,
+            Block::BrownWool => 1395,
+            Block::GreenWool => 1396,
+            Block::RedWool => 1397,
+            Block::BlackWool => 1398,
+            Block::MovingPiston => 1410,
+            Block::Dandelion => 1411,
+            Block::Poppy => 1412,
+            Block::BlueOrchid => 1413,
+            Block::Allium => 1414,
+            Block::AzureBluet => 1415,
+            Block::RedTulip => 1416,
+            Block::OrangeTulip => 1417,
+            Block::WhiteTulip => 1418,
+            Block::PinkTulip => 1419,
+            Block::OxeyeDaisy => 1420,
+            Block::Cornflower => 1421,
+            Block::WitherRose => 1422,
+            Block::LilyOfTheValley => 1423,
+            Block::BrownMushroom => 1424,
+            Block::RedMushroom => 1425,
+            Block::GoldBlock => 1426,
+            Block::IronBlock => 1427,
+            Block::Bricks => 1428,
+            Block::Tnt => 1430,
+            Block::Bookshelf => 1431,
+            Block::MossyCobblestone => 1432,
+            Block::Obsidian => 1433,
+            Block::Torch => 1434,
+            Block::WallTorch => 1438,
+            Block::Fire => 1950,
+            Block::Spawner => 1951,
+            Block::OakStairs => 2031,
+            Block::Chest => 2055,
+            Block::RedstoneWire => 3351,
+            Block::DiamondOre => 3352,
+            Block::DiamondBlock => 3353,
+            Block::CraftingTable => 3354,
+            Block::Wheat => 3362,
+            Block::Farmland => 3370,
+            Block::Furnace => 3378,
+            Block::OakSign => 3410,
+            Block::SpruceSign => 3442,
+            Block::BirchSign => 3474,
+            Block::AcaciaSign => 3506,
+            Block::JungleSign => 3538,
+            Block::DarkOakSign => 3570,
+            Block::OakDoor => 3634,
+            Block::Ladder => 3642,
+            Block::Rail => 3652,
+            Block::CobblestoneStairs => 3732,
+            Block::OakWallSign => 3740,
+            Block::SpruceWallSign => 3748,
+            Block::BirchWallSign => 3756,
+            Block::AcaciaWallSign => 3764,
+            Block::JungleWallSign => 3772,
+            Block::DarkOakWallSign => 3780,
+            Block::Lever => 3804,
+            Block::StonePressurePlate => 3806,
+            Block::IronDoor => 3870,
+            Block::OakPressurePlate => 3872,
+            Block::SprucePressurePlate => 3874,
+            Block::BirchPressurePlate => 3876,
+            Block::JunglePressurePlate => 3878,
+            Block::AcaciaPressurePlate => 3880,
+            Block::DarkOakPressurePlate => 3882,
+            Block::RedstoneOre => 3884,
+            Block::RedstoneTorch => 3886,
+            Block::RedstoneWallTorch => 3894,
+            Block::StoneButton => 3918,
+            Block::Snow => 3926,
+            Block::Ice => 3927,
+            Block::SnowBlock => 3928,
+            Block::Cactus => 3944,
+            Block::Clay => 3945,
+            Block::SugarCane => 3961,
+            Block::Jukebox => 3963,
+            Block::OakFence => 3995,
+            Block::Pumpkin => 3996,
+            Block::Netherrack => 3997,
+            Block::SoulSand => 3998,
+            Block::Glowstone => 3999,
+            Block::NetherPortal => 4001,
+            Block::CarvedPumpkin => 4005,
+            Block::JackOLantern => 4009,
+            Block::Cake => 4016,
+            Block::Repeater => 4080,
+            Block::WhiteStainedGlass => 4081,
+            Block::OrangeStainedGlass => 4082,
+            Block::MagentaStainedGlass => 4083,
+            Block::LightBlueStainedGlass => 4084,
+            Block::YellowStainedGlass => 4085,
+            Block::LimeStainedGlass => 4086,
+            Block::PinkStainedGlass => 4087,
+            Block::GrayStainedGlass => 4088,
+            Block::LightGrayStainedGlass => 4089,
+            Block::CyanStainedGlass => 4090,
+            Block::PurpleStainedGlass => 4091,
+            Block::BlueStainedGlass => 4092,
+            Block::BrownStainedGlass => 4093,
+            Block::GreenStainedGlass => 4094,
+            Block::RedStainedGlass => 4095,
+            Block::BlackStainedGlass => 4096,
+            Block::OakTrapdoor => 4160,
+            Block::SpruceTrapdoor => 4224,
+            Block::BirchTrapdoor => 4288,
+            Block::JungleTrapdoor => 4352,
+            Block::AcaciaTrapdoor => 4416,
+            Block::DarkOakTrapdoor => 4480,
+            Block::StoneBricks => 4481,
+            Block::MossyStoneBricks => 4482,
+            Block::CrackedStoneBricks => 4483,
+            Block::ChiseledStoneBricks => 4484,
+            Block::InfestedStone => 4485,
+            Block::InfestedCobblestone => 4486,
+            Block::InfestedStoneBricks => 4487,
+            Block::InfestedMossyStoneBricks => 4488,
+            Block::InfestedCrackedStoneBricks => 4489,
+            Block::InfestedChiseledStoneBricks => 4490,
+            Block::BrownMushroomBlock => 4554,
+            Block::RedMushroomBlock => 4618,
+            Block::MushroomStem => 4682,
+            Block::IronBars => 4714,
+            Block::GlassPane => 4746,
+            Block::Melon => 4747,
+            Block::AttachedPumpkinStem => 4751,
+            Block::AttachedMelonStem => 4755,
+            Block::PumpkinStem => 4763,
+            Block::MelonStem => 4771,
+            Block::Vine => 4803,
+            Block::OakFenceGate => 4835,
+            Block::BrickStairs => 4915,
+            Block::StoneBrickStairs => 4995,
+            Block::Mycelium => 4997,
+            Block::LilyPad => 4998,
+            Block::NetherBricks => 4999,
+            Block::NetherBrickFence => 5031,
+            Block::NetherBrickStairs => 5111,
+            Block::NetherWart => 5115,
+            Block::EnchantingTable => 5116,
+            Block::BrewingStand => 5124,
+            Block::Cauldron => 5128,
+            Block::EndPortal => 5129,
+            Block::EndPortalFrame => 5137,
+            Block::EndStone => 5138,
+            Block::DragonEgg => 5139,
+            Block::RedstoneLamp => 5141,
+            Block::Cocoa => 5153,
+            Block::SandstoneStairs => 5233,
+            Block::EmeraldOre => 5234,
+            Block::EnderChest => 5242,
+            Block::TripwireHook => 5258,
+            Block::Tripwire => 5386,
+            Block::EmeraldBlock => 5387,
+            Block::SpruceStairs => 5467,
+            Block::BirchStairs => 5547,
+            Block::JungleStairs => 5627,
+            Block::CommandBlock => 5639,
+            Block::Beacon => 5640,
+            Block::CobblestoneWall => 5704,
+            Block::MossyCobblestoneWall => 5768,
+            Block::FlowerPot => 5769,
+            Block::PottedOakSapling => 5770,
+            Block::PottedSpruceSapling => 5771,
+            Block::PottedBirchSapling => 5772,
+            Block::PottedJungleSapling => 5773,
+            Block::PottedAcaciaSapling => 5774,
+            Block::PottedDarkOakSapling => 5775,
+            Block::PottedFern => 5776,
+            Block::PottedDandelion => 5777,
+            Block::PottedPoppy => 5778,
+            Block::PottedBlueOrchid => 5779,
+            Block::PottedAllium => 5780,
+            Block::PottedAzureBluet => 5781,
+            Block::PottedRedTulip => 5782,
+            Block::PottedOrangeTulip => 5783,
+            Block::PottedWhiteTulip => 5784,
+            Block::PottedPinkTulip => 5785,
+            Block::PottedOxeyeDaisy => 5786,
+            Block::PottedCornflower => 5787,
+            Block::PottedLilyOfTheValley => 5788,
+            Block::PottedWitherRose => 5789,
+            Block::PottedRedMushroom => 5790,
+            Block::PottedBrownMushroom => 5791,
+            Block::PottedDeadBush => 5792,
+            Block::PottedCactus => 5793,
+            Block::Carrots => 5801,
+            Block::Potatoes => 5809,
+            Block::OakButton => 5833,
+            Block::SpruceButton => 5857,
+            Block::BirchButton => 5881,
+            Block::JungleButton => 5905,
+            Block::AcaciaButton => 5929,
+            Block::DarkOakButton => 5953,
+            Block::SkeletonSkull => 5969,
+            Block::SkeletonWallSkull => 5973,
+            Block::WitherSkeletonSkull => 5989,
+            Block::WitherSkeletonWallSkull => 5993,
+            Block::ZombieHead => 6009,
+            Block::ZombieWallHead => 6013,
+            Block::PlayerHead => 6029,
+            Block::PlayerWallHead => 6033,
+            Block::CreeperHead => 6049,
+            Block::CreeperWallHead => 6053,
+            Block::DragonHead => 6069,
+            Block::DragonWallHead => 6073,
+            Block::Anvil => 6077,
+            Block::ChippedAnvil => 6081,
+            Block::DamagedAnvil => 6085,
+            Block::TrappedChest => 6109,
+            Block::LightWeightedPressurePlate => 6125,
+            Block::HeavyWeightedPressurePlate => 6141,
+            Block::Comparator => 6157,
+            Block::DaylightDetector => 6189,
+            Block::RedstoneBlock => 6190,
+            Block::NetherQuartzOre => 6191,
+            Block::Hopper => 6201,
+            Block::QuartzBlock => 6202,
+            Block::ChiseledQuartzBlock => 6203,
+            Block::QuartzPillar => 6206,
+            Block::QuartzStairs => 6286,
+            Block::ActivatorRail => 6298,
+            Block::Dropper => 6310,
+            Block::WhiteTerracotta => 6311,
+            Block::OrangeTerracotta => 6312,
+            Block::MagentaTerracotta => 6313,
+            Block::LightBlueTerracotta => 6314,
+            Block::YellowTerracotta => 6315,
+            Block::LimeTerracotta => 6316,
+            Block::PinkTerracotta => 6317,
+            Block::GrayTerracotta => 6318,
+            Block::LightGrayTerracotta => 6319,
+            Block::CyanTerracotta => 6320,
+            Block::PurpleTerracotta => 6321,
+            Block::BlueTerracotta => 6322,
+            Block::BrownTerracotta => 6323,
+            Block::GreenTerracotta => 6324,
+            Block::RedTerracotta => 6325,
+            Block::BlackTerracotta => 6326,
+            Block::WhiteStainedGlassPane => 6358,
+            Block::OrangeStainedGlassPane => 6390,
+            Block::MagentaStainedGlassPane => 6422,
+            Block::LightBlueStainedGlassPane => 6454,
+            Block::YellowStainedGlassPane => 6486,
+            Block::LimeStainedGlassPane => 6518,
+            Block::PinkStainedGlassPane => 6550,
+            Block::GrayStainedGlassPane => 6582,
+            Block::LightGrayStainedGlassPane => 6614,
+            Block::CyanStainedGlassPane => 6646,
+            Block::PurpleStainedGlassPane => 6678,
+            Block::BlueStainedGlassPane => 6710,
+            Block::BrownStainedGlassPane => 6742,
+            Block::GreenStainedGlassPane => 6774,
+            Block::RedStainedGlassPane => 6806,
+            Block::BlackStainedGlassPane => 6838,
+            Block::AcaciaStairs => 6918,
+            Block::DarkOakStairs => 6998,
+            Block::SlimeBlock => 6999,
+            Block::Barrier => 7000,
+            Block::IronTrapdoor => 7064,
+            Block::Prismarine => 7065,
+            Block::PrismarineBricks => 7066,
+            Block::DarkPrismarine => 7067,
+            Block::PrismarineStairs => 7147,
+            Block::PrismarineBrickStairs => 7227,
+            Block::DarkPrismarineStairs => 7307,
+            Block::PrismarineSlab => 7313,
+            Block::PrismarineBrickSlab => 7319,
+            Block::DarkPrismarineSlab => 7325,
+            Block::SeaLantern => 7326,
+            Block::HayBlock => 7329,
+            Block::WhiteCarpet => 7330,
+            Block::OrangeCarpet => 7331,
+            Block::MagentaCarpet => 7332,
+            Block::LightBlueCarpet => 7333,
+            Block::YellowCarpet => 7334,
+            Block::LimeCarpet => 7335,
+            Block::PinkCarpet => 7336,
+            Block::GrayCarpet => 7337,
+            Block::LightGrayCarpet => 7338,
+            Block::CyanCarpet => 7339,
+            Block::PurpleCarpet => 7340,
+            Block::BlueCarpet => 7341,
+            Block::BrownCarpet => 7342,
+            Block::GreenCarpet => 7343,
+            Block::RedCarpet => 7344,
+            Block::BlackCarpet => 7345,
+            Block::Terracotta => 7346,
+            Block::CoalBlock => 7347,
+            Block::PackedIce => 7348,
+            Block::Sunflower => 7350,
+            Block::Lilac => 7352,
+            Block::RoseBush => 7354,
+            Block::Peony => 7356,
+            Block::TallGrass => 7358,
+            Block::LargeFern => 7360,
+            Block::WhiteBanner => 7376,
+            Block::OrangeBanner => 7392,
+            Block::MagentaBanner => 7408,
+            Block::LightBlueBanner => 7424,
+            Block::YellowBanner => 7440,
+            Block::LimeBanner => 7456,
+            Block::PinkBanner => 7472,
+            Block::GrayBanner => 7488,
+            Block::LightGrayBanner => 7504,
+            Block::CyanBanner => 7520,
+            Block::PurpleBanner => 7536,
+            Block::BlueBanner => 7552,
+            Block::BrownBanner => 7568,
+            Block::GreenBanner => 7584,
+            Block::RedBanner => 7600,
+            Block::BlackBanner => 7616,
+            Block::WhiteWallBanner => 7620,
+            Block::OrangeWallBanner => 7624,
+            Block::MagentaWallBanner => 7628,
+            Block::LightBlueWallBanner => 7632,
+            Block::YellowWallBanner => 7636,
+            Block::LimeWallBanner => 7640,
+            Block::PinkWallBanner => 7644,
+            Block::GrayWallBanner => 7648,
+            Block::LightGrayWallBanner => 7652,
+            Block::CyanWallBanner => 7656,
+            Block::PurpleWallBanner => 7660,
+            Block::BlueWallBanner => 7664,
+            Block::BrownWallBanner => 7668,
+            Block::GreenWallBanner => 7672,
+            Block::RedWallBanner => 7676,
+            Block::BlackWallBanner => 7680,
+            Block::RedSandstone => 7681,
+            Block::ChiseledRedSandstone => 7682,
+            Block::CutRedSandstone => 7683,
+            Block::RedSandstoneStairs => 7763,
+            Block::OakSlab => 7769,
+            Block::SpruceSlab => 7775,
+            Block::BirchSlab => 7781,
+            Block::JungleSlab => 7787,
+            Block::AcaciaSlab => 7793,
+            Block::DarkOakSlab => 7799,
+            Block::StoneSlab => 7805,
+            Block::SmoothStoneSlab => 7811,
+            Block::SandstoneSlab => 7817,
+            Block::CutSandstoneSlab => 7823,
+            Block::PetrifiedOakSlab => 7829,
+            Block::CobblestoneSlab => 7835,
+            Block::BrickSlab => 7841,
+            Block::StoneBrickSlab => 7847,
+            Block::NetherBrickSlab => 7853,
+            Block::QuartzSlab => 7859,
+            Block::RedSandstoneSlab => 7865,
+            Block::CutRedSandstoneSlab => 7871,
+            Block::PurpurSlab => 7877,
+            Block::SmoothStone => 7878,
+            Block::SmoothSandstone => 7879,
+            Block::SmoothQuartz => 7880,
+            Block::SmoothRedSandstone => 7881,
+            Block::SpruceFenceGate => 7913,
+            Block::BirchFenceGate => 7945,
+            Block::JungleFenceGate => 7977,
+            Block::AcaciaFenceGate => 8009,
+            Block::DarkOakFenceGate => 8041,
+            Block::SpruceFence => 8073,
+            Block::BirchFence => 8105,
+            Block::JungleFence => 8137,
+            Block::AcaciaFence => 8169,
+            Block::DarkOakFence => 8201,
+            Block::SpruceDoor => 8265,
+            Block::BirchDoor => 8329,
+            Block::JungleDoor => 8393,
+            Block::AcaciaDoor => 8457,
+            Block::DarkOakDoor => 8521,
+            Block::EndRod => 8527,
+            Block::ChorusPlant => 8591,
+            Block::ChorusFlower => 8597,
+            Block::PurpurBlock => 8598,
+            Block::PurpurPillar => 8601,
+            Block::PurpurStairs => 8681,
+            Block::EndStoneBricks => 8682,
+            Block::Beetroots => 8686,
+            Block::GrassPath => 8687,
+            Block::EndGateway => 8688,
+            Block::RepeatingCommandBlock => 8700,
+            Block::ChainCommandBlock => 8712,
+            Block::FrostedIce => 8716,
+            Block::MagmaBlock => 8717,
+            Block::NetherWartBlock => 8718,
+            Block::RedNetherBricks => 8719,
+            Block::BoneBlock => 8722,
+            Block::StructureVoid => 8723,
+            Block::Observer => 8735,
+            Block::ShulkerBox => 8741,
+            Block::WhiteShulkerBox => 8747,
+            Block::OrangeShulkerBox => 8753,
+            Block::MagentaShulkerBox => 8759,
+            Block::LightBlueShulkerBox => 8765,
+            Block::YellowShulkerBox => 8771,
+            Block::LimeShulkerBox => 8777,
+            Block::PinkShulkerBox => 8783,
+            Block::GrayShulkerBox => 8789,
+            Block::LightGrayShulkerBox => 8795,
+            Block::CyanShulkerBox => 8801,
+            Block::PurpleShulkerBox => 8807,
+            Block::BlueShulkerBox => 8813,
+            Block::BrownShulkerBox => 8819,
+            Block::GreenShulkerBox => 8825,
+            Block::RedShulkerBox => 8831,
+            Block::BlackShulkerBox => 8837,
+            Block::WhiteGlazedTerracotta => 8841,
+            Block::OrangeGlazedTerracotta => 8845,
+            Block::MagentaGlazedTerracotta => 8849,
+            Block::LightBlueGlazedTerracotta => 8853,
+            Block::YellowGlazedTerracotta => 8857,
+            Block::LimeGlazedTerracotta => 8861,
+            Block::PinkGlazedTerracotta => 8865,
+            Block::GrayGlazedTerracotta => 8869,
+            Block::LightGrayGlazedTerracotta => 8873,
+            Block::CyanGlazedTerracotta => 8877,
+            Block::PurpleGlazedTerracotta => 8881,
+            Block::BlueGlazedTerracotta => 8885,
+            Block::BrownGlazedTerracotta => 8889,
+            Block::GreenGlazedTerracotta => 8893,
+            Block::RedGlazedTerracotta => 8897,
+            Block::BlackGlazedTerracotta => 8901,
+            Block::WhiteConcrete => 8902,
+            Block::OrangeConcrete => 8903,
+            Block::MagentaConcrete => 8904,
+            Block::LightBlueConcrete => 8905,
+            Block::YellowConcrete => 8906,
+            Block::LimeConcrete => 8907,
+            Block::PinkConcrete => 8908,
+            Block::GrayConcrete => 8909,
+            Block::LightGrayConcrete => 8910,
+            Block::CyanConcrete => 8911,
+            Block::PurpleConcrete => 8912,
+            Block::BlueConcrete => 8913,
+            Block::BrownConcrete => 8914,
+            Block::GreenConcrete => 8915,
+            Block::RedConcrete => 8916,
+            Block::BlackConcrete => 8917,
+            Block::WhiteConcretePowder => 8918,
+            Block::OrangeConcretePowder => 8919,
+            Block::MagentaConcretePowder => 8920,
+            Block::LightBlueConcretePowder => 8921,
+            Block::YellowConcretePowder => 8922,
+            Block::LimeConcretePowder => 8923,
+            Block::PinkConcretePowder => 8924,
+            Block::GrayConcretePowder => 8925,
+            Block::LightGrayConcretePowder => 8926,
+            Block::CyanConcretePowder => 8927,
+            Block::PurpleConcretePowder => 8928,
+            Block::BlueConcretePowder => 8929,
+            Block::BrownConcretePowder => 8930,
+            Block::GreenConcretePowder => 8931,
+            Block::RedConcretePowder => 8932,
+            Block::BlackConcretePowder => 8933,
+            Block::Kelp => 8959,
+            Block::KelpPlant => 8960,
+            Block::DriedKelpBlock => 8961,
+            Block::TurtleEgg => 8973,
+            Block::DeadTubeCoralBlock => 8974,
+            Block::DeadBrainCoralBlock => 8975,
+            Block::DeadBubbleCoralBlock => 8976,
+            Block::DeadFireCoralBlock => 8977,
+            Block::DeadHornCoralBlock => 8978,
+            Block::TubeCoralBlock => 8979,
+            Block::BrainCoralBlock => 8980,
+            Block::BubbleCoralBlock => 8981,
+            Block::FireCoralBlock => 8982,
+            Block::HornCoralBlock => 8983,
+            Block::DeadTubeCoral => 8985,
+            Block::DeadBrainCoral => 8987,
+            Block::DeadBubbleCoral => 8989,
+            Block::DeadFireCoral => 8991,
+            Block::DeadHornCoral => 8993,
+            Block::TubeCoral => 8995,
+            Block::BrainCoral => 8997,
+            Block::BubbleCoral => 8999,
+            Block::FireCoral => 9001,
+            Block::HornCoral => 9003,
+            Block::DeadTubeCoralFan => 9005,
+            Block::DeadBrainCoralFan => 9007,
+            Block::DeadBubbleCoralFan => 9009,
+            Block::DeadFireCoralFan => 9011,
+            Block::DeadHornCoralFan => 9013,
+            Block::TubeCoralFan => 9015,
+            Block::BrainCoralFan => 9017,
+            Block::BubbleCoralFan => 9019,
+            Block::FireCoralFan => 9021,
+            Block::HornCoralFan => 9023,
+            Block::DeadTubeCoralWallFan => 9031,
+            Block::DeadBrainCoralWallFan => 9039,
+            Block::DeadBubbleCoralWallFan => 9047,
+            Block::DeadFireCoralWallFan => 9055,
+            Block::DeadHornCoralWallFan => 9063,
+            Block::TubeCoralWallFan => 9071,
+            Block::BrainCoralWallFan => 9079,
+            Block::BubbleCoralWallFan => 9087,
+            Block::FireCoralWallFan => 9095,
+            Block::HornCoralWallFan => 9103,
+            Block::SeaPickle => 9111,
+            Block::BlueIce => 9112,
+            Block::Conduit => 9114,
+            Block::BambooSapling => 9115,
+            Block::Bamboo => 9127,
+            Block::PottedBamboo => 9128,
+            Block::VoidAir => 9129,
+            Block::CaveAir => 9130,
+            Block::BubbleColumn => 9132,
+            Block::PolishedGraniteStairs => 9212,
+            Block::SmoothRedSandstoneStairs => 9292,
+            Block::MossyStoneBrickStairs => 9372,
+            Block::PolishedDioriteStairs => 9452,
+            Block::MossyCobblestoneStairs => 9532,
+            Block::EndStoneBrickStairs => 9612,
+            Block::StoneStairs => 9692,
+            Block::SmoothSandstoneStairs => 9772,
+            Block::SmoothQuartzStairs => 9852,
+            Block::GraniteStairs => 9932,
+            Block::AndesiteStairs => 10012,
+            Block::RedNetherBrickStairs => 10092,
+            Block::PolishedAndesiteStairs => 10172,
+            Block::DioriteStairs => 10252,
+            Block::PolishedGraniteSlab => 10258,
+            Block::SmoothRedSandstoneSlab => 10264,
+            Block::MossyStoneBrickSlab => 10270,
+            Block::PolishedDioriteSlab => 10276,
+            Block::MossyCobblestoneSlab => 10282,
+            Block::EndStoneBrickSlab => 10288,
+            Block::SmoothSandstoneSlab => 10294,
+            Block::SmoothQuartzSlab => 10300,
+            Block::GraniteSlab => 10306,
+            Block::AndesiteSlab => 10312,
+            Block::RedNetherBrickSlab => 10318,
+            Block::PolishedAndesiteSlab => 10324,
+            Block::DioriteSlab => 10330,
+            Block::BrickWall => 10394,
+            Block::PrismarineWall => 10458,
+            Block::RedSandstoneWall => 10522,
+            Block::MossyStoneBrickWall => 10586,
+            Block::GraniteWall => 10650,
+            Block::StoneBrickWall => 10714,
+            Block::NetherBrickWall => 10778,
+            Block::AndesiteWall => 10842,
+            Block::RedNetherBrickWall => 10906,
+            Block::SandstoneWall => 10970,
+            Block::EndStoneBrickWall => 11034,
+            Block::DioriteWall => 11098,
+            Block::Scaffolding => 11130,
+            Block::Loom => 11134,
+            Block::Barrel => 11146,
+            Block::Smoker => 11154,
+            Block::BlastFurnace => 11162,
+            Block::CartographyTable => 11163,
+            Block::FletchingTable => 11164,
+            Block::Grindstone => 11176,
+            Block::Lectern => 11192,
+            Block::SmithingTable => 11193,
+            Block::Stonecutter => 11197,
+            Block::Bell => 11229,
+            Block::Lantern => 11231,
+            Block::Campfire => 11263,
+            Block::SweetBerryBush => 11267,
+            Block::StructureBlock => 11271,
+            Block::Jigsaw => 11277,
+            Block::Composter => 11286,
+            Block::BeeNest => 11310,
+            Block::Beehive => 11334,
+            Block::HoneyBlock => 11335,
+            Block::HoneycombBlock => 11336,
+        }
+    }
+    pub fn from_state_id(state_id: u32) -> Option<Self> {
+        match state_id {
+            0..=0 => Some(Block::Air),
+            1..=1 => Some(Block::Stone),
+            2..=2 => Some(Block::Granite),
+            3..=3 => Some(Block::PolishedGranite),
+            4..=4 => Some(Block::Diorite),
+            5..=5 => Some(Block::PolishedDiorite),
+            6..=6 => Some(Block::Andesite),
+            7..=7 => Some(Block::PolishedAndesite),
+            8..=9 => Some(Block::GrassBlock),
+            10..=10 => Some(Block::Dirt),
+            11..=11 => Some(Block::CoarseDirt),
+            12..=13 => Some(Block::Podzol),
+            14..=14 => Some(Block::Cobblestone),
+            15..=15 => Some(Block::OakPlanks),
+            16..=16 => Some(Block::SprucePlanks),
+            17..=17 => Some(Block::BirchPlanks),
+            18..=18 => Some(Block::JunglePlanks),
+            19..=19 => Some(Block::AcaciaPlanks),
+            20..=20 => Some(Block::DarkOakPlanks),
+            21..=22 => Some(Block::OakSapling),
+            23..=24 => Some(Block::SpruceSapling),
+            25..=26 => Some(Block::BirchSapling),
+            27..=28 => Some(Block::JungleSapling),
+            29..=30 => Some(Block::AcaciaSapling),
+            31..=32 => Some(Block::DarkOakSapling),
+            33..=33 => Some(Block::Bedrock),
+            34..=49 => Some(Block::Water),
+            50..=65 => Some(Block::Lava),
+            66..=66 => Some(Block::Sand),
+            67..=67 => Some(Block::RedSand),
+            68..=68 => Some(Block::Gravel),
+            69..=69 => Some(Block::GoldOre),
+            70..=70 => Some(Block::IronOre),
+            71..=71 => Some(Block::CoalOre),
+            72..=74 => Some(Block::OakLog),
+            75..=77 => Some(Block::SpruceLog),
+            78..=80 => Some(Block::BirchLog),
+            81..=83 => Some(Block::JungleLog),
+            84..=86 => Some(Block::AcaciaLog),
+            87..=89 => Some(Block::DarkOakLog),
+            90..=92 => Some(Block::StrippedSpruceLog),
+            93..=95 => Some(Block::StrippedBirchLog),
+            96..=98 => Some(Block::StrippedJungleLog),
+            99..=101 => Some(Block::StrippedAcaciaLog),
+            102..=104 => Some(Block::StrippedDarkOakLog),
+            105..=107 => Some(Block::StrippedOakLog),
+            108..=110 => Some(Block::OakWood),
+            111..=113 => Some(Block::SpruceWood),
+            114..=116 => Some(Block::BirchWood),
+            117..=119 => Some(Block::JungleWood),
+            120..=122 => Some(Block::AcaciaWood),
+            123..=125 => Some(Block::DarkOakWood),
+            126..=128 => Some(Block::StrippedOakWood),
+            129..=131 => Some(Block::StrippedSpruceWood),
+            132..=134 => Some(Block::StrippedBirchWood),
+            135..=137 => Some(Block::StrippedJungleWood),
+            138..=140 => Some(Block::StrippedAcaciaWood),
+            141..=143 => Some(Block::StrippedDarkOakWood),
+            144..=157 => Some(Block::OakLeaves),
+            158..=171 => Some(Block::SpruceLeaves),
+            172..=185 => Some(Block::BirchLeaves),
+            186..=199 => Some(Block::JungleLeaves),
+            200..=213 => Some(Block::AcaciaLeaves),
+            214..=227 => Some(Block::DarkOakLeaves),
+            228..=228 => Some(Block::Sponge),
+            229..=229 => Some(Block::WetSponge),
+            230..=230 => Some(Block::Glass),
+            231..=231 => Some(Block::LapisOre),
+            232..=232 => Some(Block::LapisBlock),
+            233..=244 => Some(Block::Dispenser),
+            245..=245 => Some(Block::Sandstone),
+            246..=246 => Some(Block::ChiseledSandstone),
+            247..=247 => Some(Block::CutSandstone),
+            248..=1047 => Some(Block::NoteBlock),
+            1048..=1063 => Some(Block::WhiteBed),
+            1064..=1079 => Some(Block::OrangeBed),
+            1080..=1095 => Some(Block::MagentaBed),
+            1096..=1111 => Some(Block::LightBlueBed),
+            1112..=1127 => Some(Block::YellowBed),
+            1128..=1143 => Some(Block::LimeBed),
+            1144..=1159 => Some(Block::PinkBed),
+            1160..=1175 => Some(Block::GrayBed),
+            1176..=1191 => Some(Block::LightGrayBed),
+            1192..=1207 => Some(Block::CyanBed),
+            1208..=1223 => Some(Block::PurpleBed),
+            1224..=1239 => Some(Block::BlueBed),
+            1240..=1255 => Some(Block::BrownBed),
+            1256..=1271 => Some(Block::GreenBed),
+            1272..=1287 => Some(Block::RedBed),
+            1288..=1303 => Some(Block::BlackBed),
+            1304..=1315 => Some(Block::PoweredRail),
+            1316..=1327 => Some(Block::DetectorRail),
+            1328..=1339 => Some(Block::StickyPiston),
+            1340..=1340 => Some(Block::Cobweb),
+            1341..=1341 => Some(Block::Grass),
+            1342..=1342 => Some(Block::Fern),
+            1343..=1343 => Some(Block::DeadBush),
+            1344..=1344 => Some(Block::Seagrass),
+            1345..=1346 => Some(Block::TallSeagrass),
+            1347..=1358 => Some(Block::Piston),
+            1359..=1382 => Some(Block::PistonHead),
+            1383..=1383 => Some(Block::WhiteWool),
+            1384..=1384 => Some(Block::OrangeWool),
+            1385..=1385 => Some(Block::MagentaWool),
+            1386..=1386 => Some(Block::LightBlueWool),
+            1387..=1387 => Some(Block::YellowWool),
+            1388..=1388 => Some(Block::LimeWool),
+            1389..=1389 => Some(Block::PinkWool),
+            1390..=1390 => Some(Block::GrayWool),
+            1391..=1391 => Some(Block::LightGrayWool),
+            1392..=1392 => Some(Block::CyanWool),
+            1393..=1393 => Some(Block::PurpleWool),
+            1394..=1394 => Some(Block::BlueWool),
+            1395..=1395 => Some(Block::BrownWool),
+            1396..=1396 => Some(Block::GreenWool),
+            1397..=1397 => Some(Block::RedWool),
+            1398..=1398 => Some(Block::BlackWool),
+            1399..=1410 => Some(Block::MovingPiston),
+            1411..=1411 => Some(Block::Dandelion),
+            1412..=1412 => Some(Block::Poppy),
+            1413..=1413 => Some(Block::BlueOrchid),
+            1414..=1414 => Some(Block::Allium),
+            1415..=1415 => Some(Block::AzureBluet),
+            1416..=1416 => Some(Block::RedTulip),
+            1417..=1417 => Some(Block::OrangeTulip),
+            1418..=1418 => Some(Block::WhiteTulip),
+            1419..=1419 => Some(Block::PinkTulip),
+            1420..=1420 => Some(Block::OxeyeDaisy),
+            1421..=1421 => Some(Block::Cornflower),
+            1422..=1422 => Some(Block::WitherRose),
+            1423..=1423 => Some(Block::LilyOfTheValley),
+            1424..=1424 => Some(Block::BrownMushroom),
+            1425..=1425 => Some(Block::RedMushroom),
+            1426..=1426 => Some(Block::GoldBlock),
+            1427..=1427 => Some(Block::IronBlock),
+            1428..=1428 => Some(Block::Bricks),
+            1429..=1430 => Some(Block::Tnt),
+            1431..=1431 => Some(Block::Bookshelf),
+            1432..=1432 => Some(Block::MossyCobblestone),
+            1433..=1433 => Some(Block::Obsidian),
+            1434..=1434 => Some(Block::Torch),
+            1435..=1438 => Some(Block::WallTorch),
+            1439..=1950 => Some(Block::Fire),
+            1951..=1951 => Some(Block::Spawner),
+            1952..=2031 => Some(Block::OakStairs),
+            2032..=2055 => Some(Block::Chest),
+            2056..=3351 => Some(Block::RedstoneWire),
+            3352..=3352 => Some(Block::DiamondOre),
+            3353..=3353 => Some(Block::DiamondBlock),
+            3354..=3354 => Some(Block::CraftingTable),
+            3355..=3362 => Some(Block::Wheat),
+            3363..=3370 => Some(Block::Farmland),
+            3371..=3378 => Some(Block::Furnace),
+            3379..=3410 => Some(Block::OakSign),
+            3411..=3442 => Some(Block::SpruceSign),
+            3443..=3474 => Some(Block::BirchSign),
+            3475..=3506 => Some(Block::AcaciaSign),
+            3507..=3538 => Some(Block::JungleSign),
+            3539..=3570 => Some(Block::DarkOakSign),
+            3571..=3634 => Some(Block::OakDoor),
+            3635..=3642 => Some(Block::Ladder),
+            3643..=3652 => Some(Block::Rail),
+            3653..=3732 => Some(Block::CobblestoneStairs),
+            3733..=3740 => Some(Block::OakWallSign),
+            3741..=3748 => Some(Block::SpruceWallSign),
+            3749..=3756 => Some(Block::BirchWallSign),
+            3757..=3764 => Some(Block::AcaciaWallSign),
+            3765..=3772 => Some(Block::JungleWallSign),
+            3773..=3780 => Some(Block::DarkOakWallSign),
+            3781..=3804 => Some(Block::Lever),
+            3805..=3806 => Some(Block::StonePressurePlate),
+            3807..=3870 => Some(Block::IronDoor),
+            3871..=3872 => Some(Block::OakPressurePlate),
+            3873..=3874 => Some(Block::SprucePressurePlate),
+            3875..=3876 => Some(Block::BirchPressurePlate),
+            3877..=3878 => Some(Block::JunglePressurePlate),
+            3879..=3880 => Some(Block::AcaciaPressurePlate),
+            3881..=3882 => Some(Block::DarkOakPressurePlate),
+            3883..=3884 => Some(Block::RedstoneOre),
+            3885..=3886 => Some(Block::RedstoneTorch),
+            3887..=3894 => Some(Block::RedstoneWallTorch),
+            3895..=3918 => Some(Block::StoneButton),
+            3919..=3926 => Some(Block::Snow),
+            3927..=3927 => Some(Block::Ice),
+            3928..=3928 => Some(Block::SnowBlock),
+            3929..=3944 => Some(Block::Cactus),
+            3945..=3945 => Some(Block::Clay),
+            3946..=3961 => Some(Block::SugarCane),
+            3962..=3963 => Some(Block::Jukebox),
+            3964..=3995 => Some(Block::OakFence),
+            3996..=3996 => Some(Block::Pumpkin),
+            3997..=3997 => Some(Block::Netherrack),
+            3998..=3998 => Some(Block::SoulSand),
+            3999..=3999 => Some(Block::Glowstone),
+            4000..=4001 => Some(Block::NetherPortal),
+            4002..=4005 => Some(Block::CarvedPumpkin),
+            4006..=4009 => Some(Block::JackOLantern),
+            4010..=4016 => Some(Block::Cake),
+            4017..=4080 => Some(Block::Repeater),
+            4081..=4081 => Some(Block::WhiteStainedGlass),
+            4082..=4082 => Some(Block::OrangeStainedGlass),
+            4083..=4083 => Some(Block::MagentaStainedGlass),
+            4084..=4084 => Some(Block::LightBlueStainedGlass),
+            4085..=4085 => Some(Block::YellowStainedGlass),
+            4086..=4086 => Some(Block::LimeStainedGlass),
+            4087..=4087 => Some(Block::PinkStainedGlass),
+            4088..=4088 => Some(Block::GrayStainedGlass),
+            4089..=4089 => Some(Block::LightGrayStainedGlass),
+            4090..=4090 => Some(Block::CyanStainedGlass),
+            4091..=4091 => Some(Block::PurpleStainedGlass),
+            4092..=4092 => Some(Block::BlueStainedGlass),
+            4093..=4093 => Some(Block::BrownStainedGlass),
+            4094..=4094 => Some(Block::GreenStainedGlass),
+            4095..=4095 => Some(Block::RedStainedGlass),
+            4096..=4096 => Some(Block::BlackStainedGlass),
+            4097..=4160 => Some(Block::OakTrapdoor),
+            4161..=4224 => Some(Block::SpruceTrapdoor),
+            4225..=4288 => Some(Block::BirchTrapdoor),
+            4289..=4352 => Some(Block::JungleTrapdoor),
+            4353..=4416 => Some(Block::AcaciaTrapdoor),
+            4417..=4480 => Some(Block::DarkOakTrapdoor),
+            4481..=4481 => Some(Block::StoneBricks),
+            4482..=4482 => Some(Block::MossyStoneBricks),
+            4483..=4483 => Some(Block::CrackedStoneBricks),
+            4484..=4484 => Some(Block::ChiseledStoneBricks),
+            4485..=4485 => Some(Block::InfestedStone),
+            4486..=4486 => Some(Block::InfestedCobblestone),
+            4487..=4487 => Some(Block::InfestedStoneBricks),
+            4488..=4488 => Some(Block::InfestedMossyStoneBricks),
+            4489..=4489 => Some(Block::InfestedCrackedStoneBricks),
+            4490..=4490 => Some(Block::InfestedChiseledStoneBricks),
+            4491..=4554 => Some(Block::BrownMushroomBlock),
+            4555..=4618 => Some(Block::RedMushroomBlock),
+            4619..=4682 => Some(Block::MushroomStem),
+            4683..=4714 => Some(Block::IronBars),
+            4715..=4746 => Some(Block::GlassPane),
+            4747..=4747 => Some(Block::Melon),
+            4748..=4751 => Some(Block::AttachedPumpkinStem),
+            4752..=4755 => Some(Block::AttachedMelonStem),
+            4756..=4763 => Some(Block::PumpkinStem),
+            4764..=4771 => Some(Block::MelonStem),
+            4772..=4803 => Some(Block::Vine),
+            4804..=4835 => Some(Block::OakFenceGate),
+            4836..=4915 => Some(Block::BrickStairs),
+            4916..=4995 => Some(Block::StoneBrickStairs),
+            4996..=4997 => Some(Block::Mycelium),
+            4998..=4998 => Some(Block::LilyPad),
+            4999..=4999 => Some(Block::NetherBricks),
+            5000..=5031 => Some(Block::NetherBrickFence),
+            5032..=5111 => Some(Block::NetherBrickStairs),
+            5112..=5115 => Some(Block::NetherWart),
+            5116..=5116 => Some(Block::EnchantingTable),
+            5117..=5124 => Some(Block::BrewingStand),
+            5125..=5128 => Some(Block::Cauldron),
+            5129..=5129 => Some(Block::EndPortal),
+            5130..=5137 => Some(Block::EndPortalFrame),
+            5138..=5138 => Some(Block::EndStone),
+            5139..=5139 => Some(Block::DragonEgg),
+            5140..=5141 => Some(Block::RedstoneLamp),
+            5142..=5153 => Some(Block::Cocoa),
+            5154..=5233 => Some(Block::SandstoneStairs),
+            5234..=5234 => Some(Block::EmeraldOre),
+            5235..=5242 => Some(Block::EnderChest),
+            5243..=5258 => Some(Block::TripwireHook),
+            5259..=5386 => Some(Block::Tripwire),
+            5387..=5387 => Some(Block::EmeraldBlock),
+            5388..=5467 => Some(Block::SpruceStairs),
+            5468..=5547 => Some(Block::BirchStairs),
+            5548..=5627 => Some(Block::JungleStairs),
+            5628..=5639 => Some(Block::CommandBlock),
+            5640..=5640 => Some(Block::Beacon),
+            5641..=5704 => Some(Block::CobblestoneWall),
+            5705..=5768 => Some(Block::MossyCobblestoneWall),
+            5769..=5769 => Some(Block::FlowerPot),
+            5770..=5770 => Some(Block::PottedOakSapling),
+            5771..=5771 => Some(Block::PottedSpruceSapling),
+            5772..=5772 => Some(Block::PottedBirchSapling),
+            5773..=5773 => Some(Block::PottedJungleSapling),
+            5774..=5774 => Some(Block::PottedAcaciaSapling),
+            5775..=5775 => Some(Block::PottedDarkOakSapling),
+            5776..=5776 => Some(Block::PottedFern),
+            5777..=5777 => Some(Block::PottedDandelion),
+            5778..=5778 => Some(Block::PottedPoppy),
+            5779..=5779 => Some(Block::PottedBlueOrchid),
+            5780..=5780 => Some(Block::PottedAllium),
+            5781..=5781 => Some(Block::PottedAzureBluet),
+            5782..=5782 => Some(Block::PottedRedTulip),
+            5783..=5783 => Some(Block::PottedOrangeTulip),
+            5784..=5784 => Some(Block::PottedWhiteTulip),
+            5785..=5785 => Some(Block::PottedPinkTulip),
+            5786..=5786 => Some(Block::PottedOxeyeDaisy),
+            5787..=5787 => Some(Block::PottedCornflower),
+            5788..=5788 => Some(Block::PottedLilyOfTheValley),
+            5789..=5789 => Some(Block::PottedWitherRose),
+            5790..=5790 => Some(Block::PottedRedMushroom),
+            5791..=5791 => Some(Block::PottedBrownMushroom),
+            5792..=5792 => Some(Block::PottedDeadBush),
+            5793..=5793 => Some(Block::PottedCactus),
+            5794..=5801 => Some(Block::Carrots),
+            5802..=5809 => Some(Block::Potatoes),
+            5810..=5833 => Some(Block::OakButton),
+            5834..=5857 => Some(Block::SpruceButton),
+            5858..=5881 => Some(Block::BirchButton),
+            5882..=5905 => Some(Block::JungleButton),
+            5906..=5929 => Some(Block::AcaciaButton),
+            5930..=5953 => Some(Block::DarkOakButton),
+            5954..=5969 => Some(Block::SkeletonSkull),
+            5970..=5973 => Some(Block::SkeletonWallSkull),
+            5974..=5989 => Some(Block::WitherSkeletonSkull),
+            5990..=5993 => Some(Block::WitherSkeletonWallSkull),
+            5994..=6009 => Some(Block::ZombieHead),
+            6010..=6013 => Some(Block::ZombieWallHead),
+            6014..=6029 => Some(Block::PlayerHead),
+            6030..=6033 => Some(Block::PlayerWallHead),
+            6034..=6049 => Some(Block::CreeperHead),
+            6050..=6053 => Some(Block::CreeperWallHead),
+            6054..=6069 => Some(Block::DragonHead),
+            6070..=6073 => Some(Block::DragonWallHead),
+            6074..=6077 => Some(Block::Anvil),
+            6078..=6081 => Some(Block::ChippedAnvil),
+            6082..=6085 => Some(Block::DamagedAnvil),
+            6086..=6109 => Some(Block::TrappedChest),
+            6110..=6125 => Some(Block::LightWeightedPressurePlate),
+            6126..=6141 => Some(Block::HeavyWeightedPressurePlate),
+            6142..=6157 => Some(Block::Comparator),
+            6158..=6189 => Some(Block::DaylightDetector),
+            6190..=6190 => Some(Block::RedstoneBlock),
+            6191..=6191 => Some(Block::NetherQuartzOre),
+            6192..=6201 => Some(Block::Hopper),
+            6202..=6202 => Some(Block::QuartzBlock),
+            6203..=6203 => Some(Block::ChiseledQuartzBlock),
+            6204..=6206 => Some(Block::QuartzPillar),
+            6207..=6286 => Some(Block::QuartzStairs),
+            6287..=6298 => Some(Block::ActivatorRail),
+            6299..=6310 => Some(Block::Dropper),
+            6311..=6311 => Some(Block::WhiteTerracotta),
+            6312..=6312 => Some(Block::OrangeTerracotta),
+            6313..=6313 => Some(Block::MagentaTerracotta),
+            6314..=6314 => Some(Block::LightBlueTerracotta),
+            6315..=6315 => Some(Block::YellowTerracotta),
+            6316..=6316 => Some(Block::LimeTerracotta),
+            6317..=6317 => Some(Block::PinkTerracotta),
+            6318..=6318 => Some(Block::GrayTerracotta),
+            6319..=6319 => Some(Block::LightGrayTerracotta),
+            6320..=6320 => Some(Block::CyanTerracotta),
+            6321..=6321 => Some(Block::PurpleTerracotta),
+            6322..=6322 => Some(Block::BlueTerracotta),
+            6323..=6323 => Some(Block::BrownTerracotta),
+            6324..=6324 => Some(Block::GreenTerracotta),
+            6325..=6325 => Some(Block::RedTerracotta),
+            6326..=6326 => Some(Block::BlackTerracotta),
+            6327..=6358 => Some(Block::WhiteStainedGlassPane),
+            6359..=6390 => Some(Block::OrangeStainedGlassPane),
+            6391..=6422 => Some(Block::MagentaStainedGlassPane),
+            6423..=6454 => Some(Block::LightBlueStainedGlassPane),
+            6455..=6486 => Some(Block::YellowStainedGlassPane),
+            6487..=6518 => Some(Block::LimeStainedGlassPane),
+            6519..=6550 => Some(Block::PinkStainedGlassPane),
+            6551..=6582 => Some(Block::GrayStainedGlassPane),
+            6583..=6614 => Some(Block::LightGrayStainedGlassPane),
+            6615..=6646 => Some(Block::CyanStainedGlassPane),
+            6647..=6678 => Some(Block::PurpleStainedGlassPane),
+            6679..=6710 => Some(Block::BlueStainedGlassPane),
+            6711..=6742 => Some(Block::BrownStainedGlassPane),
+            6743..=6774 => Some(Block::GreenStainedGlassPane),
+            6775..=6806 => Some(Block::RedStainedGlassPane),
+            6807..=6838 => Some(Block::BlackStainedGlassPane),
+            6839..=6918 => Some(Block::AcaciaStairs),
+            6919..=6998 => Some(Block::DarkOakStairs),
+            6999..=6999 => Some(Block::SlimeBlock),
+            7000..=7000 => Some(Block::Barrier),
+            7001..=7064 => Some(Block::IronTrapdoor),
+            7065..=7065 => Some(Block::Prismarine),
+            7066..=7066 => Some(Block::PrismarineBricks),
+            7067..=7067 => Some(Block::DarkPrismarine),
+            7068..=7147 => Some(Block::PrismarineStairs),
+            7148..=7227 => Some(Block::PrismarineBrickStairs),
+            7228..=7307 => Some(Block::DarkPrismarineStairs),
+            7308..=7313 => Some(Block::PrismarineSlab),
+            7314..=7319 => Some(Block::PrismarineBrickSlab),
+            7320..=7325 => Some(Block::DarkPrismarineSlab),
+            7326..=7326 => Some(Block::SeaLantern),
+            7327..=7329 => Some(Block::HayBlock),
+            7330..=7330 => Some(Block::WhiteCarpet),
+            7331..=7331 => Some(Block::OrangeCarpet),
+            7332..=7332 => Some(Block::MagentaCarpet),
+            7333..=7333 => Some(Block::LightBlueCarpet),
+            7334..=7334 => Some(Block::YellowCarpet),
+            7335..=7335 => Some(Block::LimeCarpet),
+            7336..=7336 => Some(Block::PinkCarpet),
+            7337..=7337 => Some(Block::GrayCarpet),
+            7338..=7338 => Some(Block::LightGrayCarpet),
+            7339..=7339 => Some(Block::CyanCarpet),
+            7340..=7340 => Some(Block::PurpleCarpet),
+            7341..=7341 => Some(Block::BlueCarpet),
+            7342..=7342 => Some(Block::BrownCarpet),
+            7343..=7343 => Some(Block::GreenCarpet),
+            7344..=7344 => Some(Block::RedCarpet),
+            7345..=7345 => Some(Block::BlackCarpet),
+            7346..=7346 => Some(Block::Terracotta),
+            7347..=7347 => Some(Block::CoalBlock),
+            7348..=7348 => Some(Block::PackedIce),
+            7349..=7350 => Some(Block::Sunflower),
+            7351..=7352 => Some(Block::Lilac),
+            7353..=7354 => Some(Block::RoseBush),
+            7355..=7356 => Some(Block::Peony),
+            7357..=7358 => Some(Block::TallGrass),
+            7359..=7360 => Some(Block::LargeFern),
+            7361..=7376 => Some(Block::WhiteBanner),
+            7377..=7392 => Some(Block::OrangeBanner),
+            7393..=7408 => Some(Block::MagentaBanner),
+            7409..=7424 => Some(Block::LightBlueBanner),
+            7425..=7440 => Some(Block::YellowBanner),
+            7441..=7456 => Some(Block::LimeBanner),
+            7457..=7472 => Some(Block::PinkBanner),
+            7473..=7488 => Some(Block::GrayBanner),
+            7489..=7504 => Some(Block::LightGrayBanner),
+            7505..=7520 => Some(Block::CyanBanner),
+            7521..=7536 => Some(Block::PurpleBanner),
+            7537..=7552 => Some(Block::BlueBanner),
+            7553..=7568 => Some(Block::BrownBanner),
+            7569..=7584 => Some(Block::GreenBanner),
+            7585..=7600 => Some(Block::RedBanner),
+            7601..=7616 => Some(Block::BlackBanner),
+            7617..=7620 => Some(Block::WhiteWallBanner),
+            7621..=7624 => Some(Block::OrangeWallBanner),
+            7625..=7628 => Some(Block::MagentaWallBanner),
+            7629..=7632 => Some(Block::LightBlueWallBanner),
+            7633..=7636 => Some(Block::YellowWallBanner),
+            7637..=7640 => Some(Block::LimeWallBanner),
+            7641..=7644 => Some(Block::PinkWallBanner),
+            7645..=7648 => Some(Block::GrayWallBanner),
+            7649..=7652 => Some(Block::LightGrayWallBanner),
+            7653..=7656 => Some(Block::CyanWallBanner),
+            7657..=7660 => Some(Block::PurpleWallBanner),
+            7661..=7664 => Some(Block::BlueWallBanner),
+            7665..=7668 => Some(Block::BrownWallBanner),
+            7669..=7672 => Some(Block::GreenWallBanner),
+            7673..=7676 => Some(Block::RedWallBanner),
+            7677..=7680 => Some(Block::BlackWallBanner),
+            7681..=7681 => Some(Block::RedSandstone),
+            7682..=7682 => Some(Block::ChiseledRedSandstone),
+            7683..=7683 => Some(Block::CutRedSandstone),
+            7684..=7763 => Some(Block::RedSandstoneStairs),
+            7764..=7769 => Some(Block::OakSlab),
+            7770..=7775 => Some(Block::SpruceSlab),
+            7776..=7781 => Some(Block::BirchSlab),
+            7782..=7787 => Some(Block::JungleSlab),
+            7788..=7793 => Some(Block::AcaciaSlab),
+            7794..=7799 => Some(Block::DarkOakSlab),
+            7800..=7805 => Some(Block::StoneSlab),
+            7806..=7811 => Some(Block::SmoothStoneSlab),
+            7812..=7817 => Some(Block::SandstoneSlab),
+            7818..=7823 => Some(Block::CutSandstoneSlab),
+            7824..=7829 => Some(Block::PetrifiedOakSlab),
+            7830..=7835 => Some(Block::CobblestoneSlab),
+            7836..=7841 => Some(Block::BrickSlab),
+            7842..=7847 => Some(Block::StoneBrickSlab),
+            7848..=7853 => Some(Block::NetherBrickSlab),
+            7854..=7859 => Some(Block::QuartzSlab),
+            7860..=7865 => Some(Block::RedSandstoneSlab),
+            7866..=7871 => Some(Block::CutRedSandstoneSlab),
+            7872..=7877 => Some(Block::PurpurSlab),
+            7878..=7878 => Some(Block::SmoothStone),
+            7879..=7879 => Some(Block::SmoothSandstone),
+            7880..=7880 => Some(Block::SmoothQuartz),
+            7881..=7881 => Some(Block::SmoothRedSandstone),
+            7882..=7913 => Some(Block::SpruceFenceGate),
+            7914..=7945 => Some(Block::BirchFenceGate),
+            7946..=7977 => Some(Block::JungleFenceGate),
+            7978..=8009 => Some(Block::AcaciaFenceGate),
+            8010..=8041 => Some(Block::DarkOakFenceGate),
+            8042..=8073 => Some(Block::SpruceFence),
+            8074..=8105 => Some(Block::BirchFence),
+            8106..=8137 => Some(Block::JungleFence),
+            8138..=8169 => Some(Block::AcaciaFence),
+            8170..=8201 => Some(Block::DarkOakFence),
+            8202..=8265 => Some(Block::SpruceDoor),
+            8266..=8329 => Some(Block::BirchDoor),
+            8330..=8393 => Some(Block::JungleDoor),
+            8394..=8457 => Some(Block::AcaciaDoor),
+            8458..=8521 => Some(Block::DarkOakDoor),
+            8522..=8527 => Some(Block::EndRod),
+            8528..=8591 => Some(Block::ChorusPlant),
+            8592..=8597 => Some(Block::ChorusFlower),
+            8598..=8598 => Some(Block::PurpurBlock),
+            8599..=8601 => Some(Block::PurpurPillar),
+            8602..=8681 => Some(Block::PurpurStairs),
+            8682..=8682 => Some(Block::EndStoneBricks),
+            8683..=8686 => Some(Block::Beetroots),
+            8687..=8687 => Some(Block::GrassPath),
+            8688..=8688 => Some(Block::EndGateway),
+            8689..=8700 => Some(Block::RepeatingCommandBlock),
+            8701..=8712 => Some(Block::ChainCommandBlock),
+            8713..=8716 => Some(Block::FrostedIce),
+            8717..=8717 => Some(Block::MagmaBlock),
+            8718..=8718 => Some(Block::NetherWartBlock),
+            8719..=8719 => Some(Block::RedNetherBricks),
+            8720..=8722 => Some(Block::BoneBlock),
+            8723..=8723 => Some(Block::StructureVoid),
+            8724..=8735 => Some(Block::Observer),
+            8736..=8741 => Some(Block::ShulkerBox),
+            8742..=8747 => Some(Block::WhiteShulkerBox),
+            8748..=8753 => Some(Block::OrangeShulkerBox),
+            8754..=8759 => Some(Block::MagentaShulkerBox),
+            8760..=8765 => Some(Block::LightBlueShulkerBox),
+            8766..=8771 => Some(Block::YellowShulkerBox),
+            8772..=8777 => Some(Block::LimeShulkerBox),
+            8778..=8783 => Some(Block::PinkShulkerBox),
+            8784..=8789 => Some(Block::GrayShulkerBox),
+            8790..=8795 => Some(Block::LightGrayShulkerBox),
+            8796..=8801 => Some(Block::CyanShulkerBox),
+            8802..=8807 => Some(Block::PurpleShulkerBox),
+            8808..=8813 => Some(Block::BlueShulkerBox),
+            8814..=8819 => Some(Block::BrownShulkerBox),
+            8820..=8825 => Some(Block::GreenShulkerBox),
+            8826..=8831 => Some(Block::RedShulkerBox),
+            8832..=8837 => Some(Block::BlackShulkerBox),
+            8838..=8841 => Some(Block::WhiteGlazedTerracotta),
+            8842..=8845 => Some(Block::OrangeGlazedTerracotta),
+            8846..=8849 => Some(Block::MagentaGlazedTerracotta),
+            8850..=8853 => Some(Block::LightBlueGlazedTerracotta),
+            8854..=8857 => Some(Block::YellowGlazedTerracotta),
+            8858..=8861 => Some(Block::LimeGlazedTerracotta),
+            8862..=8865 => Some(Block::PinkGlazedTerracotta),
+            8866..=8869 => Some(Block::GrayGlazedTerracotta),
+            8870..=8873 => Some(Block::LightGrayGlazedTerracotta),
+            8874..=8877 => Some(Block::CyanGlazedTerracotta),
+            8878..=8881 => Some(Block::PurpleGlazedTerracotta),
+            8882..=8885 => Some(Block::BlueGlazedTerracotta),
+            8886..=8889 => Some(Block::BrownGlazedTerracotta),
+            8890..=8893 => Some(Block::GreenGlazedTerracotta),
+            8894..=8897 => Some(Block::RedGlazedTerracotta),
+            8898..=8901 => Some(Block::BlackGlazedTerracotta),
+            8902..=8902 => Some(Block::WhiteConcrete),
+            8903..=8903 => Some(Block::OrangeConcrete),
+            8904..=8904 => Some(Block::MagentaConcrete),
+            8905..=8905 => Some(Block::LightBlueConcrete),
+            8906..=8906 => Some(Block::YellowConcrete),
+            8907..=8907 => Some(Block::LimeConcrete),
+            8908..=8908 => Some(Block::PinkConcrete),
+            8909..=8909 => Some(Block::GrayConcrete),
+            8910..=8910 => Some(Block::LightGrayConcrete),
+            8911..=8911 => Some(Block::CyanConcrete),
+            8912..=8912 => Some(Block::PurpleConcrete),
+            8913..=8913 => Some(Block::BlueConcrete),
+            8914..=8914 => Some(Block::BrownConcrete),
+            8915..=8915 => Some(Block::GreenConcrete),
+            8916..=8916 => Some(Block::RedConcrete),
+            8917..=8917 => Some(Block::BlackConcrete),
+            8918..=8918 => Some(Block::WhiteConcretePowder),
+            8919..=8919 => Some(Block::OrangeConcretePowder),
+            8920..=8920 => Some(Block::MagentaConcretePowder),
+            8921..=8921 => Some(Block::LightBlueConcretePowder),
+            8922..=8922 => Some(Block::YellowConcretePowder),
+            8923..=8923 => Some(Block::LimeConcretePowder),
+            8924..=8924 => Some(Block::PinkConcretePowder),
+            8925..=8925 => Some(Block::GrayConcretePowder),
+            8926..=8926 => Some(Block::LightGrayConcretePowder),
+            8927..=8927 => Some(Block::CyanConcretePowder),
+            8928..=8928 => Some(Block::PurpleConcretePowder),
+            8929..=8929 => Some(Block::BlueConcretePowder),
+            8930..=8930 => Some(Block::BrownConcretePowder),
+            8931..=8931 => Some(Block::GreenConcretePowder),
+            8932..=8932 => Some(Block::RedConcretePowder),
+            8933..=8933 => Some(Block::BlackConcretePowder),
+            8934..=8959 => Some(Block::Kelp),
+            8960..=8960 => Some(Block::KelpPlant),
+            8961..=8961 => Some(Block::DriedKelpBlock),
+            8962..=8973 => Some(Block::TurtleEgg),
+            8974..=8974 => Some(Block::DeadTubeCoralBlock),
+            8975..=8975 => Some(Block::DeadBrainCoralBlock),
+            8976..=8976 => Some(Block::DeadBubbleCoralBlock),
+            8977..=8977 => Some(Block::DeadFireCoralBlock),
+            8978..=8978 => Some(Block::DeadHornCoralBlock),
+            8979..=8979 => Some(Block::TubeCoralBlock),
+            8980..=8980 => Some(Block::BrainCoralBlock),
+            8981..=8981 => Some(Block::BubbleCoralBlock),
+            8982..=8982 => Some(Block::FireCoralBlock),
+            8983..=8983 => Some(Block::HornCoralBlock),
+            8984..=8985 => Some(Block::DeadTubeCoral),
+            8986..=8987 => Some(Block::DeadBrainCoral),
+            8988..=8989 => Some(Block::DeadBubbleCoral),
+            8990..=8991 => Some(Block::DeadFireCoral),
+            8992..=8993 => Some(Block::DeadHornCoral),
+            8994..=8995 => Some(Block::TubeCoral),
+            8996..=8997 => Some(Block::BrainCoral),
+            8998..=8999 => Some(Block::BubbleCoral),
+            9000..=9001 => Some(Block::FireCoral),
+            9002..=9003 => Some(Block::HornCoral),
+            9004..=9005 => Some(Block::DeadTubeCoralFan),
+            9006..=9007 => Some(Block::DeadBrainCoralFan),
+            9008..=9009 => Some(Block::DeadBubbleCoralFan),
+            9010..=9011 => Some(Block::DeadFireCoralFan),
+            9012..=9013 => Some(Block::DeadHornCoralFan),
+            9014..=9015 => Some(Block::TubeCoralFan),
+            9016..=9017 => Some(Block::BrainCoralFan),
+            9018..=9019 => Some(Block::BubbleCoralFan),
+            9020..=9021 => Some(Block::FireCoralFan),
+            9022..=9023 => Some(Block::HornCoralFan),
+            9024..=9031 => Some(Block::DeadTubeCoralWallFan),
+            9032..=9039 => Some(Block::DeadBrainCoralWallFan),
+            9040..=9047 => Some(Block::DeadBubbleCoralWallFan),
+            9048..=9055 => Some(Block::DeadFireCoralWallFan),
+            9056..=9063 => Some(Block::DeadHornCoralWallFan),
+            9064..=9071 => Some(Block::TubeCoralWallFan),
+            9072..=9079 => Some(Block::BrainCoralWallFan),
+            9080..=9087 => Some(Block::BubbleCoralWallFan),
+            9088..=9095 => Some(Block::FireCoralWallFan),
+            9096..=9103 => Some(Block::HornCoralWallFan),
+            9104..=9111 => Some(Block::SeaPickle),
+            9112..=9112 => Some(Block::BlueIce),
+            9113..=9114 => Some(Block::Conduit),
+            9115..=9115 => Some(Block::BambooSapling),
+            9116..=9127 => Some(Block::Bamboo),
+            9128..=9128 => Some(Block::PottedBamboo),
+            9129..=9129 => Some(Block::VoidAir),
+            9130..=9130 => Some(Block::CaveAir),
+            9131..=9132 => Some(Block::BubbleColumn),
+            9133..=9212 => Some(Block::PolishedGraniteStairs),
+            9213..=9292 => Some(Block::SmoothRedSandstoneStairs),
+            9293..=9372 => Some(Block::MossyStoneBrickStairs),
+            9373..=9452 => Some(Block::PolishedDioriteStairs),
+            9453..=9532 => Some(Block::MossyCobblestoneStairs),
+            9533..=9612 => Some(Block::EndStoneBrickStairs),
+            9613..=9692 => Some(Block::StoneStairs),
+            9693..=9772 => Some(Block::SmoothSandstoneStairs),
+            9773..=9852 => Some(Block::SmoothQuartzStairs),
+            9853..=9932 => Some(Block::GraniteStairs),
+            9933..=10012 => Some(Block::AndesiteStairs),
+            10013..=10092 => Some(Block::RedNetherBrickStairs),
+            10093..=10172 => Some(Block::PolishedAndesiteStairs),
+            10173..=10252 => Some(Block::DioriteStairs),
+            10253..=10258 => Some(Block::PolishedGraniteSlab),
+            10259..=10264 => Some(Block::SmoothRedSandstoneSlab),
+            10265..=10270 => Some(Block::MossyStoneBrickSlab),
+            10271..=10276 => Some(Block::PolishedDioriteSlab),
+            10277..=10282 => Some(Block::MossyCobblestoneSlab),
+            10283..=10288 => Some(Block::EndStoneBrickSlab),
+            10289..=10294 => Some(Block::SmoothSandstoneSlab),
+            10295..=10300 => Some(Block::SmoothQuartzSlab),
+            10301..=10306 => Some(Block::GraniteSlab),
+            10307..=10312 => Some(Block::AndesiteSlab),
+            10313..=10318 => Some(Block::RedNetherBrickSlab),
+            10319..=10324 => Some(Block::PolishedAndesiteSlab),
+            10325..=10330 => Some(Block::DioriteSlab),
+            10331..=10394 => Some(Block::BrickWall),
+            10395..=10458 => Some(Block::PrismarineWall),
+            10459..=10522 => Some(Block::RedSandstoneWall),
+            10523..=10586 => Some(Block::MossyStoneBrickWall),
+            10587..=10650 => Some(Block::GraniteWall),
+            10651..=10714 => Some(Block::StoneBrickWall),
+            10715..=10778 => Some(Block::NetherBrickWall),
+            10779..=10842 => Some(Block::AndesiteWall),
+            10843..=10906 => Some(Block::RedNetherBrickWall),
+            10907..=10970 => Some(Block::SandstoneWall),
+            10971..=11034 => Some(Block::EndStoneBrickWall),
+            11035..=11098 => Some(Block::DioriteWall),
+            11099..=11130 => Some(Block::Scaffolding),
+            11131..=11134 => Some(Block::Loom),
+            11135..=11146 => Some(Block::Barrel),
+            11147..=11154 => Some(Block::Smoker),
+            11155..=11162 => Some(Block::BlastFurnace),
+            11163..=11163 => Some(Block::CartographyTable),
+            11164..=11164 => Some(Block::FletchingTable),
+            11165..=11176 => Some(Block::Grindstone),
+            11177..=11192 => Some(Block::Lectern),
+            11193..=11193 => Some(Block::SmithingTable),
+            11194..=11197 => Some(Block::Stonecutter),
+            11198..=11229 => Some(Block::Bell),
+            11230..=11231 => Some(Block::Lantern),
+            11232..=11263 => Some(Block::Campfire),
+            11264..=11267 => Some(Block::SweetBerryBush),
+            11268..=11271 => Some(Block::StructureBlock),
+            11272..=11277 => Some(Block::Jigsaw),
+            11278..=11286 => Some(Block::Composter),
+            11287..=11310 => Some(Block::BeeNest),
+            11311..=11334 => Some(Block::Beehive),
+            11335..=11335 => Some(Block::HoneyBlock),
+            11336..=11336 => Some(Block::HoneycombBlock),
+            _ => None,
+        }
+    }
+    pub fn hardness(&self) -> Option<f32> {
+        match self {
+            Block::Air => Some(0.0),
+            Block::Stone => Some(1.5),
+            Block::Granite => Some(1.5),
+            Block::PolishedGranite => Some(1.5),
+            Block::Diorite => Some(1.5),
+            Block::PolishedDiorite => Some(1.5),
+            Block::Andesite => Some(1.5),
+            Block::PolishedAndesite => Some(1.5),
+            Block::GrassBlock => Some(0.6),
+            Block::Dirt => Some(0.5),
+            Block::CoarseDirt => Some(0.5),
+            Block::Podzol => Some(0.5),
+            Block::Cobblestone => Some(2.0),
+            Block::OakPlanks => Some(2.0),
+            Block::SprucePlanks => Some(2.0),
+            Block::BirchPlanks => Some(2.0),
+            Block::JunglePlanks => Some(2.0),
+            Block::AcaciaPlanks => Some(2.0),
+            Block::DarkOakPlanks => Some(2.0),
+            Block::OakSapling => Some(0.0),
+            Block::SpruceSapling => Some(0.0),
+            Block::BirchSapling => Some(0.0),
+            Block::JungleSapling => Some(0.0),
+            Block::AcaciaSapling => Some(0.0),
+            Block::DarkOakSapling => Some(0.0),
+            Block::Bedrock => None,
+            Block::Water => Some(100.0),
+            Block::Lava => Some(100.0),
+            Block::Sand => Some(0.5),
+            Block::RedSand => Some(0.5),
+            Block::Gravel => Some(0.6),
+            Block::GoldOre => Some(3.0),
+            Block::IronOre => Some(3.0),
+            Block::CoalOre => Some(3.0),
+            Block::OakLog => Some(2.0),
+            Block::SpruceLog => Some(2.0),
+            Block::BirchLog => Some(2.0),
+            Block::JungleLog => Some(2.0),
+            Block::AcaciaLog => Some(2.0),
+            Block::DarkOakLog => Some(2.0),
+            Block::StrippedSpruceLog => Some(2.0),
+            Block::StrippedBirchLog => Some(2.0),
+            Block::StrippedJungleLog => Some(2.0),
+            Block::StrippedAcaciaLog => Some(2.0),
+            Block::StrippedDarkOakLog => Some(2.0),
+            Block::StrippedOakLog => Some(2.0),
+            Block::OakWood => Some(2.0),
+            Block::SpruceWood => Some(2.0),
+            Block::BirchWood => Some(2.0),
+            Block::JungleWood => Some(2.0),
+            Block::AcaciaWood => Some(2.0),
+            Block::DarkOakWood => Some(2.0),
+            Block::StrippedOakWood => Some(2.0),
+            Block::StrippedSpruceWood => Some(2.0),
+            Block::StrippedBirchWood => Some(2.0),
+            Block::StrippedJungleWood => Some(2.0),
+            Block::StrippedAcaciaWood => Some(2.0),
+            Block::StrippedDarkOakWood => Some(2.0),
+            Block::OakLeaves => Some(0.2),
+            Block::SpruceLeaves => Some(0.2),
+            Block::BirchLeaves => Some(0.2),
+            Block::JungleLeaves => Some(0.2),
+            Block::AcaciaLeaves => Some(0.2),
+            Block::DarkOakLeaves => Some(0.2),
+            Block::Sponge => Some(0.6),
+            Block::WetSponge => Some(0.6),
+            Block::Glass => Some(0.3),
+            Block::LapisOre => Some(3.0),
+            Block::LapisBlock => Some(3.0),
+            Block::Dispenser => Some(3.5),
+            Block::Sandstone => Some(0.8),
+            Block::ChiseledSandstone => Some(0.8),
+            Block::CutSandstone => Some(0.8),
+            Block::NoteBlock => Some(0.8),
+            Block::WhiteBed => Some(0.2),
+            Block::OrangeBed => Some(0.2),
+            Block::MagentaBed => Some(0.2),
+            Block::LightBlueBed => Some(0.2),
+            Block::YellowBed => Some(0.2),
+            Block::LimeBed => Some(0.2),
+            Block::PinkBed => Some(0.2),
+            Block::GrayBed => Some(0.2),
+            Block::LightGrayBed => Some(0.2),
+            Block::CyanBed => Some(0.2),
+            Block::PurpleBed => Some(0.2),
+            Block::BlueBed => Some(0.2),
+            Block::BrownBed => Some(0.2),
+            Block::GreenBed => Some(0.2),
+            Block::RedBed => Some(0.2),
+            Block::BlackBed => Some(0.2),
+            Block::PoweredRail => Some(0.7),
+            Block::DetectorRail => Some(0.7),
+            Block::StickyPiston => Some(1.5),
+            Block::Cobweb => Some(4.0),
+            Block::Grass => Some(0.0),
+            Block::Fern => Some(0.0),
+            Block::DeadBush => Some(0.0),
+            Block::Seagrass => Some(0.0),
+            Block::TallSeagrass => Some(0.0),
+            Block::Piston => Some(1.5),
+            Block::PistonHead => Some(1.5),
+            Block::WhiteWool => Some(0.8),
+            Block::OrangeWool => Some(0.8),
+            Block::MagentaWool => Some(0.8),
+            Block::LightBlueWool => Some(0.8),
+            Block::YellowWool => Some(0.8),
+            Block::LimeWool => Some(0.8),
+            Block::PinkWool => Some(0.8),
+            Block::GrayWool => Some(0.8),
+            Block::LightGrayWool => Some(0.8),
+            Block::CyanWool => Some(0.8),
+            Block::PurpleWool => Some(0.8),
+            Block::BlueWool => Some(0.8),
+            Block::BrownWool => Some(0.8),
+            Block::GreenWool => Some(0.8),
+            Block::RedWool => Some(0.8),
+            Block::BlackWool => Some(0.8),
+            Block::MovingPiston => None,
+            Block::Dandelion => Some(0.0),
+            Block::Poppy => Some(0.0),
+            Block::BlueOrchid => Some(0.0),
+            Block::Allium => Some(0.0),
+            Block::AzureBluet => Some(0.0),
+            Block::RedTulip => Some(0.0),
+            Block::OrangeTulip => Some(0.0),
+            Block::WhiteTulip => Some(0.0),
+            Block::PinkTulip => Some(0.0),
+            Block::OxeyeDaisy => Some(0.0),
+            Block::Cornflower => Some(0.0),
+            Block::WitherRose => Some(0.0),
+            Block::LilyOfTheValley => Some(0.0),
+            Block::BrownMushroom => Some(0.0),
+            Block::RedMushroom => Some(0.0),
+            Block::GoldBlock => Some(3.0),
+            Block::IronBlock => Some(5.0),
+            Block::Bricks => Some(2.0),
+            Block::Tnt => Some(0.0),
+            Block::Bookshelf => Some(1.5),
+            Block::MossyCobblestone => Some(2.0),
+            Block::Obsidian => Some(50.0),
+            Block::Torch => Some(0.0),
+            Block::WallTorch => Some(0.0),
+            Block::Fire => Some(0.0),
+            Block::Spawner => Some(5.0),
+            Block::OakStairs => Some(2.0),
+            Block::Chest => Some(2.5),
+            Block::RedstoneWire => Some(0.0),
+            Block::DiamondOre => Some(3.0),
+            Block::DiamondBlock => Some(5.0),
+            Block::CraftingTable => Some(2.5),
+            Block::Wheat => Some(0.0),
+            Block::Farmland => Some(0.6),
+            Block::Furnace => Some(3.5),
+            Block::OakSign => Some(1.0),
+            Block::SpruceSign => Some(1.0),
+            Block::BirchSign => Some(1.0),
+            Block::AcaciaSign => Some(1.0),
+            Block::JungleSign => Some(1.0),
+            Block::DarkOakSign => Some(1.0),
+            Block::OakDoor => Some(3.0),
+            Block::Ladder => Some(0.4),
+            Block::Rail => Some(0.7),
+            Block::CobblestoneStairs => Some(2.0),
+            Block::OakWallSign => Some(1.0),
+            Block::SpruceWallSign => Some(1.0),
+            Block::BirchWallSign => Some(1.0),
+            Block::AcaciaWallSign => Some(1.0),
+            Block::JungleWallSign => Some(1.0),
+            Block::DarkOakWallSign => Some(1.0),
+            Block::Lever => Some(0.5),
+            Block::StonePressurePlate => Some(0.5),
+            Block::IronDoor => Some(5.0),
+            Block::OakPressurePlate => Some(0.5),
+            Block::SprucePressurePlate => Some(0.5),
+            Block::BirchPressurePlate => Some(0.5),
+            Block::JunglePressurePlate => Some(0.5),
+            Block::AcaciaPressurePlate => Some(0.5),
+            Block::DarkOakPressurePlate => Some(0.5),
+            Block::RedstoneOre => Some(3.0),
+            Block::RedstoneTorch => Some(0.0),
+            Block::RedstoneWallTorch => Some(0.0),
+            Block::StoneButton => Some(0.5),
+            Block::Snow => Some(0.1),
+            Block::Ice => Some(0.5),
+            Block::SnowBlock => Some(0.2),
+            Block::Cactus => Some(0.4),
+            Block::Clay => Some(0.6),
+            Block::SugarCane => Some(0.0),
+            Block::Jukebox => Some(2.0),
+            Block::OakFence => Some(2.0),
+            Block::Pumpkin => Some(1.0),
+            Block::Netherrack => Some(0.4),
+            Block::SoulSand => Some(0.5),
+            Block::Glowstone => Some(0.3),
+            Block::NetherPortal => None,
+            Block::CarvedPumpkin => Some(1.0),
+            Block::JackOLantern => Some(1.0),
+            Block::Cake => Some(0.5),
+            Block::Repeater => Some(0.0),
+            Block::WhiteStainedGlass => Some(0.3),
+            Block::OrangeStainedGlass => Some(0.3),
+            Block::MagentaStainedGlass => Some(0.3),
+            Block::LightBlueStainedGlass => Some(0.3),
+            Block::YellowStainedGlass => Some(0.3),
+            Block::LimeStainedGlass => Some(0.3),
+            Block::PinkStainedGlass => Some(0.3),
+            Block::GrayStainedGlass => Some(0.3),
+            Block::LightGrayStainedGlass => Some(0.3),
+            Block::CyanStainedGlass => Some(0.3),
+            Block::PurpleStainedGlass => Some(0.3),
+            Block::BlueStainedGlass => Some(0.3),
+            Block::BrownStainedGlass => Some(0.3),
+            Block::GreenStainedGlass => Some(0.3),
+            Block::RedStainedGlass => Some(0.3),
+            Block::BlackStainedGlass => Some(0.3),
+            Block::OakTrapdoor => Some(3.0),
+            Block::SpruceTrapdoor => Some(3.0),
+            Block::BirchTrapdoor => Some(3.0),
+            Block::JungleTrapdoor => Some(3.0),
+            Block::AcaciaTrapdoor => Some(3.0),
+            Block::DarkOakTrapdoor => Some(3.0),
+            Block::StoneBricks => Some(1.5),
+            Block::MossyStoneBricks => Some(1.5),
+            Block::CrackedStoneBricks => Some(1.5),
+            Block::ChiseledStoneBricks => Some(1.5),
+            Block::InfestedStone => Some(0.75),
+            Block::InfestedCobblestone => Some(1.0),
+            Block::InfestedStoneBricks => Some(0.75),
+            Block::InfestedMossyStoneBricks => Some(0.75),
+            Block::InfestedCrackedStoneBricks => Some(0.75),
+            Block::InfestedChiseledStoneBricks => Some(0.75),
+            Block::BrownMushroomBlock => Some(0.2),
+            Block::RedMushroomBlock => Some(0.2),
+            Block::MushroomStem => Some(0.2),
+            Block::IronBars => Some(5.0),
+            Block::GlassPane => Some(0.3),
+            Block::Melon => Some(1.0),
+            Block::AttachedPumpkinStem => Some(0.0),
+            Block::AttachedMelonStem => Some(0.0),
+            Block::PumpkinStem => Some(0.0),
+            Block::MelonStem => Some(0.0),
+            Block::Vine => Some(0.2),
+            Block::OakFenceGate => Some(2.0),
+            Block::BrickStairs => Some(2.0),
+            Block::StoneBrickStairs => Some(1.5),
+            Block::Mycelium => Some(0.6),
+            Block::LilyPad => Some(0.0),
+            Block::NetherBricks => Some(2.0),
+            Block::NetherBrickFence => Some(2.0),
+            Block::NetherBrickStairs => Some(2.0),
+            Block::NetherWart => Some(0.0),
+            Block::EnchantingTable => Some(5.0),
+            Block::BrewingStand => Some(0.5),
+            Block::Cauldron => Some(2.0),
+            Block::EndPortal => None,
+            Block::EndPortalFrame => None,
+            Block::EndStone => Some(3.0),
+            Block::DragonEgg => Some(3.0),
+            Block::RedstoneLamp => Some(0.3),
+            Block::Cocoa => Some(0.2),
+            Block::SandstoneStairs => Some(0.8),
+            Block::EmeraldOre => Some(3.0),
+            Block::EnderChest => Some(22.5),
+            Block::TripwireHook => Some(0.0),
+            Block::Tripwire => Some(0.0),
+            Block::EmeraldBlock => Some(5.0),
+            Block::SpruceStairs => Some(2.0),
+            Block::BirchStairs => Some(2.0),
+            Block::JungleStairs => Some(2.0),
+            Block::CommandBlock => None,
+            Block::Beacon => Some(3.0),
+            Block::CobblestoneWall => Some(2.0),
+            Block::MossyCobblestoneWall => Some(2.0),
+            Block::FlowerPot => Some(0.0),
+            Block::PottedOakSapling => Some(0.0),
+            Block::PottedSpruceSapling => Some(0.0),
+            Block::PottedBirchSapling => Some(0.0),
+            Block::PottedJungleSapling => Some(0.0),
+            Block::PottedAcaciaSapling => Some(0.0),
+            Block::PottedDarkOakSapling => Some(0.0),
+            Block::PottedFern => Some(0.0),
+            Block::PottedDandelion => Some(0.0),
+            Block::PottedPoppy => Some(0.0),
+            Block::PottedBlueOrchid => Some(0.0),
+            Block::PottedAllium => Some(0.0),
+            Block::PottedAzureBluet => Some(0.0),
+            Block::PottedRedTulip => Some(0.0),
+            Block::PottedOrangeTulip => Some(0.0),
+            Block::PottedWhiteTulip => Some(0.0),
+            Block::PottedPinkTulip => Some(0.0),
+            Block::PottedOxeyeDaisy => Some(0.0),
+            Block::PottedCornflower => Some(0.0),
+            Block::PottedLilyOfTheValley => Some(0.0),
+            Block::PottedWitherRose => Some(0.0),
+            Block::PottedRedMushroom => Some(0.0),
+            Block::PottedBrownMushroom => Some(0.0),
+            Block::PottedDeadBush => Some(0.0),
+            Block::PottedCactus => Some(0.0),
+            Block::Carrots => Some(0.0),
+            Block::Potatoes => Some(0.0),
+            Block::OakButton => Some(0.5),
+            Block::SpruceButton => Some(0.5),
+            Block::BirchButton => Some(0.5),
+            Block::JungleButton => Some(0.5),
+            Block::AcaciaButton => Some(0.5),
+            Block::DarkOakButton => Some(0.5),
+            Block::SkeletonSkull => Some(1.0),
+            Block::SkeletonWallSkull => Some(1.0),
+            Block::WitherSkeletonSkull => Some(1.0),
+            Block::WitherSkeletonWallSkull => Some(1.0),
+            Block::ZombieHead => Some(1.0),
+            Block::ZombieWallHead => Some(1.0),
+            Block::PlayerHead => Some(1.0),
+            Block::PlayerWallHead => Some(1.0),
+            Block::CreeperHead => Some(1.0),
+            Block::CreeperWallHead => Some(1.0),
+            Block::DragonHead => Some(1.0),
+            Block::DragonWallHead => Some(1.0),
+            Block::Anvil => Some(5.0),
+            Block::ChippedAnvil => Some(5.0),
+            Block::DamagedAnvil => Some(5.0),
+            Block::TrappedChest => Some(2.5),
+            Block::LightWeightedPressurePlate => Some(0.5),
+            Block::HeavyWeightedPressurePlate => Some(0.5),
+            Block::Comparator => Some(0.0),
+            Block::DaylightDetector => Some(0.2),
+            Block::RedstoneBlock => Some(5.0),
+            Block::NetherQuartzOre => Some(3.0),
+            Block::Hopper => Some(3.0),
+            Block::QuartzBlock => Some(0.8),
+            Block::ChiseledQuartzBlock => Some(0.8),
+            Block::QuartzPillar => Some(0.8),
+            Block::QuartzStairs => Some(0.8),
+            Block::ActivatorRail => Some(0.7),
+            Block::Dropper => Some(3.5),
+            Block::WhiteTerracotta => Some(1.25),
+            Block::OrangeTerracotta => Some(1.25),
+            Block::MagentaTerracotta => Some(1.25),
+            Block::LightBlueTerracotta => Some(1.25),
+            Block::YellowTerracotta => Some(1.25),
+            Block::LimeTerracotta => Some(1.25),
+            Block::PinkTerracotta => Some(1.25),
+            Block::GrayTerracotta => Some(1.25),
+            Block::LightGrayTerracotta => Some(1.25),
+            Block::CyanTerracotta => Some(1.25),
+            Block::PurpleTerracotta => Some(1.25),
+            Block::BlueTerracotta => Some(1.25),
+            Block::BrownTerracotta => Some(1.25),
+            Block::GreenTerracotta => Some(1.25),
+            Block::RedTerracotta => Some(1.25),
+            Block::BlackTerracotta => Some(1.25),
+            Block::WhiteStainedGlassPane => Some(0.3),
+            Block::OrangeStainedGlassPane => Some(0.3),
+            Block::MagentaStainedGlassPane => Some(0.3),
+            Block::LightBlueStainedGlassPane => Some(0.3),
+            Block::YellowStainedGlassPane => Some(0.3),
+            Block::LimeStainedGlassPane => Some(0.3),
+            Block::PinkStainedGlassPane => Some(0.3),
+            Block::GrayStainedGlassPane => Some(0.3),
+            Block::LightGrayStainedGlassPane => Some(0.3),
+            Block::CyanStainedGlassPane => Some(0.3),
+            Block::PurpleStainedGlassPane => Some(0.3),
+            Block::BlueStainedGlassPane => Some(0.3),
+            Block::BrownStainedGlassPane => Some(0.3),
+            Block::GreenStainedGlassPane => Some(0.3),
+            Block::RedStainedGlassPane => Some(0.3),
+            Block::BlackStainedGlassPane => Some(0.3),
+            Block::AcaciaStairs => Some(2.0),
+            Block::DarkOakStairs => Some(2.0),
+            Block::SlimeBlock => Some(0.0),
+            Block::Barrier => None,
+            Block::IronTrapdoor => Some(5.0),
+            Block::Prismarine => Some(1.5),
+            Block::PrismarineBricks => Some(1.5),
+            Block::DarkPrismarine => Some(1.5),
+            Block::PrismarineStairs => Some(1.5),
+            Block::PrismarineBrickStairs => Some(1.5),
+            Block::DarkPrismarineStairs => Some(1.5),
+            Block::PrismarineSlab => Some(1.5),
+            Block::PrismarineBrickSlab => Some(1.5),
+            Block::DarkPrismarineSlab => Some(1.5),
+            Block::SeaLantern => Some(0.3),
+            Block::HayBlock => Some(0.5),
+            Block::WhiteCarpet => Some(0.1),
+            Block::OrangeCarpet => Some(0.1),
+            Block::MagentaCarpet => Some(0.1),
+            Block::LightBlueCarpet => Some(0.1),
+            Block::YellowCarpet => Some(0.1),
+            Block::LimeCarpet => Some(0.1),
+            Block::PinkCarpet => Some(0.1),
+            Block::GrayCarpet => Some(0.1),
+            Block::LightGrayCarpet => Some(0.1),
+            Block::CyanCarpet => Some(0.1),
+            Block::PurpleCarpet => Some(0.1),
+            Block::BlueCarpet => Some(0.1),
+            Block::BrownCarpet => Some(0.1),
+            Block::GreenCarpet => Some(0.1),
+            Block::RedCarpet => Some(0.1),
+            Block::BlackCarpet => Some(0.1),
+            Block::Terracotta => Some(1.25),
+            Block::CoalBlock => Some(5.0),
+            Block::PackedIce => Some(0.5),
+            Block::Sunflower => Some(0.0),
+            Block::Lilac => Some(0.0),
+            Block::RoseBush => Some(0.0),
+            Block::Peony => Some(0.0),
+            Block::TallGrass => Some(0.0),
+            Block::LargeFern => Some(0.0),
+            Block::WhiteBanner => Some(1.0),
+            Block::OrangeBanner => Some(1.0),
+            Block::MagentaBanner => Some(1.0),
+            Block::LightBlueBanner => Some(1.0),
+            Block::YellowBanner => Some(1.0),
+            Block::LimeBanner => Some(1.0),
+            Block::PinkBanner => Some(1.0),
+            Block::GrayBanner => Some(1.0),
+            Block::LightGrayBanner => Some(1.0),
+            Block::CyanBanner => Some(1.0),
+            Block::PurpleBanner => Some(1.0),
+            Block::BlueBanner => Some(1.0),
+            Block::BrownBanner => Some(1.0),
+            Block::GreenBanner => Some(1.0),
+            Block::RedBanner => Some(1.0),
+            Block::BlackBanner => Some(1.0),
+            Block::WhiteWallBanner => Some(1.0),
+            Block::OrangeWallBanner => Some(1.0),
+            Block::MagentaWallBanner => Some(1.0),
+            Block::LightBlueWallBanner => Some(1.0),
+            Block::YellowWallBanner => Some(1.0),
+            Block::LimeWallBanner => Some(1.0),
+            Block::PinkWallBanner => Some(1.0),
+            Block::GrayWallBanner => Some(1.0),
+            Block::LightGrayWallBanner => Some(1.0),
+            Block::CyanWallBanner => Some(1.0),
+            Block::PurpleWallBanner => Some(1.0),
+            Block::BlueWallBanner => Some(1.0),
+            Block::BrownWallBanner => Some(1.0),
+            Block::GreenWallBanner => Some(1.0),
+            Block::RedWallBanner => Some(1.0),
+            Block::BlackWallBanner => Some(1.0),
+            Block::RedSandstone => Some(0.8),
+            Block::ChiseledRedSandstone => Some(0.8),
+            Block::CutRedSandstone => Some(0.8),
+            Block::RedSandstoneStairs => Some(0.8),
+            Block::OakSlab => Some(2.0),
+            Block::SpruceSlab => Some(2.0),
+            Block::BirchSlab => Some(2.0),
+            Block::JungleSlab => Some(2.0),
+            Block::AcaciaSlab => Some(2.0),
+            Block::DarkOakSlab => Some(2.0),
+            Block::StoneSlab => Some(2.0),
+            Block::SmoothStoneSlab => Some(2.0),
+            Block::SandstoneSlab => Some(2.0),
+            Block::CutSandstoneSlab => Some(2.0),
+            Block::PetrifiedOakSlab => Some(2.0),
+            Block::CobblestoneSlab => Some(2.0),
+            Block::BrickSlab => Some(2.0),
+            Block::StoneBrickSlab => Some(2.0),
+            Block::NetherBrickSlab => Some(2.0),
+            Block::QuartzSlab => Some(2.0),
+            Block::RedSandstoneSlab => Some(2.0),
+            Block::CutRedSandstoneSlab => Some(2.0),
+            Block::PurpurSlab => Some(2.0),
+            Block::SmoothStone => Some(2.0),
+            Block::SmoothSandstone => Some(2.0),
+            Block::SmoothQuartz => Some(2.0),
+            Block::SmoothRedSandstone => Some(2.0),
+            Block::SpruceFenceGate => Some(2.0),
+            Block::BirchFenceGate => Some(2.0),
+            Block::JungleFenceGate => Some(2.0),
+            Block::AcaciaFenceGate => Some(2.0),
+            Block::DarkOakFenceGate => Some(2.0),
+            Block::SpruceFence => Some(2.0),
+            Block::BirchFence => Some(2.0),
+            Block::JungleFence => Some(2.0),
+            Block::AcaciaFence => Some(2.0),
+            Block::DarkOakFence => Some(2.0),
+            Block::SpruceDoor => Some(3.0),
+            Block::BirchDoor => Some(3.0),
+            Block::JungleDoor => Some(3.0),
+            Block::AcaciaDoor => Some(3.0),
+            Block::DarkOakDoor => Some(3.0),
+            Block::EndRod => Some(0.0),
+            Block::ChorusPlant => Some(0.4),
+            Block::ChorusFlower => Some(0.4),
+            Block::PurpurBlock => Some(1.5),
+            Block::PurpurPillar => Some(1.5),
+            Block::PurpurStairs => Some(1.5),
+            Block::EndStoneBricks => Some(3.0),
+            Block::Beetroots => Some(0.0),
+            Block::GrassPath => Some(0.65),
+            Block::EndGateway => None,
+            Block::RepeatingCommandBlock => None,
+            Block::ChainCommandBlock => None,
+            Block::FrostedIce => Some(0.5),
+            Block::MagmaBlock => Some(0.5),
+            Block::NetherWartBlock => Some(1.0),
+            Block::RedNetherBricks => Some(2.0),
+            Block::BoneBlock => Some(2.0),
+            Block::StructureVoid => Some(0.0),
+            Block::Observer => Some(3.0),
+            Block::ShulkerBox => Some(2.0),
+            Block::WhiteShulkerBox => Some(2.0),
+            Block::OrangeShulkerBox => Some(2.0),
+            Block::MagentaShulkerBox => Some(2.0),
+            Block::LightBlueShulkerBox => Some(2.0),
+            Block::YellowShulkerBox => Some(2.0),
+            Block::LimeShulkerBox => Some(2.0),
+            Block::PinkShulkerBox => Some(2.0),
+            Block::GrayShulkerBox => Some(2.0),
+            Block::LightGrayShulkerBox => Some(2.0),
+            Block::CyanShulkerBox => Some(2.0),
+            Block::PurpleShulkerBox => Some(2.0),
+            Block::BlueShulkerBox => Some(2.0),
+            Block::BrownShulkerBox => Some(2.0),
+            Block::GreenShulkerBox => Some(2.0),
+            Block::RedShulkerBox => Some(2.0),
+            Block::BlackShulkerBox => Some(2.0),
+            Block::WhiteGlazedTerracotta => Some(1.4),
+            Block::OrangeGlazedTerracotta => Some(1.4),
+            Block::MagentaGlazedTerracotta => Some(1.4),
+            Block::LightBlueGlazedTerracotta => Some(1.4),
+            Block::YellowGlazedTerracotta => Some(1.4),
+            Block::LimeGlazedTerracotta => Some(1.4),
+            Block::PinkGlazedTerracotta => Some(1.4),
+            Block::GrayGlazedTerracotta => Some(1.4),
+            Block::LightGrayGlazedTerracotta => Some(1.4),
+            Block::CyanGlazedTerracotta => Some(1.4),
+            Block::PurpleGlazedTerracotta => Some(1.4),
+            Block::BlueGlazedTerracotta => Some(1.4),
+            Block::BrownGlazedTerracotta => Some(1.4),
+            Block::GreenGlazedTerracotta => Some(1.4),
+            Block::RedGlazedTerracotta => Some(1.4),
+            Block::BlackGlazedTerracotta => Some(1.4),
+            Block::WhiteConcrete => Some(1.8),
+            Block::OrangeConcrete => Some(1.8),
+            Block::MagentaConcrete => Some(1.8),
+            Block::LightBlueConcrete => Some(1.8),
+            Block::YellowConcrete => Some(1.8),
+            Block::LimeConcrete => Some(1.8),
+            Block::PinkConcrete => Some(1.8),
+            Block::GrayConcrete => Some(1.8),
+            Block::LightGrayConcrete => Some(1.8),
+            Block::CyanConcrete => Some(1.8),
+            Block::PurpleConcrete => Some(1.8),
+            Block::BlueConcrete => Some(1.8),
+            Block::BrownConcrete => Some(1.8),
+            Block::GreenConcrete => Some(1.8),
+            Block::RedConcrete => Some(1.8),
+            Block::BlackConcrete => Some(1.8),
+            Block::WhiteConcretePowder => Some(0.5),
+            Block::OrangeConcretePowder => Some(0.5),
+            Block::MagentaConcretePowder => Some(0.5),
+            Block::LightBlueConcretePowder => Some(0.5),
+            Block::YellowConcretePowder => Some(0.5),
+            Block::LimeConcretePowder => Some(0.5),
+            Block::PinkConcretePowder => Some(0.5),
+            Block::GrayConcretePowder => Some(0.5),
+            Block::LightGrayConcretePowder => Some(0.5),
+            Block::CyanConcretePowder => Some(0.5),
+            Block::PurpleConcretePowder => Some(0.5),
+            Block::BlueConcretePowder => Some(0.5),
+            Block::BrownConcretePowder => Some(0.5),
+            Block::GreenConcretePowder => Some(0.5),
+            Block::RedConcretePowder => Some(0.5),
+            Block::BlackConcretePowder => Some(0.5),
+            Block::Kelp => Some(0.0),
+            Block::KelpPlant => Some(0.0),
+            Block::DriedKelpBlock => Some(0.5),
+            Block::TurtleEgg => Some(0.5),
+            Block::DeadTubeCoralBlock => Some(1.5),
+            Block::DeadBrainCoralBlock => Some(1.5),
+            Block::DeadBubbleCoralBlock => Some(1.5),
+            Block::DeadFireCoralBlock => Some(1.5),
+            Block::DeadHornCoralBlock => Some(1.5),
+            Block::TubeCoralBlock => Some(1.5),
+            Block::BrainCoralBlock => Some(1.5),
+            Block::BubbleCoralBlock => Some(1.5),
+            Block::FireCoralBlock => Some(1.5),
+            Block::HornCoralBlock => Some(1.5),
+            Block::DeadTubeCoral => Some(0.0),
+            Block::DeadBrainCoral => Some(0.0),
+            Block::DeadBubbleCoral => Some(0.0),
+            Block::DeadFireCoral => Some(0.0),
+            Block::DeadHornCoral => Some(0.0),
+            Block::TubeCoral => Some(0.0),
+            Block::BrainCoral => Some(0.0),
+            Block::BubbleCoral => Some(0.0),
+            Block::FireCoral => Some(0.0),
+            Block::HornCoral => Some(0.0),
+            Block::DeadTubeCoralFan => Some(0.0),
+            Block::DeadBrainCoralFan => Some(0.0),
+            Block::DeadBubbleCoralFan => Some(0.0),
+            Block::DeadFireCoralFan => Some(0.0),
+            Block::DeadHornCoralFan => Some(0.0),
+            Block::TubeCoralFan => Some(0.0),
+            Block::BrainCoralFan => Some(0.0),
+            Block::BubbleCoralFan => Some(0.0),
+            Block::FireCoralFan => Some(0.0),
+            Block::HornCoralFan => Some(0.0),
+            Block::DeadTubeCoralWallFan => Some(0.0),
+            Block::DeadBrainCoralWallFan => Some(0.0),
+            Block::DeadBubbleCoralWallFan => Some(0.0),
+            Block::DeadFireCoralWallFan => Some(0.0),
+            Block::DeadHornCoralWallFan => Some(0.0),
+            Block::TubeCoralWallFan => Some(0.0),
+            Block::BrainCoralWallFan => Some(0.0),
+            Block::BubbleCoralWallFan => Some(0.0),
+            Block::FireCoralWallFan => Some(0.0),
+            Block::HornCoralWallFan => Some(0.0),
+            Block::SeaPickle => Some(0.0),
+            Block::BlueIce => Some(2.8),
+            Block::Conduit => Some(3.0),
+            Block::BambooSapling => Some(1.0),
+            Block::Bamboo => Some(1.0),
+            Block::PottedBamboo => Some(0.0),
+            Block::VoidAir => Some(0.0),
+            Block::CaveAir => Some(0.0),
+            Block::BubbleColumn => Some(0.0),
+            Block::PolishedGraniteStairs => Some(1.5),
+            Block::SmoothRedSandstoneStairs => Some(2.0),
+            Block::MossyStoneBrickStairs => Some(1.5),
+            Block::PolishedDioriteStairs => Some(1.5),
+            Block::MossyCobblestoneStairs => Some(2.0),
+            Block::EndStoneBrickStairs => Some(3.0),
+            Block::StoneStairs => Some(1.5),
+            Block::SmoothSandstoneStairs => Some(2.0),
+            Block::SmoothQuartzStairs => Some(2.0),
+            Block::GraniteStairs => Some(1.5),
+            Block::AndesiteStairs => Some(1.5),
+            Block::RedNetherBrickStairs => Some(2.0),
+            Block::PolishedAndesiteStairs => Some(1.5),
+            Block::DioriteStairs => Some(1.5),
+            Block::PolishedGraniteSlab => Some(1.5),
+            Block::SmoothRedSandstoneSlab => Some(2.0),
+            Block::MossyStoneBrickSlab => Some(1.5),
+            Block::PolishedDioriteSlab => Some(1.5),
+            Block::MossyCobblestoneSlab => Some(2.0),
+            Block::EndStoneBrickSlab => Some(3.0),
+            Block::SmoothSandstoneSlab => Some(2.0),
+            Block::SmoothQuartzSlab => Some(2.0),
+            Block::GraniteSlab => Some(1.5),
+            Block::AndesiteSlab => Some(1.5),
+            Block::RedNetherBrickSlab => Some(2.0),
+            Block::PolishedAndesiteSlab => Some(1.5),
+            Block::DioriteSlab => Some(1.5),
+            Block::BrickWall => Some(2.0),
+            Block::PrismarineWall => Some(1.5),
+            Block::RedSandstoneWall => Some(0.8),
+            Block::MossyStoneBrickWall => Some(1.5),
+            Block::GraniteWall => Some(1.5),
+            Block::StoneBrickWall => Some(1.5),
+            Block::NetherBrickWall => Some(2.0),
+            Block::AndesiteWall => Some(1.5),
+            Block::RedNetherBrickWall => Some(2.0),
+            Block::SandstoneWall => Some(0.8),
+            Block::EndStoneBrickWall => Some(3.0),
+            Block::DioriteWall => Some(1.5),
+            Block::Scaffolding => Some(0.0),
+            Block::Loom => Some(2.5),
+            Block::Barrel => Some(2.5),
+            Block::Smoker => Some(3.5),
+            Block::BlastFurnace => Some(3.5),
+            Block::CartographyTable => Some(2.5),
+            Block::FletchingTable => Some(2.5),
+            Block::Grindstone => Some(2.0),
+            Block::Lectern => Some(2.5),
+            Block::SmithingTable => Some(2.5),
+            Block::Stonecutter => Some(3.5),
+            Block::Bell => Some(5.0),
+            Block::Lantern => Some(3.5),
+            Block::Campfire => Some(2.0),
+            Block::SweetBerryBush => Some(0.0),
+            Block::StructureBlock => None,
+            Block::Jigsaw => None,
+            Block::Composter => Some(0.6),
+            Block::BeeNest => Some(0.3),
+            Block::Beehive => Some(0.6),
+            Block::HoneyBlock => Some(0.0),
+            Block::HoneycombBlock => Some(0.6),
+        }
+    }
+    pub fn diggable(&self) -> bool {
+        match self {
+            Block::Air => false,
+            Block::Stone => true,
+            Block::Granite => true,
+            Block::PolishedGranite => true,
+            Block::Diorite => true,
+            Block::PolishedDiorite => true,
+            Block::Andesite => true,
+            Block::PolishedAndesite => true,
+            Block::GrassBlock => true,
+            Block::Dirt => true,
+            Block::CoarseDirt => true,
+            Block::Podzol => true,
+            Block::Cobblestone => true,
+            Block::OakPlanks => true,
+            Block::SprucePlanks => true,
+            Block::BirchPlanks => true,
+            Block::JunglePlanks => true,
+            Block::AcaciaPlanks => true,
+            Block::DarkOakPlanks => true,
+            Block::OakSapling => true,
+            Block::SpruceSapling => true,
+            Block::BirchSapling => true,
+            Block::JungleSapling => true,
+            Block::AcaciaSapling => true,
+            Block::DarkOakSapling => true,
+            Block::Bedrock => false,
+            Block::Water => false,
+            Block::Lava => false,
+            Block::Sand => true,
+            Block::RedSand => true,
+            Block::Gravel => true,
+            Block::GoldOre => true,
+            Block::IronOre => true,
+            Block::CoalOre => true,
+            Block::OakLog => true,
+            Block::SpruceLog => true,
+            Block::BirchLog => true,
+            Block::JungleLog => true,
+            Block::AcaciaLog => true,
+            Block::DarkOakLog => true,
+            Block::StrippedSpruceLog => true,
+            Block::StrippedBirchLog => true,
+            Block::StrippedJungleLog => true,
+            Block::StrippedAcaciaLog => true,
+            Block::StrippedDarkOakLog => true,
+            Block::StrippedOakLog => true,
+            Block::OakWood => true,
+            Block::SpruceWood => true,
+            Block::BirchWood => true,
+            Block::JungleWood => true,
+            Block::AcaciaWood => true,
+            Block::DarkOakWood => true,
+            Block::StrippedOakWood => true,
+            Block::StrippedSpruceWood => true,
+            Block::StrippedBirchWood => true,
+            Block::StrippedJungleWood => true,
+            Block::StrippedAcaciaWood => true,
+            Block::StrippedDarkOakWood => true,
+            Block::OakLeaves => true,
+            Block::SpruceLeaves => true,
+            Block::BirchLeaves => true,
+            Block::JungleLeaves => true,
+            Block::AcaciaLeaves => true,
+            Block::DarkOakLeaves => true,
+            Block::Sponge => true,
+            Block::WetSponge => true,
+            Block::Glass => true,
+            Block::LapisOre => true,
+            Block::LapisBlock => true,
+            Block::Dispenser => true,
+            Block::Sandstone => true,
+            Block::ChiseledSandstone => true,
+            Block::CutSandstone => true,
+            Block::NoteBlock => true,
+            Block::WhiteBed => true,
+            Block::OrangeBed => true,
+            Block::MagentaBed => true,
+            Block::LightBlueBed => true,
+            Block::YellowBed => true,
+            Block::LimeBed => true,
+            Block::PinkBed => true,
+            Block::GrayBed => true,
+            Block::LightGrayBed => true,
+            Block::CyanBed => true,
+            Block::PurpleBed => true,
+            Block::BlueBed => true,
+            Block::BrownBed => true,
+            Block::GreenBed => true,
+            Block::RedBed => true,
+            Block::BlackBed => true,
+            Block::PoweredRail => true,
+            Block::DetectorRail => true,
+            Block::StickyPiston => true,
+            Block::Cobweb => true,
+            Block::Grass => true,
+            Block::Fern => true,
+            Block::DeadBush => true,
+            Block::Seagrass => true,
+            Block::TallSeagrass => true,
+            Block::Piston => true,
+            Block::PistonHead => true,
+            Block::WhiteWool => true,
+            Block::OrangeWool => true,
+            Block::MagentaWool => true,
+            Block::LightBlueWool => true,
+            Block::YellowWool => true,
+            Block::LimeWool => true,
+            Block::PinkWool => true,
+            Block::GrayWool => true,
+            Block::LightGrayWool => true,
+            Block::CyanWool => true,
+            Block::PurpleWool => true,
+            Block::BlueWool => true,
+            Block::BrownWool => true,
+            Block::GreenWool => true,
+            Block::RedWool => true,
+            Block::BlackWool => true,
+            Block::MovingPiston => false,
+            Block::Dandelion => true,
+            Block::Poppy => true,
+            Block::BlueOrchid => true,
+            Block::Allium => true,
+            Block::AzureBluet => true,
+            Block::RedTulip => true,
+            Block::OrangeTulip => true,
+            Block::WhiteTulip => true,
+            Block::PinkTulip => true,
+            Block::OxeyeDaisy => true,
+            Block::Cornflower => true,
+            Block::WitherRose => true,
+            Block::LilyOfTheValley => true,
+            Block::BrownMushroom => true,
+            Block::RedMushroom => true,
+            Block::GoldBlock => true,
+            Block::IronBlock => true,
+            Block::Bricks => true,
+            Block::Tnt => true,
+            Block::Bookshelf => true,
+            Block::MossyCobblestone => true,
+            Block::Obsidian => true,
+            Block::Torch => true,
+            Block::WallTorch => true,
+            Block::Fire => true,
+            Block::Spawner => true,
+            Block::OakStairs => true,
+            Block::Chest => true,
+            Block::RedstoneWire => true,
+            Block::DiamondOre => true,
+            Block::DiamondBlock => true,
+            Block::CraftingTable => true,
+            Block::Wheat => true,
+            Block::Farmland => true,
+            Block::Furnace => true,
+            Block::OakSign => true,
+            Block::SpruceSign => true,
+            Block::BirchSign => true,
+            Block::AcaciaSign => true,
+            Block::JungleSign => true,
+            Block::DarkOakSign => true,
+            Block::OakDoor => true,
+            Block::Ladder => true,
+            Block::Rail => true,
+            Block::CobblestoneStairs => true,
+            Block::OakWallSign => true,
+            Block::SpruceWallSign => true,
+            Block::BirchWallSign => true,
+            Block::AcaciaWallSign => true,
+            Block::JungleWallSign => true,
+            Block::DarkOakWallSign => true,
+            Block::Lever => true,
+            Block::StonePressurePlate => true,
+            Block::IronDoor => true,
+            Block::OakPressurePlate => true,
+            Block::SprucePressurePlate => true,
+            Block::BirchPressurePlate => true,
+            Block::JunglePressurePlate => true,
+            Block::AcaciaPressurePlate => true,
+            Block::DarkOakPressurePlate => true,
+            Block::RedstoneOre => true,
+            Block::RedstoneTorch => true,
+            Block::RedstoneWallTorch => true,
+            Block::StoneButton => true,
+            Block::Snow => true,
+            Block::Ice => true,
+            Block::SnowBlock => true,
+            Block::Cactus => true,
+            Block::Clay => true,
+            Block::SugarCane => true,
+            Block::Jukebox => true,
+            Block::OakFence => true,
+            Block::Pumpkin => true,
+            Block::Netherrack => true,
+            Block::SoulSand => true,
+            Block::Glowstone => true,
+            Block::NetherPortal => false,
+            Block::CarvedPumpkin => true,
+            Block::JackOLantern => true,
+            Block::Cake => true,
+            Block::Repeater => true,
+            Block::WhiteStainedGlass => true,
+            Block::OrangeStainedGlass => true,
+            Block::MagentaStainedGlass => true,
+            Block::LightBlueStainedGlass => true,
+            Block::YellowStainedGlass => true,
+            Block::LimeStainedGlass => true,
+            Block::PinkStainedGlass => true,
+            Block::GrayStainedGlass => true,
+            Block::LightGrayStainedGlass => true,
+            Block::CyanStainedGlass => true,
+            Block::PurpleStainedGlass => true,
+            Block::BlueStainedGlass => true,
+            Block::BrownStainedGlass => true,
+            Block::GreenStainedGlass => true,
+            Block::RedStainedGlass => true,
+            Block::BlackStainedGlass => true,
+            Block::OakTrapdoor => true,
+            Block::SpruceTrapdoor => true,
+            Block::BirchTrapdoor => true,
+            Block::JungleTrapdoor => true,
+            Block::AcaciaTrapdoor => true,
+            Block::DarkOakTrapdoor => true,
+            Block::StoneBricks => true,
+            Block::MossyStoneBricks => true,
+            Block::CrackedStoneBricks => true,
+            Block::ChiseledStoneBricks => true,
+            Block::InfestedStone => true,
+            Block::InfestedCobblestone => true,
+            Block::InfestedStoneBricks => true,
+            Block::InfestedMossyStoneBricks => true,
+            Block::InfestedCrackedStoneBricks => true,
+            Block::InfestedChiseledStoneBricks => true,
+            Block::BrownMushroomBlock => true,
+            Block::RedMushroomBlock => true,
+            Block::MushroomStem => true,
+            Block::IronBars => true,
+            Block::GlassPane => true,
+            Block::Melon => true,
+            Block::AttachedPumpkinStem => true,
+            Block::AttachedMelonStem => true,
+            Block::PumpkinStem => true,
+            Block::MelonStem => true,
+            Block::Vine => true,
+            Block::OakFenceGate => true,
+            Block::BrickStairs => true,
+            Block::StoneBrickStairs => true,
+            Block::Mycelium => true,
+            Block::LilyPad => true,
+            Block::NetherBricks => true,
+            Block::NetherBrickFence => true,
+            Block::NetherBrickStairs => true,
+            Block::NetherWart => true,
+            Block::EnchantingTable => true,
+            Block::BrewingStand => true,
+            Block::Cauldron => true,
+            Block::EndPortal => false,
+            Block::EndPortalFrame => false,
+            Block::EndStone => true,
+            Block::DragonEgg => true,
+            Block::RedstoneLamp => true,
+            Block::Cocoa => true,
+            Block::SandstoneStairs => true,
+            Block::EmeraldOre => true,
+            Block::EnderChest => true,
+            Block::TripwireHook => true,
+            Block::Tripwire => true,
+            Block::EmeraldBlock => true,
+            Block::SpruceStairs => true,
+            Block::BirchStairs => true,
+            Block::JungleStairs => true,
+            Block::CommandBlock => false,
+            Block::Beacon => true,
+            Block::CobblestoneWall => true,
+            Block::MossyCobblestoneWall => true,
+            Block::FlowerPot => true,
+            Block::PottedOakSapling => true,
+            Block::PottedSpruceSapling => true,
+            Block::PottedBirchSapling => true,
+            Block::PottedJungleSapling => true,
+            Block::PottedAcaciaSapling => true,
+            Block::PottedDarkOakSapling => true,
+            Block::PottedFern => true,
+            Block::PottedDandelion => true,
+            Block::PottedPoppy => true,
+            Block::PottedBlueOrchid => true,
+            Block::PottedAllium => true,
+            Block::PottedAzureBluet => true,
+            Block::PottedRedTulip => true,
+            Block::PottedOrangeTulip => true,
+            Block::PottedWhiteTulip => true,
+            Block::PottedPinkTulip => true,
+            Block::PottedOxeyeDaisy => true,
+            Block::PottedCornflower => true,
+            Block::PottedLilyOfTheValley => true,
+            Block::PottedWitherRose => true,
+            Block::PottedRedMushroom => true,
+            Block::PottedBrownMushroom => true,
+            Block::PottedDeadBush => true,
+            Block::PottedCactus => true,
+            Block::Carrots => true,
+            Block::Potatoes => true,
+            Block::OakButton => true,
+            Block::SpruceButton => true,
+            Block::BirchButton => true,
+            Block::JungleButton => true,
+            Block::AcaciaButton => true,
+            Block::DarkOakButton => true,
+            Block::SkeletonSkull => true,
+            Block::SkeletonWallSkull => true,
+            Block::WitherSkeletonSkull => true,
+            Block::WitherSkeletonWallSkull => true,
+            Block::ZombieHead => true,
+            Block::ZombieWallHead => true,
+            Block::PlayerHead => true,
+            Block::PlayerWallHead => true,
+            Block::CreeperHead => true,
+            Block::CreeperWallHead => true,
+            Block::DragonHead => true,
+            Block::DragonWallHead => true,
+            Block::Anvil => true,
+            Block::ChippedAnvil => true,
+            Block::DamagedAnvil => true,
+            Block::TrappedChest => true,
+            Block::LightWeightedPressurePlate => true,
+            Block::HeavyWeightedPressurePlate => true,
+            Block::Comparator => true,
+            Block::DaylightDetector => true,
+            Block::RedstoneBlock => true,
+            Block::NetherQuartzOre => true,
+            Block::Hopper => true,
+            Block::QuartzBlock => true,
+            Block::ChiseledQuartzBlock => true,
+            Block::QuartzPillar => true,
+            Block::QuartzStairs => true,
+            Block::ActivatorRail => true,
+            Block::Dropper => true,
+            Block::WhiteTerracotta => true,
+            Block::OrangeTerracotta => true,
+            Block::MagentaTerracotta => true,
+            Block::LightBlueTerracotta => true,
+            Block::YellowTerracotta => true,
+            Block::LimeTerracotta => true,
+            Block::PinkTerracotta => true,
+            Block::GrayTerracotta => true,
+            Block::LightGrayTerracotta => true,
+            Block::CyanTerracotta => true,
+            Block::PurpleTerracotta => true,
+            Block::BlueTerracotta => true,
+            Block::BrownTerracotta => true,
+            Block::GreenTerracotta => true,
+            Block::RedTerracotta => true,
+            Block::BlackTerracotta => true,
+            Block::WhiteStainedGlassPane => true,
+            Block::OrangeStainedGlassPane => true,
+            Block::MagentaStainedGlassPane => true,
+            Block::LightBlueStainedGlassPane => true,
+            Block::YellowStainedGlassPane => true,
+            Block::LimeStainedGlassPane => true,
+            Block::PinkStainedGlassPane => true,
+            Block::GrayStainedGlassPane => true,
+            Block::LightGrayStainedGlassPane => true,
+            Block::CyanStainedGlassPane => true,
+            Block::PurpleStainedGlassPane => true,
+            Block::BlueStainedGlassPane => true,
+            Block::BrownStainedGlassPane => true,
+            Block::GreenStainedGlassPane => true,
+            Block::RedStainedGlassPane => true,
+            Block::BlackStainedGlassPane => true,
+            Block::AcaciaStairs => true,
+            Block::DarkOakStairs => true,
+            Block::SlimeBlock => true,
+            Block::Barrier => false,
+            Block::IronTrapdoor => true,
+            Block::Prismarine => true,
+            Block::PrismarineBricks => true,
+            Block::DarkPrismarine => true,
+            Block::PrismarineStairs => true,
+            Block::PrismarineBrickStairs => true,
+            Block::DarkPrismarineStairs => true,
+            Block::PrismarineSlab => true,
+            Block::PrismarineBrickSlab => true,
+            Block::DarkPrismarineSlab => true,
+            Block::SeaLantern => true,
+            Block::HayBlock => true,
+            Block::WhiteCarpet => true,
+            Block::OrangeCarpet => true,
+            Block::MagentaCarpet => true,
+            Block::LightBlueCarpet => true,
+            Block::YellowCarpet => true,
+            Block::LimeCarpet => true,
+            Block::PinkCarpet => true,
+            Block::GrayCarpet => true,
+            Block::LightGrayCarpet => true,
+            Block::CyanCarpet => true,
+            Block::PurpleCarpet => true,
+            Block::BlueCarpet => true,
+            Block::BrownCarpet => true,
+            Block::GreenCarpet => true,
+            Block::RedCarpet => true,
+            Block::BlackCarpet => true,
+            Block::Terracotta => true,
+            Block::CoalBlock => true,
+            Block::PackedIce => true,
+            Block::Sunflower => true,
+            Block::Lilac => true,
+            Block::RoseBush => true,
+            Block::Peony => true,
+            Block::TallGrass => true,
+            Block::LargeFern => true,
+            Block::WhiteBanner => true,
+            Block::OrangeBanner => true,
+            Block::MagentaBanner => true,
+            Block::LightBlueBanner => true,
+            Block::YellowBanner => true,
+            Block::LimeBanner => true,
+            Block::PinkBanner => true,
+            Block::GrayBanner => true,
+            Block::LightGrayBanner => true,
+            Block::CyanBanner => true,
+            Block::PurpleBanner => true,
+            Block::BlueBanner => true,
+            Block::BrownBanner => true,
+            Block::GreenBanner => true,
+            Block::RedBanner => true,
+            Block::BlackBanner => true,
+            Block::WhiteWallBanner => true,
+            Block::OrangeWallBanner => true,
+            Block::MagentaWallBanner => true,
+            Block::LightBlueWallBanner => true,
+            Block::YellowWallBanner => true,
+            Block::LimeWallBanner => true,
+            Block::PinkWallBanner => true,
+            Block::GrayWallBanner => true,
+            Block::LightGrayWallBanner => true,
+            Block::CyanWallBanner => true,
+            Block::PurpleWallBanner => true,
+            Block::BlueWallBanner => true,
+            Block::BrownWallBanner => true,
+            Block::GreenWallBanner => true,
+            Block::RedWallBanner => true,
+            Block::BlackWallBanner => true,
+            Block::RedSandstone => true,
+            Block::ChiseledRedSandstone => true,
+            Block::CutRedSandstone => true,
+            Block::RedSandstoneStairs => true,
+            Block::OakSlab => true,
+            Block::SpruceSlab => true,
+            Block::BirchSlab => true,
+            Block::JungleSlab => true,
+            Block::AcaciaSlab => true,
+            Block::DarkOakSlab => true,
+            Block::StoneSlab => true,
+            Block::SmoothStoneSlab => true,
+            Block::SandstoneSlab => true,
+            Block::CutSandstoneSlab => true,
+            Block::PetrifiedOakSlab => true,
+            Block::CobblestoneSlab => true,
+            Block::BrickSlab => true,
+            Block::StoneBrickSlab => true,
+            Block::NetherBrickSlab => true,
+            Block::QuartzSlab => true,
+            Block::RedSandstoneSlab => true,
+            Block::CutRedSandstoneSlab => true,
+            Block::PurpurSlab => true,
+            Block::SmoothStone => true,
+            Block::SmoothSandstone => true,
+            Block::SmoothQuartz => true,
+            Block::SmoothRedSandstone => true,
+            Block::SpruceFenceGate => true,
+            Block::BirchFenceGate => true,
+            Block::JungleFenceGate => true,
+            Block::AcaciaFenceGate => true,
+            Block::DarkOakFenceGate => true,
+            Block::SpruceFence => true,
+            Block::BirchFence => true,
+            Block::JungleFence => true,
+            Block::AcaciaFence => true,
+            Block::DarkOakFence => true,
+            Block::SpruceDoor => true,
+            Block::BirchDoor => true,
+            Block::JungleDoor => true,
+            Block::AcaciaDoor => true,
+            Block::DarkOakDoor => true,
+            Block::EndRod => true,
+            Block::ChorusPlant => true,
+            Block::ChorusFlower => true,
+            Block::PurpurBlock => true,
+            Block::PurpurPillar => true,
+            Block::PurpurStairs => true,
+            Block::EndStoneBricks => true,
+            Block::Beetroots => true,
+            Block::GrassPath => true,
+            Block::EndGateway => false,
+            Block::RepeatingCommandBlock => false,
+            Block::ChainCommandBlock => false,
+            Block::FrostedIce => true,
+            Block::MagmaBlock => true,
+            Block::NetherWartBlock => true,
+            Block::RedNetherBricks => true,
+            Block::BoneBlock => true,
+            Block::StructureVoid => true,
+            Block::Observer => true,
+            Block::ShulkerBox => true,
+            Block::WhiteShulkerBox => true,
+            Block::OrangeShulkerBox => true,
+            Block::MagentaShulkerBox => true,
+            Block::LightBlueShulkerBox => true,
+            Block::YellowShulkerBox => true,
+            Block::LimeShulkerBox => true,
+            Block::PinkShulkerBox => true,
+            Block::GrayShulkerBox => true,
+            Block::LightGrayShulkerBox => true,
+            Block::CyanShulkerBox => true,
+            Block::PurpleShulkerBox => true,
+            Block::BlueShulkerBox => true,
+            Block::BrownShulkerBox => true,
+            Block::GreenShulkerBox => true,
+            Block::RedShulkerBox => true,
+            Block::BlackShulkerBox => true,
+            Block::WhiteGlazedTerracotta => true,
+            Block::OrangeGlazedTerracotta => true,
+            Block::MagentaGlazedTerracotta => true,
+            Block::LightBlueGlazedTerracotta => true,
+            Block::YellowGlazedTerracotta => true,
+            Block::LimeGlazedTerracotta => true,
+            Block::PinkGlazedTerracotta => true,
+            Block::GrayGlazedTerracotta => true,
+            Block::LightGrayGlazedTerracotta => true,
+            Block::CyanGlazedTerracotta => true,
+            Block::PurpleGlazedTerracotta => true,
+            Block::BlueGlazedTerracotta => true,
+            Block::BrownGlazedTerracotta => true,
+            Block::GreenGlazedTerracotta => true,
+            Block::RedGlazedTerracotta => true,
+            Block::BlackGlazedTerracotta => true,
+            Block::WhiteConcrete => true,
+            Block::OrangeConcrete => true,
+            Block::MagentaConcrete => true,
+            Block::LightBlueConcrete => true,
+            Block::YellowConcrete => true,
+            Block::LimeConcrete => true,
+            Block::PinkConcrete => true,
+            Block::GrayConcrete => true,
+            Block::LightGrayConcrete => true,
+            Block::CyanConcrete => true,
+            Block::PurpleConcrete => true,
+            Block::BlueConcrete => true,
+            Block::BrownConcrete => true,
+            Block::GreenConcrete => true,
+            Block::RedConcrete => true,
+            Block::BlackConcrete => true,
+            Block::WhiteConcretePowder => true,
+            Block::OrangeConcretePowder => true,
+            Block::MagentaConcretePowder => true,
+            Block::LightBlueConcretePowder => true,
+            Block::YellowConcretePowder => true,
+            Block::LimeConcretePowder => true,
+            Block::PinkConcretePowder => true,
+            Block::GrayConcretePowder => true,
+            Block::LightGrayConcretePowder => true,
+            Block::CyanConcretePowder => true,
+            Block::PurpleConcretePowder => true,
+            Block::BlueConcretePowder => true,
+            Block::BrownConcretePowder => true,
+            Block::GreenConcretePowder => true,
+            Block::RedConcretePowder => true,
+            Block::BlackConcretePowder => true,
+            Block::Kelp => true,
+            Block::KelpPlant => true,
+            Block::DriedKelpBlock => true,
+            Block::TurtleEgg => true,
+            Block::DeadTubeCoralBlock => true,
+            Block::DeadBrainCoralBlock => true,
+            Block::DeadBubbleCoralBlock => true,
+            Block::DeadFireCoralBlock => true,
+            Block::DeadHornCoralBlock => true,
+            Block::TubeCoralBlock => true,
+            Block::BrainCoralBlock => true,
+            Block::BubbleCoralBlock => true,
+            Block::FireCoralBlock => true,
+            Block::HornCoralBlock => true,
+            Block::DeadTubeCoral => true,
+            Block::DeadBrainCoral => true,
+            Block::DeadBubbleCoral => true,
+            Block::DeadFireCoral => true,
+            Block::DeadHornCoral => true,
+            Block::TubeCoral => true,
+            Block::BrainCoral => true,
+            Block::BubbleCoral => true,
+            Block::FireCoral => true,
+            Block::HornCoral => true,
+            Block::DeadTubeCoralFan => true,
+            Block::DeadBrainCoralFan => true,
+            Block::DeadBubbleCoralFan => true,
+            Block::DeadFireCoralFan => true,
+            Block::DeadHornCoralFan => true,
+            Block::TubeCoralFan => true,
+            Block::BrainCoralFan => true,
+            Block::BubbleCoralFan => true,
+            Block::FireCoralFan => true,
+            Block::HornCoralFan => true,
+            Block::DeadTubeCoralWallFan => true,
+            Block::DeadBrainCoralWallFan => true,
+            Block::DeadBubbleCoralWallFan => true,
+            Block::DeadFireCoralWallFan => true,
+            Block::DeadHornCoralWallFan => true,
+            Block::TubeCoralWallFan => true,
+            Block::BrainCoralWallFan => true,
+            Block::BubbleCoralWallFan => true,
+            Block::FireCoralWallFan => true,
+            Block::HornCoralWallFan => true,
+            Block::SeaPickle => true,
+            Block::BlueIce => true,
+            Block::Conduit => true,
+            Block::BambooSapling => true,
+            Block::Bamboo => true,
+            Block::PottedBamboo => true,
+            Block::VoidAir => false,
+            Block::CaveAir => false,
+            Block::BubbleColumn => false,
+            Block::PolishedGraniteStairs => true,
+            Block::SmoothRedSandstoneStairs => true,
+            Block::MossyStoneBrickStairs => true,
+            Block::PolishedDioriteStairs => true,
+            Block::MossyCobblestoneStairs => true,
+            Block::EndStoneBrickStairs => true,
+            Block::StoneStairs => true,
+            Block::SmoothSandstoneStairs => true,
+            Block::SmoothQuartzStairs => true,
+            Block::GraniteStairs => true,
+            Block::AndesiteStairs => true,
+            Block::RedNetherBrickStairs => true,
+            Block::PolishedAndesiteStairs => true,
+            Block::DioriteStairs => true,
+            Block::PolishedGraniteSlab => true,
+            Block::SmoothRedSandstoneSlab => true,
+            Block::MossyStoneBrickSlab => true,
+            Block::PolishedDioriteSlab => true,
+            Block::MossyCobblestoneSlab => true,
+            Block::EndStoneBrickSlab => true,
+            Block::SmoothSandstoneSlab => true,
+            Block::SmoothQuartzSlab => true,
+            Block::GraniteSlab => true,
+            Block::AndesiteSlab => true,
+            Block::RedNetherBrickSlab => true,
+            Block::PolishedAndesiteSlab => true,
+            Block::DioriteSlab => true,
+            Block::BrickWall => true,
+            Block::PrismarineWall => true,
+            Block::RedSandstoneWall => true,
+            Block::MossyStoneBrickWall => true,
+            Block::GraniteWall => true,
+            Block::StoneBrickWall => true,
+            Block::NetherBrickWall => true,
+            Block::AndesiteWall => true,
+            Block::RedNetherBrickWall => true,
+            Block::SandstoneWall => true,
+            Block::EndStoneBrickWall => true,
+            Block::DioriteWall => true,
+            Block::Scaffolding => true,
+            Block::Loom => true,
+            Block::Barrel => true,
+            Block::Smoker => true,
+            Block::BlastFurnace => true,
+            Block::CartographyTable => true,
+            Block::FletchingTable => true,
+            Block::Grindstone => true,
+            Block::Lectern => true,
+            Block::SmithingTable => true,
+            Block::Stonecutter => true,
+            Block::Bell => true,
+            Block::Lantern => true,
+            Block::Campfire => true,
+            Block::SweetBerryBush => true,
+            Block::StructureBlock => false,
+            Block::Jigsaw => false,
+            Block::Composter => true,
+            Block::BeeNest => true,
+            Block::Beehive => true,
+            Block::HoneyBlock => true,
+            Block::HoneycombBlock => true,
+        }
+    }
+    pub fn drops(&self) -> &'static [u32] {
+        match self {
+            Block::Air => &[],
+            Block::Stone => &[12],
+            Block::Granite => &[2],
+            Block::PolishedGranite => &[3],
+            Block::Diorite => &[4],
+            Block::PolishedDiorite => &[5],
+            Block::Andesite => &[6],
+            Block::PolishedAndesite => &[7],
+            Block::GrassBlock => &[9],
+            Block::Dirt => &[9],
+            Block::CoarseDirt => &[10],
+            Block::Podzol => &[9],
+            Block::Cobblestone => &[12],
+            Block::OakPlanks => &[13],
+            Block::SprucePlanks => &[14],
+            Block::BirchPlanks => &[15],
+            Block::JunglePlanks => &[16],
+            Block::AcaciaPlanks => &[17],
+            Block::DarkOakPlanks => &[18],
+            Block::OakSapling => &[19],
+            Block::SpruceSapling => &[20],
+            Block::BirchSapling => &[21],
+            Block::JungleSapling => &[22],
+            Block::AcaciaSapling => &[23],
+            Block::DarkOakSapling => &[24],
+            Block::Bedrock => &[],
+            Block::Water => &[],
+            Block::Lava => &[],
+            Block::Sand => &[26],
+            Block::RedSand => &[27],
+            Block::Gravel => &[28],
+            Block::GoldOre => &[29],
+            Block::IronOre => &[30],
+            Block::CoalOre => &[527],
+            Block::OakLog => &[32],
+            Block::SpruceLog => &[33],
+            Block::BirchLog => &[34],
+            Block::JungleLog => &[35],
+            Block::AcaciaLog => &[36],
+            Block::DarkOakLog => &[37],
+            Block::StrippedSpruceLog => &[39],
+            Block::StrippedBirchLog => &[40],
+            Block::StrippedJungleLog => &[41],
+            Block::StrippedAcaciaLog => &[42],
+            Block::StrippedDarkOakLog => &[43],
+            Block::StrippedOakLog => &[38],
+            Block::OakWood => &[50],
+            Block::SpruceWood => &[51],
+            Block::BirchWood => &[52],
+            Block::JungleWood => &[53],
+            Block::AcaciaWood => &[54],
+            Block::DarkOakWood => &[55],
+            Block::StrippedOakWood => &[44],
+            Block::StrippedSpruceWood => &[45],
+            Block::StrippedBirchWood => &[46],
+            Block::StrippedJungleWood => &[47],
+            Block::StrippedAcaciaWood => &[48],
+            Block::StrippedDarkOakWood => &[49],
+            Block::OakLeaves => &[19],
+            Block::SpruceLeaves => &[20],
+            Block::BirchLeaves => &[21],
+            Block::JungleLeaves => &[22],
+            Block::AcaciaLeaves => &[23],
+            Block::DarkOakLeaves => &[24],
+            Block::Sponge => &[62],
+            Block::WetSponge => &[63],
+            Block::Glass => &[],
+            Block::LapisOre => &[635],
+            Block::LapisBlock => &[66],
+            Block::Dispenser => &[67],
+            Block::Sandstone => &[68],
+            Block::ChiseledSandstone => &[69],
+            Block::CutSandstone => &[70],
+            Block::NoteBlock => &[71],
+            Block::WhiteBed => &[654],
+            Block::OrangeBed => &[655],
+            Block::MagentaBed => &[656],
+            Block::LightBlueBed => &[657],
+            Block::YellowBed => &[658],
+            Block::LimeBed => &[659],
+            Block::PinkBed => &[660],
+            Block::GrayBed => &[661],
+            Block::LightGrayBed => &[662],
+            Block::CyanBed => &[663],
+            Block::PurpleBed => &[664],
+            Block::BlueBed => &[665],
+            Block::BrownBed => &[666],
+            Block::GreenBed => &[667],
+            Block::RedBed => &[668],
+            Block::BlackBed => &[669],
+            Block::PoweredRail => &[72],
+            Block::DetectorRail => &[73],
+            Block::StickyPiston => &[74],
+            Block::Cobweb => &[552],
+            Block::Grass => &[],
+            Block::Fern => &[],
+            Block::DeadBush => &[545],
+            Block::Seagrass => &[],
+            Block::TallSeagrass => &[],
+            Block::Piston => &[81],
+            Block::PistonHead => &[],
+            Block::WhiteWool => &[82],
+            Block::OrangeWool => &[83],
+            Block::MagentaWool => &[84],
+            Block::LightBlueWool => &[85],
+            Block::YellowWool => &[86],
+            Block::LimeWool => &[87],
+            Block::PinkWool => &[88],
+            Block::GrayWool => &[89],
+            Block::LightGrayWool => &[90],
+            Block::CyanWool => &[91],
+            Block::PurpleWool => &[92],
+            Block::BlueWool => &[93],
+            Block::BrownWool => &[94],
+            Block::GreenWool => &[95],
+            Block::RedWool => &[96],
+            Block::BlackWool => &[97],
+            Block::MovingPiston => &[],
+            Block::Dandelion => &[98],
+            Block::Poppy => &[99],
+            Block::BlueOrchid => &[100],
+            Block::Allium => &[101],
+            Block::AzureBluet => &[102],
+            Block::RedTulip => &[103],
+            Block::OrangeTulip => &[104],
+            Block::WhiteTulip => &[105],
+            Block::PinkTulip => &[106],
+            Block::OxeyeDaisy => &[107],
+            Block::Cornflower => &[108],
+            Block::WitherRose => &[110],
+            Block::LilyOfTheValley => &[109],
+            Block::BrownMushroom => &[111],
+            Block::RedMushroom => &[112],
+            Block::GoldBlock => &[113],
+            Block::IronBlock => &[114],
+            Block::Bricks => &[141],
+            Block::Tnt => &[142],
+            Block::Bookshelf => &[616],
+            Block::MossyCobblestone => &[144],
+            Block::Obsidian => &[145],
+            Block::Torch => &[146],
+            Block::WallTorch => &[146],
+            Block::Fire => &[],
+            Block::Spawner => &[],
+            Block::OakStairs => &[154],
+            Block::Chest => &[155],
+            Block::RedstoneWire => &[600],
+            Block::DiamondOre => &[529],
+            Block::DiamondBlock => &[157],
+            Block::CraftingTable => &[158],
+            Block::Wheat => &[560],
+            Block::Farmland => &[9],
+            Block::Furnace => &[160],
+            Block::OakSign => &[589],
+            Block::SpruceSign => &[590],
+            Block::BirchSign => &[591],
+            Block::AcaciaSign => &[593],
+            Block::JungleSign => &[592],
+            Block::DarkOakSign => &[594],
+            Block::OakDoor => &[507],
+            Block::Ladder => &[161],
+            Block::Rail => &[162],
+            Block::CobblestoneStairs => &[163],
+            Block::OakWallSign => &[589],
+            Block::SpruceWallSign => &[590],
+            Block::BirchWallSign => &[591],
+            Block::AcaciaWallSign => &[593],
+            Block::JungleWallSign => &[592],
+            Block::DarkOakWallSign => &[594],
+            Block::Lever => &[164],
+            Block::StonePressurePlate => &[165],
+            Block::IronDoor => &[506],
+            Block::OakPressurePlate => &[166],
+            Block::SprucePressurePlate => &[167],
+            Block::BirchPressurePlate => &[168],
+            Block::JunglePressurePlate => &[169],
+            Block::AcaciaPressurePlate => &[170],
+            Block::DarkOakPressurePlate => &[171],
+            Block::RedstoneOre => &[600],
+            Block::RedstoneTorch => &[173],
+            Block::RedstoneWallTorch => &[173],
+            Block::StoneButton => &[174],
+            Block::Snow => &[601],
+            Block::Ice => &[],
+            Block::SnowBlock => &[601],
+            Block::Cactus => &[178],
+            Block::Clay => &[610],
+            Block::SugarCane => &[611],
+            Block::Jukebox => &[180],
+            Block::OakFence => &[181],
+            Block::Pumpkin => &[187],
+            Block::Netherrack => &[189],
+            Block::SoulSand => &[190],
+            Block::Glowstone => &[624],
+            Block::NetherPortal => &[],
+            Block::CarvedPumpkin => &[188],
+            Block::JackOLantern => &[192],
+            Block::Cake => &[],
+            Block::Repeater => &[513],
+            Block::WhiteStainedGlass => &[],
+            Block::OrangeStainedGlass => &[],
+            Block::MagentaStainedGlass => &[],
+            Block::LightBlueStainedGlass => &[],
+            Block::YellowStainedGlass => &[],
+            Block::LimeStainedGlass => &[],
+            Block::PinkStainedGlass => &[],
+            Block::GrayStainedGlass => &[],
+            Block::LightGrayStainedGlass => &[],
+            Block::CyanStainedGlass => &[],
+            Block::PurpleStainedGlass => &[],
+            Block::BlueStainedGlass => &[],
+            Block::BrownStainedGlass => &[],
+            Block::GreenStainedGlass => &[],
+            Block::RedStainedGlass => &[],
+            Block::BlackStainedGlass => &[],
+            Block::OakTrapdoor => &[193],
+            Block::SpruceTrapdoor => &[194],
+            Block::BirchTrapdoor => &[195],
+            Block::JungleTrapdoor => &[196],
+            Block::AcaciaTrapdoor => &[197],
+            Block::DarkOakTrapdoor => &[198],
+            Block::StoneBricks => &[205],
+            Block::MossyStoneBricks => &[206],
+            Block::CrackedStoneBricks => &[207],
+            Block::ChiseledStoneBricks => &[208],
+            Block::InfestedStone => &[],
+            Block::InfestedCobblestone => &[],
+            Block::InfestedStoneBricks => &[],
+            Block::InfestedMossyStoneBricks => &[],
+            Block::InfestedCrackedStoneBricks => &[],
+            Block::InfestedChiseledStoneBricks => &[],
+            Block::BrownMushroomBlock => &[209],
+            Block::RedMushroomBlock => &[210],
+            Block::MushroomStem => &[211],
+            Block::IronBars => &[212],
+            Block::GlassPane => &[],
+            Block::Melon => &[673],
+            Block::AttachedPumpkinStem => &[675],
+            Block::AttachedMelonStem => &[676],
+            Block::PumpkinStem => &[675],
+            Block::MelonStem => &[676],
+            Block::Vine => &[215],
+            Block::OakFenceGate => &[216],
+            Block::BrickStairs => &[222],
+            Block::StoneBrickStairs => &[223],
+            Block::Mycelium => &[9],
+            Block::LilyPad => &[225],
+            Block::NetherBricks => &[226],
+            Block::NetherBrickFence => &[227],
+            Block::NetherBrickStairs => &[228],
+            Block::NetherWart => &[686],
+            Block::EnchantingTable => &[229],
+            Block::BrewingStand => &[693],
+            Block::Cauldron => &[694],
+            Block::EndPortal => &[],
+            Block::EndPortalFrame => &[],
+            Block::EndStone => &[231],
+            Block::DragonEgg => &[233],
+            Block::RedstoneLamp => &[234],
+            Block::Cocoa => &[634],
+            Block::SandstoneStairs => &[235],
+            Block::EmeraldOre => &[760],
+            Block::EnderChest => &[237],
+            Block::TripwireHook => &[238],
+            Block::Tripwire => &[552],
+            Block::EmeraldBlock => &[239],
+            Block::SpruceStairs => &[240],
+            Block::BirchStairs => &[241],
+            Block::JungleStairs => &[242],
+            Block::CommandBlock => &[],
+            Block::Beacon => &[244],
+            Block::CobblestoneWall => &[245],
+            Block::MossyCobblestoneWall => &[246],
+            Block::FlowerPot => &[762],
+            Block::PottedOakSapling => &[762, 19],
+            Block::PottedSpruceSapling => &[762, 20],
+            Block::PottedBirchSapling => &[762, 21],
+            Block::PottedJungleSapling => &[762, 22],
+            Block::PottedAcaciaSapling => &[762, 23],
+            Block::PottedDarkOakSapling => &[762, 24],
+            Block::PottedFern => &[762, 77],
+            Block::PottedDandelion => &[762, 98],
+            Block::PottedPoppy => &[762, 99],
+            Block::PottedBlueOrchid => &[762, 100],
+            Block::PottedAllium => &[762, 101],
+            Block::PottedAzureBluet => &[762, 102],
+            Block::PottedRedTulip => &[762, 103],
+            Block::PottedOrangeTulip => &[762, 104],
+            Block::PottedWhiteTulip => &[762, 105],
+            Block::PottedPinkTulip => &[762, 106],
+            Block::PottedOxeyeDaisy => &[762, 107],
+            Block::PottedCornflower => &[762, 108],
+            Block::PottedLilyOfTheValley => &[762, 109],
+            Block::PottedWitherRose => &[762, 110],
+            Block::PottedRedMushroom => &[762, 112],
+            Block::PottedBrownMushroom => &[762, 111],
+            Block::PottedDeadBush => &[762, 78],
+            Block::PottedCactus => &[762, 178],
+            Block::Carrots => &[763],
+            Block::Potatoes => &[764],
+            Block::OakButton => &[259],
+            Block::SpruceButton => &[260],
+            Block::BirchButton => &[261],
+            Block::JungleButton => &[262],
+            Block::AcaciaButton => &[263],
+            Block::DarkOakButton => &[264],
+            Block::SkeletonSkull => &[769],
+            Block::SkeletonWallSkull => &[769],
+            Block::WitherSkeletonSkull => &[770],
+            Block::WitherSkeletonWallSkull => &[770],
+            Block::ZombieHead => &[772],
+            Block::ZombieWallHead => &[772],
+            Block::PlayerHead => &[771],
+            Block::PlayerWallHead => &[771],
+            Block::CreeperHead => &[773],
+            Block::CreeperWallHead => &[773],
+            Block::DragonHead => &[774],
+            Block::DragonWallHead => &[774],
+            Block::Anvil => &[265],
+            Block::ChippedAnvil => &[266],
+            Block::DamagedAnvil => &[267],
+            Block::TrappedChest => &[268],
+            Block::LightWeightedPressurePlate => &[269],
+            Block::HeavyWeightedPressurePlate => &[270],
+            Block::Comparator => &[514],
+            Block::DaylightDetector => &[271],
+            Block::RedstoneBlock => &[272],
+            Block::NetherQuartzOre => &[782],
+            Block::Hopper => &[274],
+            Block::QuartzBlock => &[276],
+            Block::ChiseledQuartzBlock => &[275],
+            Block::QuartzPillar => &[277],
+            Block::QuartzStairs => &[278],
+            Block::ActivatorRail => &[279],
+            Block::Dropper => &[280],
+            Block::WhiteTerracotta => &[281],
+            Block::OrangeTerracotta => &[282],
+            Block::MagentaTerracotta => &[283],
+            Block::LightBlueTerracotta => &[284],
+            Block::YellowTerracotta => &[285],
+            Block::LimeTerracotta => &[286],
+            Block::PinkTerracotta => &[287],
+            Block::GrayTerracotta => &[288],
+            Block::LightGrayTerracotta => &[289],
+            Block::CyanTerracotta => &[290],
+            Block::PurpleTerracotta => &[291],
+            Block::BlueTerracotta => &[292],
+            Block::BrownTerracotta => &[293],
+            Block::GreenTerracotta => &[294],
+            Block::RedTerracotta => &[295],
+            Block::BlackTerracotta => &[296],
+            Block::WhiteStainedGlassPane => &[],
+            Block::OrangeStainedGlassPane => &[],
+            Block::MagentaStainedGlassPane => &[],
+            Block::LightBlueStainedGlassPane => &[],
+            Block::YellowStainedGlassPane => &[],
+            Block::LimeStainedGlassPane => &[],
+            Block::PinkStainedGlassPane => &[],
+            Block::GrayStainedGlassPane => &[],
+            Block::LightGrayStainedGlassPane => &[],
+            Block::CyanStainedGlassPane => &[],
+            Block::PurpleStainedGlassPane => &[],
+            Block::BlueStainedGlassPane => &[],
+            Block::BrownStainedGlassPane => &[],
+            Block::GreenStainedGlassPane => &[],
+            Block::RedStainedGlassPane => &[],
+            Block::BlackStainedGlassPane => &[],
+            Block::AcaciaStairs => &[319],
+            Block::DarkOakStairs => &[320],
+            Block::SlimeBlock => &[321],
+            Block::Barrier => &[],
+            Block::IronTrapdoor => &[298],
+            Block::Prismarine => &[361],
+            Block::PrismarineBricks => &[362],
+            Block::DarkPrismarine => &[363],
+            Block::PrismarineStairs => &[364],
+            Block::PrismarineBrickStairs => &[365],
+            Block::DarkPrismarineStairs => &[366],
+            Block::PrismarineSlab => &[134],
+            Block::PrismarineBrickSlab => &[135],
+            Block::DarkPrismarineSlab => &[136],
+            Block::SeaLantern => &[367],
+            Block::HayBlock => &[299],
+            Block::WhiteCarpet => &[300],
+            Block::OrangeCarpet => &[301],
+            Block::MagentaCarpet => &[302],
+            Block::LightBlueCarpet => &[303],
+            Block::YellowCarpet => &[304],
+            Block::LimeCarpet => &[305],
+            Block::PinkCarpet => &[306],
+            Block::GrayCarpet => &[307],
+            Block::LightGrayCarpet => &[308],
+            Block::CyanCarpet => &[309],
+            Block::PurpleCarpet => &[310],
+            Block::BlueCarpet => &[311],
+            Block::BrownCarpet => &[312],
+            Block::GreenCarpet => &[313],
+            Block::RedCarpet => &[314],
+            Block::BlackCarpet => &[315],
+            Block::Terracotta => &[316],
+            Block::CoalBlock => &[317],
+            Block::PackedIce => &[],
+            Block::Sunflower => &[323],
+            Block::Lilac => &[324],
+            Block::RoseBush => &[325],
+            Block::Peony => &[326],
+            Block::TallGrass => &[],
+            Block::LargeFern => &[],
+            Block::WhiteBanner => &[802],
+            Block::OrangeBanner => &[803],
+            Block::MagentaBanner => &[804],
+            Block::LightBlueBanner => &[805],
+            Block::YellowBanner => &[806],
+            Block::LimeBanner => &[807],
+            Block::PinkBanner => &[808],
+            Block::GrayBanner => &[809],
+            Block::LightGrayBanner => &[810],
+            Block::CyanBanner => &[811],
+            Block::PurpleBanner => &[812],
+            Block::BlueBanner => &[813],
+            Block::BrownBanner => &[814],
+            Block::GreenBanner => &[815],
+            Block::RedBanner => &[816],
+            Block::BlackBanner => &[817],
+            Block::WhiteWallBanner => &[802],
+            Block::OrangeWallBanner => &[803],
+            Block::MagentaWallBanner => &[804],
+            Block::LightBlueWallBanner => &[805],
+            Block::YellowWallBanner => &[806],
+            Block::LimeWallBanner => &[807],
+            Block::PinkWallBanner => &[808],
+            Block::GrayWallBanner => &[809],
+            Block::LightGrayWallBanner => &[810],
+            Block::CyanWallBanner => &[811],
+            Block::PurpleWallBanner => &[812],
+            Block::BlueWallBanner => &[813],
+            Block::BrownWallBanner => &[814],
+            Block::GreenWallBanner => &[815],
+            Block::RedWallBanner => &[816],
+            Block::BlackWallBanner => &[817],
+            Block::RedSandstone => &[368],
+            Block::ChiseledRedSandstone => &[369],
+            Block::CutRedSandstone => &[370],
+            Block::RedSandstoneStairs => &[371],
+            Block::OakSlab => &[115],
+            Block::SpruceSlab => &[116],
+            Block::BirchSlab => &[117],
+            Block::JungleSlab => &[118],
+            Block::AcaciaSlab => &[119],
+            Block::DarkOakSlab => &[120],
+            Block::StoneSlab => &[121],
+            Block::SmoothStoneSlab => &[122],
+            Block::SandstoneSlab => &[123],
+            Block::CutSandstoneSlab => &[124],
+            Block::PetrifiedOakSlab => &[125],
+            Block::CobblestoneSlab => &[126],
+            Block::BrickSlab => &[127],
+            Block::StoneBrickSlab => &[128],
+            Block::NetherBrickSlab => &[129],
+            Block::QuartzSlab => &[130],
+            Block::RedSandstoneSlab => &[131],
+            Block::CutRedSandstoneSlab => &[132],
+            Block::PurpurSlab => &[133],
+            Block::SmoothStone => &[140],
+            Block::SmoothSandstone => &[139],
+            Block::SmoothQuartz => &[137],
+            Block::SmoothRedSandstone => &[138],
+            Block::SpruceFenceGate => &[217],
+            Block::BirchFenceGate => &[218],
+            Block::JungleFenceGate => &[219],
+            Block::AcaciaFenceGate => &[220],
+            Block::DarkOakFenceGate => &[221],
+            Block::SpruceFence => &[182],
+            Block::BirchFence => &[183],
+            Block::JungleFence => &[184],
+            Block::AcaciaFence => &[185],
+            Block::DarkOakFence => &[186],
+            Block::SpruceDoor => &[508],
+            Block::BirchDoor => &[509],
+            Block::JungleDoor => &[510],
+            Block::AcaciaDoor => &[511],
+            Block::DarkOakDoor => &[512],
+            Block::EndRod => &[147],
+            Block::ChorusPlant => &[819],
+            Block::ChorusFlower => &[149],
+            Block::PurpurBlock => &[150],
+            Block::PurpurPillar => &[151],
+            Block::PurpurStairs => &[152],
+            Block::EndStoneBricks => &[232],
+            Block::Beetroots => &[822],
+            Block::GrassPath => &[9],
+            Block::EndGateway => &[],
+            Block::RepeatingCommandBlock => &[],
+            Block::ChainCommandBlock => &[],
+            Block::FrostedIce => &[],
+            Block::MagmaBlock => &[374],
+            Block::NetherWartBlock => &[375],
+            Block::RedNetherBricks => &[376],
+            Block::BoneBlock => &[377],
+            Block::StructureVoid => &[378],
+            Block::Observer => &[379],
+            Block::ShulkerBox => &[380],
+            Block::WhiteShulkerBox => &[381],
+            Block::OrangeShulkerBox => &[382],
+            Block::MagentaShulkerBox => &[383],
+            Block::LightBlueShulkerBox => &[384],
+            Block::YellowShulkerBox => &[385],
+            Block::LimeShulkerBox => &[386],
+            Block::PinkShulkerBox => &[387],
+            Block::GrayShulkerBox => &[388],
+            Block::LightGrayShulkerBox => &[389],
+            Block::CyanShulkerBox => &[390],
+            Block::PurpleShulkerBox => &[391],
+            Block::BlueShulkerBox => &[392],
+            Block::BrownShulkerBox => &[393],
+            Block::GreenShulkerBox => &[394],
+            Block::RedShulkerBox => &[395],
+            Block::BlackShulkerBox => &[396],
+            Block::WhiteGlazedTerracotta => &[397],
+            Block::OrangeGlazedTerracotta => &[398],
+            Block::MagentaGlazedTerracotta => &[399],
+            Block::LightBlueGlazedTerracotta => &[400],
+            Block::YellowGlazedTerracotta => &[401],
+            Block::LimeGlazedTerracotta => &[402],
+            Block::PinkGlazedTerracotta => &[403],
+            Block::GrayGlazedTerracotta => &[404],
+            Block::LightGrayGlazedTerracotta => &[405],
+            Block::CyanGlazedTerracotta => &[406],
+            Block::PurpleGlazedTerracotta => &[407],
+            Block::BlueGlazedTerracotta => &[408],
+            Block::BrownGlazedTerracotta => &[409],
+            Block::GreenGlazedTerracotta => &[410],
+            Block::RedGlazedTerracotta => &[411],
+            Block::BlackGlazedTerracotta => &[412],
+            Block::WhiteConcrete => &[413],
+            Block::OrangeConcrete => &[414],
+            Block::MagentaConcrete => &[415],
+            Block::LightBlueConcrete => &[416],
+            Block::YellowConcrete => &[417],
+            Block::LimeConcrete => &[418],
+            Block::PinkConcrete => &[419],
+            Block::GrayConcrete => &[420],
+            Block::LightGrayConcrete => &[421],
+            Block::CyanConcrete => &[422],
+            Block::PurpleConcrete => &[423],
+            Block::BlueConcrete => &[424],
+            Block::BrownConcrete => &[425],
+            Block::GreenConcrete => &[426],
+            Block::RedConcrete => &[427],
+            Block::BlackConcrete => &[428],
+            Block::WhiteConcretePowder => &[429],
+            Block::OrangeConcretePowder => &[430],
+            Block::MagentaConcretePowder => &[431],
+            Block::LightBlueConcretePowder => &[432],
+            Block::YellowConcretePowder => &[433],
+            Block::LimeConcretePowder => &[434],
+            Block::PinkConcretePowder => &[435],
+            Block::GrayConcretePowder => &[436],
+            Block::LightGrayConcretePowder => &[437],
+            Block::CyanConcretePowder => &[438],
+            Block::PurpleConcretePowder => &[439],
+            Block::BlueConcretePowder => &[440],
+            Block::BrownConcretePowder => &[441],
+            Block::GreenConcretePowder => &[442],
+            Block::RedConcretePowder => &[443],
+            Block::BlackConcretePowder => &[444],
+            Block::Kelp => &[612],
+            Block::KelpPlant => &[612],
+            Block::DriedKelpBlock => &[613],
+            Block::TurtleEgg => &[],
+            Block::DeadTubeCoralBlock => &[446],
+            Block::DeadBrainCoralBlock => &[447],
+            Block::DeadBubbleCoralBlock => &[448],
+            Block::DeadFireCoralBlock => &[449],
+            Block::DeadHornCoralBlock => &[450],
+            Block::TubeCoralBlock => &[446],
+            Block::BrainCoralBlock => &[447],
+            Block::BubbleCoralBlock => &[448],
+            Block::FireCoralBlock => &[449],
+            Block::HornCoralBlock => &[450],
+            Block::DeadTubeCoral => &[465],
+            Block::DeadBrainCoral => &[461],
+            Block::DeadBubbleCoral => &[462],
+            Block::DeadFireCoral => &[463],
+            Block::DeadHornCoral => &[464],
+            Block::TubeCoral => &[],
+            Block::BrainCoral => &[],
+            Block::BubbleCoral => &[],
+            Block::FireCoral => &[],
+            Block::HornCoral => &[],
+            Block::DeadTubeCoralFan => &[471],
+            Block::DeadBrainCoralFan => &[472],
+            Block::DeadBubbleCoralFan => &[473],
+            Block::DeadFireCoralFan => &[474],
+            Block::DeadHornCoralFan => &[475],
+            Block::TubeCoralFan => &[],
+            Block::BrainCoralFan => &[],
+            Block::BubbleCoralFan => &[],
+            Block::FireCoralFan => &[],
+            Block::HornCoralFan => &[],
+            Block::DeadTubeCoralWallFan => &[471],
+            Block::DeadBrainCoralWallFan => &[472],
+            Block::DeadBubbleCoralWallFan => &[473],
+            Block::DeadFireCoralWallFan => &[474],
+            Block::DeadHornCoralWallFan => &[475],
+            Block::TubeCoralWallFan => &[],
+            Block::BrainCoralWallFan => &[],
+            Block::BubbleCoralWallFan => &[],
+            Block::FireCoralWallFan => &[],
+            Block::HornCoralWallFan => &[],
+            Block::SeaPickle => &[80],
+            Block::BlueIce => &[],
+            Block::Conduit => &[477],
+            Block::BambooSapling => &[614],
+            Block::Bamboo => &[614],
+            Block::PottedBamboo => &[762, 614],
+            Block::VoidAir => &[],
+            Block::CaveAir => &[],
+            Block::BubbleColumn => &[],
+            Block::PolishedGraniteStairs => &[478],
+            Block::SmoothRedSandstoneStairs => &[479],
+            Block::MossyStoneBrickStairs => &[480],
+            Block::PolishedDioriteStairs => &[481],
+            Block::MossyCobblestoneStairs => &[482],
+            Block::EndStoneBrickStairs => &[483],
+            Block::StoneStairs => &[484],
+            Block::SmoothSandstoneStairs => &[485],
+            Block::SmoothQuartzStairs => &[486],
+            Block::GraniteStairs => &[487],
+            Block::AndesiteStairs => &[488],
+            Block::RedNetherBrickStairs => &[489],
+            Block::PolishedAndesiteStairs => &[490],
+            Block::DioriteStairs => &[491],
+            Block::PolishedGraniteSlab => &[492],
+            Block::SmoothRedSandstoneSlab => &[493],
+            Block::MossyStoneBrickSlab => &[494],
+            Block::PolishedDioriteSlab => &[495],
+            Block::MossyCobblestoneSlab => &[496],
+            Block::EndStoneBrickSlab => &[497],
+            Block::SmoothSandstoneSlab => &[498],
+            Block::SmoothQuartzSlab => &[499],
+            Block::GraniteSlab => &[500],
+            Block::AndesiteSlab => &[501],
+            Block::RedNetherBrickSlab => &[502],
+            Block::PolishedAndesiteSlab => &[503],
+            Block::DioriteSlab => &[504],
+            Block::BrickWall => &[247],
+            Block::PrismarineWall => &[248],
+            Block::RedSandstoneWall => &[249],
+            Block::MossyStoneBrickWall => &[250],
+            Block::GraniteWall => &[251],
+            Block::StoneBrickWall => &[252],
+            Block::NetherBrickWall => &[253],
+            Block::AndesiteWall => &[254],
+            Block::RedNetherBrickWall => &[255],
+            Block::SandstoneWall => &[256],
+            Block::EndStoneBrickWall => &[257],
+            Block::DioriteWall => &[258],
+            Block::Scaffolding => &[505],
+            Block::Loom => &[859],
+            Block::Barrel => &[865],
+            Block::Smoker => &[866],
+            Block::BlastFurnace => &[867],
+            Block::CartographyTable => &[868],
+            Block::FletchingTable => &[869],
+            Block::Grindstone => &[870],
+            Block::Lectern => &[871],
+            Block::SmithingTable => &[872],
+            Block::Stonecutter => &[873],
+            Block::Bell => &[874],
+            Block::Lantern => &[875],
+            Block::Campfire => &[877],
+            Block::SweetBerryBush => &[876],
+            Block::StructureBlock => &[],
+            Block::Jigsaw => &[],
+            Block::Composter => &[517],
+            Block::BeeNest => &[],
+            Block::Beehive => &[880],
+            Block::HoneyBlock => &[882],
+            Block::HoneycombBlock => &[883],
+        }
+    }
+    pub fn solid(&self) -> bool {
+        match self {
+            Block::Air => false,
+            Block::Stone => true,
+            Block::Granite => true,
+            Block::PolishedGranite => true,
+            Block::Diorite => true,
+            Block::PolishedDiorite => true,
+            Block::Andesite => true,
+            Block::PolishedAndesite => true,
+            Block::GrassBlock => true,
+            Block::Dirt => true,
+            Block::CoarseDirt => true,
+            Block::Podzol => true,
+            Block::Cobblestone => true,
+            Block::OakPlanks => true,
+            Block::SprucePlanks => true,
+            Block::BirchPlanks => true,
+            Block::JunglePlanks => true,
+            Block::AcaciaPlanks => true,
+            Block::DarkOakPlanks => true,
+            Block::OakSapling => false,
+            Block::SpruceSapling => false,
+            Block::BirchSapling => false,
+            Block::JungleSapling => false,
+            Block::AcaciaSapling => false,
+            Block::DarkOakSapling => false,
+            Block::Bedrock => true,
+            Block::Water => false,
+            Block::Lava => false,
+            Block::Sand => true,
+            Block::RedSand => true,
+            Block::Gravel => true,
+            Block::GoldOre => true,
+            Block::IronOre => true,
+            Block::CoalOre => true,
+            Block::OakLog => true,
+            Block::SpruceLog => true,
+            Block::BirchLog => true,
+            Block::JungleLog => true,
+            Block::AcaciaLog => true,
+            Block::DarkOakLog => true,
+            Block::StrippedSpruceLog => true,
+            Block::StrippedBirchLog => true,
+            Block::StrippedJungleLog => true,
+            Block::StrippedAcaciaLog => true,
+            Block::StrippedDarkOakLog => true,
+            Block::StrippedOakLog => true,
+            Block::OakWood => true,
+            Block::SpruceWood => true,
+            Block::BirchWood => true,
+            Block::JungleWood => true,
+            Block::AcaciaWood => true,
+            Block::DarkOakWood => true,
+            Block::StrippedOakWood => true,
+            Block::StrippedSpruceWood => true,
+            Block::StrippedBirchWood => true,
+            Block::StrippedJungleWood => true,
+            Block::StrippedAcaciaWood => true,
+            Block::StrippedDarkOakWood => true,
+            Block::OakLeaves => true,
+            Block::SpruceLeaves => true,
+            Block::BirchLeaves => true,
+            Block::JungleLeaves => true,
+            Block::AcaciaLeaves => true,
+            Block::DarkOakLeaves => true,
+            Block::Sponge => true,
+            Block::WetSponge => true,
+            Block::Glass => true,
+            Block::LapisOre => true,
+            Block::LapisBlock => true,
+            Block::Dispenser => true,
+            Block::Sandstone => true,
+            Block::ChiseledSandstone => true,
+            Block::CutSandstone => true,
+            Block::NoteBlock => true,
+            Block::WhiteBed => true,
+            Block::OrangeBed => true,
+            Block::MagentaBed => true,
+            Block::LightBlueBed => true,
+            Block::YellowBed => true,
+            Block::LimeBed => true,
+            Block::PinkBed => true,
+            Block::GrayBed => true,
+            Block::LightGrayBed => true,
+            Block::CyanBed => true,
+            Block::PurpleBed => true,
+            Block::BlueBed => true,
+            Block::BrownBed => true,
+            Block::GreenBed => true,
+            Block::RedBed => true,
+            Block::BlackBed => true,
+            Block::PoweredRail => false,
+            Block::DetectorRail => false,
+            Block::StickyPiston => true,
+            Block::Cobweb => false,
+            Block::Grass => false,
+            Block::Fern => false,
+            Block::DeadBush => false,
+            Block::Seagrass => false,
+            Block::TallSeagrass => false,
+            Block::Piston => true,
+            Block::PistonHead => true,
+            Block::WhiteWool => true,
+            Block::OrangeWool => true,
+            Block::MagentaWool => true,
+            Block::LightBlueWool => true,
+            Block::YellowWool => true,
+            Block::LimeWool => true,
+            Block::PinkWool => true,
+            Block::GrayWool => true,
+            Block::LightGrayWool => true,
+            Block::CyanWool => true,
+            Block::PurpleWool => true,
+            Block::BlueWool => true,
+            Block::BrownWool => true,
+            Block::GreenWool => true,
+            Block::RedWool => true,
+            Block::BlackWool => true,
+            Block::MovingPiston => false,
+            Block::Dandelion => false,
+            Block::Poppy => false,
+            Block::BlueOrchid => false,
+            Block::Allium => false,
+            Block::AzureBluet => false,
+            Block::RedTulip => false,
+            Block::OrangeTulip => false,
+            Block::WhiteTulip => false,
+            Block::PinkTulip => false,
+            Block::OxeyeDaisy => false,
+            Block::Cornflower => false,
+            Block::WitherRose => false,
+            Block::LilyOfTheValley => false,
+            Block::BrownMushroom => false,
+            Block::RedMushroom => false,
+            Block::GoldBlock => true,
+            Block::IronBlock => true,
+            Block::Bricks => true,
+            Block::Tnt => true,
+            Block::Bookshelf => true,
+            Block::MossyCobblestone => true,
+            Block::Obsidian => true,
+            Block::Torch => false,
+            Block::WallTorch => false,
+            Block::Fire => false,
+            Block::Spawner => true,
+            Block::OakStairs => true,
+            Block::Chest => true,
+            Block::RedstoneWire => false,
+            Block::DiamondOre => true,
+            Block::DiamondBlock => true,
+            Block::CraftingTable => true,
+            Block::Wheat => false,
+            Block::Farmland => true,
+            Block::Furnace => true,
+            Block::OakSign => false,
+            Block::SpruceSign => false,
+            Block::BirchSign => false,
+            Block::AcaciaSign => false,
+            Block::JungleSign => false,
+            Block::DarkOakSign => false,
+            Block::OakDoor => true,
+            Block::Ladder => false,
+            Block::Rail => false,
+            Block::CobblestoneStairs => true,
+            Block::OakWallSign => false,
+            Block::SpruceWallSign => false,
+            Block::BirchWallSign => false,
+            Block::AcaciaWallSign => false,
+            Block::JungleWallSign => false,
+            Block::DarkOakWallSign => false,
+            Block::Lever => false,
+            Block::StonePressurePlate => false,
+            Block::IronDoor => true,
+            Block::OakPressurePlate => false,
+            Block::SprucePressurePlate => false,
+            Block::BirchPressurePlate => false,
+            Block::JunglePressurePlate => false,
+            Block::AcaciaPressurePlate => false,
+            Block::DarkOakPressurePlate => false,
+            Block::RedstoneOre => true,
+            Block::RedstoneTorch => false,
+            Block::RedstoneWallTorch => false,
+            Block::StoneButton => false,
+            Block::Snow => true,
+            Block::Ice => true,
+            Block::SnowBlock => true,
+            Block::Cactus => true,
+            Block::Clay => true,
+            Block::SugarCane => false,
+            Block::Jukebox => true,
+            Block::OakFence => true,
+            Block::Pumpkin => true,
+            Block::Netherrack => true,
+            Block::SoulSand => true,
+            Block::Glowstone => true,
+            Block::NetherPortal => false,
+            Block::CarvedPumpkin => true,
+            Block::JackOLantern => true,
+            Block::Cake => true,
+            Block::Repeater => true,
+            Block::WhiteStainedGlass => true,
+            Block::OrangeStainedGlass => true,
+            Block::MagentaStainedGlass => true,
+            Block::LightBlueStainedGlass => true,
+            Block::YellowStainedGlass => true,
+            Block::LimeStainedGlass => true,
+            Block::PinkStainedGlass => true,
+            Block::GrayStainedGlass => true,
+            Block::LightGrayStainedGlass => true,
+            Block::CyanStainedGlass => true,
+            Block::PurpleStainedGlass => true,
+            Block::BlueStainedGlass => true,
+            Block::BrownStainedGlass => true,
+            Block::GreenStainedGlass => true,
+            Block::RedStainedGlass => true,
+            Block::BlackStainedGlass => true,
+            Block::OakTrapdoor => true,
+            Block::SpruceTrapdoor => true,
+            Block::BirchTrapdoor => true,
+            Block::JungleTrapdoor => true,
+            Block::AcaciaTrapdoor => true,
+            Block::DarkOakTrapdoor => true,
+            Block::StoneBricks => true,
+            Block::MossyStoneBricks => true,
+            Block::CrackedStoneBricks => true,
+            Block::ChiseledStoneBricks => true,
+            Block::InfestedStone => true,
+            Block::InfestedCobblestone => true,
+            Block::InfestedStoneBricks => true,
+            Block::InfestedMossyStoneBricks => true,
+            Block::InfestedCrackedStoneBricks => true,
+            Block::InfestedChiseledStoneBricks => true,
+            Block::BrownMushroomBlock => true,
+            Block::RedMushroomBlock => true,
+            Block::MushroomStem => true,
+            Block::IronBars => true,
+            Block::GlassPane => true,
+            Block::Melon => true,
+            Block::AttachedPumpkinStem => false,
+            Block::AttachedMelonStem => false,
+            Block::PumpkinStem => false,
+            Block::MelonStem => false,
+            Block::Vine => false,
+            Block::OakFenceGate => true,
+            Block::BrickStairs => true,
+            Block::StoneBrickStairs => true,
+            Block::Mycelium => true,
+            Block::LilyPad => true,
+            Block::NetherBricks => true,
+            Block::NetherBrickFence => true,
+            Block::NetherBrickStairs => true,
+            Block::NetherWart => false,
+            Block::EnchantingTable => true,
+            Block::BrewingStand => true,
+            Block::Cauldron => true,
+            Block::EndPortal => false,
+            Block::EndPortalFrame => true,
+            Block::EndStone => true,
+            Block::DragonEgg => true,
+            Block::RedstoneLamp => true,
+            Block::Cocoa => true,
+            Block::SandstoneStairs => true,
+            Block::EmeraldOre => true,
+            Block::EnderChest => true,
+            Block::TripwireHook => false,
+            Block::Tripwire => false,
+            Block::EmeraldBlock => true,
+            Block::SpruceStairs => true,
+            Block::BirchStairs => true,
+            Block::JungleStairs => true,
+            Block::CommandBlock => true,
+            Block::Beacon => true,
+            Block::CobblestoneWall => true,
+            Block::MossyCobblestoneWall => true,
+            Block::FlowerPot => true,
+            Block::PottedOakSapling => false,
+            Block::PottedSpruceSapling => false,
+            Block::PottedBirchSapling => false,
+            Block::PottedJungleSapling => false,
+            Block::PottedAcaciaSapling => false,
+            Block::PottedDarkOakSapling => false,
+            Block::PottedFern => true,
+            Block::PottedDandelion => true,
+            Block::PottedPoppy => true,
+            Block::PottedBlueOrchid => true,
+            Block::PottedAllium => true,
+            Block::PottedAzureBluet => true,
+            Block::PottedRedTulip => true,
+            Block::PottedOrangeTulip => true,
+            Block::PottedWhiteTulip => true,
+            Block::PottedPinkTulip => true,
+            Block::PottedOxeyeDaisy => true,
+            Block::PottedCornflower => true,
+            Block::PottedLilyOfTheValley => true,
+            Block::PottedWitherRose => true,
+            Block::PottedRedMushroom => true,
+            Block::PottedBrownMushroom => true,
+            Block::PottedDeadBush => true,
+            Block::PottedCactus => true,
+            Block::Carrots => false,
+            Block::Potatoes => false,
+            Block::OakButton => false,
+            Block::SpruceButton => false,
+            Block::BirchButton => false,
+            Block::JungleButton => false,
+            Block::AcaciaButton => false,
+            Block::DarkOakButton => false,
+            Block::SkeletonSkull => true,
+            Block::SkeletonWallSkull => true,
+            Block::WitherSkeletonSkull => true,
+            Block::WitherSkeletonWallSkull => true,
+            Block::ZombieHead => true,
+            Block::ZombieWallHead => true,
+            Block::PlayerHead => true,
+            Block::PlayerWallHead => true,
+            Block::CreeperHead => true,
+            Block::CreeperWallHead => true,
+            Block::DragonHead => true,
+            Block::DragonWallHead => true,
+            Block::Anvil => true,
+            Block::ChippedAnvil => true,
+            Block::DamagedAnvil => true,
+            Block::TrappedChest => true,
+            Block::LightWeightedPressurePlate => false,
+            Block::HeavyWeightedPressurePlate => false,
+            Block::Comparator => true,
+            Block::DaylightDetector => true,
+            Block::RedstoneBlock => true,
+            Block::NetherQuartzOre => true,
+            Block::Hopper => true,
+            Block::QuartzBlock => true,
+            Block::ChiseledQuartzBlock => true,
+            Block::QuartzPillar => true,
+            Block::QuartzStairs => true,
+            Block::ActivatorRail => false,
+            Block::Dropper => true,
+            Block::WhiteTerracotta => true,
+            Block::OrangeTerracotta => true,
+            Block::MagentaTerracotta => true,
+            Block::LightBlueTerracotta => true,
+            Block::YellowTerracotta => true,
+            Block::LimeTerracotta => true,
+            Block::PinkTerracotta => true,
+            Block::GrayTerracotta => true,
+            Block::LightGrayTerracotta => true,
+            Block::CyanTerracotta => true,
+            Block::PurpleTerracotta => true,
+            Block::BlueTerracotta => true,
+            Block::BrownTerracotta => true,
+            Block::GreenTerracotta => true,
+            Block::RedTerracotta => true,
+            Block::BlackTerracotta => true,
+            Block::WhiteStainedGlassPane => true,
+            Block::OrangeStainedGlassPane => true,
+            Block::MagentaStainedGlassPane => true,
+            Block::LightBlueStainedGlassPane => true,
+            Block::YellowStainedGlassPane => true,
+            Block::LimeStainedGlassPane => true,
+            Block::PinkStainedGlassPane => true,
+            Block::GrayStainedGlassPane => true,
+            Block::LightGrayStainedGlassPane => true,
+            Block::CyanStainedGlassPane => true,
+            Block::PurpleStainedGlassPane => true,
+            Block::BlueStainedGlassPane => true,
+            Block::BrownStainedGlassPane => true,
+            Block::GreenStainedGlassPane => true,
+            Block::RedStainedGlassPane => true,
+            Block::BlackStainedGlassPane => true,
+            Block::AcaciaStairs => true,
+            Block::DarkOakStairs => true,
+            Block::SlimeBlock => true,
+            Block::Barrier => true,
+            Block::IronTrapdoor => true,
+            Block::Prismarine => true,
+            Block::PrismarineBricks => true,
+            Block::DarkPrismarine => true,
+            Block::PrismarineStairs => true,
+            Block::PrismarineBrickStairs => true,
+            Block::DarkPrismarineStairs => true,
+            Block::PrismarineSlab => true,
+            Block::PrismarineBrickSlab => true,
+            Block::DarkPrismarineSlab => true,
+            Block::SeaLantern => true,
+            Block::HayBlock => true,
+            Block::WhiteCarpet => true,
+            Block::OrangeCarpet => true,
+            Block::MagentaCarpet => true,
+            Block::LightBlueCarpet => true,
+            Block::YellowCarpet => true,
+            Block::LimeCarpet => true,
+            Block::PinkCarpet => true,
+            Block::GrayCarpet => true,
+            Block::LightGrayCarpet => true,
+            Block::CyanCarpet => true,
+            Block::PurpleCarpet => true,
+            Block::BlueCarpet => true,
+            Block::BrownCarpet => true,
+            Block::GreenCarpet => true,
+            Block::RedCarpet => true,
+            Block::BlackCarpet => true,
+            Block::Terracotta => true,
+            Block::CoalBlock => true,
+            Block::PackedIce => true,
+            Block::Sunflower => false,
+            Block::Lilac => false,
+            Block::RoseBush => false,
+            Block::Peony => false,
+            Block::TallGrass => false,
+            Block::LargeFern => false,
+            Block::WhiteBanner => false,
+            Block::OrangeBanner => false,
+            Block::MagentaBanner => false,
+            Block::LightBlueBanner => false,
+            Block::YellowBanner => false,
+            Block::LimeBanner => false,
+            Block::PinkBanner => false,
+            Block::GrayBanner => false,
+            Block::LightGrayBanner => false,
+            Block::CyanBanner => false,
+            Block::PurpleBanner => false,
+            Block::BlueBanner => false,
+            Block::BrownBanner => false,
+            Block::GreenBanner => false,
+            Block::RedBanner => false,
+            Block::BlackBanner => false,
+            Block::WhiteWallBanner => false,
+            Block::OrangeWallBanner => false,
+            Block::MagentaWallBanner => false,
+            Block::LightBlueWallBanner => false,
+            Block::YellowWallBanner => false,
+            Block::LimeWallBanner => false,
+            Block::PinkWallBanner => false,
+            Block::GrayWallBanner => false,
+            Block::LightGrayWallBanner => false,
+            Block::CyanWallBanner => false,
+            Block::PurpleWallBanner => false,
+            Block::BlueWallBanner => false,
+            Block::BrownWallBanner => false,
+            Block::GreenWallBanner => false,
+            Block::RedWallBanner => false,
+            Block::BlackWallBanner => false,
+            Block::RedSandstone => true,
+            Block::ChiseledRedSandstone => true,
+            Block::CutRedSandstone => true,
+            Block::RedSandstoneStairs => true,
+            Block::OakSlab => true,
+            Block::SpruceSlab => true,
+            Block::BirchSlab => true,
+            Block::JungleSlab => true,
+            Block::AcaciaSlab => true,
+            Block::DarkOakSlab => true,
+            Block::StoneSlab => true,
+            Block::SmoothStoneSlab => true,
+            Block::SandstoneSlab => true,
+            Block::CutSandstoneSlab => true,
+            Block::PetrifiedOakSlab => true,
+            Block::CobblestoneSlab => true,
+            Block::BrickSlab => true,
+            Block::StoneBrickSlab => true,
+            Block::NetherBrickSlab => true,
+            Block::QuartzSlab => true,
+            Block::RedSandstoneSlab => true,
+            Block::CutRedSandstoneSlab => true,
+            Block::PurpurSlab => true,
+            Block::SmoothStone => true,
+            Block::SmoothSandstone => true,
+            Block::SmoothQuartz => true,
+            Block::SmoothRedSandstone => true,
+            Block::SpruceFenceGate => true,
+            Block::BirchFenceGate => true,
+            Block::JungleFenceGate => true,
+            Block::AcaciaFenceGate => true,
+            Block::DarkOakFenceGate => true,
+            Block::SpruceFence => true,
+            Block::BirchFence => true,
+            Block::JungleFence => true,
+            Block::AcaciaFence => true,
+            Block::DarkOakFence => true,
+            Block::SpruceDoor => true,
+            Block::BirchDoor => true,
+            Block::JungleDoor => true,
+            Block::AcaciaDoor => true,
+            Block::DarkOakDoor => true,
+            Block::EndRod => true,
+            Block::ChorusPlant => true,
+            Block::ChorusFlower => true,
+            Block::PurpurBlock => true,
+            Block::PurpurPillar => true,
+            Block::PurpurStairs => true,
+            Block::EndStoneBricks => true,
+            Block::Beetroots => false,
+            Block::GrassPath => true,
+            Block::EndGateway => false,
+            Block::RepeatingCommandBlock => true,
+            Block::ChainCommandBlock => true,
+            Block::FrostedIce => true,
+            Block::MagmaBlock => true,
+            Block::NetherWartBlock => true,
+            Block::RedNetherBricks => true,
+            Block::BoneBlock => true,
+            Block::StructureVoid => false,
+            Block::Observer => true,
+            Block::ShulkerBox => true,
+            Block::WhiteShulkerBox => true,
+            Block::OrangeShulkerBox => true,
+            Block::MagentaShulkerBox => true,
+            Block::LightBlueShulkerBox => true,
+            Block::YellowShulkerBox => true,
+            Block::LimeShulkerBox => true,
+            Block::PinkShulkerBox => true,
+            Block::GrayShulkerBox => true,
+            Block::LightGrayShulkerBox => true,
+            Block::CyanShulkerBox => true,
+            Block::PurpleShulkerBox => true,
+            Block::BlueShulkerBox => true,
+            Block::BrownShulkerBox => true,
+            Block::GreenShulkerBox => true,
+            Block::RedShulkerBox => true,
+            Block::BlackShulkerBox => true,
+            Block::WhiteGlazedTerracotta => true,
+            Block::OrangeGlazedTerracotta => true,
+            Block::MagentaGlazedTerracotta => true,
+            Block::LightBlueGlazedTerracotta => true,
+            Block::YellowGlazedTerracotta => true,
+            Block::LimeGlazedTerracotta => true,
+            Block::PinkGlazedTerracotta => true,
+            Block::GrayGlazedTerracotta => true,
+            Block::LightGrayGlazedTerracotta => true,
+            Block::CyanGlazedTerracotta => true,
+            Block::PurpleGlazedTerracotta => true,
+            Block::BlueGlazedTerracotta => true,
+            Block::BrownGlazedTerracotta => true,
+            Block::GreenGlazedTerracotta => true,
+            Block::RedGlazedTerracotta => true,
+            Block::BlackGlazedTerracotta => true,
+            Block::WhiteConcrete => true,
+            Block::OrangeConcrete => true,
+            Block::MagentaConcrete => true,
+            Block::LightBlueConcrete => true,
+            Block::YellowConcrete => true,
+            Block::LimeConcrete => true,
+            Block::PinkConcrete => true,
+            Block::GrayConcrete => true,
+            Block::LightGrayConcrete => true,
+            Block::CyanConcrete => true,
+            Block::PurpleConcrete => true,
+            Block::BlueConcrete => true,
+            Block::BrownConcrete => true,
+            Block::GreenConcrete => true,
+            Block::RedConcrete => true,
+            Block::BlackConcrete => true,
+            Block::WhiteConcretePowder => true,
+            Block::OrangeConcretePowder => true,
+            Block::MagentaConcretePowder => true,
+            Block::LightBlueConcretePowder => true,
+            Block::YellowConcretePowder => true,
+            Block::LimeConcretePowder => true,
+            Block::PinkConcretePowder => true,
+            Block::GrayConcretePowder => true,
+            Block::LightGrayConcretePowder => true,
+            Block::CyanConcretePowder => true,
+            Block::PurpleConcretePowder => true,
+            Block::BlueConcretePowder => true,
+            Block::BrownConcretePowder => true,
+            Block::GreenConcretePowder => true,
+            Block::RedConcretePowder => true,
+            Block::BlackConcretePowder => true,
+            Block::Kelp => false,
+            Block::KelpPlant => false,
+            Block::DriedKelpBlock => true,
+            Block::TurtleEgg => true,
+            Block::DeadTubeCoralBlock => true,
+            Block::DeadBrainCoralBlock => true,
+            Block::DeadBubbleCoralBlock => true,
+            Block::DeadFireCoralBlock => true,
+            Block::DeadHornCoralBlock => true,
+            Block::TubeCoralBlock => true,
+            Block::BrainCoralBlock => true,
+            Block::BubbleCoralBlock => true,
+            Block::FireCoralBlock => true,
+            Block::HornCoralBlock => true,
+            Block::DeadTubeCoral => false,
+            Block::DeadBrainCoral => false,
+            Block::DeadBubbleCoral => false,
+            Block::DeadFireCoral => false,
+            Block::DeadHornCoral => false,
+            Block::TubeCoral => false,
+            Block::BrainCoral => false,
+            Block::BubbleCoral => false,
+            Block::FireCoral => false,
+            Block::HornCoral => false,
+            Block::DeadTubeCoralFan => false,
+            Block::DeadBrainCoralFan => false,
+            Block::DeadBubbleCoralFan => false,
+            Block::DeadFireCoralFan => false,
+            Block::DeadHornCoralFan => false,
+            Block::TubeCoralFan => false,
+            Block::BrainCoralFan => false,
+            Block::BubbleCoralFan => false,
+            Block::FireCoralFan => false,
+            Block::HornCoralFan => false,
+            Block::DeadTubeCoralWallFan => false,
+            Block::DeadBrainCoralWallFan => false,
+            Block::DeadBubbleCoralWallFan => false,
+            Block::DeadFireCoralWallFan => false,
+            Block::DeadHornCoralWallFan => false,
+            Block::TubeCoralWallFan => false,
+            Block::BrainCoralWallFan => false,
+            Block::BubbleCoralWallFan => false,
+            Block::FireCoralWallFan => false,
+            Block::HornCoralWallFan => false,
+            Block::SeaPickle => true,
+            Block::BlueIce => true,
+            Block::Conduit => true,
+            Block::BambooSapling => false,
+            Block::Bamboo => true,
+            Block::PottedBamboo => true,
+            Block::VoidAir => false,
+            Block::CaveAir => false,
+            Block::BubbleColumn => false,
+            Block::PolishedGraniteStairs => true,
+            Block::SmoothRedSandstoneStairs => true,
+            Block::MossyStoneBrickStairs => true,
+            Block::PolishedDioriteStairs => true,
+            Block::MossyCobblestoneStairs => true,
+            Block::EndStoneBrickStairs => true,
+            Block::StoneStairs => true,
+            Block::SmoothSandstoneStairs => true,
+            Block::SmoothQuartzStairs => true,
+            Block::GraniteStairs => true,
+            Block::AndesiteStairs => true,
+            Block::RedNetherBrickStairs => true,
+            Block::PolishedAndesiteStairs => true,
+            Block::DioriteStairs => true,
+            Block::PolishedGraniteSlab => true,
+            Block::SmoothRedSandstoneSlab => true,
+            Block::MossyStoneBrickSlab => true,
+            Block::PolishedDioriteSlab => true,
+            Block::MossyCobblestoneSlab => true,
+            Block::EndStoneBrickSlab => true,
+            Block::SmoothSandstoneSlab => true,
+            Block::SmoothQuartzSlab => true,
+            Block::GraniteSlab => true,
+            Block::AndesiteSlab => true,
+            Block::RedNetherBrickSlab => true,
+            Block::PolishedAndesiteSlab => true,
+            Block::DioriteSlab => true,
+            Block::BrickWall => true,
+            Block::PrismarineWall => true,
+            Block::RedSandstoneWall => true,
+            Block::MossyStoneBrickWall => true,
+            Block::GraniteWall => true,
+            Block::StoneBrickWall => true,
+            Block::NetherBrickWall => true,
+            Block::AndesiteWall => true,
+            Block::RedNetherBrickWall => true,
+            Block::SandstoneWall => true,
+            Block::EndStoneBrickWall => true,
+            Block::DioriteWall => true,
+            Block::Scaffolding => true,
+            Block::Loom => true,
+            Block::Barrel => true,
+            Block::Smoker => true,
+            Block::BlastFurnace => true,
+            Block::CartographyTable => true,
+            Block::FletchingTable => true,
+            Block::Grindstone => true,
+            Block::Lectern => true,
+            Block::SmithingTable => true,
+            Block::Stonecutter => true,
+            Block::Bell => true,
+            Block::Lantern => true,
+            Block::Campfire => true,
+            Block::SweetBerryBush => false,
+            Block::StructureBlock => true,
+            Block::Jigsaw => true,
+            Block::Composter => true,
+            Block::BeeNest => true,
+            Block::Beehive => true,
+            Block::HoneyBlock => true,
+            Block::HoneycombBlock => true,
+        }
+    }
+    pub fn filter_light(&self) -> u8 {
+        match self {
+            Block::Air => 0,
+            Block::Stone => 15,
+            Block::Granite => 15,
+            Block::PolishedGranite => 15,
+            Block::Diorite => 15,
+            Block::PolishedDiorite => 15,
+            Block::Andesite => 15,
+            Block::PolishedAndesite => 15,
+            Block::GrassBlock => 15,
+            Block::Dirt => 15,
+            Block::CoarseDirt => 15,
+            Block::Podzol => 15,
+            Block::Cobblestone => 15,
+            Block::OakPlanks => 15,
+            Block::SprucePlanks => 15,
+            Block::BirchPlanks => 15,
+            Block::JunglePlanks => 15,
+            Block::AcaciaPlanks => 15,
+            Block::DarkOakPlanks => 15,
+            Block::OakSapling => 0,
+            Block::SpruceSapling => 0,
+            Block::BirchSapling => 0,
+            Block::JungleSapling => 0,
+            Block::AcaciaSapling => 0,
+            Block::DarkOakSapling => 0,
+            Block::Bedrock => 15,
+            Block::Water => 1,
+            Block::Lava => 0,
+            Block::Sand => 15,
+            Block::RedSand => 15,
+            Block::Gravel => 15,
+            Block::GoldOre => 15,
+            Block::IronOre => 15,
+            Block::CoalOre => 15,
+            Block::OakLog => 15,
+            Block::SpruceLog => 15,
+            Block::BirchLog => 15,
+            Block::JungleLog => 15,
+            Block::AcaciaLog => 15,
+            Block::DarkOakLog => 15,
+            Block::StrippedSpruceLog => 15,
+            Block::StrippedBirchLog => 15,
+            Block::StrippedJungleLog => 15,
+            Block::StrippedAcaciaLog => 15,
+            Block::StrippedDarkOakLog => 15,
+            Block::StrippedOakLog => 15,
+            Block::OakWood => 15,
+            Block::SpruceWood => 15,
+            Block::BirchWood => 15,
+            Block::JungleWood => 15,
+            Block::AcaciaWood => 15,
+            Block::DarkOakWood => 15,
+            Block::StrippedOakWood => 15,
+            Block::StrippedSpruceWood => 15,
+            Block::StrippedBirchWood => 15,
+            Block::StrippedJungleWood => 15,
+            Block::StrippedAcaciaWood => 15,
+            Block::StrippedDarkOakWood => 15,
+            Block::OakLeaves => 1,
+            Block::SpruceLeaves => 1,
+            Block::BirchLeaves => 1,
+            Block::JungleLeaves => 1,
+            Block::AcaciaLeaves => 1,
+            Block::DarkOakLeaves => 1,
+            Block::Sponge => 15,
+            Block::WetSponge => 15,
+            Block::Glass => 0,
+            Block::LapisOre => 15,
+            Block::LapisBlock => 15,
+            Block::Dispenser => 15,
+            Block::Sandstone => 15,
+            Block::ChiseledSandstone => 15,
+            Block::CutSandstone => 15,
+            Block::NoteBlock => 15,
+            Block::WhiteBed => 0,
+            Block::OrangeBed => 0,
+            Block::MagentaBed => 0,
+            Block::LightBlueBed => 0,
+            Block::YellowBed => 0,
+            Block::LimeBed => 0,
+            Block::PinkBed => 0,
+            Block::GrayBed => 0,
+            Block::LightGrayBed => 0,
+            Block::CyanBed => 0,
+            Block::PurpleBed => 0,
+            Block::BlueBed => 0,
+            Block::BrownBed => 0,
+            Block::GreenBed => 0,
+            Block::RedBed => 0,
+            Block::BlackBed => 0,
+            Block::PoweredRail => 0,
+            Block::DetectorRail => 0,
+            Block::StickyPiston => 15,
+            Block::Cobweb => 1,
+            Block::Grass => 0,
+            Block::Fern => 0,
+            Block::DeadBush => 0,
+            Block::Seagrass => 0,
+            Block::TallSeagrass => 0,
+            Block::Piston => 15,
+            Block::PistonHead => 0,
+            Block::WhiteWool => 15,
+            Block::OrangeWool => 15,
+            Block::MagentaWool => 15,
+            Block::LightBlueWool => 15,
+            Block::YellowWool => 15,
+            Block::LimeWool => 15,
+            Block::PinkWool => 15,
+            Block::GrayWool => 15,
+            Block::LightGrayWool => 15,
+            Block::CyanWool => 15,
+            Block::PurpleWool => 15,
+            Block::BlueWool => 15,
+            Block::BrownWool => 15,
+            Block::GreenWool => 15,
+            Block::RedWool => 15,
+            Block::BlackWool => 15,
+            Block::MovingPiston => 0,
+            Block::Dandelion => 0,
+            Block::Poppy => 0,
+            Block::BlueOrchid => 0,
+            Block::Allium => 0,
+            Block::AzureBluet => 0,
+            Block::RedTulip => 0,
+            Block::OrangeTulip => 0,
+            Block::WhiteTulip => 0,
+            Block::PinkTulip => 0,
+            Block::OxeyeDaisy => 0,
+            Block::Cornflower => 0,
+            Block::WitherRose => 0,
+            Block::LilyOfTheValley => 0,
+            Block::BrownMushroom => 0,
+            Block::RedMushroom => 0,
+            Block::GoldBlock => 15,
+            Block::IronBlock => 15,
+            Block::Bricks => 15,
+            Block::Tnt => 15,
+            Block::Bookshelf => 15,
+            Block::MossyCobblestone => 15,
+            Block::Obsidian => 15,
+            Block::Torch => 0,
+            Block::WallTorch => 0,
+            Block::Fire => 0,
+            Block::Spawner => 0,
+            Block::OakStairs => 0,
+            Block::Chest => 0,
+            Block::RedstoneWire => 0,
+            Block::DiamondOre => 15,
+            Block::DiamondBlock => 15,
+            Block::CraftingTable => 15,
+            Block::Wheat => 0,
+            Block::Farmland => 0,
+            Block::Furnace => 15,
+            Block::OakSign => 0,
+            Block::SpruceSign => 0,
+            Block::BirchSign => 0,
+            Block::AcaciaSign => 0,
+            Block::JungleSign => 0,
+            Block::DarkOakSign => 0,
+            Block::OakDoor => 0,
+            Block::Ladder => 0,
+            Block::Rail => 0,
+            Block::CobblestoneStairs => 0,
+            Block::OakWallSign => 0,
+            Block::SpruceWallSign => 0,
+            Block::BirchWallSign => 0,
+            Block::AcaciaWallSign => 0,
+            Block::JungleWallSign => 0,
+            Block::DarkOakWallSign => 0,
+            Block::Lever => 0,
+            Block::StonePressurePlate => 0,
+            Block::IronDoor => 0,
+            Block::OakPressurePlate => 0,
+            Block::SprucePressurePlate => 0,
+            Block::BirchPressurePlate => 0,
+            Block::JunglePressurePlate => 0,
+            Block::AcaciaPressurePlate => 0,
+            Block::DarkOakPressurePlate => 0,
+            Block::RedstoneOre => 15,
+            Block::RedstoneTorch => 0,
+            Block::RedstoneWallTorch => 0,
+            Block::StoneButton => 0,
+            Block::Snow => 0,
+            Block::Ice => 1,
+            Block::SnowBlock => 15,
+            Block::Cactus => 0,
+            Block::Clay => 15,
+            Block::SugarCane => 0,
+            Block::Jukebox => 15,
+            Block::OakFence => 0,
+            Block::Pumpkin => 15,
+            Block::Netherrack => 15,
+            Block::SoulSand => 15,
+            Block::Glowstone => 15,
+            Block::NetherPortal => 0,
+            Block::CarvedPumpkin => 15,
+            Block::JackOLantern => 15,
+            Block::Cake => 0,
+            Block::Repeater => 0,
+            Block::WhiteStainedGlass => 0,
+            Block::OrangeStainedGlass => 0,
+            Block::MagentaStainedGlass => 0,
+            Block::LightBlueStainedGlass => 0,
+            Block::YellowStainedGlass => 0,
+            Block::LimeStainedGlass => 0,
+            Block::PinkStainedGlass => 0,
+            Block::GrayStainedGlass => 0,
+            Block::LightGrayStainedGlass => 0,
+            Block::CyanStainedGlass => 0,
+            Block::PurpleStainedGlass => 0,
+            Block::BlueStainedGlass => 0,
+            Block::BrownStainedGlass => 0,
+            Block::GreenStainedGlass => 0,
+            Block::RedStainedGlass => 0,
+            Block::BlackStainedGlass => 0,
+            Block::OakTrapdoor => 0,
+            Block::SpruceTrapdoor => 0,
+            Block::BirchTrapdoor => 0,
+            Block::JungleTrapdoor => 0,
+            Block::AcaciaTrapdoor => 0,
+            Block::DarkOakTrapdoor => 0,
+            Block::StoneBricks => 15,
+            Block::MossyStoneBricks => 15,
+            Block::CrackedStoneBricks => 15,
+            Block::ChiseledStoneBricks => 15,
+            Block::InfestedStone => 15,
+            Block::InfestedCobblestone => 15,
+            Block::InfestedStoneBricks => 15,
+            Block::InfestedMossyStoneBricks => 15,
+            Block::InfestedCrackedStoneBricks => 15,
+            Block::InfestedChiseledStoneBricks => 15,
+            Block::BrownMushroomBlock => 15,
+            Block::RedMushroomBlock => 15,
+            Block::MushroomStem => 15,
+            Block::IronBars => 0,
+            Block::GlassPane => 0,
+            Block::Melon => 15,
+            Block::AttachedPumpkinStem => 0,
+            Block::AttachedMelonStem => 0,
+            Block::PumpkinStem => 0,
+            Block::MelonStem => 0,
+            Block::Vine => 0,
+            Block::OakFenceGate => 0,
+            Block::BrickStairs => 0,
+            Block::StoneBrickStairs => 0,
+            Block::Mycelium => 15,
+            Block::LilyPad => 0,
+            Block::NetherBricks => 15,
+            Block::NetherBrickFence => 0,
+            Block::NetherBrickStairs => 0,
+            Block::NetherWart => 0,
+            Block::EnchantingTable => 0,
+            Block::BrewingStand => 0,
+            Block::Cauldron => 0,
+            Block::EndPortal => 0,
+            Block::EndPortalFrame => 0,
+            Block::EndStone => 15,
+            Block::DragonEgg => 0,
+            Block::RedstoneLamp => 15,
+            Block::Cocoa => 0,
+            Block::SandstoneStairs => 0,
+            Block::EmeraldOre => 15,
+            Block::EnderChest => 0,
+            Block::TripwireHook => 0,
+            Block::Tripwire => 0,
+            Block::EmeraldBlock => 15,
+            Block::SpruceStairs => 0,
+            Block::BirchStairs => 0,
+            Block::JungleStairs => 0,
+            Block::CommandBlock => 15,
+            Block::Beacon => 0,
+            Block::CobblestoneWall => 0,
+            Block::MossyCobblestoneWall => 0,
+            Block::FlowerPot => 0,
+            Block::PottedOakSapling => 0,
+            Block::PottedSpruceSapling => 0,
+            Block::PottedBirchSapling => 0,
+            Block::PottedJungleSapling => 0,
+            Block::PottedAcaciaSapling => 0,
+            Block::PottedDarkOakSapling => 0,
+            Block::PottedFern => 0,
+            Block::PottedDandelion => 0,
+            Block::PottedPoppy => 0,
+            Block::PottedBlueOrchid => 0,
+            Block::PottedAllium => 0,
+            Block::PottedAzureBluet => 0,
+            Block::PottedRedTulip => 0,
+            Block::PottedOrangeTulip => 0,
+            Block::PottedWhiteTulip => 0,
+            Block::PottedPinkTulip => 0,
+            Block::PottedOxeyeDaisy => 0,
+            Block::PottedCornflower => 0,
+            Block::PottedLilyOfTheValley => 0,
+            Block::PottedWitherRose => 0,
+            Block::PottedRedMushroom => 0,
+            Block::PottedBrownMushroom => 0,
+            Block::PottedDeadBush => 0,
+            Block::PottedCactus => 0,
+            Block::Carrots => 0,
+            Block::Potatoes => 0,
+            Block::OakButton => 0,
+            Block::SpruceButton => 0,
+            Block::BirchButton => 0,
+            Block::JungleButton => 0,
+            Block::AcaciaButton => 0,
+            Block::DarkOakButton => 0,
+            Block::SkeletonSkull => 0,
+            Block::SkeletonWallSkull => 0,
+            Block::WitherSkeletonSkull => 0,
+            Block::WitherSkeletonWallSkull => 0,
+            Block::ZombieHead => 0,
+            Block::ZombieWallHead => 0,
+            Block::PlayerHead => 0,
+            Block::PlayerWallHead => 0,
+            Block::CreeperHead => 0,
+            Block::CreeperWallHead => 0,
+            Block::DragonHead => 0,
+            Block::DragonWallHead => 0,
+            Block::Anvil => 0,
+            Block::ChippedAnvil => 0,
+            Block::DamagedAnvil => 0,
+            Block::TrappedChest => 0,
+            Block::LightWeightedPressurePlate => 0,
+            Block::HeavyWeightedPressurePlate => 0,
+            Block::Comparator => 0,
+            Block::DaylightDetector => 0,
+            Block::RedstoneBlock => 15,
+            Block::NetherQuartzOre => 15,
+            Block::Hopper => 0,
+            Block::QuartzBlock => 15,
+            Block::ChiseledQuartzBlock => 15,
+            Block::QuartzPillar => 15,
+            Block::QuartzStairs => 0,
+            Block::ActivatorRail => 0,
+            Block::Dropper => 15,
+            Block::WhiteTerracotta => 15,
+            Block::OrangeTerracotta => 15,
+            Block::MagentaTerracotta => 15,
+            Block::LightBlueTerracotta => 15,
+            Block::YellowTerracotta => 15,
+            Block::LimeTerracotta => 15,
+            Block::PinkTerracotta => 15,
+            Block::GrayTerracotta => 15,
+            Block::LightGrayTerracotta => 15,
+            Block::CyanTerracotta => 15,
+            Block::PurpleTerracotta => 15,
+            Block::BlueTerracotta => 15,
+            Block::BrownTerracotta => 15,
+            Block::GreenTerracotta => 15,
+            Block::RedTerracotta => 15,
+            Block::BlackTerracotta => 15,
+            Block::WhiteStainedGlassPane => 0,
+            Block::OrangeStainedGlassPane => 0,
+            Block::MagentaStainedGlassPane => 0,
+            Block::LightBlueStainedGlassPane => 0,
+            Block::YellowStainedGlassPane => 0,
+            Block::LimeStainedGlassPane => 0,
+            Block::PinkStainedGlassPane => 0,
+            Block::GrayStainedGlassPane => 0,
+            Block::LightGrayStainedGlassPane => 0,
+            Block::CyanStainedGlassPane => 0,
+            Block::PurpleStainedGlassPane => 0,
+            Block::BlueStainedGlassPane => 0,
+            Block::BrownStainedGlassPane => 0,
+            Block::GreenStainedGlassPane => 0,
+            Block::RedStainedGlassPane => 0,
+            Block::BlackStainedGlassPane => 0,
+            Block::AcaciaStairs => 0,
+            Block::DarkOakStairs => 0,
+            Block::SlimeBlock => 1,
+            Block::Barrier => 0,
+            Block::IronTrapdoor => 0,
+            Block::Prismarine => 15,
+            Block::PrismarineBricks => 15,
+            Block::DarkPrismarine => 15,
+            Block::PrismarineStairs => 0,
+            Block::PrismarineBrickStairs => 0,
+            Block::DarkPrismarineStairs => 0,
+            Block::PrismarineSlab => 0,
+            Block::PrismarineBrickSlab => 0,
+            Block::DarkPrismarineSlab => 0,
+            Block::SeaLantern => 15,
+            Block::HayBlock => 15,
+            Block::WhiteCarpet => 0,
+            Block::OrangeCarpet => 0,
+            Block::MagentaCarpet => 0,
+            Block::LightBlueCarpet => 0,
+            Block::YellowCarpet => 0,
+            Block::LimeCarpet => 0,
+            Block::PinkCarpet => 0,
+            Block::GrayCarpet => 0,
+            Block::LightGrayCarpet => 0,
+            Block::CyanCarpet => 0,
+            Block::PurpleCarpet => 0,
+            Block::BlueCarpet => 0,
+            Block::BrownCarpet => 0,
+            Block::GreenCarpet => 0,
+            Block::RedCarpet => 0,
+            Block::BlackCarpet => 0,
+            Block::Terracotta => 15,
+            Block::CoalBlock => 15,
+            Block::PackedIce => 15,
+            Block::Sunflower => 0,
+            Block::Lilac => 0,
+            Block::RoseBush => 0,
+            Block::Peony => 0,
+            Block::TallGrass => 0,
+            Block::LargeFern => 0,
+            Block::WhiteBanner => 0,
+            Block::OrangeBanner => 0,
+            Block::MagentaBanner => 0,
+            Block::LightBlueBanner => 0,
+            Block::YellowBanner => 0,
+            Block::LimeBanner => 0,
+            Block::PinkBanner => 0,
+            Block::GrayBanner => 0,
+            Block::LightGrayBanner => 0,
+            Block::CyanBanner => 0,
+            Block::PurpleBanner => 0,
+            Block::BlueBanner => 0,
+            Block::BrownBanner => 0,
+            Block::GreenBanner => 0,
+            Block::RedBanner => 0,
+            Block::BlackBanner => 0,
+            Block::WhiteWallBanner => 0,
+            Block::OrangeWallBanner => 0,
+            Block::MagentaWallBanner => 0,
+            Block::LightBlueWallBanner => 0,
+            Block::YellowWallBanner => 0,
+            Block::LimeWallBanner => 0,
+            Block::PinkWallBanner => 0,
+            Block::GrayWallBanner => 0,
+            Block::LightGrayWallBanner => 0,
+            Block::CyanWallBanner => 0,
+            Block::PurpleWallBanner => 0,
+            Block::BlueWallBanner => 0,
+            Block::BrownWallBanner => 0,
+            Block::GreenWallBanner => 0,
+            Block::RedWallBanner => 0,
+            Block::BlackWallBanner => 0,
+            Block::RedSandstone => 15,
+            Block::ChiseledRedSandstone => 15,
+            Block::CutRedSandstone => 15,
+            Block::RedSandstoneStairs => 0,
+            Block::OakSlab => 0,
+            Block::SpruceSlab => 0,
+            Block::BirchSlab => 0,
+            Block::JungleSlab => 0,
+            Block::AcaciaSlab => 0,
+            Block::DarkOakSlab => 0,
+            Block::StoneSlab => 0,
+            Block::SmoothStoneSlab => 0,
+            Block::SandstoneSlab => 0,
+            Block::CutSandstoneSlab => 0,
+            Block::PetrifiedOakSlab => 0,
+            Block::CobblestoneSlab => 0,
+            Block::BrickSlab => 0,
+            Block::StoneBrickSlab => 0,
+            Block::NetherBrickSlab => 0,
+            Block::QuartzSlab => 0,
+            Block::RedSandstoneSlab => 0,
+            Block::CutRedSandstoneSlab => 0,
+            Block::PurpurSlab => 0,
+            Block::SmoothStone => 15,
+            Block::SmoothSandstone => 15,
+            Block::SmoothQuartz => 15,
+            Block::SmoothRedSandstone => 15,
+            Block::SpruceFenceGate => 0,
+            Block::BirchFenceGate => 0,
+            Block::JungleFenceGate => 0,
+            Block::AcaciaFenceGate => 0,
+            Block::DarkOakFenceGate => 0,
+            Block::SpruceFence => 0,
+            Block::BirchFence => 0,
+            Block::JungleFence => 0,
+            Block::AcaciaFence => 0,
+            Block::DarkOakFence => 0,
+            Block::SpruceDoor => 0,
+            Block::BirchDoor => 0,
+            Block::JungleDoor => 0,
+            Block::AcaciaDoor => 0,
+            Block::DarkOakDoor => 0,
+            Block::EndRod => 0,
+            Block::ChorusPlant => 0,
+            Block::ChorusFlower => 0,
+            Block::PurpurBlock => 15,
+            Block::PurpurPillar => 15,
+            Block::PurpurStairs => 0,
+            Block::EndStoneBricks => 15,
+            Block::Beetroots => 0,
+            Block::GrassPath => 0,
+            Block::EndGateway => 0,
+            Block::RepeatingCommandBlock => 15,
+            Block::ChainCommandBlock => 15,
+            Block::FrostedIce => 1,
+            Block::MagmaBlock => 15,
+            Block::NetherWartBlock => 15,
+            Block::RedNetherBricks => 15,
+            Block::BoneBlock => 15,
+            Block::StructureVoid => 0,
+            Block::Observer => 15,
+            Block::ShulkerBox => 0,
+            Block::WhiteShulkerBox => 0,
+            Block::OrangeShulkerBox => 0,
+            Block::MagentaShulkerBox => 0,
+            Block::LightBlueShulkerBox => 0,
+            Block::YellowShulkerBox => 0,
+            Block::LimeShulkerBox => 0,
+            Block::PinkShulkerBox => 0,
+            Block::GrayShulkerBox => 0,
+            Block::LightGrayShulkerBox => 0,
+            Block::CyanShulkerBox => 0,
+            Block::PurpleShulkerBox => 0,
+            Block::BlueShulkerBox => 0,
+            Block::BrownShulkerBox => 0,
+            Block::GreenShulkerBox => 0,
+            Block::RedShulkerBox => 0,
+            Block::BlackShulkerBox => 0,
+            Block::WhiteGlazedTerracotta => 15,
+            Block::OrangeGlazedTerracotta => 15,
+            Block::MagentaGlazedTerracotta => 15,
+            Block::LightBlueGlazedTerracotta => 15,
+            Block::YellowGlazedTerracotta => 15,
+            Block::LimeGlazedTerracotta => 15,
+            Block::PinkGlazedTerracotta => 15,
+            Block::GrayGlazedTerracotta => 15,
+            Block::LightGrayGlazedTerracotta => 15,
+            Block::CyanGlazedTerracotta => 15,
+            Block::PurpleGlazedTerracotta => 15,
+            Block::BlueGlazedTerracotta => 15,
+            Block::BrownGlazedTerracotta => 15,
+            Block::GreenGlazedTerracotta => 15,
+            Block::RedGlazedTerracotta => 15,
+            Block::BlackGlazedTerracotta => 15,
+            Block::WhiteConcrete => 15,
+            Block::OrangeConcrete => 15,
+            Block::MagentaConcrete => 15,
+            Block::LightBlueConcrete => 15,
+            Block::YellowConcrete => 15,
+            Block::LimeConcrete => 15,
+            Block::PinkConcrete => 15,
+            Block::GrayConcrete => 15,
+            Block::LightGrayConcrete => 15,
+            Block::CyanConcrete => 15,
+            Block::PurpleConcrete => 15,
+            Block::BlueConcrete => 15,
+            Block::BrownConcrete => 15,
+            Block::GreenConcrete => 15,
+            Block::RedConcrete => 15,
+            Block::BlackConcrete => 15,
+            Block::WhiteConcretePowder => 15,
+            Block::OrangeConcretePowder => 15,
+            Block::MagentaConcretePowder => 15,
+            Block::LightBlueConcretePowder => 15,
+            Block::YellowConcretePowder => 15,
+            Block::LimeConcretePowder => 15,
+            Block::PinkConcretePowder => 15,
+            Block::GrayConcretePowder => 15,
+            Block::LightGrayConcretePowder => 15,
+            Block::CyanConcretePowder => 15,
+            Block::PurpleConcretePowder => 15,
+            Block::BlueConcretePowder => 15,
+            Block::BrownConcretePowder => 15,
+            Block::GreenConcretePowder => 15,
+            Block::RedConcretePowder => 15,
+            Block::BlackConcretePowder => 15,
+            Block::Kelp => 0,
+            Block::KelpPlant => 0,
+            Block::DriedKelpBlock => 15,
+            Block::TurtleEgg => 0,
+            Block::DeadTubeCoralBlock => 15,
+            Block::DeadBrainCoralBlock => 15,
+            Block::DeadBubbleCoralBlock => 15,
+            Block::DeadFireCoralBlock => 15,
+            Block::DeadHornCoralBlock => 15,
+            Block::TubeCoralBlock => 15,
+            Block::BrainCoralBlock => 15,
+            Block::BubbleCoralBlock => 15,
+            Block::FireCoralBlock => 15,
+            Block::HornCoralBlock => 15,
+            Block::DeadTubeCoral => 0,
+            Block::DeadBrainCoral => 0,
+            Block::DeadBubbleCoral => 0,
+            Block::DeadFireCoral => 0,
+            Block::DeadHornCoral => 0,
+            Block::TubeCoral => 0,
+            Block::BrainCoral => 0,
+            Block::BubbleCoral => 0,
+            Block::FireCoral => 0,
+            Block::HornCoral => 0,
+            Block::DeadTubeCoralFan => 0,
+            Block::DeadBrainCoralFan => 0,
+            Block::DeadBubbleCoralFan => 0,
+            Block::DeadFireCoralFan => 0,
+            Block::DeadHornCoralFan => 0,
+            Block::TubeCoralFan => 0,
+            Block::BrainCoralFan => 0,
+            Block::BubbleCoralFan => 0,
+            Block::FireCoralFan => 0,
+            Block::HornCoralFan => 0,
+            Block::DeadTubeCoralWallFan => 0,
+            Block::DeadBrainCoralWallFan => 0,
+            Block::DeadBubbleCoralWallFan => 0,
+            Block::DeadFireCoralWallFan => 0,
+            Block::DeadHornCoralWallFan => 0,
+            Block::TubeCoralWallFan => 0,
+            Block::BrainCoralWallFan => 0,
+            Block::BubbleCoralWallFan => 0,
+            Block::FireCoralWallFan => 0,
+            Block::HornCoralWallFan => 0,
+            Block::SeaPickle => 0,
+            Block::BlueIce => 15,
+            Block::Conduit => 0,
+            Block::BambooSapling => 0,
+            Block::Bamboo => 0,
+            Block::PottedBamboo => 0,
+            Block::VoidAir => 0,
+            Block::CaveAir => 0,
+            Block::BubbleColumn => 1,
+            Block::PolishedGraniteStairs => 0,
+            Block::SmoothRedSandstoneStairs => 0,
+            Block::MossyStoneBrickStairs => 0,
+            Block::PolishedDioriteStairs => 0,
+            Block::MossyCobblestoneStairs => 0,
+            Block::EndStoneBrickStairs => 0,
+            Block::StoneStairs => 0,
+            Block::SmoothSandstoneStairs => 0,
+            Block::SmoothQuartzStairs => 0,
+            Block::GraniteStairs => 0,
+            Block::AndesiteStairs => 0,
+            Block::RedNetherBrickStairs => 0,
+            Block::PolishedAndesiteStairs => 0,
+            Block::DioriteStairs => 0,
+            Block::PolishedGraniteSlab => 0,
+            Block::SmoothRedSandstoneSlab => 0,
+            Block::MossyStoneBrickSlab => 0,
+            Block::PolishedDioriteSlab => 0,
+            Block::MossyCobblestoneSlab => 0,
+            Block::EndStoneBrickSlab => 0,
+            Block::SmoothSandstoneSlab => 0,
+            Block::SmoothQuartzSlab => 0,
+            Block::GraniteSlab => 0,
+            Block::AndesiteSlab => 0,
+            Block::RedNetherBrickSlab => 0,
+            Block::PolishedAndesiteSlab => 0,
+            Block::DioriteSlab => 0,
+            Block::BrickWall => 0,
+            Block::PrismarineWall => 0,
+            Block::RedSandstoneWall => 0,
+            Block::MossyStoneBrickWall => 0,
+            Block::GraniteWall => 0,
+            Block::StoneBrickWall => 0,
+            Block::NetherBrickWall => 0,
+            Block::AndesiteWall => 0,
+            Block::RedNetherBrickWall => 0,
+            Block::SandstoneWall => 0,
+            Block::EndStoneBrickWall => 0,
+            Block::DioriteWall => 0,
+            Block::Scaffolding => 0,
+            Block::Loom => 15,
+            Block::Barrel => 15,
+            Block::Smoker => 15,
+            Block::BlastFurnace => 15,
+            Block::CartographyTable => 15,
+            Block::FletchingTable => 15,
+            Block::Grindstone => 0,
+            Block::Lectern => 0,
+            Block::SmithingTable => 15,
+            Block::Stonecutter => 0,
+            Block::Bell => 0,
+            Block::Lantern => 0,
+            Block::Campfire => 0,
+            Block::SweetBerryBush => 0,
+            Block::StructureBlock => 15,
+            Block::Jigsaw => 15,
+            Block::Composter => 0,
+            Block::BeeNest => 15,
+            Block::Beehive => 15,
+            Block::HoneyBlock => 1,
+            Block::HoneycombBlock => 15,
+        }
+    }
+    pub fn emit_light(&self) -> u8 {
+        match self {
+            Block::Air => 0,
+            Block::Stone => 0,
+            Block::Granite => 0,
+            Block::PolishedGranite => 0,
+            Block::Diorite => 0,
+            Block::PolishedDiorite => 0,
+            Block::Andesite => 0,
+            Block::PolishedAndesite => 0,
+            Block::GrassBlock => 0,
+            Block::Dirt => 0,
+            Block::CoarseDirt => 0,
+            Block::Podzol => 0,
+            Block::Cobblestone => 0,
+            Block::OakPlanks => 0,
+            Block::SprucePlanks => 0,
+            Block::BirchPlanks => 0,
+            Block::JunglePlanks => 0,
+            Block::AcaciaPlanks => 0,
+            Block::DarkOakPlanks => 0,
+            Block::OakSapling => 0,
+            Block::SpruceSapling => 0,
+            Block::BirchSapling => 0,
+            Block::JungleSapling => 0,
+            Block::AcaciaSapling => 0,
+            Block::DarkOakSapling => 0,
+            Block::Bedrock => 0,
+            Block::Water => 0,
+            Block::Lava => 15,
+            Block::Sand => 0,
+            Block::RedSand => 0,
+            Block::Gravel => 0,
+            Block::GoldOre => 0,
+            Block::IronOre => 0,
+            Block::CoalOre => 0,
+            Block::OakLog => 0,
+            Block::SpruceLog => 0,
+            Block::BirchLog => 0,
+            Block::JungleLog => 0,
+            Block::AcaciaLog => 0,
+            Block::DarkOakLog => 0,
+            Block::StrippedSpruceLog => 0,
+            Block::StrippedBirchLog => 0,
+            Block::StrippedJungleLog => 0,
+            Block::StrippedAcaciaLog => 0,
+            Block::StrippedDarkOakLog => 0,
+            Block::StrippedOakLog => 0,
+            Block::OakWood => 0,
+            Block::SpruceWood => 0,
+            Block::BirchWood => 0,
+            Block::JungleWood => 0,
+            Block::AcaciaWood => 0,
+            Block::DarkOakWood => 0,
+            Block::StrippedOakWood => 0,
+            Block::StrippedSpruceWood => 0,
+            Block::StrippedBirchWood => 0,
+            Block::StrippedJungleWood => 0,
+            Block::StrippedAcaciaWood => 0,
+            Block::StrippedDarkOakWood => 0,
+            Block::OakLeaves => 0,
+            Block::SpruceLeaves => 0,
+            Block::BirchLeaves => 0,
+            Block::JungleLeaves => 0,
+            Block::AcaciaLeaves => 0,
+            Block::DarkOakLeaves => 0,
+            Block::Sponge => 0,
+            Block::WetSponge => 0,
+            Block::Glass => 0,
+            Block::LapisOre => 0,
+            Block::LapisBlock => 0,
+            Block::Dispenser => 0,
+            Block::Sandstone => 0,
+            Block::ChiseledSandstone => 0,
+            Block::CutSandstone => 0,
+            Block::NoteBlock => 0,
+            Block::WhiteBed => 0,
+            Block::OrangeBed => 0,
+            Block::MagentaBed => 0,
+            Block::LightBlueBed => 0,
+            Block::YellowBed => 0,
+            Block::LimeBed => 0,
+            Block::PinkBed => 0,
+            Block::GrayBed => 0,
+            Block::LightGrayBed => 0,
+            Block::CyanBed => 0,
+            Block::PurpleBed => 0,
+            Block::BlueBed => 0,
+            Block::BrownBed => 0,
+            Block::GreenBed => 0,
+            Block::RedBed => 0,
+            Block::BlackBed => 0,
+            Block::PoweredRail => 0,
+            Block::DetectorRail => 0,
+            Block::StickyPiston => 0,
+            Block::Cobweb => 0,
+            Block::Grass => 0,
+            Block::Fern => 0,
+            Block::DeadBush => 0,
+            Block::Seagrass => 0,
+            Block::TallSeagrass => 0,
+            Block::Piston => 0,
+            Block::PistonHead => 0,
+            Block::WhiteWool => 0,
+            Block::OrangeWool => 0,
+            Block::MagentaWool => 0,
+            Block::LightBlueWool => 0,
+            Block::YellowWool => 0,
+            Block::LimeWool => 0,
+            Block::PinkWool => 0,
+            Block::GrayWool => 0,
+            Block::LightGrayWool => 0,
+            Block::CyanWool => 0,
+            Block::PurpleWool => 0,
+            Block::BlueWool => 0,
+            Block::BrownWool => 0,
+            Block::GreenWool => 0,
+            Block::RedWool => 0,
+            Block::BlackWool => 0,
+            Block::MovingPiston => 0,
+            Block::Dandelion => 0,
+            Block::Poppy => 0,
+            Block::BlueOrchid => 0,
+            Block::Allium => 0,
+            Block::AzureBluet => 0,
+            Block::RedTulip => 0,
+            Block::OrangeTulip => 0,
+            Block::WhiteTulip => 0,
+            Block::PinkTulip => 0,
+            Block::OxeyeDaisy => 0,
+            Block::Cornflower => 0,
+            Block::WitherRose => 0,
+            Block::LilyOfTheValley => 0,
+            Block::BrownMushroom => 1,
+            Block::RedMushroom => 0,
+            Block::GoldBlock => 0,
+            Block::IronBlock => 0,
+            Block::Bricks => 0,
+            Block::Tnt => 0,
+            Block::Bookshelf => 0,
+            Block::MossyCobblestone => 0,
+            Block::Obsidian => 0,
+            Block::Torch => 14,
+            Block::WallTorch => 14,
+            Block::Fire => 15,
+            Block::Spawner => 0,
+            Block::OakStairs => 0,
+            Block::Chest => 0,
+            Block::RedstoneWire => 0,
+            Block::DiamondOre => 0,
+            Block::DiamondBlock => 0,
+            Block::CraftingTable => 0,
+            Block::Wheat => 0,
+            Block::Farmland => 0,
+            Block::Furnace => 13,
+            Block::OakSign => 0,
+            Block::SpruceSign => 0,
+            Block::BirchSign => 0,
+            Block::AcaciaSign => 0,
+            Block::JungleSign => 0,
+            Block::DarkOakSign => 0,
+            Block::OakDoor => 0,
+            Block::Ladder => 0,
+            Block::Rail => 0,
+            Block::CobblestoneStairs => 0,
+            Block::OakWallSign => 0,
+            Block::SpruceWallSign => 0,
+            Block::BirchWallSign => 0,
+            Block::AcaciaWallSign => 0,
+            Block::JungleWallSign => 0,
+            Block::DarkOakWallSign => 0,
+            Block::Lever => 0,
+            Block::StonePressurePlate => 0,
+            Block::IronDoor => 0,
+            Block::OakPressurePlate => 0,
+            Block::SprucePressurePlate => 0,
+            Block::BirchPressurePlate => 0,
+            Block::JunglePressurePlate => 0,
+            Block::AcaciaPressurePlate => 0,
+            Block::DarkOakPressurePlate => 0,
+            Block::RedstoneOre => 9,
+            Block::RedstoneTorch => 7,
+            Block::RedstoneWallTorch => 7,
+            Block::StoneButton => 0,
+            Block::Snow => 0,
+            Block::Ice => 0,
+            Block::SnowBlock => 0,
+            Block::Cactus => 0,
+            Block::Clay => 0,
+            Block::SugarCane => 0,
+            Block::Jukebox => 0,
+            Block::OakFence => 0,
+            Block::Pumpkin => 0,
+            Block::Netherrack => 0,
+            Block::SoulSand => 0,
+            Block::Glowstone => 15,
+            Block::NetherPortal => 11,
+            Block::CarvedPumpkin => 0,
+            Block::JackOLantern => 15,
+            Block::Cake => 0,
+            Block::Repeater => 0,
+            Block::WhiteStainedGlass => 0,
+            Block::OrangeStainedGlass => 0,
+            Block::MagentaStainedGlass => 0,
+            Block::LightBlueStainedGlass => 0,
+            Block::YellowStainedGlass => 0,
+            Block::LimeStainedGlass => 0,
+            Block::PinkStainedGlass => 0,
+            Block::GrayStainedGlass => 0,
+            Block::LightGrayStainedGlass => 0,
+            Block::CyanStainedGlass => 0,
+            Block::PurpleStainedGlass => 0,
+            Block::BlueStainedGlass => 0,
+            Block::BrownStainedGlass => 0,
+            Block::GreenStainedGlass => 0,
+            Block::RedStainedGlass => 0,
+            Block::BlackStainedGlass => 0,
+            Block::OakTrapdoor => 0,
+            Block::SpruceTrapdoor => 0,
+            Block::BirchTrapdoor => 0,
+            Block::JungleTrapdoor => 0,
+            Block::AcaciaTrapdoor => 0,
+            Block::DarkOakTrapdoor => 0,
+            Block::StoneBricks => 0,
+            Block::MossyStoneBricks => 0,
+            Block::CrackedStoneBricks => 0,
+            Block::ChiseledStoneBricks => 0,
+            Block::InfestedStone => 0,
+            Block::InfestedCobblestone => 0,
+            Block::InfestedStoneBricks => 0,
+            Block::InfestedMossyStoneBricks => 0,
+            Block::InfestedCrackedStoneBricks => 0,
+            Block::InfestedChiseledStoneBricks => 0,
+            Block::BrownMushroomBlock => 0,
+            Block::RedMushroomBlock => 0,
+            Block::MushroomStem => 0,
+            Block::IronBars => 0,
+            Block::GlassPane => 0,
+            Block::Melon => 0,
+            Block::AttachedPumpkinStem => 0,
+            Block::AttachedMelonStem => 0,
+            Block::PumpkinStem => 0,
+            Block::MelonStem => 0,
+            Block::Vine => 0,
+            Block::OakFenceGate => 0,
+            Block::BrickStairs => 0,
+            Block::StoneBrickStairs => 0,
+            Block::Mycelium => 0,
+            Block::LilyPad => 0,
+            Block::NetherBricks => 0,
+            Block::NetherBrickFence => 0,
+            Block::NetherBrickStairs => 0,
+            Block::NetherWart => 0,
+            Block::EnchantingTable => 0,
+            Block::BrewingStand => 1,
+            Block::Cauldron => 0,
+            Block::EndPortal => 15,
+            Block::EndPortalFrame => 1,
+            Block::EndStone => 0,
+            Block::DragonEgg => 1,
+            Block::RedstoneLamp => 15,
+            Block::Cocoa => 0,
+            Block::SandstoneStairs => 0,
+            Block::EmeraldOre => 0,
+            Block::EnderChest => 0,
+            Block::TripwireHook => 0,
+            Block::Tripwire => 0,
+            Block::EmeraldBlock => 0,
+            Block::SpruceStairs => 0,
+            Block::BirchStairs => 0,
+            Block::JungleStairs => 0,
+            Block::CommandBlock => 0,
+            Block::Beacon => 15,
+            Block::CobblestoneWall => 0,
+            Block::MossyCobblestoneWall => 0,
+            Block::FlowerPot => 0,
+            Block::PottedOakSapling => 0,
+            Block::PottedSpruceSapling => 0,
+            Block::PottedBirchSapling => 0,
+            Block::PottedJungleSapling => 0,
+            Block::PottedAcaciaSapling => 0,
+            Block::PottedDarkOakSapling => 0,
+            Block::PottedFern => 0,
+            Block::PottedDandelion => 0,
+            Block::PottedPoppy => 0,
+            Block::PottedBlueOrchid => 0,
+            Block::PottedAllium => 0,
+            Block::PottedAzureBluet => 0,
+            Block::PottedRedTulip => 0,
+            Block::PottedOrangeTulip => 0,
+            Block::PottedWhiteTulip => 0,
+            Block::PottedPinkTulip => 0,
+            Block::PottedOxeyeDaisy => 0,
+            Block::PottedCornflower => 0,
+            Block::PottedLilyOfTheValley => 0,
+            Block::PottedWitherRose => 0,
+            Block::PottedRedMushroom => 0,
+            Block::PottedBrownMushroom => 0,
+            Block::PottedDeadBush => 0,
+            Block::PottedCactus => 0,
+            Block::Carrots => 0,
+            Block::Potatoes => 0,
+            Block::OakButton => 0,
+            Block::SpruceButton => 0,
+            Block::BirchButton => 0,
+            Block::JungleButton => 0,
+            Block::AcaciaButton => 0,
+            Block::DarkOakButton => 0,
+            Block::SkeletonSkull => 0,
+            Block::SkeletonWallSkull => 0,
+            Block::WitherSkeletonSkull => 0,
+            Block::WitherSkeletonWallSkull => 0,
+            Block::ZombieHead => 0,
+            Block::ZombieWallHead => 0,
+            Block::PlayerHead => 0,
+            Block::PlayerWallHead => 0,
+            Block::CreeperHead => 0,
+            Block::CreeperWallHead => 0,
+            Block::DragonHead => 0,
+            Block::DragonWallHead => 0,
+            Block::Anvil => 0,
+            Block::ChippedAnvil => 0,
+            Block::DamagedAnvil => 0,
+            Block::TrappedChest => 0,
+            Block::LightWeightedPressurePlate => 0,
+            Block::HeavyWeightedPressurePlate => 0,
+            Block::Comparator => 0,
+            Block::DaylightDetector => 0,
+            Block::RedstoneBlock => 0,
+            Block::NetherQuartzOre => 0,
+            Block::Hopper => 0,
+            Block::QuartzBlock => 0,
+            Block::ChiseledQuartzBlock => 0,
+            Block::QuartzPillar => 0,
+            Block::QuartzStairs => 0,
+            Block::ActivatorRail => 0,
+            Block::Dropper => 0,
+            Block::WhiteTerracotta => 0,
+            Block::OrangeTerracotta => 0,
+            Block::MagentaTerracotta => 0,
+            Block::LightBlueTerracotta => 0,
+            Block::YellowTerracotta => 0,
+            Block::LimeTerracotta => 0,
+            Block::PinkTerracotta => 0,
+            Block::GrayTerracotta => 0,
+            Block::LightGrayTerracotta => 0,
+            Block::CyanTerracotta => 0,
+            Block::PurpleTerracotta => 0,
+            Block::BlueTerracotta => 0,
+            Block::BrownTerracotta => 0,
+            Block::GreenTerracotta => 0,
+            Block::RedTerracotta => 0,
+            Block::BlackTerracotta => 0,
+            Block::WhiteStainedGlassPane => 0,
+            Block::OrangeStainedGlassPane => 0,
+            Block::MagentaStainedGlassPane => 0,
+            Block::LightBlueStainedGlassPane => 0,
+            Block::YellowStainedGlassPane => 0,
+            Block::LimeStainedGlassPane => 0,
+            Block::PinkStainedGlassPane => 0,
+            Block::GrayStainedGlassPane => 0,
+            Block::LightGrayStainedGlassPane => 0,
+            Block::CyanStainedGlassPane => 0,
+            Block::PurpleStainedGlassPane => 0,
+            Block::BlueStainedGlassPane => 0,
+            Block::BrownStainedGlassPane => 0,
+            Block::GreenStainedGlassPane => 0,
+            Block::RedStainedGlassPane => 0,
+            Block::BlackStainedGlassPane => 0,
+            Block::AcaciaStairs => 0,
+            Block::DarkOakStairs => 0,
+            Block::SlimeBlock => 0,
+            Block::Barrier => 0,
+            Block::IronTrapdoor => 0,
+            Block::Prismarine => 0,
+            Block::PrismarineBricks => 0,
+            Block::DarkPrismarine => 0,
+            Block::PrismarineStairs => 0,
+            Block::PrismarineBrickStairs => 0,
+            Block::DarkPrismarineStairs => 0,
+            Block::PrismarineSlab => 0,
+            Block::PrismarineBrickSlab => 0,
+            Block::DarkPrismarineSlab => 0,
+            Block::SeaLantern => 15,
+            Block::HayBlock => 0,
+            Block::WhiteCarpet => 0,
+            Block::OrangeCarpet => 0,
+            Block::MagentaCarpet => 0,
+            Block::LightBlueCarpet => 0,
+            Block::YellowCarpet => 0,
+            Block::LimeCarpet => 0,
+            Block::PinkCarpet => 0,
+            Block::GrayCarpet => 0,
+            Block::LightGrayCarpet => 0,
+            Block::CyanCarpet => 0,
+            Block::PurpleCarpet => 0,
+            Block::BlueCarpet => 0,
+            Block::BrownCarpet => 0,
+            Block::GreenCarpet => 0,
+            Block::RedCarpet => 0,
+            Block::BlackCarpet => 0,
+            Block::Terracotta => 0,
+            Block::CoalBlock => 0,
+            Block::PackedIce => 0,
+            Block::Sunflower => 0,
+            Block::Lilac => 0,
+            Block::RoseBush => 0,
+            Block::Peony => 0,
+            Block::TallGrass => 0,
+            Block::LargeFern => 0,
+            Block::WhiteBanner => 0,
+            Block::OrangeBanner => 0,
+            Block::MagentaBanner => 0,
+            Block::LightBlueBanner => 0,
+            Block::YellowBanner => 0,
+            Block::LimeBanner => 0,
+            Block::PinkBanner => 0,
+            Block::GrayBanner => 0,
+            Block::LightGrayBanner => 0,
+            Block::CyanBanner => 0,
+            Block::PurpleBanner => 0,
+            Block::BlueBanner => 0,
+            Block::BrownBanner => 0,
+            Block::GreenBanner => 0,
+            Block::RedBanner => 0,
+            Block::BlackBanner => 0,
+            Block::WhiteWallBanner => 0,
+            Block::OrangeWallBanner => 0,
+            Block::MagentaWallBanner => 0,
+            Block::LightBlueWallBanner => 0,
+            Block::YellowWallBanner => 0,
+            Block::LimeWallBanner => 0,
+            Block::PinkWallBanner => 0,
+            Block::GrayWallBanner => 0,
+            Block::LightGrayWallBanner => 0,
+            Block::CyanWallBanner => 0,
+            Block::PurpleWallBanner => 0,
+            Block::BlueWallBanner => 0,
+            Block::BrownWallBanner => 0,
+            Block::GreenWallBanner => 0,
+            Block::RedWallBanner => 0,
+            Block::BlackWallBanner => 0,
+            Block::RedSandstone => 0,
+            Block::ChiseledRedSandstone => 0,
+            Block::CutRedSandstone => 0,
+            Block::RedSandstoneStairs => 0,
+            Block::OakSlab => 0,
+            Block::SpruceSlab => 0,
+            Block::BirchSlab => 0,
+            Block::JungleSlab => 0,
+            Block::AcaciaSlab => 0,
+            Block::DarkOakSlab => 0,
+            Block::StoneSlab => 0,
+            Block::SmoothStoneSlab => 0,
+            Block::SandstoneSlab => 0,
+            Block::CutSandstoneSlab => 0,
+            Block::PetrifiedOakSlab => 0,
+            Block::CobblestoneSlab => 0,
+            Block::BrickSlab => 0,
+            Block::StoneBrickSlab => 0,
+            Block::NetherBrickSlab => 0,
+            Block::QuartzSlab => 0,
+            Block::RedSandstoneSlab => 0,
+            Block::CutRedSandstoneSlab => 0,
+            Block::PurpurSlab => 0,
+            Block::SmoothStone => 0,
+            Block::SmoothSandstone => 0,
+            Block::SmoothQuartz => 0,
+            Block::SmoothRedSandstone => 0,
+            Block::SpruceFenceGate => 0,
+            Block::BirchFenceGate => 0,
+            Block::JungleFenceGate => 0,
+            Block::AcaciaFenceGate => 0,
+            Block::DarkOakFenceGate => 0,
+            Block::SpruceFence => 0,
+            Block::BirchFence => 0,
+            Block::JungleFence => 0,
+            Block::AcaciaFence => 0,
+            Block::DarkOakFence => 0,
+            Block::SpruceDoor => 0,
+            Block::BirchDoor => 0,
+            Block::JungleDoor => 0,
+            Block::AcaciaDoor => 0,
+            Block::DarkOakDoor => 0,
+            Block::EndRod => 14,
+            Block::ChorusPlant => 0,
+            Block::ChorusFlower => 0,
+            Block::PurpurBlock => 0,
+            Block::PurpurPillar => 0,
+            Block::PurpurStairs => 0,
+            Block::EndStoneBricks => 0,
+            Block::Beetroots => 0,
+            Block::GrassPath => 0,
+            Block::EndGateway => 15,
+            Block::RepeatingCommandBlock => 0,
+            Block::ChainCommandBlock => 0,
+            Block::FrostedIce => 0,
+            Block::MagmaBlock => 3,
+            Block::NetherWartBlock => 0,
+            Block::RedNetherBricks => 0,
+            Block::BoneBlock => 0,
+            Block::StructureVoid => 0,
+            Block::Observer => 0,
+            Block::ShulkerBox => 0,
+            Block::WhiteShulkerBox => 0,
+            Block::OrangeShulkerBox => 0,
+            Block::MagentaShulkerBox => 0,
+            Block::LightBlueShulkerBox => 0,
+            Block::YellowShulkerBox => 0,
+            Block::LimeShulkerBox => 0,
+            Block::PinkShulkerBox => 0,
+            Block::GrayShulkerBox => 0,
+            Block::LightGrayShulkerBox => 0,
+            Block::CyanShulkerBox => 0,
+            Block::PurpleShulkerBox => 0,
+            Block::BlueShulkerBox => 0,
+            Block::BrownShulkerBox => 0,
+            Block::GreenShulkerBox => 0,
+            Block::RedShulkerBox => 0,
+            Block::BlackShulkerBox => 0,
+            Block::WhiteGlazedTerracotta => 0,
+            Block::OrangeGlazedTerracotta => 0,
+            Block::MagentaGlazedTerracotta => 0,
+            Block::LightBlueGlazedTerracotta => 0,
+            Block::YellowGlazedTerracotta => 0,
+            Block::LimeGlazedTerracotta => 0,
+            Block::PinkGlazedTerracotta => 0,
+            Block::GrayGlazedTerracotta => 0,
+            Block::LightGrayGlazedTerracotta => 0,
+            Block::CyanGlazedTerracotta => 0,
+            Block::PurpleGlazedTerracotta => 0,
+            Block::BlueGlazedTerracotta => 0,
+            Block::BrownGlazedTerracotta => 0,
+            Block::GreenGlazedTerracotta => 0,
+            Block::RedGlazedTerracotta => 0,
+            Block::BlackGlazedTerracotta => 0,
+            Block::WhiteConcrete => 0,
+            Block::OrangeConcrete => 0,
+            Block::MagentaConcrete => 0,
+            Block::LightBlueConcrete => 0,
+            Block::YellowConcrete => 0,
+            Block::LimeConcrete => 0,
+            Block::PinkConcrete => 0,
+            Block::GrayConcrete => 0,
+            Block::LightGrayConcrete => 0,
+            Block::CyanConcrete => 0,
+            Block::PurpleConcrete => 0,
+            Block::BlueConcrete => 0,
+            Block::BrownConcrete => 0,
+            Block::GreenConcrete => 0,
+            Block::RedConcrete => 0,
+            Block::BlackConcrete => 0,
+            Block::WhiteConcretePowder => 0,
+            Block::OrangeConcretePowder => 0,
+            Block::MagentaConcretePowder => 0,
+            Block::LightBlueConcretePowder => 0,
+            Block::YellowConcretePowder => 0,
+            Block::LimeConcretePowder => 0,
+            Block::PinkConcretePowder => 0,
+            Block::GrayConcretePowder => 0,
+            Block::LightGrayConcretePowder => 0,
+            Block::CyanConcretePowder => 0,
+            Block::PurpleConcretePowder => 0,
+            Block::BlueConcretePowder => 0,
+            Block::BrownConcretePowder => 0,
+            Block::GreenConcretePowder => 0,
+            Block::RedConcretePowder => 0,
+            Block::BlackConcretePowder => 0,
+            Block::Kelp => 0,
+            Block::KelpPlant => 0,
+            Block::DriedKelpBlock => 0,
+            Block::TurtleEgg => 0,
+            Block::DeadTubeCoralBlock => 0,
+            Block::DeadBrainCoralBlock => 0,
+            Block::DeadBubbleCoralBlock => 0,
+            Block::DeadFireCoralBlock => 0,
+            Block::DeadHornCoralBlock => 0,
+            Block::TubeCoralBlock => 0,
+            Block::BrainCoralBlock => 0,
+            Block::BubbleCoralBlock => 0,
+            Block::FireCoralBlock => 0,
+            Block::HornCoralBlock => 0,
+            Block::DeadTubeCoral => 0,
+            Block::DeadBrainCoral => 0,
+            Block::DeadBubbleCoral => 0,
+            Block::DeadFireCoral => 0,
+            Block::DeadHornCoral => 0,
+            Block::TubeCoral => 0,
+            Block::BrainCoral => 0,
+            Block::BubbleCoral => 0,
+            Block::FireCoral => 0,
+            Block::HornCoral => 0,
+            Block::DeadTubeCoralFan => 0,
+            Block::DeadBrainCoralFan => 0,
+            Block::DeadBubbleCoralFan => 0,
+            Block::DeadFireCoralFan => 0,
+            Block::DeadHornCoralFan => 0,
+            Block::TubeCoralFan => 0,
+            Block::BrainCoralFan => 0,
+            Block::BubbleCoralFan => 0,
+            Block::FireCoralFan => 0,
+            Block::HornCoralFan => 0,
+            Block::DeadTubeCoralWallFan => 0,
+            Block::DeadBrainCoralWallFan => 0,
+            Block::DeadBubbleCoralWallFan => 0,
+            Block::DeadFireCoralWallFan => 0,
+            Block::DeadHornCoralWallFan => 0,
+            Block::TubeCoralWallFan => 0,
+            Block::BrainCoralWallFan => 0,
+            Block::BubbleCoralWallFan => 0,
+            Block::FireCoralWallFan => 0,
+            Block::HornCoralWallFan => 0,
+            Block::SeaPickle => 15,
+            Block::BlueIce => 0,
+            Block::Conduit => 15,
+            Block::BambooSapling => 0,
+            Block::Bamboo => 0,
+            Block::PottedBamboo => 0,
+            Block::VoidAir => 0,
+            Block::CaveAir => 0,
+            Block::BubbleColumn => 0,
+            Block::PolishedGraniteStairs => 0,
+            Block::SmoothRedSandstoneStairs => 0,
+            Block::MossyStoneBrickStairs => 0,
+            Block::PolishedDioriteStairs => 0,
+            Block::MossyCobblestoneStairs => 0,
+            Block::EndStoneBrickStairs => 0,
+            Block::StoneStairs => 0,
+            Block::SmoothSandstoneStairs => 0,
+            Block::SmoothQuartzStairs => 0,
+            Block::GraniteStairs => 0,
+            Block::AndesiteStairs => 0,
+            Block::RedNetherBrickStairs => 0,
+            Block::PolishedAndesiteStairs => 0,
+            Block::DioriteStairs => 0,
+            Block::PolishedGraniteSlab => 0,
+            Block::SmoothRedSandstoneSlab => 0,
+            Block::MossyStoneBrickSlab => 0,
+            Block::PolishedDioriteSlab => 0,
+            Block::MossyCobblestoneSlab => 0,
+            Block::EndStoneBrickSlab => 0,
+            Block::SmoothSandstoneSlab => 0,
+            Block::SmoothQuartzSlab => 0,
+            Block::GraniteSlab => 0,
+            Block::AndesiteSlab => 0,
+            Block::RedNetherBrickSlab => 0,
+            Block::PolishedAndesiteSlab => 0,
+            Block::DioriteSlab => 0,
+            Block::BrickWall => 0,
+            Block::PrismarineWall => 0,
+            Block::RedSandstoneWall => 0,
+            Block::MossyStoneBrickWall => 0,
+            Block::GraniteWall => 0,
+            Block::StoneBrickWall => 0,
+            Block::NetherBrickWall => 0,
+            Block::AndesiteWall => 0,
+            Block::RedNetherBrickWall => 0,
+            Block::SandstoneWall => 0,
+            Block::EndStoneBrickWall => 0,
+            Block::DioriteWall => 0,
+            Block::Scaffolding => 0,
+            Block::Loom => 0,
+            Block::Barrel => 0,
+            Block::Smoker => 13,
+            Block::BlastFurnace => 13,
+            Block::CartographyTable => 0,
+            Block::FletchingTable => 0,
+            Block::Grindstone => 0,
+            Block::Lectern => 0,
+            Block::SmithingTable => 0,
+            Block::Stonecutter => 0,
+            Block::Bell => 0,
+            Block::Lantern => 15,
+            Block::Campfire => 15,
+            Block::SweetBerryBush => 0,
+            Block::StructureBlock => 0,
+            Block::Jigsaw => 0,
+            Block::Composter => 0,
+            Block::BeeNest => 0,
+            Block::Beehive => 0,
+            Block::HoneyBlock => 0,
+            Block::HoneycombBlock => 0,
+        }
+    }
+    pub fn material(&self) -> &'static str {
+        match self {
+            Block::Air => "air",
+            Block::Stone => "rock",
+            Block::Granite => "rock",
+            Block::PolishedGranite => "rock",
+            Block::Diorite => "rock",
+            Block::PolishedDiorite => "rock",
+            Block::Andesite => "rock",
+            Block::PolishedAndesite => "rock",
+            Block::GrassBlock => "dirt",
+            Block::Dirt => "dirt",
+            Block::CoarseDirt => "dirt",
+            Block::Podzol => "dirt",
+            Block::Cobblestone => "rock",
+            Block::OakPlanks => "wood",
+            Block::SprucePlanks => "wood",
+            Block::BirchPlanks => "wood",
+            Block::JunglePlanks => "wood",
+            Block::AcaciaPlanks => "wood",
+            Block::DarkOakPlanks => "wood",
+            Block::OakSapling => "plant",
+            Block::SpruceSapling => "plant",
+            Block::BirchSapling => "plant",
+            Block::JungleSapling => "plant",
+            Block::AcaciaSapling => "plant",
+            Block::DarkOakSapling => "plant",
+            Block::Bedrock => "default",
+            Block::Water => "water",
+            Block::Lava => "lava",
+            Block::Sand => "dirt",
+            Block::RedSand => "dirt",
+            Block::Gravel => "dirt",
+            Block::GoldOre => "rock",
+            Block::IronOre => "rock",
+            Block::CoalOre => "rock",
+            Block::OakLog => "wood",
+            Block::SpruceLog => "wood",
+            Block::BirchLog => "wood",
+            Block::JungleLog => "wood",
+            Block::AcaciaLog => "wood",
+            Block::DarkOakLog => "wood",
+            Block::StrippedSpruceLog => "wood",
+            Block::StrippedBirchLog => "wood",
+            Block::StrippedJungleLog => "wood",
+            Block::StrippedAcaciaLog => "wood",
+            Block::StrippedDarkOakLog => "wood",
+            Block::StrippedOakLog => "wood",
+            Block::OakWood => "wood",
+            Block::SpruceWood => "wood",
+            Block::BirchWood => "wood",
+            Block::JungleWood => "wood",
+            Block::AcaciaWood => "wood",
+            Block::DarkOakWood => "wood",
+            Block::StrippedOakWood => "wood",
+            Block::StrippedSpruceWood => "wood",
+            Block::StrippedBirchWood => "wood",
+            Block::StrippedJungleWood => "wood",
+            Block::StrippedAcaciaWood => "wood",
+            Block::StrippedDarkOakWood => "wood",
+            Block::OakLeaves => "leaves",
+            Block::SpruceLeaves => "leaves",
+            Block::BirchLeaves => "leaves",
+            Block::JungleLeaves => "leaves",
+            Block::AcaciaLeaves => "leaves",
+            Block::DarkOakLeaves => "leaves",
+            Block::Sponge => "default",
+            Block::WetSponge => "default",
+            Block::Glass => "default",
+            Block::LapisOre => "rock",
+            Block::LapisBlock => "iron",
+            Block::Dispenser => "rock",
+            Block::Sandstone => "rock",
+            Block::ChiseledSandstone => "rock",
+            Block::CutSandstone => "rock",
+            Block::NoteBlock => "wood",
+            Block::WhiteBed => "default",
+            Block::OrangeBed => "default",
+            Block::MagentaBed => "default",
+            Block::LightBlueBed => "default",
+            Block::YellowBed => "default",
+            Block::LimeBed => "default",
+            Block::PinkBed => "default",
+            Block::GrayBed => "default",
+            Block::LightGrayBed => "default",
+            Block::CyanBed => "default",
+            Block::PurpleBed => "default",
+            Block::BlueBed => "default",
+            Block::BrownBed => "default",
+            Block::GreenBed => "default",
+            Block::RedBed => "default",
+            Block::BlackBed => "default",
+            Block::PoweredRail => "rock",
+            Block::DetectorRail => "rock",
+            Block::StickyPiston => "default",
+            Block::Cobweb => "web",
+            Block::Grass => "plant",
+            Block::Fern => "plant",
+            Block::DeadBush => "plant",
+            Block::Seagrass => "plant",
+            Block::TallSeagrass => "plant",
+            Block::Piston => "default",
+            Block::PistonHead => "default",
+            Block::WhiteWool => "wool",
+            Block::OrangeWool => "wool",
+            Block::MagentaWool => "wool",
+            Block::LightBlueWool => "wool",
+            Block::YellowWool => "wool",
+            Block::LimeWool => "wool",
+            Block::PinkWool => "wool",
+            Block::GrayWool => "wool",
+            Block::LightGrayWool => "wool",
+            Block::CyanWool => "wool",
+            Block::PurpleWool => "wool",
+            Block::BlueWool => "wool",
+            Block::BrownWool => "wool",
+            Block::GreenWool => "wool",
+            Block::RedWool => "wool",
+            Block::BlackWool => "wool",
+            Block::MovingPiston => "default",
+            Block::Dandelion => "plant",
+            Block::Poppy => "plant",
+            Block::BlueOrchid => "plant",
+            Block::Allium => "plant",
+            Block::AzureBluet => "plant",
+            Block::RedTulip => "plant",
+            Block::OrangeTulip => "plant",
+            Block::WhiteTulip => "plant",
+            Block::PinkTulip => "plant",
+            Block::OxeyeDaisy => "plant",
+            Block::Cornflower => "plant",
+            Block::WitherRose => "plant",
+            Block::LilyOfTheValley => "plant",
+            Block::BrownMushroom => "plant",
+            Block::RedMushroom => "plant",
+            Block::GoldBlock => "iron",
+            Block::IronBlock => "iron",
+            Block::Bricks => "rock",
+            Block::Tnt => "default",
+            Block::Bookshelf => "wood",
+            Block::MossyCobblestone => "rock",
+            Block::Obsidian => "rock",
+            Block::Torch => "default",
+            Block::WallTorch => "default",
+            Block::Fire => "default",
+            Block::Spawner => "rock",
+            Block::OakStairs => "wood",
+            Block::Chest => "wood",
+            Block::RedstoneWire => "default",
+            Block::DiamondOre => "rock",
+            Block::DiamondBlock => "iron",
+            Block::CraftingTable => "wood",
+            Block::Wheat => "plant",
+            Block::Farmland => "dirt",
+            Block::Furnace => "rock",
+            Block::OakSign => "wood",
+            Block::SpruceSign => "wood",
+            Block::BirchSign => "wood",
+            Block::AcaciaSign => "wood",
+            Block::JungleSign => "wood",
+            Block::DarkOakSign => "wood",
+            Block::OakDoor => "wood",
+            Block::Ladder => "wood",
+            Block::Rail => "rock",
+            Block::CobblestoneStairs => "rock",
+            Block::OakWallSign => "wood",
+            Block::SpruceWallSign => "wood",
+            Block::BirchWallSign => "wood",
+            Block::AcaciaWallSign => "wood",
+            Block::JungleWallSign => "wood",
+            Block::DarkOakWallSign => "wood",
+            Block::Lever => "default",
+            Block::StonePressurePlate => "rock",
+            Block::IronDoor => "iron",
+            Block::OakPressurePlate => "wood",
+            Block::SprucePressurePlate => "wood",
+            Block::BirchPressurePlate => "wood",
+            Block::JunglePressurePlate => "wood",
+            Block::AcaciaPressurePlate => "wood",
+            Block::DarkOakPressurePlate => "wood",
+            Block::RedstoneOre => "rock",
+            Block::RedstoneTorch => "default",
+            Block::RedstoneWallTorch => "default",
+            Block::StoneButton => "rock",
+            Block::Snow => "dirt",
+            Block::Ice => "default",
+            Block::SnowBlock => "dirt",
+            Block::Cactus => "plant",
+            Block::Clay => "dirt",
+            Block::SugarCane => "plant",
+            Block::Jukebox => "wood",
+            Block::OakFence => "wood",
+            Block::Pumpkin => "gourd",
+            Block::Netherrack => "rock",
+            Block::SoulSand => "dirt",
+            Block::Glowstone => "default",
+            Block::NetherPortal => "default",
+            Block::CarvedPumpkin => "gourd",
+            Block::JackOLantern => "gourd",
+            Block::Cake => "default",
+            Block::Repeater => "default",
+            Block::WhiteStainedGlass => "default",
+            Block::OrangeStainedGlass => "default",
+            Block::MagentaStainedGlass => "default",
+            Block::LightBlueStainedGlass => "default",
+            Block::YellowStainedGlass => "default",
+            Block::LimeStainedGlass => "default",
+            Block::PinkStainedGlass => "default",
+            Block::GrayStainedGlass => "default",
+            Block::LightGrayStainedGlass => "default",
+            Block::CyanStainedGlass => "default",
+            Block::PurpleStainedGlass => "default",
+            Block::BlueStainedGlass => "default",
+            Block::BrownStainedGlass => "default",
+            Block::GreenStainedGlass => "default",
+            Block::RedStainedGlass => "default",
+            Block::BlackStainedGlass => "default",
+            Block::OakTrapdoor => "wood",
+            Block::SpruceTrapdoor => "wood",
+            Block::BirchTrapdoor => "wood",
+            Block::JungleTrapdoor => "wood",
+            Block::AcaciaTrapdoor => "wood",
+            Block::DarkOakTrapdoor => "wood",
+            Block::StoneBricks => "rock",
+            Block::MossyStoneBricks => "rock",
+            Block::CrackedStoneBricks => "rock",
+            Block::ChiseledStoneBricks => "rock",
+            Block::InfestedStone => "rock",
+            Block::InfestedCobblestone => "rock",
+            Block::InfestedStoneBricks => "rock",
+            Block::InfestedMossyStoneBricks => "rock",
+            Block::InfestedCrackedStoneBricks => "rock",
+            Block::InfestedChiseledStoneBricks => "rock",
+            Block::BrownMushroomBlock => "wood",
+            Block::RedMushroomBlock => "wood",
+            Block::MushroomStem => "wood",
+            Block::IronBars => "iron",
+            Block::GlassPane => "default",
+            Block::Melon => "gourd",
+            Block::AttachedPumpkinStem => "plant",
+            Block::AttachedMelonStem => "plant",
+            Block::PumpkinStem => "plant",
+            Block::MelonStem => "plant",
+            Block::Vine => "plant",
+            Block::OakFenceGate => "wood",
+            Block::BrickStairs => "rock",
+            Block::StoneBrickStairs => "rock",
+            Block::Mycelium => "dirt",
+            Block::LilyPad => "plant",
+            Block::NetherBricks => "rock",
+            Block::NetherBrickFence => "rock",
+            Block::NetherBrickStairs => "rock",
+            Block::NetherWart => "plant",
+            Block::EnchantingTable => "rock",
+            Block::BrewingStand => "iron",
+            Block::Cauldron => "iron",
+            Block::EndPortal => "default",
+            Block::EndPortalFrame => "default",
+            Block::EndStone => "rock",
+            Block::DragonEgg => "rock",
+            Block::RedstoneLamp => "default",
+            Block::Cocoa => "plant",
+            Block::SandstoneStairs => "rock",
+            Block::EmeraldOre => "rock",
+            Block::EnderChest => "rock",
+            Block::TripwireHook => "default",
+            Block::Tripwire => "default",
+            Block::EmeraldBlock => "iron",
+            Block::SpruceStairs => "wood",
+            Block::BirchStairs => "wood",
+            Block::JungleStairs => "wood",
+            Block::CommandBlock => "default",
+            Block::Beacon => "default",
+            Block::CobblestoneWall => "rock",
+            Block::MossyCobblestoneWall => "rock",
+            Block::FlowerPot => "default",
+            Block::PottedOakSapling => "plant",
+            Block::PottedSpruceSapling => "plant",
+            Block::PottedBirchSapling => "plant",
+            Block::PottedJungleSapling => "plant",
+            Block::PottedAcaciaSapling => "plant",
+            Block::PottedDarkOakSapling => "plant",
+            Block::PottedFern => "default",
+            Block::PottedDandelion => "default",
+            Block::PottedPoppy => "default",
+            Block::PottedBlueOrchid => "default",
+            Block::PottedAllium => "default",
+            Block::PottedAzureBluet => "default",
+            Block::PottedRedTulip => "default",
+            Block::PottedOrangeTulip => "default",
+            Block::PottedWhiteTulip => "default",
+            Block::PottedPinkTulip => "default",
+            Block::PottedOxeyeDaisy => "default",
+            Block::PottedCornflower => "default",
+            Block::PottedLilyOfTheValley => "default",
+            Block::PottedWitherRose => "default",
+            Block::PottedRedMushroom => "default",
+            Block::PottedBrownMushroom => "default",
+            Block::PottedDeadBush => "default",
+            Block::PottedCactus => "default",
+            Block::Carrots => "plant",
+            Block::Potatoes => "plant",
+            Block::OakButton => "wood",
+            Block::SpruceButton => "wood",
+            Block::BirchButton => "wood",
+            Block::JungleButton => "wood",
+            Block::AcaciaButton => "wood",
+            Block::DarkOakButton => "wood",
+            Block::SkeletonSkull => "default",
+            Block::SkeletonWallSkull => "default",
+            Block::WitherSkeletonSkull => "default",
+            Block::WitherSkeletonWallSkull => "default",
+            Block::ZombieHead => "default",
+            Block::ZombieWallHead => "default",
+            Block::PlayerHead => "default",
+            Block::PlayerWallHead => "default",
+            Block::CreeperHead => "default",
+            Block::CreeperWallHead => "default",
+            Block::DragonHead => "default",
+            Block::DragonWallHead => "default",
+            Block::Anvil => "iron",
+            Block::ChippedAnvil => "iron",
+            Block::DamagedAnvil => "iron",
+            Block::TrappedChest => "wood",
+            Block::LightWeightedPressurePlate => "iron",
+            Block::HeavyWeightedPressurePlate => "iron",
+            Block::Comparator => "default",
+            Block::DaylightDetector => "wood",
+            Block::RedstoneBlock => "iron",
+            Block::NetherQuartzOre => "rock",
+            Block::Hopper => "iron",
+            Block::QuartzBlock => "rock",
+            Block::ChiseledQuartzBlock => "rock",
+            Block::QuartzPillar => "rock",
+            Block::QuartzStairs => "rock",
+            Block::ActivatorRail => "rock",
+            Block::Dropper => "rock",
+            Block::WhiteTerracotta => "rock",
+            Block::OrangeTerracotta => "rock",
+            Block::MagentaTerracotta => "rock",
+            Block::LightBlueTerracotta => "rock",
+            Block::YellowTerracotta => "rock",
+            Block::LimeTerracotta => "rock",
+            Block::PinkTerracotta => "rock",
+            Block::GrayTerracotta => "rock",
+            Block::LightGrayTerracotta => "rock",
+            Block::CyanTerracotta => "rock",
+            Block::PurpleTerracotta => "rock",
+            Block::BlueTerracotta => "rock",
+            Block::BrownTerracotta => "rock",
+            Block::GreenTerracotta => "rock",
+            Block::RedTerracotta => "rock",
+            Block::BlackTerracotta => "rock",
+            Block::WhiteStainedGlassPane => "default",
+            Block::OrangeStainedGlassPane => "default",
+            Block::MagentaStainedGlassPane => "default",
+            Block::LightBlueStainedGlassPane => "default",
+            Block::YellowStainedGlassPane => "default",
+            Block::LimeStainedGlassPane => "default",
+            Block::PinkStainedGlassPane => "default",
+            Block::GrayStainedGlassPane => "default",
+            Block::LightGrayStainedGlassPane => "default",
+            Block::CyanStainedGlassPane => "default",
+            Block::PurpleStainedGlassPane => "default",
+            Block::BlueStainedGlassPane => "default",
+            Block::BrownStainedGlassPane => "default",
+            Block::GreenStainedGlassPane => "default",
+            Block::RedStainedGlassPane => "default",
+            Block::BlackStainedGlassPane => "default",
+            Block::AcaciaStairs => "wood",
+            Block::DarkOakStairs => "wood",
+            Block::SlimeBlock => "default",
+            Block::Barrier => "default",
+            Block::IronTrapdoor => "iron",
+            Block::Prismarine => "rock",
+            Block::PrismarineBricks => "rock",
+            Block::DarkPrismarine => "rock",
+            Block::PrismarineStairs => "rock",
+            Block::PrismarineBrickStairs => "rock",
+            Block::DarkPrismarineStairs => "rock",
+            Block::PrismarineSlab => "rock",
+            Block::PrismarineBrickSlab => "rock",
+            Block::DarkPrismarineSlab => "rock",
+            Block::SeaLantern => "default",
+            Block::HayBlock => "default",
+            Block::WhiteCarpet => "default",
+            Block::OrangeCarpet => "default",
+            Block::MagentaCarpet => "default",
+            Block::LightBlueCarpet => "default",
+            Block::YellowCarpet => "default",
+            Block::LimeCarpet => "default",
+            Block::PinkCarpet => "default",
+            Block::GrayCarpet => "default",
+            Block::LightGrayCarpet => "default",
+            Block::CyanCarpet => "default",
+            Block::PurpleCarpet => "default",
+            Block::BlueCarpet => "default",
+            Block::BrownCarpet => "default",
+            Block::GreenCarpet => "default",
+            Block::RedCarpet => "default",
+            Block::BlackCarpet => "default",
+            Block::Terracotta => "rock",
+            Block::CoalBlock => "rock",
+            Block::PackedIce => "default",
+            Block::Sunflower => "plant",
+            Block::Lilac => "plant",
+            Block::RoseBush => "plant",
+            Block::Peony => "plant",
+            Block::TallGrass => "plant",
+            Block::LargeFern => "plant",
+            Block::WhiteBanner => "wood",
+            Block::OrangeBanner => "wood",
+            Block::MagentaBanner => "wood",
+            Block::LightBlueBanner => "wood",
+            Block::YellowBanner => "wood",
+            Block::LimeBanner => "wood",
+            Block::PinkBanner => "wood",
+            Block::GrayBanner => "wood",
+            Block::LightGrayBanner => "wood",
+            Block::CyanBanner => "wood",
+            Block::PurpleBanner => "wood",
+            Block::BlueBanner => "wood",
+            Block::BrownBanner => "wood",
+            Block::GreenBanner => "wood",
+            Block::RedBanner => "wood",
+            Block::BlackBanner => "wood",
+            Block::WhiteWallBanner => "wood",
+            Block::OrangeWallBanner => "wood",
+            Block::MagentaWallBanner => "wood",
+            Block::LightBlueWallBanner => "wood",
+            Block::YellowWallBanner => "wood",
+            Block::LimeWallBanner => "wood",
+            Block::PinkWallBanner => "wood",
+            Block::GrayWallBanner => "wood",
+            Block::LightGrayWallBanner => "wood",
+            Block::CyanWallBanner => "wood",
+            Block::PurpleWallBanner => "wood",
+            Block::BlueWallBanner => "wood",
+            Block::BrownWallBanner => "wood",
+            Block::GreenWallBanner => "wood",
+            Block::RedWallBanner => "wood",
+            Block::BlackWallBanner => "wood",
+            Block::RedSandstone => "rock",
+            Block::ChiseledRedSandstone => "rock",
+            Block::CutRedSandstone => "rock",
+            Block::RedSandstoneStairs => "rock",
+            Block::OakSlab => "wood",
+            Block::SpruceSlab => "wood",
+            Block::BirchSlab => "wood",
+            Block::JungleSlab => "wood",
+            Block::AcaciaSlab => "wood",
+            Block::DarkOakSlab => "wood",
+            Block::StoneSlab => "rock",
+            Block::SmoothStoneSlab => "rock",
+            Block::SandstoneSlab => "rock",
+            Block::CutSandstoneSlab => "rock",
+            Block::PetrifiedOakSlab => "wood",
+            Block::CobblestoneSlab => "rock",
+            Block::BrickSlab => "rock",
+            Block::StoneBrickSlab => "rock",
+            Block::NetherBrickSlab => "rock",
+            Block::QuartzSlab => "rock",
+            Block::RedSandstoneSlab => "rock",
+            Block::CutRedSandstoneSlab => "rock",
+            Block::PurpurSlab => "rock",
+            Block::SmoothStone => "rock",
+            Block::SmoothSandstone => "rock",
+            Block::SmoothQuartz => "rock",
+            Block::SmoothRedSandstone => "rock",
+            Block::SpruceFenceGate => "wood",
+            Block::BirchFenceGate => "wood",
+            Block::JungleFenceGate => "wood",
+            Block::AcaciaFenceGate => "wood",
+            Block::DarkOakFenceGate => "wood",
+            Block::SpruceFence => "wood",
+            Block::BirchFence => "wood",
+            Block::JungleFence => "wood",
+            Block::AcaciaFence => "wood",
+            Block::DarkOakFence => "wood",
+            Block::SpruceDoor => "wood",
+            Block::BirchDoor => "wood",
+            Block::JungleDoor => "wood",
+            Block::AcaciaDoor => "wood",
+            Block::DarkOakDoor => "wood",
+            Block::EndRod => "default",
+            Block::ChorusPlant => "plant",
+            Block::ChorusFlower => "plant",
+            Block::PurpurBlock => "rock",
+            Block::PurpurPillar => "rock",
+            Block::PurpurStairs => "rock",
+            Block::EndStoneBricks => "rock",
+            Block::Beetroots => "plant",
+            Block::GrassPath => "dirt",
+            Block::EndGateway => "default",
+            Block::RepeatingCommandBlock => "default",
+            Block::ChainCommandBlock => "default",
+            Block::FrostedIce => "default",
+            Block::MagmaBlock => "rock",
+            Block::NetherWartBlock => "rock",
+            Block::RedNetherBricks => "rock",
+            Block::BoneBlock => "rock",
+            Block::StructureVoid => "default",
+            Block::Observer => "rock",
+            Block::ShulkerBox => "rock",
+            Block::WhiteShulkerBox => "rock",
+            Block::OrangeShulkerBox => "rock",
+            Block::MagentaShulkerBox => "rock",
+            Block::LightBlueShulkerBox => "rock",
+            Block::YellowShulkerBox => "rock",
+            Block::LimeShulkerBox => "rock",
+            Block::PinkShulkerBox => "rock",
+            Block::GrayShulkerBox => "rock",
+            Block::LightGrayShulkerBox => "rock",
+            Block::CyanShulkerBox => "rock",
+            Block::PurpleShulkerBox => "rock",
+            Block::BlueShulkerBox => "rock",
+            Block::BrownShulkerBox => "rock",
+            Block::GreenShulkerBox => "rock",
+            Block::RedShulkerBox => "rock",
+            Block::BlackShulkerBox => "rock",
+            Block::WhiteGlazedTerracotta => "rock",
+            Block::OrangeGlazedTerracotta => "rock",
+            Block::MagentaGlazedTerracotta => "rock",
+            Block::LightBlueGlazedTerracotta => "rock",
+            Block::YellowGlazedTerracotta => "rock",
+            Block::LimeGlazedTerracotta => "rock",
+            Block::PinkGlazedTerracotta => "rock",
+            Block::GrayGlazedTerracotta => "rock",
+            Block::LightGrayGlazedTerracotta => "rock",
+            Block::CyanGlazedTerracotta => "rock",
+            Block::PurpleGlazedTerracotta => "rock",
+            Block::BlueGlazedTerracotta => "rock",
+            Block::BrownGlazedTerracotta => "rock",
+            Block::GreenGlazedTerracotta => "rock",
+            Block::RedGlazedTerracotta => "rock",
+            Block::BlackGlazedTerracotta => "rock",
+            Block::WhiteConcrete => "rock",
+            Block::OrangeConcrete => "rock",
+            Block::MagentaConcrete => "rock",
+            Block::LightBlueConcrete => "rock",
+            Block::YellowConcrete => "rock",
+            Block::LimeConcrete => "rock",
+            Block::PinkConcrete => "rock",
+            Block::GrayConcrete => "rock",
+            Block::LightGrayConcrete => "rock",
+            Block::CyanConcrete => "rock",
+            Block::PurpleConcrete => "rock",
+            Block::BlueConcrete => "rock",
+            Block::BrownConcrete => "rock",
+            Block::GreenConcrete => "rock",
+            Block::RedConcrete => "rock",
+            Block::BlackConcrete => "rock",
+            Block::WhiteConcretePowder => "dirt",
+            Block::OrangeConcretePowder => "dirt",
+            Block::MagentaConcretePowder => "dirt",
+            Block::LightBlueConcretePowder => "dirt",
+            Block::YellowConcretePowder => "dirt",
+            Block::LimeConcretePowder => "dirt",
+            Block::PinkConcretePowder => "dirt",
+            Block::GrayConcretePowder => "dirt",
+            Block::LightGrayConcretePowder => "dirt",
+            Block::CyanConcretePowder => "dirt",
+            Block::PurpleConcretePowder => "dirt",
+            Block::BlueConcretePowder => "dirt",
+            Block::BrownConcretePowder => "dirt",
+            Block::GreenConcretePowder => "dirt",
+            Block::RedConcretePowder => "dirt",
+            Block::BlackConcretePowder => "dirt",
+            Block::Kelp => "plant",
+            Block::KelpPlant => "plant",
+            Block::DriedKelpBlock => "default",
+            Block::TurtleEgg => "default",
+            Block::DeadTubeCoralBlock => "rock",
+            Block::DeadBrainCoralBlock => "rock",
+            Block::DeadBubbleCoralBlock => "rock",
+            Block::DeadFireCoralBlock => "rock",
+            Block::DeadHornCoralBlock => "rock",
+            Block::TubeCoralBlock => "rock",
+            Block::BrainCoralBlock => "rock",
+            Block::BubbleCoralBlock => "rock",
+            Block::FireCoralBlock => "rock",
+            Block::HornCoralBlock => "rock",
+            Block::DeadTubeCoral => "plant",
+            Block::DeadBrainCoral => "plant",
+            Block::DeadBubbleCoral => "plant",
+            Block::DeadFireCoral => "plant",
+            Block::DeadHornCoral => "plant",
+            Block::TubeCoral => "plant",
+            Block::BrainCoral => "plant",
+            Block::BubbleCoral => "plant",
+            Block::FireCoral => "plant",
+            Block::HornCoral => "plant",
+            Block::DeadTubeCoralFan => "plant",
+            Block::DeadBrainCoralFan => "plant",
+            Block::DeadBubbleCoralFan => "plant",
+            Block::DeadFireCoralFan => "plant",
+            Block::DeadHornCoralFan => "plant",
+            Block::TubeCoralFan => "plant",
+            Block::BrainCoralFan => "plant",
+            Block::BubbleCoralFan => "plant",
+            Block::FireCoralFan => "plant",
+            Block::HornCoralFan => "plant",
+            Block::DeadTubeCoralWallFan => "plant",
+            Block::DeadBrainCoralWallFan => "plant",
+            Block::DeadBubbleCoralWallFan => "plant",
+            Block::DeadFireCoralWallFan => "plant",
+            Block::DeadHornCoralWallFan => "plant",
+            Block::TubeCoralWallFan => "plant",
+            Block::BrainCoralWallFan => "plant",
+            Block::BubbleCoralWallFan => "plant",
+            Block::FireCoralWallFan => "plant",
+            Block::HornCoralWallFan => "plant",
+            Block::SeaPickle => "plant",
+            Block::BlueIce => "default",
+            Block::Conduit => "default",
+            Block::BambooSapling => "wood",
+            Block::Bamboo => "wood",
+            Block::PottedBamboo => "default",
+            Block::VoidAir => "air",
+            Block::CaveAir => "air",
+            Block::BubbleColumn => "water",
+            Block::PolishedGraniteStairs => "rock",
+            Block::SmoothRedSandstoneStairs => "rock",
+            Block::MossyStoneBrickStairs => "rock",
+            Block::PolishedDioriteStairs => "rock",
+            Block::MossyCobblestoneStairs => "rock",
+            Block::EndStoneBrickStairs => "rock",
+            Block::StoneStairs => "rock",
+            Block::SmoothSandstoneStairs => "rock",
+            Block::SmoothQuartzStairs => "rock",
+            Block::GraniteStairs => "rock",
+            Block::AndesiteStairs => "rock",
+            Block::RedNetherBrickStairs => "rock",
+            Block::PolishedAndesiteStairs => "rock",
+            Block::DioriteStairs => "rock",
+            Block::PolishedGraniteSlab => "rock",
+            Block::SmoothRedSandstoneSlab => "rock",
+            Block::MossyStoneBrickSlab => "rock",
+            Block::PolishedDioriteSlab => "rock",
+            Block::MossyCobblestoneSlab => "rock",
+            Block::EndStoneBrickSlab => "rock",
+            Block::SmoothSandstoneSlab => "rock",
+            Block::SmoothQuartzSlab => "rock",
+            Block::GraniteSlab => "rock",
+            Block::AndesiteSlab => "rock",
+            Block::RedNetherBrickSlab => "rock",
+            Block::PolishedAndesiteSlab => "rock",
+            Block::DioriteSlab => "rock",
+            Block::BrickWall => "rock",
+            Block::PrismarineWall => "rock",
+            Block::RedSandstoneWall => "rock",
+            Block::MossyStoneBrickWall => "rock",
+            Block::GraniteWall => "rock",
+            Block::StoneBrickWall => "rock",
+            Block::NetherBrickWall => "rock",
+            Block::AndesiteWall => "rock",
+            Block::RedNetherBrickWall => "rock",
+            Block::SandstoneWall => "rock",
+            Block::EndStoneBrickWall => "rock",
+            Block::DioriteWall => "rock",
+            Block::Scaffolding => "default",
+            Block::Loom => "wood",
+            Block::Barrel => "wood",
+            Block::Smoker => "rock",
+            Block::BlastFurnace => "rock",
+            Block::CartographyTable => "wood",
+            Block::FletchingTable => "wood",
+            Block::Grindstone => "rock",
+            Block::Lectern => "wood",
+            Block::SmithingTable => "wood",
+            Block::Stonecutter => "rock",
+            Block::Bell => "iron",
+            Block::Lantern => "iron",
+            Block::Campfire => "wood",
+            Block::SweetBerryBush => "plant",
+            Block::StructureBlock => "default",
+            Block::Jigsaw => "default",
+            Block::Composter => "wood",
+            Block::BeeNest => "wood",
+            Block::Beehive => "wood",
+            Block::HoneyBlock => "default",
+            Block::HoneycombBlock => "default",
+        }
+    }
+    pub fn harvest_tools(&self) -> Option<&'static [u32]> {
+        match self {
+            Block::Air => None,
+            Block::Stone => Some(&[521, 535, 539, 543, 550]),
+            Block::Granite => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedGranite => Some(&[521, 535, 539, 543, 550]),
+            Block::Diorite => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedDiorite => Some(&[521, 535, 539, 543, 550]),
+            Block::Andesite => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedAndesite => Some(&[521, 535, 539, 543, 550]),
+            Block::GrassBlock => None,
+            Block::Dirt => None,
+            Block::CoarseDirt => None,
+            Block::Podzol => None,
+            Block::Cobblestone => Some(&[521, 535, 539, 543, 550]),
+            Block::OakPlanks => None,
+            Block::SprucePlanks => None,
+            Block::BirchPlanks => None,
+            Block::JunglePlanks => None,
+            Block::AcaciaPlanks => None,
+            Block::DarkOakPlanks => None,
+            Block::OakSapling => None,
+            Block::SpruceSapling => None,
+            Block::BirchSapling => None,
+            Block::JungleSapling => None,
+            Block::AcaciaSapling => None,
+            Block::DarkOakSapling => None,
+            Block::Bedrock => None,
+            Block::Water => None,
+            Block::Lava => None,
+            Block::Sand => None,
+            Block::RedSand => None,
+            Block::Gravel => None,
+            Block::GoldOre => Some(&[521, 543]),
+            Block::IronOre => Some(&[521, 539, 543]),
+            Block::CoalOre => Some(&[521, 535, 539, 543, 550]),
+            Block::OakLog => None,
+            Block::SpruceLog => None,
+            Block::BirchLog => None,
+            Block::JungleLog => None,
+            Block::AcaciaLog => None,
+            Block::DarkOakLog => None,
+            Block::StrippedSpruceLog => None,
+            Block::StrippedBirchLog => None,
+            Block::StrippedJungleLog => None,
+            Block::StrippedAcaciaLog => None,
+            Block::StrippedDarkOakLog => None,
+            Block::StrippedOakLog => None,
+            Block::OakWood => None,
+            Block::SpruceWood => None,
+            Block::BirchWood => None,
+            Block::JungleWood => None,
+            Block::AcaciaWood => None,
+            Block::DarkOakWood => None,
+            Block::StrippedOakWood => None,
+            Block::StrippedSpruceWood => None,
+            Block::StrippedBirchWood => None,
+            Block::StrippedJungleWood => None,
+            Block::StrippedAcaciaWood => None,
+            Block::StrippedDarkOakWood => None,
+            Block::OakLeaves => None,
+            Block::SpruceLeaves => None,
+            Block::BirchLeaves => None,
+            Block::JungleLeaves => None,
+            Block::AcaciaLeaves => None,
+            Block::DarkOakLeaves => None,
+            Block::Sponge => None,
+            Block::WetSponge => None,
+            Block::Glass => None,
+            Block::LapisOre => Some(&[521, 539, 543]),
+            Block::LapisBlock => Some(&[521, 539, 543]),
+            Block::Dispenser => Some(&[521, 535, 539, 543, 550]),
+            Block::Sandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::ChiseledSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::CutSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::NoteBlock => None,
+            Block::WhiteBed => None,
+            Block::OrangeBed => None,
+            Block::MagentaBed => None,
+            Block::LightBlueBed => None,
+            Block::YellowBed => None,
+            Block::LimeBed => None,
+            Block::PinkBed => None,
+            Block::GrayBed => None,
+            Block::LightGrayBed => None,
+            Block::CyanBed => None,
+            Block::PurpleBed => None,
+            Block::BlueBed => None,
+            Block::BrownBed => None,
+            Block::GreenBed => None,
+            Block::RedBed => None,
+            Block::BlackBed => None,
+            Block::PoweredRail => Some(&[521, 535, 539, 543, 550]),
+            Block::DetectorRail => Some(&[521, 535, 539, 543, 550]),
+            Block::StickyPiston => None,
+            Block::Cobweb => Some(&[532, 533, 537, 541, 548, 672]),
+            Block::Grass => None,
+            Block::Fern => None,
+            Block::DeadBush => None,
+            Block::Seagrass => None,
+            Block::TallSeagrass => None,
+            Block::Piston => None,
+            Block::PistonHead => None,
+            Block::WhiteWool => None,
+            Block::OrangeWool => None,
+            Block::MagentaWool => None,
+            Block::LightBlueWool => None,
+            Block::YellowWool => None,
+            Block::LimeWool => None,
+            Block::PinkWool => None,
+            Block::GrayWool => None,
+            Block::LightGrayWool => None,
+            Block::CyanWool => None,
+            Block::PurpleWool => None,
+            Block::BlueWool => None,
+            Block::BrownWool => None,
+            Block::GreenWool => None,
+            Block::RedWool => None,
+            Block::BlackWool => None,
+            Block::MovingPiston => None,
+            Block::Dandelion => None,
+            Block::Poppy => None,
+            Block::BlueOrchid => None,
+            Block::Allium => None,
+            Block::AzureBluet => None,
+            Block::RedTulip => None,
+            Block::OrangeTulip => None,
+            Block::WhiteTulip => None,
+            Block::PinkTulip => None,
+            Block::OxeyeDaisy => None,
+            Block::Cornflower => None,
+            Block::WitherRose => None,
+            Block::LilyOfTheValley => None,
+            Block::BrownMushroom => None,
+            Block::RedMushroom => None,
+            Block::GoldBlock => Some(&[521, 543]),
+            Block::IronBlock => Some(&[521, 539, 543]),
+            Block::Bricks => Some(&[521, 535, 539, 543, 550]),
+            Block::Tnt => None,
+            Block::Bookshelf => None,
+            Block::MossyCobblestone => Some(&[521, 535, 539, 543, 550]),
+            Block::Obsidian => Some(&[543]),
+            Block::Torch => None,
+            Block::WallTorch => None,
+            Block::Fire => None,
+            Block::Spawner => Some(&[521, 535, 539, 543, 550]),
+            Block::OakStairs => None,
+            Block::Chest => None,
+            Block::RedstoneWire => None,
+            Block::DiamondOre => Some(&[521, 543]),
+            Block::DiamondBlock => Some(&[521, 543]),
+            Block::CraftingTable => None,
+            Block::Wheat => None,
+            Block::Farmland => None,
+            Block::Furnace => Some(&[521, 535, 539, 543, 550]),
+            Block::OakSign => None,
+            Block::SpruceSign => None,
+            Block::BirchSign => None,
+            Block::AcaciaSign => None,
+            Block::JungleSign => None,
+            Block::DarkOakSign => None,
+            Block::OakDoor => None,
+            Block::Ladder => None,
+            Block::Rail => Some(&[521, 535, 539, 543, 550]),
+            Block::CobblestoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::OakWallSign => None,
+            Block::SpruceWallSign => None,
+            Block::BirchWallSign => None,
+            Block::AcaciaWallSign => None,
+            Block::JungleWallSign => None,
+            Block::DarkOakWallSign => None,
+            Block::Lever => None,
+            Block::StonePressurePlate => Some(&[521, 535, 539, 543, 550]),
+            Block::IronDoor => Some(&[521, 535, 539, 543, 550]),
+            Block::OakPressurePlate => None,
+            Block::SprucePressurePlate => None,
+            Block::BirchPressurePlate => None,
+            Block::JunglePressurePlate => None,
+            Block::AcaciaPressurePlate => None,
+            Block::DarkOakPressurePlate => None,
+            Block::RedstoneOre => Some(&[521, 543]),
+            Block::RedstoneTorch => None,
+            Block::RedstoneWallTorch => None,
+            Block::StoneButton => Some(&[521, 535, 539, 543, 550]),
+            Block::Snow => Some(&[520, 534, 538, 542, 549]),
+            Block::Ice => None,
+            Block::SnowBlock => Some(&[520, 534, 538, 542, 549]),
+            Block::Cactus => None,
+            Block::Clay => None,
+            Block::SugarCane => None,
+            Block::Jukebox => None,
+            Block::OakFence => None,
+            Block::Pumpkin => None,
+            Block::Netherrack => Some(&[521, 535, 539, 543, 550]),
+            Block::SoulSand => None,
+            Block::Glowstone => None,
+            Block::NetherPortal => None,
+            Block::CarvedPumpkin => None,
+            Block::JackOLantern => None,
+            Block::Cake => None,
+            Block::Repeater => None,
+            Block::WhiteStainedGlass => None,
+            Block::OrangeStainedGlass => None,
+            Block::MagentaStainedGlass => None,
+            Block::LightBlueStainedGlass => None,
+            Block::YellowStainedGlass => None,
+            Block::LimeStainedGlass => None,
+            Block::PinkStainedGlass => None,
+            Block::GrayStainedGlass => None,
+            Block::LightGrayStainedGlass => None,
+            Block::CyanStainedGlass => None,
+            Block::PurpleStainedGlass => None,
+            Block::BlueStainedGlass => None,
+            Block::BrownStainedGlass => None,
+            Block::GreenStainedGlass => None,
+            Block::RedStainedGlass => None,
+            Block::BlackStainedGlass => None,
+            Block::OakTrapdoor => None,
+            Block::SpruceTrapdoor => None,
+            Block::BirchTrapdoor => None,
+            Block::JungleTrapdoor => None,
+            Block::AcaciaTrapdoor => None,
+            Block::DarkOakTrapdoor => None,
+            Block::StoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::CrackedStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::ChiseledStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedStone => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedCobblestone => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedMossyStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedCrackedStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::InfestedChiseledStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::BrownMushroomBlock => None,
+            Block::RedMushroomBlock => None,
+            Block::MushroomStem => None,
+            Block::IronBars => Some(&[521, 535, 539, 543, 550]),
+            Block::GlassPane => None,
+            Block::Melon => None,
+            Block::AttachedPumpkinStem => None,
+            Block::AttachedMelonStem => None,
+            Block::PumpkinStem => None,
+            Block::MelonStem => None,
+            Block::Vine => None,
+            Block::OakFenceGate => None,
+            Block::BrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::StoneBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::Mycelium => None,
+            Block::LilyPad => None,
+            Block::NetherBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherBrickFence => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherWart => None,
+            Block::EnchantingTable => Some(&[521, 535, 539, 543, 550]),
+            Block::BrewingStand => Some(&[521, 535, 539, 543, 550]),
+            Block::Cauldron => Some(&[521, 535, 539, 543, 550]),
+            Block::EndPortal => None,
+            Block::EndPortalFrame => None,
+            Block::EndStone => Some(&[521, 535, 539, 543, 550]),
+            Block::DragonEgg => Some(&[521, 535, 539, 543, 550]),
+            Block::RedstoneLamp => None,
+            Block::Cocoa => None,
+            Block::SandstoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::EmeraldOre => Some(&[521, 543]),
+            Block::EnderChest => Some(&[521, 535, 539, 543, 550]),
+            Block::TripwireHook => None,
+            Block::Tripwire => None,
+            Block::EmeraldBlock => Some(&[521, 543]),
+            Block::SpruceStairs => None,
+            Block::BirchStairs => None,
+            Block::JungleStairs => None,
+            Block::CommandBlock => None,
+            Block::Beacon => None,
+            Block::CobblestoneWall => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyCobblestoneWall => Some(&[521, 535, 539, 543, 550]),
+            Block::FlowerPot => None,
+            Block::PottedOakSapling => None,
+            Block::PottedSpruceSapling => None,
+            Block::PottedBirchSapling => None,
+            Block::PottedJungleSapling => None,
+            Block::PottedAcaciaSapling => None,
+            Block::PottedDarkOakSapling => None,
+            Block::PottedFern => None,
+            Block::PottedDandelion => None,
+            Block::PottedPoppy => None,
+            Block::PottedBlueOrchid => None,
+            Block::PottedAllium => None,
+            Block::PottedAzureBluet => None,
+            Block::PottedRedTulip => None,
+            Block::PottedOrangeTulip => None,
+            Block::PottedWhiteTulip => None,
+            Block::PottedPinkTulip => None,
+            Block::PottedOxeyeDaisy => None,
+            Block::PottedCornflower => None,
+            Block::PottedLilyOfTheValley => None,
+            Block::PottedWitherRose => None,
+            Block::PottedRedMushroom => None,
+            Block::PottedBrownMushroom => None,
+            Block::PottedDeadBush => None,
+            Block::PottedCactus => None,
+            Block::Carrots => None,
+            Block::Potatoes => None,
+            Block::OakButton => None,
+            Block::SpruceButton => None,
+            Block::BirchButton => None,
+            Block::JungleButton => None,
+            Block::AcaciaButton => None,
+            Block::DarkOakButton => None,
+            Block::SkeletonSkull => None,
+            Block::SkeletonWallSkull => None,
+            Block::WitherSkeletonSkull => None,
+            Block::WitherSkeletonWallSkull => None,
+            Block::ZombieHead => None,
+            Block::ZombieWallHead => None,
+            Block::PlayerHead => None,
+            Block::PlayerWallHead => None,
+            Block::CreeperHead => None,
+            Block::CreeperWallHead => None,
+            Block::DragonHead => None,
+            Block::DragonWallHead => None,
+            Block::Anvil => Some(&[521, 535, 539, 543, 550]),
+            Block::ChippedAnvil => Some(&[521, 535, 539, 543, 550]),
+            Block::DamagedAnvil => Some(&[521, 535, 539, 543, 550]),
+            Block::TrappedChest => None,
+            Block::LightWeightedPressurePlate => Some(&[521, 535, 539, 543, 550]),
+            Block::HeavyWeightedPressurePlate => Some(&[521, 535, 539, 543, 550]),
+            Block::Comparator => None,
+            Block::DaylightDetector => None,
+            Block::RedstoneBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherQuartzOre => Some(&[521, 535, 539, 543, 550]),
+            Block::Hopper => Some(&[521, 535, 539, 543, 550]),
+            Block::QuartzBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::ChiseledQuartzBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::QuartzPillar => Some(&[521, 535, 539, 543, 550]),
+            Block::QuartzStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::ActivatorRail => Some(&[521, 535, 539, 543, 550]),
+            Block::Dropper => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::OrangeTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::MagentaTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LightBlueTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::YellowTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LimeTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::PinkTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::GrayTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LightGrayTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::CyanTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpleTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BlueTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BrownTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::GreenTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::RedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BlackTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteStainedGlassPane => None,
+            Block::OrangeStainedGlassPane => None,
+            Block::MagentaStainedGlassPane => None,
+            Block::LightBlueStainedGlassPane => None,
+            Block::YellowStainedGlassPane => None,
+            Block::LimeStainedGlassPane => None,
+            Block::PinkStainedGlassPane => None,
+            Block::GrayStainedGlassPane => None,
+            Block::LightGrayStainedGlassPane => None,
+            Block::CyanStainedGlassPane => None,
+            Block::PurpleStainedGlassPane => None,
+            Block::BlueStainedGlassPane => None,
+            Block::BrownStainedGlassPane => None,
+            Block::GreenStainedGlassPane => None,
+            Block::RedStainedGlassPane => None,
+            Block::BlackStainedGlassPane => None,
+            Block::AcaciaStairs => None,
+            Block::DarkOakStairs => None,
+            Block::SlimeBlock => None,
+            Block::Barrier => None,
+            Block::IronTrapdoor => Some(&[521, 539, 543]),
+            Block::Prismarine => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::DarkPrismarine => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::DarkPrismarineStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::DarkPrismarineSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SeaLantern => None,
+            Block::HayBlock => None,
+            Block::WhiteCarpet => None,
+            Block::OrangeCarpet => None,
+            Block::MagentaCarpet => None,
+            Block::LightBlueCarpet => None,
+            Block::YellowCarpet => None,
+            Block::LimeCarpet => None,
+            Block::PinkCarpet => None,
+            Block::GrayCarpet => None,
+            Block::LightGrayCarpet => None,
+            Block::CyanCarpet => None,
+            Block::PurpleCarpet => None,
+            Block::BlueCarpet => None,
+            Block::BrownCarpet => None,
+            Block::GreenCarpet => None,
+            Block::RedCarpet => None,
+            Block::BlackCarpet => None,
+            Block::Terracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::CoalBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::PackedIce => None,
+            Block::Sunflower => None,
+            Block::Lilac => None,
+            Block::RoseBush => None,
+            Block::Peony => None,
+            Block::TallGrass => None,
+            Block::LargeFern => None,
+            Block::WhiteBanner => None,
+            Block::OrangeBanner => None,
+            Block::MagentaBanner => None,
+            Block::LightBlueBanner => None,
+            Block::YellowBanner => None,
+            Block::LimeBanner => None,
+            Block::PinkBanner => None,
+            Block::GrayBanner => None,
+            Block::LightGrayBanner => None,
+            Block::CyanBanner => None,
+            Block::PurpleBanner => None,
+            Block::BlueBanner => None,
+            Block::BrownBanner => None,
+            Block::GreenBanner => None,
+            Block::RedBanner => None,
+            Block::BlackBanner => None,
+            Block::WhiteWallBanner => None,
+            Block::OrangeWallBanner => None,
+            Block::MagentaWallBanner => None,
+            Block::LightBlueWallBanner => None,
+            Block::YellowWallBanner => None,
+            Block::LimeWallBanner => None,
+            Block::PinkWallBanner => None,
+            Block::GrayWallBanner => None,
+            Block::LightGrayWallBanner => None,
+            Block::CyanWallBanner => None,
+            Block::PurpleWallBanner => None,
+            Block::BlueWallBanner => None,
+            Block::BrownWallBanner => None,
+            Block::GreenWallBanner => None,
+            Block::RedWallBanner => None,
+            Block::BlackWallBanner => None,
+            Block::RedSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::ChiseledRedSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::CutRedSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::RedSandstoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::OakSlab => None,
+            Block::SpruceSlab => None,
+            Block::BirchSlab => None,
+            Block::JungleSlab => None,
+            Block::AcaciaSlab => None,
+            Block::DarkOakSlab => None,
+            Block::StoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothStoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::CutSandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::PetrifiedOakSlab => None,
+            Block::CobblestoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::BrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::StoneBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::QuartzSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::RedSandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::CutRedSandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpurSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothStone => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothQuartz => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothRedSandstone => Some(&[521, 535, 539, 543, 550]),
+            Block::SpruceFenceGate => None,
+            Block::BirchFenceGate => None,
+            Block::JungleFenceGate => None,
+            Block::AcaciaFenceGate => None,
+            Block::DarkOakFenceGate => None,
+            Block::SpruceFence => None,
+            Block::BirchFence => None,
+            Block::JungleFence => None,
+            Block::AcaciaFence => None,
+            Block::DarkOakFence => None,
+            Block::SpruceDoor => None,
+            Block::BirchDoor => None,
+            Block::JungleDoor => None,
+            Block::AcaciaDoor => None,
+            Block::DarkOakDoor => None,
+            Block::EndRod => None,
+            Block::ChorusPlant => None,
+            Block::ChorusFlower => None,
+            Block::PurpurBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpurPillar => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpurStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::EndStoneBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::Beetroots => None,
+            Block::GrassPath => None,
+            Block::EndGateway => None,
+            Block::RepeatingCommandBlock => None,
+            Block::ChainCommandBlock => None,
+            Block::FrostedIce => None,
+            Block::MagmaBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherWartBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::RedNetherBricks => Some(&[521, 535, 539, 543, 550]),
+            Block::BoneBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::StructureVoid => None,
+            Block::Observer => Some(&[521, 535, 539, 543, 550]),
+            Block::ShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::OrangeShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::MagentaShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::LightBlueShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::YellowShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::LimeShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::PinkShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::GrayShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::LightGrayShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::CyanShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpleShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::BlueShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::BrownShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::GreenShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::RedShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::BlackShulkerBox => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::OrangeGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::MagentaGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LightBlueGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::YellowGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LimeGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::PinkGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::GrayGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::LightGrayGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::CyanGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpleGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BlueGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BrownGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::GreenGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::RedGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::BlackGlazedTerracotta => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::OrangeConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::MagentaConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::LightBlueConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::YellowConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::LimeConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::PinkConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::GrayConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::LightGrayConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::CyanConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::PurpleConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::BlueConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::BrownConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::GreenConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::RedConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::BlackConcrete => Some(&[521, 535, 539, 543, 550]),
+            Block::WhiteConcretePowder => None,
+            Block::OrangeConcretePowder => None,
+            Block::MagentaConcretePowder => None,
+            Block::LightBlueConcretePowder => None,
+            Block::YellowConcretePowder => None,
+            Block::LimeConcretePowder => None,
+            Block::PinkConcretePowder => None,
+            Block::GrayConcretePowder => None,
+            Block::LightGrayConcretePowder => None,
+            Block::CyanConcretePowder => None,
+            Block::PurpleConcretePowder => None,
+            Block::BlueConcretePowder => None,
+            Block::BrownConcretePowder => None,
+            Block::GreenConcretePowder => None,
+            Block::RedConcretePowder => None,
+            Block::BlackConcretePowder => None,
+            Block::Kelp => None,
+            Block::KelpPlant => None,
+            Block::DriedKelpBlock => None,
+            Block::TurtleEgg => None,
+            Block::DeadTubeCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::DeadBrainCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::DeadBubbleCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::DeadFireCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::DeadHornCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::TubeCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::BrainCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::BubbleCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::FireCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::HornCoralBlock => Some(&[521, 535, 539, 543, 550]),
+            Block::DeadTubeCoral => None,
+            Block::DeadBrainCoral => None,
+            Block::DeadBubbleCoral => None,
+            Block::DeadFireCoral => None,
+            Block::DeadHornCoral => None,
+            Block::TubeCoral => None,
+            Block::BrainCoral => None,
+            Block::BubbleCoral => None,
+            Block::FireCoral => None,
+            Block::HornCoral => None,
+            Block::DeadTubeCoralFan => None,
+            Block::DeadBrainCoralFan => None,
+            Block::DeadBubbleCoralFan => None,
+            Block::DeadFireCoralFan => None,
+            Block::DeadHornCoralFan => None,
+            Block::TubeCoralFan => None,
+            Block::BrainCoralFan => None,
+            Block::BubbleCoralFan => None,
+            Block::FireCoralFan => None,
+            Block::HornCoralFan => None,
+            Block::DeadTubeCoralWallFan => None,
+            Block::DeadBrainCoralWallFan => None,
+            Block::DeadBubbleCoralWallFan => None,
+            Block::DeadFireCoralWallFan => None,
+            Block::DeadHornCoralWallFan => None,
+            Block::TubeCoralWallFan => None,
+            Block::BrainCoralWallFan => None,
+            Block::BubbleCoralWallFan => None,
+            Block::FireCoralWallFan => None,
+            Block::HornCoralWallFan => None,
+            Block::SeaPickle => None,
+            Block::BlueIce => None,
+            Block::Conduit => None,
+            Block::BambooSapling => None,
+            Block::Bamboo => None,
+            Block::PottedBamboo => None,
+            Block::VoidAir => None,
+            Block::CaveAir => None,
+            Block::BubbleColumn => None,
+            Block::PolishedGraniteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothRedSandstoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyStoneBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedDioriteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyCobblestoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::EndStoneBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::StoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothSandstoneStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothQuartzStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::GraniteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::AndesiteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::RedNetherBrickStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedAndesiteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::DioriteStairs => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedGraniteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothRedSandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyStoneBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedDioriteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyCobblestoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::EndStoneBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothSandstoneSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::SmoothQuartzSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::GraniteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::AndesiteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::RedNetherBrickSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::PolishedAndesiteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::DioriteSlab => Some(&[521, 535, 539, 543, 550]),
+            Block::BrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::PrismarineWall => Some(&[521, 535, 539, 543, 550]),
+            Block::RedSandstoneWall => Some(&[521, 535, 539, 543, 550]),
+            Block::MossyStoneBrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::GraniteWall => Some(&[521, 535, 539, 543, 550]),
+            Block::StoneBrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::NetherBrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::AndesiteWall => Some(&[521, 535, 539, 543, 550]),
+            Block::RedNetherBrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::SandstoneWall => Some(&[521, 535, 539, 543, 550]),
+            Block::EndStoneBrickWall => Some(&[521, 535, 539, 543, 550]),
+            Block::DioriteWall => Some(&[521, 535, 539, 543, 550]),
+            Block::Scaffolding => None,
+            Block::Loom => None,
+            Block::Barrel => None,
+            Block::Smoker => Some(&[521, 535, 539, 543, 550]),
+            Block::BlastFurnace => Some(&[521, 535, 539, 543, 550]),
+            Block::CartographyTable => None,
+            Block::FletchingTable => None,
+            Block::Grindstone => Some(&[521, 535, 539, 543, 550]),
+            Block::Lectern => None,
+            Block::SmithingTable => None,
+            Block::Stonecutter => Some(&[521, 535, 539, 543, 550]),
+            Block::Bell => Some(&[521, 535, 539, 543, 550]),
+            Block::Lantern => Some(&[521, 535, 539, 543, 550]),
+            Block::Campfire => None,
+            Block::SweetBerryBush => None,
+            Block::StructureBlock => None,
+            Block::Jigsaw => None,
+            Block::Composter => None,
+            Block::BeeNest => None,
+            Block::Beehive => None,
+            Block::HoneyBlock => None,
+            Block::HoneycombBlock => None,
+        }
+    }
+    pub fn properties(&self) -> &'static [(&'static str, &'static [&'static str])] {
+        match self {
+            Block::Air => &[],
+            Block::Stone => &[],
+            Block::Granite => &[],
+            Block::PolishedGranite => &[],
+            Block::Diorite => &[],
+            Block::PolishedDiorite => &[],
+            Block::Andesite => &[],
+            Block::PolishedAndesite => &[],
+            Block::GrassBlock => &[("snowy", &["true", "false"])],
+            Block::Dirt => &[],
+            Block::CoarseDirt => &[],
+            Block::Podzol => &[("snowy", &["true", "false"])],
+            Block::Cobblestone => &[],
+            Block::OakPlanks => &[],
+            Block::SprucePlanks => &[],
+            Block::BirchPlanks => &[],
+            Block::JunglePlanks => &[],
+            Block::AcaciaPlanks => &[],
+            Block::DarkOakPlanks => &[],
+            Block::OakSapling => &[("stage", &["0", "1"])],
+            Block::SpruceSapling => &[("stage", &["0", "1"])],
+            Block::BirchSapling => &[("stage", &["0", "1"])],
+            Block::JungleSapling => &[("stage", &["0", "1"])],
+            Block::AcaciaSapling => &[("stage", &["0", "1"])],
+            Block::DarkOakSapling => &[("stage", &["0", "1"])],
+            Block::Bedrock => &[],
+            Block::Water => &[(
+                "level",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::Lava => &[(
+                "level",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::Sand => &[],
+            Block::RedSand => &[],
+            Block::Gravel => &[],
+            Block::GoldOre => &[],
+            Block::IronOre => &[],
+            Block::CoalOre => &[],
+            Block::OakLog => &[("axis", &["x", "y", "z"])],
+            Block::SpruceLog => &[("axis", &["x", "y", "z"])],
+            Block::BirchLog => &[("axis", &["x", "y", "z"])],
+            Block::JungleLog => &[("axis", &["x", "y", "z"])],
+            Block::AcaciaLog => &[("axis", &["x", "y", "z"])],
+            Block::DarkOakLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedSpruceLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedBirchLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedJungleLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedAcaciaLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedDarkOakLog => &[("axis", &["x", "y", "z"])],
+            Block::StrippedOakLog => &[("axis", &["x", "y", "z"])],
+            Block::OakWood => &[("axis", &["x", "y", "z"])],
+            Block::SpruceWood => &[("axis", &["x", "y", "z"])],
+            Block::BirchWood => &[("axis", &["x", "y", "z"])],
+            Block::JungleWood => &[("axis", &["x", "y", "z"])],
+            Block::AcaciaWood => &[("axis", &["x", "y", "z"])],
+            Block::DarkOakWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedOakWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedSpruceWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedBirchWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedJungleWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedAcaciaWood => &[("axis", &["x", "y", "z"])],
+            Block::StrippedDarkOakWood => &[("axis", &["x", "y", "z"])],
+            Block::OakLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::SpruceLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::BirchLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::JungleLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::AcaciaLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::DarkOakLeaves => &[
+                ("distance", &["1", "2", "3", "4", "5", "6", "7"]),
+                ("persistent", &["true", "false"]),
+            ],
+            Block::Sponge => &[],
+            Block::WetSponge => &[],
+            Block::Glass => &[],
+            Block::LapisOre => &[],
+            Block::LapisBlock => &[],
+            Block::Dispenser => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("triggered", &["true", "false"]),
+            ],
+            Block::Sandstone => &[],
+            Block::ChiseledSandstone => &[],
+            Block::CutSandstone => &[],
+            Block::NoteBlock => &[
+                (
+                    "instrument",
+                    &[
+                        "harp",
+                        "basedrum",
+                        "snare",
+                        "hat",
+                        "bass",
+                        "flute",
+                        "bell",
+                        "guitar",
+                        "chime",
+                        "xylophone",
+                        "iron_xylophone",
+                        "cow_bell",
+                        "didgeridoo",
+                        "bit",
+                        "banjo",
+                        "pling",
+                    ],
+                ),
+                (
+                    "note",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24",
+                    ],
+                ),
+                ("powered", &["true", "false"]),
+            ],
+            Block::WhiteBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::OrangeBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::MagentaBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::LightBlueBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::YellowBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::LimeBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::PinkBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::GrayBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::LightGrayBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::CyanBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::PurpleBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::BlueBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::BrownBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::GreenBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::RedBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::BlackBed => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("occupied", &["true", "false"]),
+                ("part", &["head", "foot"]),
+            ],
+            Block::PoweredRail => &[
+                ("powered", &["true", "false"]),
+                (
+                    "shape",
+                    &[
+                        "north_south",
+                        "east_west",
+                        "ascending_east",
+                        "ascending_west",
+                        "ascending_north",
+                        "ascending_south",
+                    ],
+                ),
+            ],
+            Block::DetectorRail => &[
+                ("powered", &["true", "false"]),
+                (
+                    "shape",
+                    &[
+                        "north_south",
+                        "east_west",
+                        "ascending_east",
+                        "ascending_west",
+                        "ascending_north",
+                        "ascending_south",
+                    ],
+                ),
+            ],
+            Block::StickyPiston => &[
+                ("extended", &["true", "false"]),
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+            ],
+            Block::Cobweb => &[],
+            Block::Grass => &[],
+            Block::Fern => &[],
+            Block::DeadBush => &[],
+            Block::Seagrass => &[],
+            Block::TallSeagrass => &[("half", &["upper", "lower"])],
+            Block::Piston => &[
+                ("extended", &["true", "false"]),
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+            ],
+            Block::PistonHead => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("short", &["true", "false"]),
+                ("type", &["normal", "sticky"]),
+            ],
+            Block::WhiteWool => &[],
+            Block::OrangeWool => &[],
+            Block::MagentaWool => &[],
+            Block::LightBlueWool => &[],
+            Block::YellowWool => &[],
+            Block::LimeWool => &[],
+            Block::PinkWool => &[],
+            Block::GrayWool => &[],
+            Block::LightGrayWool => &[],
+            Block::CyanWool => &[],
+            Block::PurpleWool => &[],
+            Block::BlueWool => &[],
+            Block::BrownWool => &[],
+            Block::GreenWool => &[],
+            Block::RedWool => &[],
+            Block::BlackWool => &[],
+            Block::MovingPiston => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("type", &["normal", "sticky"]),
+            ],
+            Block::Dandelion => &[],
+            Block::Poppy => &[],
+            Block::BlueOrchid => &[],
+            Block::Allium => &[],
+            Block::AzureBluet => &[],
+            Block::RedTulip => &[],
+            Block::OrangeTulip => &[],
+            Block::WhiteTulip => &[],
+            Block::PinkTulip => &[],
+            Block::OxeyeDaisy => &[],
+            Block::Cornflower => &[],
+            Block::WitherRose => &[],
+            Block::LilyOfTheValley => &[],
+            Block::BrownMushroom => &[],
+            Block::RedMushroom => &[],
+            Block::GoldBlock => &[],
+            Block::IronBlock => &[],
+            Block::Bricks => &[],
+            Block::Tnt => &[("unstable", &["true", "false"])],
+            Block::Bookshelf => &[],
+            Block::MossyCobblestone => &[],
+            Block::Obsidian => &[],
+            Block::Torch => &[],
+            Block::WallTorch => &[("facing", &["north", "south", "west", "east"])],
+            Block::Fire => &[
+                (
+                    "age",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::Spawner => &[],
+            Block::OakStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Chest => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("type", &["single", "left", "right"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::RedstoneWire => &[
+                ("east", &["up", "side", "none"]),
+                ("north", &["up", "side", "none"]),
+                (
+                    "power",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("south", &["up", "side", "none"]),
+                ("west", &["up", "side", "none"]),
+            ],
+            Block::DiamondOre => &[],
+            Block::DiamondBlock => &[],
+            Block::CraftingTable => &[],
+            Block::Wheat => &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::Farmland => &[("moisture", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::Furnace => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("lit", &["true", "false"]),
+            ],
+            Block::OakSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SpruceSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BirchSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AcaciaSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::JungleSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkOakSign => &[
+                (
+                    "rotation",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::OakDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::Ladder => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Rail => &[(
+                "shape",
+                &[
+                    "north_south",
+                    "east_west",
+                    "ascending_east",
+                    "ascending_west",
+                    "ascending_north",
+                    "ascending_south",
+                    "south_east",
+                    "south_west",
+                    "north_west",
+                    "north_east",
+                ],
+            )],
+            Block::CobblestoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::OakWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SpruceWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BirchWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AcaciaWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::JungleWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkOakWallSign => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Lever => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::StonePressurePlate => &[("powered", &["true", "false"])],
+            Block::IronDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::OakPressurePlate => &[("powered", &["true", "false"])],
+            Block::SprucePressurePlate => &[("powered", &["true", "false"])],
+            Block::BirchPressurePlate => &[("powered", &["true", "false"])],
+            Block::JunglePressurePlate => &[("powered", &["true", "false"])],
+            Block::AcaciaPressurePlate => &[("powered", &["true", "false"])],
+            Block::DarkOakPressurePlate => &[("powered", &["true", "false"])],
+            Block::RedstoneOre => &[("lit", &["true", "false"])],
+            Block::RedstoneTorch => &[("lit", &["true", "false"])],
+            Block::RedstoneWallTorch => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("lit", &["true", "false"]),
+            ],
+            Block::StoneButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::Snow => &[("layers", &["1", "2", "3", "4", "5", "6", "7", "8"])],
+            Block::Ice => &[],
+            Block::SnowBlock => &[],
+            Block::Cactus => &[(
+                "age",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::Clay => &[],
+            Block::SugarCane => &[(
+                "age",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::Jukebox => &[("has_record", &["true", "false"])],
+            Block::OakFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::Pumpkin => &[],
+            Block::Netherrack => &[],
+            Block::SoulSand => &[],
+            Block::Glowstone => &[],
+            Block::NetherPortal => &[("axis", &["x", "z"])],
+            Block::CarvedPumpkin => &[("facing", &["north", "south", "west", "east"])],
+            Block::JackOLantern => &[("facing", &["north", "south", "west", "east"])],
+            Block::Cake => &[("bites", &["0", "1", "2", "3", "4", "5", "6"])],
+            Block::Repeater => &[
+                ("delay", &["1", "2", "3", "4"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("locked", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::WhiteStainedGlass => &[],
+            Block::OrangeStainedGlass => &[],
+            Block::MagentaStainedGlass => &[],
+            Block::LightBlueStainedGlass => &[],
+            Block::YellowStainedGlass => &[],
+            Block::LimeStainedGlass => &[],
+            Block::PinkStainedGlass => &[],
+            Block::GrayStainedGlass => &[],
+            Block::LightGrayStainedGlass => &[],
+            Block::CyanStainedGlass => &[],
+            Block::PurpleStainedGlass => &[],
+            Block::BlueStainedGlass => &[],
+            Block::BrownStainedGlass => &[],
+            Block::GreenStainedGlass => &[],
+            Block::RedStainedGlass => &[],
+            Block::BlackStainedGlass => &[],
+            Block::OakTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SpruceTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BirchTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::JungleTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AcaciaTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkOakTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::StoneBricks => &[],
+            Block::MossyStoneBricks => &[],
+            Block::CrackedStoneBricks => &[],
+            Block::ChiseledStoneBricks => &[],
+            Block::InfestedStone => &[],
+            Block::InfestedCobblestone => &[],
+            Block::InfestedStoneBricks => &[],
+            Block::InfestedMossyStoneBricks => &[],
+            Block::InfestedCrackedStoneBricks => &[],
+            Block::InfestedChiseledStoneBricks => &[],
+            Block::BrownMushroomBlock => &[
+                ("down", &["true", "false"]),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::RedMushroomBlock => &[
+                ("down", &["true", "false"]),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::MushroomStem => &[
+                ("down", &["true", "false"]),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::IronBars => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::GlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::Melon => &[],
+            Block::AttachedPumpkinStem => &[("facing", &["north", "south", "west", "east"])],
+            Block::AttachedMelonStem => &[("facing", &["north", "south", "west", "east"])],
+            Block::PumpkinStem => &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::MelonStem => &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::Vine => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::OakFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::BrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::StoneBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Mycelium => &[("snowy", &["true", "false"])],
+            Block::LilyPad => &[],
+            Block::NetherBricks => &[],
+            Block::NetherBrickFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::NetherBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::NetherWart => &[("age", &["0", "1", "2", "3"])],
+            Block::EnchantingTable => &[],
+            Block::BrewingStand => &[
+                ("has_bottle_0", &["true", "false"]),
+                ("has_bottle_1", &["true", "false"]),
+                ("has_bottle_2", &["true", "false"]),
+            ],
+            Block::Cauldron => &[("level", &["0", "1", "2", "3"])],
+            Block::EndPortal => &[],
+            Block::EndPortalFrame => &[
+                ("eye", &["true", "false"]),
+                ("facing", &["north", "south", "west", "east"]),
+            ],
+            Block::EndStone => &[],
+            Block::DragonEgg => &[],
+            Block::RedstoneLamp => &[("lit", &["true", "false"])],
+            Block::Cocoa => &[
+                ("age", &["0", "1", "2"]),
+                ("facing", &["north", "south", "west", "east"]),
+            ],
+            Block::SandstoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::EmeraldOre => &[],
+            Block::EnderChest => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::TripwireHook => &[
+                ("attached", &["true", "false"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::Tripwire => &[
+                ("attached", &["true", "false"]),
+                ("disarmed", &["true", "false"]),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::EmeraldBlock => &[],
+            Block::SpruceStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BirchStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::JungleStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::CommandBlock => &[
+                ("conditional", &["true", "false"]),
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+            ],
+            Block::Beacon => &[],
+            Block::CobblestoneWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::MossyCobblestoneWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::FlowerPot => &[],
+            Block::PottedOakSapling => &[],
+            Block::PottedSpruceSapling => &[],
+            Block::PottedBirchSapling => &[],
+            Block::PottedJungleSapling => &[],
+            Block::PottedAcaciaSapling => &[],
+            Block::PottedDarkOakSapling => &[],
+            Block::PottedFern => &[],
+            Block::PottedDandelion => &[],
+            Block::PottedPoppy => &[],
+            Block::PottedBlueOrchid => &[],
+            Block::PottedAllium => &[],
+            Block::PottedAzureBluet => &[],
+            Block::PottedRedTulip => &[],
+            Block::PottedOrangeTulip => &[],
+            Block::PottedWhiteTulip => &[],
+            Block::PottedPinkTulip => &[],
+            Block::PottedOxeyeDaisy => &[],
+            Block::PottedCornflower => &[],
+            Block::PottedLilyOfTheValley => &[],
+            Block::PottedWitherRose => &[],
+            Block::PottedRedMushroom => &[],
+            Block::PottedBrownMushroom => &[],
+            Block::PottedDeadBush => &[],
+            Block::PottedCactus => &[],
+            Block::Carrots => &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::Potatoes => &[("age", &["0", "1", "2", "3", "4", "5", "6", "7"])],
+            Block::OakButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::SpruceButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::BirchButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::JungleButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::AcaciaButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::DarkOakButton => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::SkeletonSkull => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::SkeletonWallSkull => &[("facing", &["north", "south", "west", "east"])],
+            Block::WitherSkeletonSkull => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::WitherSkeletonWallSkull => &[("facing", &["north", "south", "west", "east"])],
+            Block::ZombieHead => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::ZombieWallHead => &[("facing", &["north", "south", "west", "east"])],
+            Block::PlayerHead => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::PlayerWallHead => &[("facing", &["north", "south", "west", "east"])],
+            Block::CreeperHead => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::CreeperWallHead => &[("facing", &["north", "south", "west", "east"])],
+            Block::DragonHead => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::DragonWallHead => &[("facing", &["north", "south", "west", "east"])],
+            Block::Anvil => &[("facing", &["north", "south", "west", "east"])],
+            Block::ChippedAnvil => &[("facing", &["north", "south", "west", "east"])],
+            Block::DamagedAnvil => &[("facing", &["north", "south", "west", "east"])],
+            Block::TrappedChest => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("type", &["single", "left", "right"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::LightWeightedPressurePlate => &[(
+                "power",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::HeavyWeightedPressurePlate => &[(
+                "power",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::Comparator => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("mode", &["compare", "subtract"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::DaylightDetector => &[
+                ("inverted", &["true", "false"]),
+                (
+                    "power",
+                    &[
+                        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+                        "14", "15",
+                    ],
+                ),
+            ],
+            Block::RedstoneBlock => &[],
+            Block::NetherQuartzOre => &[],
+            Block::Hopper => &[
+                ("enabled", &["true", "false"]),
+                ("facing", &["down", "north", "south", "west", "east"]),
+            ],
+            Block::QuartzBlock => &[],
+            Block::ChiseledQuartzBlock => &[],
+            Block::QuartzPillar => &[("axis", &["x", "y", "z"])],
+            Block::QuartzStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::ActivatorRail => &[
+                ("powered", &["true", "false"]),
+                (
+                    "shape",
+                    &[
+                        "north_south",
+                        "east_west",
+                        "ascending_east",
+                        "ascending_west",
+                        "ascending_north",
+                        "ascending_south",
+                    ],
+                ),
+            ],
+            Block::Dropper => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("triggered", &["true", "false"]),
+            ],
+            Block::WhiteTerracotta => &[],
+            Block::OrangeTerracotta => &[],
+            Block::MagentaTerracotta => &[],
+            Block::LightBlueTerracotta => &[],
+            Block::YellowTerracotta => &[],
+            Block::LimeTerracotta => &[],
+            Block::PinkTerracotta => &[],
+            Block::GrayTerracotta => &[],
+            Block::LightGrayTerracotta => &[],
+            Block::CyanTerracotta => &[],
+            Block::PurpleTerracotta => &[],
+            Block::BlueTerracotta => &[],
+            Block::BrownTerracotta => &[],
+            Block::GreenTerracotta => &[],
+            Block::RedTerracotta => &[],
+            Block::BlackTerracotta => &[],
+            Block::WhiteStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::OrangeStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::MagentaStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::LightBlueStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::YellowStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::LimeStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::PinkStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::GrayStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::LightGrayStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::CyanStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::PurpleStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::BlueStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::BrownStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::GreenStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::RedStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::BlackStainedGlassPane => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::AcaciaStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkOakStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SlimeBlock => &[],
+            Block::Barrier => &[],
+            Block::IronTrapdoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Prismarine => &[],
+            Block::PrismarineBricks => &[],
+            Block::DarkPrismarine => &[],
+            Block::PrismarineStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PrismarineBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkPrismarineStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PrismarineSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PrismarineBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkPrismarineSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SeaLantern => &[],
+            Block::HayBlock => &[("axis", &["x", "y", "z"])],
+            Block::WhiteCarpet => &[],
+            Block::OrangeCarpet => &[],
+            Block::MagentaCarpet => &[],
+            Block::LightBlueCarpet => &[],
+            Block::YellowCarpet => &[],
+            Block::LimeCarpet => &[],
+            Block::PinkCarpet => &[],
+            Block::GrayCarpet => &[],
+            Block::LightGrayCarpet => &[],
+            Block::CyanCarpet => &[],
+            Block::PurpleCarpet => &[],
+            Block::BlueCarpet => &[],
+            Block::BrownCarpet => &[],
+            Block::GreenCarpet => &[],
+            Block::RedCarpet => &[],
+            Block::BlackCarpet => &[],
+            Block::Terracotta => &[],
+            Block::CoalBlock => &[],
+            Block::PackedIce => &[],
+            Block::Sunflower => &[("half", &["upper", "lower"])],
+            Block::Lilac => &[("half", &["upper", "lower"])],
+            Block::RoseBush => &[("half", &["upper", "lower"])],
+            Block::Peony => &[("half", &["upper", "lower"])],
+            Block::TallGrass => &[("half", &["upper", "lower"])],
+            Block::LargeFern => &[("half", &["upper", "lower"])],
+            Block::WhiteBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::OrangeBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::MagentaBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::LightBlueBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::YellowBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::LimeBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::PinkBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::GrayBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::LightGrayBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::CyanBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::PurpleBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::BlueBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::BrownBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::GreenBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::RedBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::BlackBanner => &[(
+                "rotation",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15",
+                ],
+            )],
+            Block::WhiteWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::OrangeWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::MagentaWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::LightBlueWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::YellowWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::LimeWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::PinkWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::GrayWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::LightGrayWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::CyanWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::PurpleWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::BlueWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::BrownWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::GreenWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::RedWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::BlackWallBanner => &[("facing", &["north", "south", "west", "east"])],
+            Block::RedSandstone => &[],
+            Block::ChiseledRedSandstone => &[],
+            Block::CutRedSandstone => &[],
+            Block::RedSandstoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::OakSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SpruceSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BirchSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::JungleSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AcaciaSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DarkOakSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::StoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothStoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::CutSandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PetrifiedOakSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::CobblestoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::StoneBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::NetherBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::QuartzSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::RedSandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::CutRedSandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PurpurSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothStone => &[],
+            Block::SmoothSandstone => &[],
+            Block::SmoothQuartz => &[],
+            Block::SmoothRedSandstone => &[],
+            Block::SpruceFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::BirchFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::JungleFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::AcaciaFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::DarkOakFenceGate => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("in_wall", &["true", "false"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::SpruceFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::BirchFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::JungleFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::AcaciaFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::DarkOakFence => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::SpruceDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::BirchDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::JungleDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::AcaciaDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::DarkOakDoor => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["upper", "lower"]),
+                ("hinge", &["left", "right"]),
+                ("open", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::EndRod => &[("facing", &["north", "east", "south", "west", "up", "down"])],
+            Block::ChorusPlant => &[
+                ("down", &["true", "false"]),
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::ChorusFlower => &[("age", &["0", "1", "2", "3", "4", "5"])],
+            Block::PurpurBlock => &[],
+            Block::PurpurPillar => &[("axis", &["x", "y", "z"])],
+            Block::PurpurStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::EndStoneBricks => &[],
+            Block::Beetroots => &[("age", &["0", "1", "2", "3"])],
+            Block::GrassPath => &[],
+            Block::EndGateway => &[],
+            Block::RepeatingCommandBlock => &[
+                ("conditional", &["true", "false"]),
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+            ],
+            Block::ChainCommandBlock => &[
+                ("conditional", &["true", "false"]),
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+            ],
+            Block::FrostedIce => &[("age", &["0", "1", "2", "3"])],
+            Block::MagmaBlock => &[],
+            Block::NetherWartBlock => &[],
+            Block::RedNetherBricks => &[],
+            Block::BoneBlock => &[("axis", &["x", "y", "z"])],
+            Block::StructureVoid => &[],
+            Block::Observer => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::ShulkerBox => &[("facing", &["north", "east", "south", "west", "up", "down"])],
+            Block::WhiteShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::OrangeShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::MagentaShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::LightBlueShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::YellowShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::LimeShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::PinkShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::GrayShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::LightGrayShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::CyanShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::PurpleShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::BlueShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::BrownShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::GreenShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::RedShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::BlackShulkerBox => {
+                &[("facing", &["north", "east", "south", "west", "up", "down"])]
+            }
+            Block::WhiteGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::OrangeGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::MagentaGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::LightBlueGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::YellowGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::LimeGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::PinkGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::GrayGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::LightGrayGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::CyanGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::PurpleGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::BlueGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::BrownGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::GreenGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::RedGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::BlackGlazedTerracotta => &[("facing", &["north", "south", "west", "east"])],
+            Block::WhiteConcrete => &[],
+            Block::OrangeConcrete => &[],
+            Block::MagentaConcrete => &[],
+            Block::LightBlueConcrete => &[],
+            Block::YellowConcrete => &[],
+            Block::LimeConcrete => &[],
+            Block::PinkConcrete => &[],
+            Block::GrayConcrete => &[],
+            Block::LightGrayConcrete => &[],
+            Block::CyanConcrete => &[],
+            Block::PurpleConcrete => &[],
+            Block::BlueConcrete => &[],
+            Block::BrownConcrete => &[],
+            Block::GreenConcrete => &[],
+            Block::RedConcrete => &[],
+            Block::BlackConcrete => &[],
+            Block::WhiteConcretePowder => &[],
+            Block::OrangeConcretePowder => &[],
+            Block::MagentaConcretePowder => &[],
+            Block::LightBlueConcretePowder => &[],
+            Block::YellowConcretePowder => &[],
+            Block::LimeConcretePowder => &[],
+            Block::PinkConcretePowder => &[],
+            Block::GrayConcretePowder => &[],
+            Block::LightGrayConcretePowder => &[],
+            Block::CyanConcretePowder => &[],
+            Block::PurpleConcretePowder => &[],
+            Block::BlueConcretePowder => &[],
+            Block::BrownConcretePowder => &[],
+            Block::GreenConcretePowder => &[],
+            Block::RedConcretePowder => &[],
+            Block::BlackConcretePowder => &[],
+            Block::Kelp => &[(
+                "age",
+                &[
+                    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14",
+                    "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25",
+                ],
+            )],
+            Block::KelpPlant => &[],
+            Block::DriedKelpBlock => &[],
+            Block::TurtleEgg => &[("eggs", &["1", "2", "3", "4"]), ("hatch", &["0", "1", "2"])],
+            Block::DeadTubeCoralBlock => &[],
+            Block::DeadBrainCoralBlock => &[],
+            Block::DeadBubbleCoralBlock => &[],
+            Block::DeadFireCoralBlock => &[],
+            Block::DeadHornCoralBlock => &[],
+            Block::TubeCoralBlock => &[],
+            Block::BrainCoralBlock => &[],
+            Block::BubbleCoralBlock => &[],
+            Block::FireCoralBlock => &[],
+            Block::HornCoralBlock => &[],
+            Block::DeadTubeCoral => &[("waterlogged", &["true", "false"])],
+            Block::DeadBrainCoral => &[("waterlogged", &["true", "false"])],
+            Block::DeadBubbleCoral => &[("waterlogged", &["true", "false"])],
+            Block::DeadFireCoral => &[("waterlogged", &["true", "false"])],
+            Block::DeadHornCoral => &[("waterlogged", &["true", "false"])],
+            Block::TubeCoral => &[("waterlogged", &["true", "false"])],
+            Block::BrainCoral => &[("waterlogged", &["true", "false"])],
+            Block::BubbleCoral => &[("waterlogged", &["true", "false"])],
+            Block::FireCoral => &[("waterlogged", &["true", "false"])],
+            Block::HornCoral => &[("waterlogged", &["true", "false"])],
+            Block::DeadTubeCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::DeadBrainCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::DeadBubbleCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::DeadFireCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::DeadHornCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::TubeCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::BrainCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::BubbleCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::FireCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::HornCoralFan => &[("waterlogged", &["true", "false"])],
+            Block::DeadTubeCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DeadBrainCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DeadBubbleCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DeadFireCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DeadHornCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::TubeCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BrainCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BubbleCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::FireCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::HornCoralWallFan => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SeaPickle => &[
+                ("pickles", &["1", "2", "3", "4"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BlueIce => &[],
+            Block::Conduit => &[("waterlogged", &["true", "false"])],
+            Block::BambooSapling => &[],
+            Block::Bamboo => &[
+                ("age", &["0", "1"]),
+                ("leaves", &["none", "small", "large"]),
+                ("stage", &["0", "1"]),
+            ],
+            Block::PottedBamboo => &[],
+            Block::VoidAir => &[],
+            Block::CaveAir => &[],
+            Block::BubbleColumn => &[("drag", &["true", "false"])],
+            Block::PolishedGraniteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothRedSandstoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::MossyStoneBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PolishedDioriteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::MossyCobblestoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::EndStoneBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::StoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothSandstoneStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothQuartzStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::GraniteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AndesiteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::RedNetherBrickStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PolishedAndesiteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DioriteStairs => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("half", &["top", "bottom"]),
+                (
+                    "shape",
+                    &[
+                        "straight",
+                        "inner_left",
+                        "inner_right",
+                        "outer_left",
+                        "outer_right",
+                    ],
+                ),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PolishedGraniteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothRedSandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::MossyStoneBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PolishedDioriteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::MossyCobblestoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::EndStoneBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothSandstoneSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SmoothQuartzSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::GraniteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::AndesiteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::RedNetherBrickSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::PolishedAndesiteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::DioriteSlab => &[
+                ("type", &["top", "bottom", "double"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::BrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::PrismarineWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::RedSandstoneWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::MossyStoneBrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::GraniteWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::StoneBrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::NetherBrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::AndesiteWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::RedNetherBrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::SandstoneWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::EndStoneBrickWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::DioriteWall => &[
+                ("east", &["true", "false"]),
+                ("north", &["true", "false"]),
+                ("south", &["true", "false"]),
+                ("up", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+                ("west", &["true", "false"]),
+            ],
+            Block::Scaffolding => &[
+                ("bottom", &["true", "false"]),
+                ("distance", &["0", "1", "2", "3", "4", "5", "6", "7"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::Loom => &[("facing", &["north", "south", "west", "east"])],
+            Block::Barrel => &[
+                ("facing", &["north", "east", "south", "west", "up", "down"]),
+                ("open", &["true", "false"]),
+            ],
+            Block::Smoker => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("lit", &["true", "false"]),
+            ],
+            Block::BlastFurnace => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("lit", &["true", "false"]),
+            ],
+            Block::CartographyTable => &[],
+            Block::FletchingTable => &[],
+            Block::Grindstone => &[
+                ("face", &["floor", "wall", "ceiling"]),
+                ("facing", &["north", "south", "west", "east"]),
+            ],
+            Block::Lectern => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("has_book", &["true", "false"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::SmithingTable => &[],
+            Block::Stonecutter => &[("facing", &["north", "south", "west", "east"])],
+            Block::Bell => &[
+                (
+                    "attachment",
+                    &["floor", "ceiling", "single_wall", "double_wall"],
+                ),
+                ("facing", &["north", "south", "west", "east"]),
+                ("powered", &["true", "false"]),
+            ],
+            Block::Lantern => &[("hanging", &["true", "false"])],
+            Block::Campfire => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("lit", &["true", "false"]),
+                ("signal_fire", &["true", "false"]),
+                ("waterlogged", &["true", "false"]),
+            ],
+            Block::SweetBerryBush => &[("age", &["0", "1", "2", "3"])],
+            Block::StructureBlock => &[("mode", &["save", "load", "corner", "data"])],
+            Block::Jigsaw => &[("facing", &["north", "east", "south", "west", "up", "down"])],
+            Block::Composter => &[("level", &["0", "1", "2", "3", "4", "5", "6", "7", "8"])],
+            Block::BeeNest => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("honey_level", &["0", "1", "2", "3", "4", "5"]),
+            ],
+            Block::Beehive => &[
+                ("facing", &["north", "south", "west", "east"]),
+                ("honey_level", &["0", "1", "2", "3", "4", "5"]),
+            ],
+            Block::HoneyBlock => &[],
+            Block::HoneycombBlock => &[],
+        }
+    }
+    pub fn state_properties(state_id: u32) -> Option<Vec<(&'static str, &'static str)>> {
+        let block = Self::from_state_id(state_id)?;
+        let mut offset = (state_id - block.min_state_id()) as usize;
+        let mut properties = vec![];
+        for (name, values) in block.properties().iter().rev() {
+            properties.push((*name, values[offset % values.len()]));
+            offset /= values.len();
+        }
+        properties.reverse();
+        Some(properties)
+    }
+    pub fn state_from_properties(&self, properties: &[(&str, &str)]) -> u32 {
+        let defaults = Self::state_properties(self.default_state()).unwrap_or_default();
+        let mut offset = 0;
+        for (index, (name, values)) in self.properties().iter().enumerate() {
+            let value = properties
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| *v)
+                .filter(|v| values.contains(v))
+                .unwrap_or(defaults[index].1);
+            let value_index = values.iter().position(|v| *v == value).unwrap_or(0);
+            offset = offset * values.len() + value_index;
+        }
+        self.min_state_id() + offset as u32
+    }
 }
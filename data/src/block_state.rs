@@ -0,0 +1,803 @@
+use crate::block::Block;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockState(u32);
+impl BlockState {
+    pub fn new(block: Block) -> Self {
+        Self(block.default_state())
+    }
+    pub fn from_id(id: u32) -> Option<Self> {
+        Block::from_state_id(id).map(|_| Self(id))
+    }
+    pub fn id(&self) -> u32 {
+        self.0
+    }
+    pub fn block(&self) -> Block {
+        Block::from_state_id(self.0).unwrap()
+    }
+    pub fn property(&self, name: &str) -> Option<&'static str> {
+        Block::state_properties(self.0)?
+            .into_iter()
+            .find(|(property, _)| *property == name)
+            .map(|(_, value)| value)
+    }
+    pub fn set_property(&mut self, name: &str, value: &str) -> bool {
+        let block = self.block();
+        let value = block
+            .properties()
+            .iter()
+            .find(|(property, _)| *property == name)
+            .and_then(|(_, values)| values.iter().find(|v| **v == value));
+        let value = match value {
+            Some(value) => *value,
+            None => return false,
+        };
+        let mut properties = Block::state_properties(self.0).unwrap_or_default();
+        for property in properties.iter_mut() {
+            if property.0 == name {
+                property.1 = value;
+            }
+        }
+        self.0 = block.state_from_properties(&properties);
+        true
+    }
+    pub fn age(&self) -> Option<u8> {
+        self.property("age").and_then(|value| value.parse().ok())
+    }
+    pub fn set_age(&mut self, value: u8) -> bool {
+        self.set_property("age", &value.to_string())
+    }
+    pub fn attached(&self) -> Option<bool> {
+        self.property("attached").map(|value| value == "true")
+    }
+    pub fn set_attached(&mut self, value: bool) -> bool {
+        self.set_property("attached", if value { "true" } else { "false" })
+    }
+    pub fn attachment(&self) -> Option<Attachment> {
+        self.property("attachment").and_then(Attachment::from_name)
+    }
+    pub fn set_attachment(&mut self, value: Attachment) -> bool {
+        self.set_property("attachment", value.name())
+    }
+    pub fn axis(&self) -> Option<Axis> {
+        self.property("axis").and_then(Axis::from_name)
+    }
+    pub fn set_axis(&mut self, value: Axis) -> bool {
+        self.set_property("axis", value.name())
+    }
+    pub fn bites(&self) -> Option<u8> {
+        self.property("bites").and_then(|value| value.parse().ok())
+    }
+    pub fn set_bites(&mut self, value: u8) -> bool {
+        self.set_property("bites", &value.to_string())
+    }
+    pub fn bottom(&self) -> Option<bool> {
+        self.property("bottom").map(|value| value == "true")
+    }
+    pub fn set_bottom(&mut self, value: bool) -> bool {
+        self.set_property("bottom", if value { "true" } else { "false" })
+    }
+    pub fn conditional(&self) -> Option<bool> {
+        self.property("conditional").map(|value| value == "true")
+    }
+    pub fn set_conditional(&mut self, value: bool) -> bool {
+        self.set_property("conditional", if value { "true" } else { "false" })
+    }
+    pub fn delay(&self) -> Option<u8> {
+        self.property("delay").and_then(|value| value.parse().ok())
+    }
+    pub fn set_delay(&mut self, value: u8) -> bool {
+        self.set_property("delay", &value.to_string())
+    }
+    pub fn disarmed(&self) -> Option<bool> {
+        self.property("disarmed").map(|value| value == "true")
+    }
+    pub fn set_disarmed(&mut self, value: bool) -> bool {
+        self.set_property("disarmed", if value { "true" } else { "false" })
+    }
+    pub fn distance(&self) -> Option<u8> {
+        self.property("distance")
+            .and_then(|value| value.parse().ok())
+    }
+    pub fn set_distance(&mut self, value: u8) -> bool {
+        self.set_property("distance", &value.to_string())
+    }
+    pub fn down(&self) -> Option<bool> {
+        self.property("down").map(|value| value == "true")
+    }
+    pub fn set_down(&mut self, value: bool) -> bool {
+        self.set_property("down", if value { "true" } else { "false" })
+    }
+    pub fn drag(&self) -> Option<bool> {
+        self.property("drag").map(|value| value == "true")
+    }
+    pub fn set_drag(&mut self, value: bool) -> bool {
+        self.set_property("drag", if value { "true" } else { "false" })
+    }
+    pub fn east(&self) -> Option<bool> {
+        self.property("east").map(|value| value == "true")
+    }
+    pub fn set_east(&mut self, value: bool) -> bool {
+        self.set_property("east", if value { "true" } else { "false" })
+    }
+    pub fn eggs(&self) -> Option<u8> {
+        self.property("eggs").and_then(|value| value.parse().ok())
+    }
+    pub fn set_eggs(&mut self, value: u8) -> bool {
+        self.set_property("eggs", &value.to_string())
+    }
+    pub fn enabled(&self) -> Option<bool> {
+        self.property("enabled").map(|value| value == "true")
+    }
+    pub fn set_enabled(&mut self, value: bool) -> bool {
+        self.set_property("enabled", if value { "true" } else { "false" })
+    }
+    pub fn extended(&self) -> Option<bool> {
+        self.property("extended").map(|value| value == "true")
+    }
+    pub fn set_extended(&mut self, value: bool) -> bool {
+        self.set_property("extended", if value { "true" } else { "false" })
+    }
+    pub fn eye(&self) -> Option<bool> {
+        self.property("eye").map(|value| value == "true")
+    }
+    pub fn set_eye(&mut self, value: bool) -> bool {
+        self.set_property("eye", if value { "true" } else { "false" })
+    }
+    pub fn face(&self) -> Option<Face> {
+        self.property("face").and_then(Face::from_name)
+    }
+    pub fn set_face(&mut self, value: Face) -> bool {
+        self.set_property("face", value.name())
+    }
+    pub fn facing(&self) -> Option<Facing> {
+        self.property("facing").and_then(Facing::from_name)
+    }
+    pub fn set_facing(&mut self, value: Facing) -> bool {
+        self.set_property("facing", value.name())
+    }
+    pub fn half(&self) -> Option<Half> {
+        self.property("half").and_then(Half::from_name)
+    }
+    pub fn set_half(&mut self, value: Half) -> bool {
+        self.set_property("half", value.name())
+    }
+    pub fn hanging(&self) -> Option<bool> {
+        self.property("hanging").map(|value| value == "true")
+    }
+    pub fn set_hanging(&mut self, value: bool) -> bool {
+        self.set_property("hanging", if value { "true" } else { "false" })
+    }
+    pub fn has_book(&self) -> Option<bool> {
+        self.property("has_book").map(|value| value == "true")
+    }
+    pub fn set_has_book(&mut self, value: bool) -> bool {
+        self.set_property("has_book", if value { "true" } else { "false" })
+    }
+    pub fn has_bottle_0(&self) -> Option<bool> {
+        self.property("has_bottle_0").map(|value| value == "true")
+    }
+    pub fn set_has_bottle_0(&mut self, value: bool) -> bool {
+        self.set_property("has_bottle_0", if value { "true" } else { "false" })
+    }
+    pub fn has_bottle_1(&self) -> Option<bool> {
+        self.property("has_bottle_1").map(|value| value == "true")
+    }
+    pub fn set_has_bottle_1(&mut self, value: bool) -> bool {
+        self.set_property("has_bottle_1", if value { "true" } else { "false" })
+    }
+    pub fn has_bottle_2(&self) -> Option<bool> {
+        self.property("has_bottle_2").map(|value| value == "true")
+    }
+    pub fn set_has_bottle_2(&mut self, value: bool) -> bool {
+        self.set_property("has_bottle_2", if value { "true" } else { "false" })
+    }
+    pub fn has_record(&self) -> Option<bool> {
+        self.property("has_record").map(|value| value == "true")
+    }
+    pub fn set_has_record(&mut self, value: bool) -> bool {
+        self.set_property("has_record", if value { "true" } else { "false" })
+    }
+    pub fn hatch(&self) -> Option<u8> {
+        self.property("hatch").and_then(|value| value.parse().ok())
+    }
+    pub fn set_hatch(&mut self, value: u8) -> bool {
+        self.set_property("hatch", &value.to_string())
+    }
+    pub fn hinge(&self) -> Option<Hinge> {
+        self.property("hinge").and_then(Hinge::from_name)
+    }
+    pub fn set_hinge(&mut self, value: Hinge) -> bool {
+        self.set_property("hinge", value.name())
+    }
+    pub fn honey_level(&self) -> Option<u8> {
+        self.property("honey_level")
+            .and_then(|value| value.parse().ok())
+    }
+    pub fn set_honey_level(&mut self, value: u8) -> bool {
+        self.set_property("honey_level", &value.to_string())
+    }
+    pub fn in_wall(&self) -> Option<bool> {
+        self.property("in_wall").map(|value| value == "true")
+    }
+    pub fn set_in_wall(&mut self, value: bool) -> bool {
+        self.set_property("in_wall", if value { "true" } else { "false" })
+    }
+    pub fn instrument(&self) -> Option<Instrument> {
+        self.property("instrument").and_then(Instrument::from_name)
+    }
+    pub fn set_instrument(&mut self, value: Instrument) -> bool {
+        self.set_property("instrument", value.name())
+    }
+    pub fn inverted(&self) -> Option<bool> {
+        self.property("inverted").map(|value| value == "true")
+    }
+    pub fn set_inverted(&mut self, value: bool) -> bool {
+        self.set_property("inverted", if value { "true" } else { "false" })
+    }
+    pub fn layers(&self) -> Option<u8> {
+        self.property("layers").and_then(|value| value.parse().ok())
+    }
+    pub fn set_layers(&mut self, value: u8) -> bool {
+        self.set_property("layers", &value.to_string())
+    }
+    pub fn leaves(&self) -> Option<Leaves> {
+        self.property("leaves").and_then(Leaves::from_name)
+    }
+    pub fn set_leaves(&mut self, value: Leaves) -> bool {
+        self.set_property("leaves", value.name())
+    }
+    pub fn level(&self) -> Option<u8> {
+        self.property("level").and_then(|value| value.parse().ok())
+    }
+    pub fn set_level(&mut self, value: u8) -> bool {
+        self.set_property("level", &value.to_string())
+    }
+    pub fn lit(&self) -> Option<bool> {
+        self.property("lit").map(|value| value == "true")
+    }
+    pub fn set_lit(&mut self, value: bool) -> bool {
+        self.set_property("lit", if value { "true" } else { "false" })
+    }
+    pub fn locked(&self) -> Option<bool> {
+        self.property("locked").map(|value| value == "true")
+    }
+    pub fn set_locked(&mut self, value: bool) -> bool {
+        self.set_property("locked", if value { "true" } else { "false" })
+    }
+    pub fn mode(&self) -> Option<Mode> {
+        self.property("mode").and_then(Mode::from_name)
+    }
+    pub fn set_mode(&mut self, value: Mode) -> bool {
+        self.set_property("mode", value.name())
+    }
+    pub fn moisture(&self) -> Option<u8> {
+        self.property("moisture")
+            .and_then(|value| value.parse().ok())
+    }
+    pub fn set_moisture(&mut self, value: u8) -> bool {
+        self.set_property("moisture", &value.to_string())
+    }
+    pub fn north(&self) -> Option<bool> {
+        self.property("north").map(|value| value == "true")
+    }
+    pub fn set_north(&mut self, value: bool) -> bool {
+        self.set_property("north", if value { "true" } else { "false" })
+    }
+    pub fn note(&self) -> Option<u8> {
+        self.property("note").and_then(|value| value.parse().ok())
+    }
+    pub fn set_note(&mut self, value: u8) -> bool {
+        self.set_property("note", &value.to_string())
+    }
+    pub fn occupied(&self) -> Option<bool> {
+        self.property("occupied").map(|value| value == "true")
+    }
+    pub fn set_occupied(&mut self, value: bool) -> bool {
+        self.set_property("occupied", if value { "true" } else { "false" })
+    }
+    pub fn open(&self) -> Option<bool> {
+        self.property("open").map(|value| value == "true")
+    }
+    pub fn set_open(&mut self, value: bool) -> bool {
+        self.set_property("open", if value { "true" } else { "false" })
+    }
+    pub fn part(&self) -> Option<Part> {
+        self.property("part").and_then(Part::from_name)
+    }
+    pub fn set_part(&mut self, value: Part) -> bool {
+        self.set_property("part", value.name())
+    }
+    pub fn persistent(&self) -> Option<bool> {
+        self.property("persistent").map(|value| value == "true")
+    }
+    pub fn set_persistent(&mut self, value: bool) -> bool {
+        self.set_property("persistent", if value { "true" } else { "false" })
+    }
+    pub fn pickles(&self) -> Option<u8> {
+        self.property("pickles")
+            .and_then(|value| value.parse().ok())
+    }
+    pub fn set_pickles(&mut self, value: u8) -> bool {
+        self.set_property("pickles", &value.to_string())
+    }
+    pub fn power(&self) -> Option<u8> {
+        self.property("power").and_then(|value| value.parse().ok())
+    }
+    pub fn set_power(&mut self, value: u8) -> bool {
+        self.set_property("power", &value.to_string())
+    }
+    pub fn powered(&self) -> Option<bool> {
+        self.property("powered").map(|value| value == "true")
+    }
+    pub fn set_powered(&mut self, value: bool) -> bool {
+        self.set_property("powered", if value { "true" } else { "false" })
+    }
+    pub fn rotation(&self) -> Option<u8> {
+        self.property("rotation")
+            .and_then(|value| value.parse().ok())
+    }
+    pub fn set_rotation(&mut self, value: u8) -> bool {
+        self.set_property("rotation", &value.to_string())
+    }
+    pub fn shape(&self) -> Option<Shape> {
+        self.property("shape").and_then(Shape::from_name)
+    }
+    pub fn set_shape(&mut self, value: Shape) -> bool {
+        self.set_property("shape", value.name())
+    }
+    pub fn short(&self) -> Option<bool> {
+        self.property("short").map(|value| value == "true")
+    }
+    pub fn set_short(&mut self, value: bool) -> bool {
+        self.set_property("short", if value { "true" } else { "false" })
+    }
+    pub fn signal_fire(&self) -> Option<bool> {
+        self.property("signal_fire").map(|value| value == "true")
+    }
+    pub fn set_signal_fire(&mut self, value: bool) -> bool {
+        self.set_property("signal_fire", if value { "true" } else { "false" })
+    }
+    pub fn snowy(&self) -> Option<bool> {
+        self.property("snowy").map(|value| value == "true")
+    }
+    pub fn set_snowy(&mut self, value: bool) -> bool {
+        self.set_property("snowy", if value { "true" } else { "false" })
+    }
+    pub fn south(&self) -> Option<bool> {
+        self.property("south").map(|value| value == "true")
+    }
+    pub fn set_south(&mut self, value: bool) -> bool {
+        self.set_property("south", if value { "true" } else { "false" })
+    }
+    pub fn stage(&self) -> Option<u8> {
+        self.property("stage").and_then(|value| value.parse().ok())
+    }
+    pub fn set_stage(&mut self, value: u8) -> bool {
+        self.set_property("stage", &value.to_string())
+    }
+    pub fn triggered(&self) -> Option<bool> {
+        self.property("triggered").map(|value| value == "true")
+    }
+    pub fn set_triggered(&mut self, value: bool) -> bool {
+        self.set_property("triggered", if value { "true" } else { "false" })
+    }
+    pub fn kind(&self) -> Option<Kind> {
+        self.property("type").and_then(Kind::from_name)
+    }
+    pub fn set_kind(&mut self, value: Kind) -> bool {
+        self.set_property("type", value.name())
+    }
+    pub fn unstable(&self) -> Option<bool> {
+        self.property("unstable").map(|value| value == "true")
+    }
+    pub fn set_unstable(&mut self, value: bool) -> bool {
+        self.set_property("unstable", if value { "true" } else { "false" })
+    }
+    pub fn up(&self) -> Option<bool> {
+        self.property("up").map(|value| value == "true")
+    }
+    pub fn set_up(&mut self, value: bool) -> bool {
+        self.set_property("up", if value { "true" } else { "false" })
+    }
+    pub fn waterlogged(&self) -> Option<bool> {
+        self.property("waterlogged").map(|value| value == "true")
+    }
+    pub fn set_waterlogged(&mut self, value: bool) -> bool {
+        self.set_property("waterlogged", if value { "true" } else { "false" })
+    }
+    pub fn west(&self) -> Option<bool> {
+        self.property("west").map(|value| value == "true")
+    }
+    pub fn set_west(&mut self, value: bool) -> bool {
+        self.set_property("west", if value { "true" } else { "false" })
+    }
+}
+impl From<Block> for BlockState {
+    fn from(block: Block) -> Self {
+        Self::new(block)
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Attachment {
+    Floor,
+    Ceiling,
+    SingleWall,
+    DoubleWall,
+}
+impl Attachment {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Attachment::Floor => "floor",
+            Attachment::Ceiling => "ceiling",
+            Attachment::SingleWall => "single_wall",
+            Attachment::DoubleWall => "double_wall",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "floor" => Some(Attachment::Floor),
+            "ceiling" => Some(Attachment::Ceiling),
+            "single_wall" => Some(Attachment::SingleWall),
+            "double_wall" => Some(Attachment::DoubleWall),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+impl Axis {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Axis::X => "x",
+            Axis::Y => "y",
+            Axis::Z => "z",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "x" => Some(Axis::X),
+            "y" => Some(Axis::Y),
+            "z" => Some(Axis::Z),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Face {
+    Floor,
+    Wall,
+    Ceiling,
+}
+impl Face {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Face::Floor => "floor",
+            Face::Wall => "wall",
+            Face::Ceiling => "ceiling",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "floor" => Some(Face::Floor),
+            "wall" => Some(Face::Wall),
+            "ceiling" => Some(Face::Ceiling),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Facing {
+    North,
+    East,
+    South,
+    West,
+    Up,
+    Down,
+}
+impl Facing {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Facing::North => "north",
+            Facing::East => "east",
+            Facing::South => "south",
+            Facing::West => "west",
+            Facing::Up => "up",
+            Facing::Down => "down",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "north" => Some(Facing::North),
+            "east" => Some(Facing::East),
+            "south" => Some(Facing::South),
+            "west" => Some(Facing::West),
+            "up" => Some(Facing::Up),
+            "down" => Some(Facing::Down),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Half {
+    Upper,
+    Lower,
+    Top,
+    Bottom,
+}
+impl Half {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Half::Upper => "upper",
+            Half::Lower => "lower",
+            Half::Top => "top",
+            Half::Bottom => "bottom",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "upper" => Some(Half::Upper),
+            "lower" => Some(Half::Lower),
+            "top" => Some(Half::Top),
+            "bottom" => Some(Half::Bottom),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hinge {
+    Left,
+    Right,
+}
+impl Hinge {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Hinge::Left => "left",
+            Hinge::Right => "right",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "left" => Some(Hinge::Left),
+            "right" => Some(Hinge::Right),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instrument {
+    Harp,
+    Basedrum,
+    Snare,
+    Hat,
+    Bass,
+    Flute,
+    Bell,
+    Guitar,
+    Chime,
+    Xylophone,
+    IronXylophone,
+    CowBell,
+    Didgeridoo,
+    Bit,
+    Banjo,
+    Pling,
+}
+impl Instrument {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instrument::Harp => "harp",
+            Instrument::Basedrum => "basedrum",
+            Instrument::Snare => "snare",
+            Instrument::Hat => "hat",
+            Instrument::Bass => "bass",
+            Instrument::Flute => "flute",
+            Instrument::Bell => "bell",
+            Instrument::Guitar => "guitar",
+            Instrument::Chime => "chime",
+            Instrument::Xylophone => "xylophone",
+            Instrument::IronXylophone => "iron_xylophone",
+            Instrument::CowBell => "cow_bell",
+            Instrument::Didgeridoo => "didgeridoo",
+            Instrument::Bit => "bit",
+            Instrument::Banjo => "banjo",
+            Instrument::Pling => "pling",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "harp" => Some(Instrument::Harp),
+            "basedrum" => Some(Instrument::Basedrum),
+            "snare" => Some(Instrument::Snare),
+            "hat" => Some(Instrument::Hat),
+            "bass" => Some(Instrument::Bass),
+            "flute" => Some(Instrument::Flute),
+            "bell" => Some(Instrument::Bell),
+            "guitar" => Some(Instrument::Guitar),
+            "chime" => Some(Instrument::Chime),
+            "xylophone" => Some(Instrument::Xylophone),
+            "iron_xylophone" => Some(Instrument::IronXylophone),
+            "cow_bell" => Some(Instrument::CowBell),
+            "didgeridoo" => Some(Instrument::Didgeridoo),
+            "bit" => Some(Instrument::Bit),
+            "banjo" => Some(Instrument::Banjo),
+            "pling" => Some(Instrument::Pling),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Leaves {
+    None,
+    Small,
+    Large,
+}
+impl Leaves {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Leaves::None => "none",
+            Leaves::Small => "small",
+            Leaves::Large => "large",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Leaves::None),
+            "small" => Some(Leaves::Small),
+            "large" => Some(Leaves::Large),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Compare,
+    Subtract,
+    Save,
+    Load,
+    Corner,
+    Data,
+}
+impl Mode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Compare => "compare",
+            Mode::Subtract => "subtract",
+            Mode::Save => "save",
+            Mode::Load => "load",
+            Mode::Corner => "corner",
+            Mode::Data => "data",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "compare" => Some(Mode::Compare),
+            "subtract" => Some(Mode::Subtract),
+            "save" => Some(Mode::Save),
+            "load" => Some(Mode::Load),
+            "corner" => Some(Mode::Corner),
+            "data" => Some(Mode::Data),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    Head,
+    Foot,
+}
+impl Part {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Part::Head => "head",
+            Part::Foot => "foot",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "head" => Some(Part::Head),
+            "foot" => Some(Part::Foot),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    NorthSouth,
+    EastWest,
+    AscendingEast,
+    AscendingWest,
+    AscendingNorth,
+    AscendingSouth,
+    Straight,
+    InnerLeft,
+    InnerRight,
+    OuterLeft,
+    OuterRight,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+    NorthEast,
+}
+impl Shape {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Shape::NorthSouth => "north_south",
+            Shape::EastWest => "east_west",
+            Shape::AscendingEast => "ascending_east",
+            Shape::AscendingWest => "ascending_west",
+            Shape::AscendingNorth => "ascending_north",
+            Shape::AscendingSouth => "ascending_south",
+            Shape::Straight => "straight",
+            Shape::InnerLeft => "inner_left",
+            Shape::InnerRight => "inner_right",
+            Shape::OuterLeft => "outer_left",
+            Shape::OuterRight => "outer_right",
+            Shape::SouthEast => "south_east",
+            Shape::SouthWest => "south_west",
+            Shape::NorthWest => "north_west",
+            Shape::NorthEast => "north_east",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "north_south" => Some(Shape::NorthSouth),
+            "east_west" => Some(Shape::EastWest),
+            "ascending_east" => Some(Shape::AscendingEast),
+            "ascending_west" => Some(Shape::AscendingWest),
+            "ascending_north" => Some(Shape::AscendingNorth),
+            "ascending_south" => Some(Shape::AscendingSouth),
+            "straight" => Some(Shape::Straight),
+            "inner_left" => Some(Shape::InnerLeft),
+            "inner_right" => Some(Shape::InnerRight),
+            "outer_left" => Some(Shape::OuterLeft),
+            "outer_right" => Some(Shape::OuterRight),
+            "south_east" => Some(Shape::SouthEast),
+            "south_west" => Some(Shape::SouthWest),
+            "north_west" => Some(Shape::NorthWest),
+            "north_east" => Some(Shape::NorthEast),
+            _ => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Normal,
+    Sticky,
+    Single,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Double,
+}
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Normal => "normal",
+            Kind::Sticky => "sticky",
+            Kind::Single => "single",
+            Kind::Left => "left",
+            Kind::Right => "right",
+            Kind::Top => "top",
+            Kind::Bottom => "bottom",
+            Kind::Double => "double",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "normal" => Some(Kind::Normal),
+            "sticky" => Some(Kind::Sticky),
+            "single" => Some(Kind::Single),
+            "left" => Some(Kind::Left),
+            "right" => Some(Kind::Right),
+            "top" => Some(Kind::Top),
+            "bottom" => Some(Kind::Bottom),
+            "double" => Some(Kind::Double),
+            _ => None,
+        }
+    }
+}
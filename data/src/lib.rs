@@ -1,11 +1,60 @@
 pub mod block;
+pub mod block_state;
 pub mod item;
+pub mod recipe;
 
 #[cfg(test)]
 mod tests {
+    use crate::{block::Block, block_state::BlockState};
+
+    // 1.15.2 has this many block states
+    const STATE_COUNT: u32 = 11337;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn every_state_id_round_trips() {
+        for id in 0..STATE_COUNT {
+            let state = BlockState::from_id(id).unwrap();
+            assert_eq!(state.id(), id);
+            let block = state.block();
+            assert!((block.min_state_id()..=block.max_state_id()).contains(&id));
+            let properties = Block::state_properties(id).unwrap_or_default();
+            assert_eq!(block.state_from_properties(&properties), id, "{:?}", block);
+        }
+        assert_eq!(BlockState::from_id(STATE_COUNT), None);
+    }
+
+    #[test]
+    fn known_state_ids() {
+        assert_eq!(BlockState::new(Block::Air).id(), 0);
+        assert_eq!(BlockState::new(Block::Stone).id(), 1);
+        assert_eq!(BlockState::new(Block::GrassBlock).id(), 9);
+        assert_eq!(BlockState::new(Block::Water).id(), 34);
+
+        let mut grass = BlockState::new(Block::GrassBlock);
+        assert!(grass.set_property("snowy", "true"));
+        assert_eq!(grass.id(), 8);
+        assert!(!grass.set_property("snowy", "maybe"));
+        assert!(!grass.set_property("facing", "north"));
+        assert_eq!(grass.id(), 8);
+    }
+
+    #[test]
+    fn setting_a_property_keeps_the_others() {
+        let mut stairs = BlockState::new(Block::OakStairs);
+        assert!(stairs.set_property("facing", "east"));
+        assert!(stairs.set_property("half", "top"));
+        assert!(stairs.set_property("waterlogged", "true"));
+        assert_eq!(stairs.block(), Block::OakStairs);
+        assert_eq!(stairs.property("facing"), Some("east"));
+        assert_eq!(stairs.property("half"), Some("top"));
+        assert_eq!(stairs.property("shape"), Some("straight"));
+        assert_eq!(stairs.waterlogged(), Some(true));
+        assert_eq!(BlockState::from_id(stairs.id()), Some(stairs));
+    }
 }
@@ -0,0 +1,10122 @@
+use crate::item::Item;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecipeKind {
+    Shaped { width: u8, height: u8 },
+    Shapeless,
+    Smelting { experience: f32, cooking_time: u32 },
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Recipe {
+    pub name: &'static str,
+    pub kind: RecipeKind,
+    pub ingredients: &'static [&'static [Item]],
+    pub result: Item,
+    pub count: u8,
+}
+
+pub const RECIPES: &[Recipe] = &[
+    Recipe {
+        name: "minecraft:granite",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Diorite], &[Item::Quartz]],
+        result: Item::Granite,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:polished_granite",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+        ],
+        result: Item::PolishedGranite,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:diorite",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Quartz],
+            &[Item::Quartz],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Diorite,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:polished_diorite",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+        ],
+        result: Item::PolishedDiorite,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:andesite",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Diorite], &[Item::Cobblestone]],
+        result: Item::Andesite,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:polished_andesite",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+        ],
+        result: Item::PolishedAndesite,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:coarse_dirt",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Dirt],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Dirt],
+        ],
+        result: Item::CoarseDirt,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:oak_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::OakLog,
+            Item::OakWood,
+            Item::StrippedOakLog,
+            Item::StrippedOakWood,
+        ]],
+        result: Item::OakPlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:spruce_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::SpruceLog,
+            Item::SpruceWood,
+            Item::StrippedSpruceLog,
+            Item::StrippedSpruceWood,
+        ]],
+        result: Item::SprucePlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:birch_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::BirchLog,
+            Item::BirchWood,
+            Item::StrippedBirchLog,
+            Item::StrippedBirchWood,
+        ]],
+        result: Item::BirchPlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:jungle_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::JungleLog,
+            Item::JungleWood,
+            Item::StrippedJungleLog,
+            Item::StrippedJungleWood,
+        ]],
+        result: Item::JunglePlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:acacia_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::AcaciaLog,
+            Item::AcaciaWood,
+            Item::StrippedAcaciaLog,
+            Item::StrippedAcaciaWood,
+        ]],
+        result: Item::AcaciaPlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_planks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[
+            Item::DarkOakLog,
+            Item::DarkOakWood,
+            Item::StrippedDarkOakLog,
+            Item::StrippedDarkOakWood,
+        ]],
+        result: Item::DarkOakPlanks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:stripped_oak_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedOakLog],
+            &[Item::StrippedOakLog],
+            &[Item::StrippedOakLog],
+            &[Item::StrippedOakLog],
+        ],
+        result: Item::StrippedOakWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:stripped_spruce_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedSpruceLog],
+            &[Item::StrippedSpruceLog],
+            &[Item::StrippedSpruceLog],
+            &[Item::StrippedSpruceLog],
+        ],
+        result: Item::StrippedSpruceWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:stripped_birch_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedBirchLog],
+            &[Item::StrippedBirchLog],
+            &[Item::StrippedBirchLog],
+            &[Item::StrippedBirchLog],
+        ],
+        result: Item::StrippedBirchWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:stripped_jungle_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedJungleLog],
+            &[Item::StrippedJungleLog],
+            &[Item::StrippedJungleLog],
+            &[Item::StrippedJungleLog],
+        ],
+        result: Item::StrippedJungleWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:stripped_acacia_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedAcaciaLog],
+            &[Item::StrippedAcaciaLog],
+            &[Item::StrippedAcaciaLog],
+            &[Item::StrippedAcaciaLog],
+        ],
+        result: Item::StrippedAcaciaWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:stripped_dark_oak_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StrippedDarkOakLog],
+            &[Item::StrippedDarkOakLog],
+            &[Item::StrippedDarkOakLog],
+            &[Item::StrippedDarkOakLog],
+        ],
+        result: Item::StrippedDarkOakWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:oak_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OakLog],
+            &[Item::OakLog],
+            &[Item::OakLog],
+            &[Item::OakLog],
+        ],
+        result: Item::OakWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:spruce_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::SpruceLog],
+            &[Item::SpruceLog],
+            &[Item::SpruceLog],
+            &[Item::SpruceLog],
+        ],
+        result: Item::SpruceWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:birch_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BirchLog],
+            &[Item::BirchLog],
+            &[Item::BirchLog],
+            &[Item::BirchLog],
+        ],
+        result: Item::BirchWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:jungle_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::JungleLog],
+            &[Item::JungleLog],
+            &[Item::JungleLog],
+            &[Item::JungleLog],
+        ],
+        result: Item::JungleWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:acacia_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::AcaciaLog],
+            &[Item::AcaciaLog],
+            &[Item::AcaciaLog],
+            &[Item::AcaciaLog],
+        ],
+        result: Item::AcaciaWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_wood",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::DarkOakLog],
+            &[Item::DarkOakLog],
+            &[Item::DarkOakLog],
+            &[Item::DarkOakLog],
+        ],
+        result: Item::DarkOakWood,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:lapis_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+            &[Item::LapisLazuli],
+        ],
+        result: Item::LapisBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dispenser",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Bow],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Redstone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Dispenser,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:sandstone",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[&[Item::Sand], &[Item::Sand], &[Item::Sand], &[Item::Sand]],
+        result: Item::Sandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:chiseled_sandstone",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::SandstoneSlab], &[Item::SandstoneSlab]],
+        result: Item::ChiseledSandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cut_sandstone",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+        ],
+        result: Item::CutSandstone,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:note_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Redstone],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::NoteBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:powered_rail",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::Stick],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::Redstone],
+            &[Item::GoldIngot],
+        ],
+        result: Item::PoweredRail,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:detector_rail",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::StonePressurePlate],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Redstone],
+            &[Item::IronIngot],
+        ],
+        result: Item::DetectorRail,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:sticky_piston",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::SlimeBall], &[Item::Piston]],
+        result: Item::StickyPiston,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:piston",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Cobblestone],
+            &[Item::IronIngot],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Redstone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Piston,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_wool",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::String],
+            &[Item::String],
+            &[Item::String],
+            &[Item::String],
+        ],
+        result: Item::WhiteWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:orange_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::OrangeDye], &[Item::WhiteWool]],
+        result: Item::OrangeWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:magenta_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::MagentaDye], &[Item::WhiteWool]],
+        result: Item::MagentaWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_blue_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LightBlueDye], &[Item::WhiteWool]],
+        result: Item::LightBlueWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:yellow_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::YellowDye], &[Item::WhiteWool]],
+        result: Item::YellowWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lime_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LimeDye], &[Item::WhiteWool]],
+        result: Item::LimeWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pink_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::PinkDye], &[Item::WhiteWool]],
+        result: Item::PinkWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gray_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GrayDye], &[Item::WhiteWool]],
+        result: Item::GrayWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LightGrayDye], &[Item::WhiteWool]],
+        result: Item::LightGrayWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cyan_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::CyanDye], &[Item::WhiteWool]],
+        result: Item::CyanWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:purple_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::PurpleDye], &[Item::WhiteWool]],
+        result: Item::PurpleWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blue_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueDye], &[Item::WhiteWool]],
+        result: Item::BlueWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brown_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BrownDye], &[Item::WhiteWool]],
+        result: Item::BrownWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:green_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GreenDye], &[Item::WhiteWool]],
+        result: Item::GreenWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RedDye], &[Item::WhiteWool]],
+        result: Item::RedWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_wool",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlackDye], &[Item::WhiteWool]],
+        result: Item::BlackWool,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gold_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::OakPlanks], &[Item::OakPlanks], &[Item::OakPlanks]],
+        result: Item::OakSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:spruce_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:birch_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:jungle_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:acacia_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:stone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Stone], &[Item::Stone], &[Item::Stone]],
+        result: Item::StoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:smooth_stone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::SmoothStone],
+            &[Item::SmoothStone],
+            &[Item::SmoothStone],
+        ],
+        result: Item::SmoothStoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Sandstone], &[Item::Sandstone], &[Item::Sandstone]],
+        result: Item::SandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:cut_sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::CutSandstone],
+            &[Item::CutSandstone],
+            &[Item::CutSandstone],
+        ],
+        result: Item::CutSandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:cobblestone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::CobblestoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Bricks], &[Item::Bricks], &[Item::Bricks]],
+        result: Item::BrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:stone_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+        ],
+        result: Item::StoneBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:nether_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+        ],
+        result: Item::NetherBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:quartz_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::QuartzBlock],
+            &[Item::QuartzBlock],
+            &[Item::QuartzBlock],
+        ],
+        result: Item::QuartzSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:red_sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+        ],
+        result: Item::RedSandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:cut_red_sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::CutRedSandstone],
+            &[Item::CutRedSandstone],
+            &[Item::CutRedSandstone],
+        ],
+        result: Item::CutRedSandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:purpur_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::PurpurBlock],
+            &[Item::PurpurBlock],
+            &[Item::PurpurBlock],
+        ],
+        result: Item::PurpurSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:prismarine_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+        ],
+        result: Item::PrismarineSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:prismarine_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::PrismarineBricks],
+            &[Item::PrismarineBricks],
+            &[Item::PrismarineBricks],
+        ],
+        result: Item::PrismarineBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:dark_prismarine_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::DarkPrismarine],
+            &[Item::DarkPrismarine],
+            &[Item::DarkPrismarine],
+        ],
+        result: Item::DarkPrismarineSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:bricks",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Brick],
+            &[Item::Brick],
+            &[Item::Brick],
+            &[Item::Brick],
+        ],
+        result: Item::Bricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:tnt",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Gunpowder],
+            &[Item::Sand, Item::RedSand],
+            &[Item::Gunpowder],
+            &[Item::Sand, Item::RedSand],
+            &[Item::Gunpowder],
+            &[Item::Sand, Item::RedSand],
+            &[Item::Gunpowder],
+            &[Item::Sand, Item::RedSand],
+            &[Item::Gunpowder],
+        ],
+        result: Item::Tnt,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:bookshelf",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Book],
+            &[Item::Book],
+            &[Item::Book],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Bookshelf,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:mossy_cobblestone",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Cobblestone], &[Item::Vine]],
+        result: Item::MossyCobblestone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:torch",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::Coal, Item::Charcoal], &[Item::Stick]],
+        result: Item::Torch,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:end_rod",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::BlazeRod], &[Item::PoppedChorusFruit]],
+        result: Item::EndRod,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:purpur_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PoppedChorusFruit],
+            &[Item::PoppedChorusFruit],
+            &[Item::PoppedChorusFruit],
+            &[Item::PoppedChorusFruit],
+        ],
+        result: Item::PurpurBlock,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:purpur_pillar",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::PurpurSlab], &[Item::PurpurSlab]],
+        result: Item::PurpurPillar,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:purpur_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PurpurBlock],
+            &[],
+            &[],
+            &[Item::PurpurBlock],
+            &[Item::PurpurBlock],
+            &[],
+            &[Item::PurpurBlock],
+            &[Item::PurpurBlock],
+            &[Item::PurpurBlock],
+        ],
+        result: Item::PurpurStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:oak_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[],
+            &[],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+        ],
+        result: Item::OakStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:chest",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Chest,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+        ],
+        result: Item::DiamondBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:crafting_table",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::CraftingTable,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:furnace",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Furnace,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:ladder",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::Ladder,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:rail",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+        ],
+        result: Item::Rail,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:cobblestone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[],
+            &[],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::CobblestoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:lever",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::Stick], &[Item::Cobblestone]],
+        result: Item::Lever,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::Stone], &[Item::Stone]],
+        result: Item::StonePressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::OakPlanks], &[Item::OakPlanks]],
+        result: Item::OakPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spruce_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::SprucePlanks], &[Item::SprucePlanks]],
+        result: Item::SprucePressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:birch_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::BirchPlanks], &[Item::BirchPlanks]],
+        result: Item::BirchPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jungle_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::JunglePlanks], &[Item::JunglePlanks]],
+        result: Item::JunglePressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:acacia_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::AcaciaPlanks], &[Item::AcaciaPlanks]],
+        result: Item::AcaciaPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::DarkOakPlanks], &[Item::DarkOakPlanks]],
+        result: Item::DarkOakPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:redstone_torch",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::Redstone], &[Item::Stick]],
+        result: Item::RedstoneTorch,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Stone]],
+        result: Item::StoneButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:snow",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::SnowBlock], &[Item::SnowBlock], &[Item::SnowBlock]],
+        result: Item::Snow,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:snow_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Snowball],
+            &[Item::Snowball],
+            &[Item::Snowball],
+            &[Item::Snowball],
+        ],
+        result: Item::SnowBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:clay",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::ClayBall],
+            &[Item::ClayBall],
+            &[Item::ClayBall],
+            &[Item::ClayBall],
+        ],
+        result: Item::Clay,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jukebox",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Diamond],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Jukebox,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[Item::Stick],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::Stick],
+            &[Item::OakPlanks],
+        ],
+        result: Item::OakFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:spruce_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[Item::Stick],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::Stick],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:birch_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[Item::Stick],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::Stick],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:jungle_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[Item::Stick],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::Stick],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:acacia_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[Item::Stick],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::Stick],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[Item::Stick],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::Stick],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakFence,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:glowstone",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GlowstoneDust],
+            &[Item::GlowstoneDust],
+            &[Item::GlowstoneDust],
+            &[Item::GlowstoneDust],
+        ],
+        result: Item::Glowstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jack_o_lantern",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::CarvedPumpkin], &[Item::Torch]],
+        result: Item::JackOLantern,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+        ],
+        result: Item::OakTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:spruce_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:birch_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:jungle_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:acacia_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakTrapdoor,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:stone_bricks",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+        ],
+        result: Item::StoneBricks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:mossy_stone_bricks",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::StoneBricks], &[Item::Vine]],
+        result: Item::MossyStoneBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:chiseled_stone_bricks",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::StoneBrickSlab], &[Item::StoneBrickSlab]],
+        result: Item::ChiseledStoneBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_bars",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronBars,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::GlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:melon",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+            &[Item::MelonSlice],
+        ],
+        result: Item::Melon,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::OakPlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::OakPlanks],
+            &[Item::Stick],
+        ],
+        result: Item::OakFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spruce_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::SprucePlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::SprucePlanks],
+            &[Item::Stick],
+        ],
+        result: Item::SpruceFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:birch_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::BirchPlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::BirchPlanks],
+            &[Item::Stick],
+        ],
+        result: Item::BirchFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jungle_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::JunglePlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::JunglePlanks],
+            &[Item::Stick],
+        ],
+        result: Item::JungleFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:acacia_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::AcaciaPlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::AcaciaPlanks],
+            &[Item::Stick],
+        ],
+        result: Item::AcaciaFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_fence_gate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::DarkOakPlanks],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::DarkOakPlanks],
+            &[Item::Stick],
+        ],
+        result: Item::DarkOakFenceGate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Bricks],
+            &[],
+            &[],
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[],
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[Item::Bricks],
+        ],
+        result: Item::BrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:stone_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::StoneBricks],
+            &[],
+            &[],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+        ],
+        result: Item::StoneBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:nether_bricks",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::NetherBrick],
+            &[Item::NetherBrick],
+            &[Item::NetherBrick],
+            &[Item::NetherBrick],
+        ],
+        result: Item::NetherBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:nether_brick_fence",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::NetherBricks],
+            &[Item::NetherBrick],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBrick],
+            &[Item::NetherBricks],
+        ],
+        result: Item::NetherBrickFence,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:nether_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::NetherBricks],
+            &[],
+            &[],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+        ],
+        result: Item::NetherBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:enchanting_table",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::Book],
+            &[],
+            &[Item::Diamond],
+            &[Item::Obsidian],
+            &[Item::Diamond],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+        ],
+        result: Item::EnchantingTable,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:end_stone_bricks",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::EndStone],
+            &[Item::EndStone],
+            &[Item::EndStone],
+            &[Item::EndStone],
+        ],
+        result: Item::EndStoneBricks,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:redstone_lamp",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::Redstone],
+            &[],
+            &[Item::Redstone],
+            &[Item::Glowstone],
+            &[Item::Redstone],
+            &[],
+            &[Item::Redstone],
+            &[],
+        ],
+        result: Item::RedstoneLamp,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:sandstone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Sandstone],
+            &[],
+            &[],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+        ],
+        result: Item::SandstoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:ender_chest",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::EnderEye],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+        ],
+        result: Item::EnderChest,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:tripwire_hook",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::TripwireHook,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:emerald_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+            &[Item::Emerald],
+        ],
+        result: Item::EmeraldBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spruce_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[],
+            &[],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:birch_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[],
+            &[],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:jungle_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[],
+            &[],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:beacon",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::NetherStar],
+            &[Item::Glass],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+            &[Item::Obsidian],
+        ],
+        result: Item::Beacon,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cobblestone_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::CobblestoneWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:mossy_cobblestone_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+        ],
+        result: Item::MossyCobblestoneWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[Item::Bricks],
+            &[Item::Bricks],
+        ],
+        result: Item::BrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:prismarine_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+        ],
+        result: Item::PrismarineWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:red_sandstone_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+        ],
+        result: Item::RedSandstoneWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:mossy_stone_brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+        ],
+        result: Item::MossyStoneBrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:granite_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+        ],
+        result: Item::GraniteWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:stone_brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+            &[Item::StoneBricks],
+        ],
+        result: Item::StoneBrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:nether_brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+            &[Item::NetherBricks],
+        ],
+        result: Item::NetherBrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:andesite_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+        ],
+        result: Item::AndesiteWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:red_nether_brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+        ],
+        result: Item::RedNetherBrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:sandstone_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+            &[Item::Sandstone],
+        ],
+        result: Item::SandstoneWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:end_stone_brick_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+        ],
+        result: Item::EndStoneBrickWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:diorite_wall",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+        ],
+        result: Item::DioriteWall,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:oak_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::OakPlanks]],
+        result: Item::OakButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spruce_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::SprucePlanks]],
+        result: Item::SpruceButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:birch_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BirchPlanks]],
+        result: Item::BirchButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jungle_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::JunglePlanks]],
+        result: Item::JungleButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:acacia_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::AcaciaPlanks]],
+        result: Item::AcaciaButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_button",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::DarkOakPlanks]],
+        result: Item::DarkOakButton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:anvil",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronBlock],
+            &[Item::IronBlock],
+            &[Item::IronBlock],
+            &[],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::Anvil,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:trapped_chest",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Chest], &[Item::TripwireHook]],
+        result: Item::TrappedChest,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_weighted_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::GoldIngot], &[Item::GoldIngot]],
+        result: Item::LightWeightedPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:heavy_weighted_pressure_plate",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::IronIngot], &[Item::IronIngot]],
+        result: Item::HeavyWeightedPressurePlate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:daylight_detector",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Quartz],
+            &[Item::Quartz],
+            &[Item::Quartz],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+        ],
+        result: Item::DaylightDetector,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:redstone_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+        ],
+        result: Item::RedstoneBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:hopper",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Chest],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[],
+        ],
+        result: Item::Hopper,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:chiseled_quartz_block",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::QuartzSlab], &[Item::QuartzSlab]],
+        result: Item::ChiseledQuartzBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:quartz_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Quartz],
+            &[Item::Quartz],
+            &[Item::Quartz],
+            &[Item::Quartz],
+        ],
+        result: Item::QuartzBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:quartz_pillar",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::QuartzBlock], &[Item::QuartzBlock]],
+        result: Item::QuartzPillar,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:quartz_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::QuartzBlock],
+            &[],
+            &[],
+            &[Item::QuartzBlock],
+            &[Item::QuartzBlock],
+            &[],
+            &[Item::QuartzBlock],
+            &[Item::QuartzBlock],
+            &[Item::QuartzBlock],
+        ],
+        result: Item::QuartzStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:activator_rail",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::RedstoneTorch],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[Item::IronIngot],
+        ],
+        result: Item::ActivatorRail,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:dropper",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Redstone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Dropper,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::WhiteDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::WhiteTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:orange_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::OrangeDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::OrangeTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:magenta_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::MagentaDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::MagentaTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_blue_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::LightBlueDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::LightBlueTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:yellow_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::YellowDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::YellowTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:lime_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::LimeDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::LimeTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:pink_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::PinkDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::PinkTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:gray_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::GrayDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::GrayTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_gray_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::LightGrayDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::LightGrayTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:cyan_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::CyanDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::CyanTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:purple_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::PurpleDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::PurpleTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:blue_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::BlueDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::BlueTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:brown_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::BrownDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::BrownTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:green_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::GreenDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::GreenTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:red_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::RedDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::RedTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:black_terracotta",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::BlackDye],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+            &[Item::Terracotta],
+        ],
+        result: Item::BlackTerracotta,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:iron_trapdoor",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronTrapdoor,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:hay_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+        ],
+        result: Item::HayBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::WhiteWool], &[Item::WhiteWool]],
+        result: Item::WhiteCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:orange_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::OrangeWool], &[Item::OrangeWool]],
+        result: Item::OrangeCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:magenta_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::MagentaWool], &[Item::MagentaWool]],
+        result: Item::MagentaCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:light_blue_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::LightBlueWool], &[Item::LightBlueWool]],
+        result: Item::LightBlueCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:yellow_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::YellowWool], &[Item::YellowWool]],
+        result: Item::YellowCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:lime_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::LimeWool], &[Item::LimeWool]],
+        result: Item::LimeCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:pink_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::PinkWool], &[Item::PinkWool]],
+        result: Item::PinkCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:gray_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::GrayWool], &[Item::GrayWool]],
+        result: Item::GrayCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:light_gray_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::LightGrayWool], &[Item::LightGrayWool]],
+        result: Item::LightGrayCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:cyan_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::CyanWool], &[Item::CyanWool]],
+        result: Item::CyanCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:purple_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::PurpleWool], &[Item::PurpleWool]],
+        result: Item::PurpleCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:blue_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::BlueWool], &[Item::BlueWool]],
+        result: Item::BlueCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:brown_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::BrownWool], &[Item::BrownWool]],
+        result: Item::BrownCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:green_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::GreenWool], &[Item::GreenWool]],
+        result: Item::GreenCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:red_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::RedWool], &[Item::RedWool]],
+        result: Item::RedCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:black_carpet",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 1,
+        },
+        ingredients: &[&[Item::BlackWool], &[Item::BlackWool]],
+        result: Item::BlackCarpet,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:coal_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+            &[Item::Coal],
+        ],
+        result: Item::CoalBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:packed_ice",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+            &[Item::Ice],
+        ],
+        result: Item::PackedIce,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:acacia_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[],
+            &[],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[],
+            &[],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:slime_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+            &[Item::SlimeBall],
+        ],
+        result: Item::SlimeBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::WhiteDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::WhiteStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:orange_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::OrangeDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::OrangeStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:magenta_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::MagentaDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::MagentaStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_blue_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::LightBlueDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::LightBlueStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:yellow_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::YellowDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::YellowStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:lime_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::LimeDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::LimeStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:pink_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::PinkDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::PinkStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:gray_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::GrayDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::GrayStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_gray_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::LightGrayDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::LightGrayStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:cyan_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::CyanDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::CyanStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:purple_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::PurpleDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::PurpleStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:blue_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::BlueDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::BlueStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:brown_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::BrownDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::BrownStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:green_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::GreenDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::GreenStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:red_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::RedDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::RedStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:black_stained_glass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::BlackDye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+        ],
+        result: Item::BlackStainedGlass,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:white_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::WhiteStainedGlass],
+            &[Item::WhiteStainedGlass],
+            &[Item::WhiteStainedGlass],
+            &[Item::WhiteStainedGlass],
+            &[Item::WhiteStainedGlass],
+            &[Item::WhiteStainedGlass],
+        ],
+        result: Item::WhiteStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:white_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::WhiteDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::WhiteStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:orange_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OrangeStainedGlass],
+            &[Item::OrangeStainedGlass],
+            &[Item::OrangeStainedGlass],
+            &[Item::OrangeStainedGlass],
+            &[Item::OrangeStainedGlass],
+            &[Item::OrangeStainedGlass],
+        ],
+        result: Item::OrangeStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:orange_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::OrangeDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::OrangeStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:magenta_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::MagentaStainedGlass],
+            &[Item::MagentaStainedGlass],
+            &[Item::MagentaStainedGlass],
+            &[Item::MagentaStainedGlass],
+            &[Item::MagentaStainedGlass],
+            &[Item::MagentaStainedGlass],
+        ],
+        result: Item::MagentaStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:magenta_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::MagentaDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::MagentaStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_blue_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LightBlueStainedGlass],
+            &[Item::LightBlueStainedGlass],
+            &[Item::LightBlueStainedGlass],
+            &[Item::LightBlueStainedGlass],
+            &[Item::LightBlueStainedGlass],
+            &[Item::LightBlueStainedGlass],
+        ],
+        result: Item::LightBlueStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:light_blue_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::LightBlueDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::LightBlueStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:yellow_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::YellowStainedGlass],
+            &[Item::YellowStainedGlass],
+            &[Item::YellowStainedGlass],
+            &[Item::YellowStainedGlass],
+            &[Item::YellowStainedGlass],
+            &[Item::YellowStainedGlass],
+        ],
+        result: Item::YellowStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:yellow_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::YellowDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::YellowStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:lime_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LimeStainedGlass],
+            &[Item::LimeStainedGlass],
+            &[Item::LimeStainedGlass],
+            &[Item::LimeStainedGlass],
+            &[Item::LimeStainedGlass],
+            &[Item::LimeStainedGlass],
+        ],
+        result: Item::LimeStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:lime_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::LimeDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::LimeStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:pink_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PinkStainedGlass],
+            &[Item::PinkStainedGlass],
+            &[Item::PinkStainedGlass],
+            &[Item::PinkStainedGlass],
+            &[Item::PinkStainedGlass],
+            &[Item::PinkStainedGlass],
+        ],
+        result: Item::PinkStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:pink_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::PinkDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::PinkStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:gray_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GrayStainedGlass],
+            &[Item::GrayStainedGlass],
+            &[Item::GrayStainedGlass],
+            &[Item::GrayStainedGlass],
+            &[Item::GrayStainedGlass],
+            &[Item::GrayStainedGlass],
+        ],
+        result: Item::GrayStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:gray_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GrayDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::GrayStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_gray_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LightGrayStainedGlass],
+            &[Item::LightGrayStainedGlass],
+            &[Item::LightGrayStainedGlass],
+            &[Item::LightGrayStainedGlass],
+            &[Item::LightGrayStainedGlass],
+            &[Item::LightGrayStainedGlass],
+        ],
+        result: Item::LightGrayStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:light_gray_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::LightGrayDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::LightGrayStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:cyan_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::CyanStainedGlass],
+            &[Item::CyanStainedGlass],
+            &[Item::CyanStainedGlass],
+            &[Item::CyanStainedGlass],
+            &[Item::CyanStainedGlass],
+            &[Item::CyanStainedGlass],
+        ],
+        result: Item::CyanStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:cyan_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::CyanDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::CyanStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:purple_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PurpleStainedGlass],
+            &[Item::PurpleStainedGlass],
+            &[Item::PurpleStainedGlass],
+            &[Item::PurpleStainedGlass],
+            &[Item::PurpleStainedGlass],
+            &[Item::PurpleStainedGlass],
+        ],
+        result: Item::PurpleStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:purple_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::PurpleDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::PurpleStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:blue_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BlueStainedGlass],
+            &[Item::BlueStainedGlass],
+            &[Item::BlueStainedGlass],
+            &[Item::BlueStainedGlass],
+            &[Item::BlueStainedGlass],
+            &[Item::BlueStainedGlass],
+        ],
+        result: Item::BlueStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:blue_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::BlueDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::BlueStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:brown_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BrownStainedGlass],
+            &[Item::BrownStainedGlass],
+            &[Item::BrownStainedGlass],
+            &[Item::BrownStainedGlass],
+            &[Item::BrownStainedGlass],
+            &[Item::BrownStainedGlass],
+        ],
+        result: Item::BrownStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:brown_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::BrownDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::BrownStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:green_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GreenStainedGlass],
+            &[Item::GreenStainedGlass],
+            &[Item::GreenStainedGlass],
+            &[Item::GreenStainedGlass],
+            &[Item::GreenStainedGlass],
+            &[Item::GreenStainedGlass],
+        ],
+        result: Item::GreenStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:green_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GreenDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::GreenStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:red_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedStainedGlass],
+            &[Item::RedStainedGlass],
+            &[Item::RedStainedGlass],
+            &[Item::RedStainedGlass],
+            &[Item::RedStainedGlass],
+            &[Item::RedStainedGlass],
+        ],
+        result: Item::RedStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:red_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::RedDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::RedStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:black_stained_glass_pane",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BlackStainedGlass],
+            &[Item::BlackStainedGlass],
+            &[Item::BlackStainedGlass],
+            &[Item::BlackStainedGlass],
+            &[Item::BlackStainedGlass],
+            &[Item::BlackStainedGlass],
+        ],
+        result: Item::BlackStainedGlassPane,
+        count: 16,
+    },
+    Recipe {
+        name: "minecraft:black_stained_glass_pane_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::BlackDye],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+            &[Item::GlassPane],
+        ],
+        result: Item::BlackStainedGlassPane,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:prismarine",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+        ],
+        result: Item::Prismarine,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:prismarine_bricks",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+        ],
+        result: Item::PrismarineBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dark_prismarine",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::InkSac],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineShard],
+        ],
+        result: Item::DarkPrismarine,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:prismarine_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Prismarine],
+            &[],
+            &[],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+            &[Item::Prismarine],
+        ],
+        result: Item::PrismarineStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:prismarine_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PrismarineBricks],
+            &[],
+            &[],
+            &[Item::PrismarineBricks],
+            &[Item::PrismarineBricks],
+            &[],
+            &[Item::PrismarineBricks],
+            &[Item::PrismarineBricks],
+            &[Item::PrismarineBricks],
+        ],
+        result: Item::PrismarineBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:dark_prismarine_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::DarkPrismarine],
+            &[],
+            &[],
+            &[Item::DarkPrismarine],
+            &[Item::DarkPrismarine],
+            &[],
+            &[Item::DarkPrismarine],
+            &[Item::DarkPrismarine],
+            &[Item::DarkPrismarine],
+        ],
+        result: Item::DarkPrismarineStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:sea_lantern",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PrismarineShard],
+            &[Item::PrismarineCrystals],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineCrystals],
+            &[Item::PrismarineCrystals],
+            &[Item::PrismarineCrystals],
+            &[Item::PrismarineShard],
+            &[Item::PrismarineCrystals],
+            &[Item::PrismarineShard],
+        ],
+        result: Item::SeaLantern,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_sandstone",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedSand],
+            &[Item::RedSand],
+            &[Item::RedSand],
+            &[Item::RedSand],
+        ],
+        result: Item::RedSandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:chiseled_red_sandstone",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::RedSandstoneSlab], &[Item::RedSandstoneSlab]],
+        result: Item::ChiseledRedSandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cut_red_sandstone",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+        ],
+        result: Item::CutRedSandstone,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:red_sandstone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::RedSandstone],
+            &[],
+            &[],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+            &[Item::RedSandstone],
+        ],
+        result: Item::RedSandstoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:magma_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::MagmaCream],
+            &[Item::MagmaCream],
+            &[Item::MagmaCream],
+            &[Item::MagmaCream],
+        ],
+        result: Item::MagmaBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:nether_wart_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+        ],
+        result: Item::NetherWartBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_nether_bricks",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::NetherBrick],
+            &[Item::NetherWart],
+            &[Item::NetherWart],
+            &[Item::NetherBrick],
+        ],
+        result: Item::RedNetherBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:bone_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+            &[Item::BoneMeal],
+        ],
+        result: Item::BoneBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:observer",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Redstone],
+            &[Item::Redstone],
+            &[Item::Quartz],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::Observer,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:shulker_box",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::ShulkerShell], &[Item::Chest], &[Item::ShulkerShell]],
+        result: Item::ShulkerBox,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::WhiteDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::WhiteConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:orange_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::OrangeDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::OrangeConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:magenta_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::MagentaDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::MagentaConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_blue_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::LightBlueDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::LightBlueConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:yellow_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::YellowDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::YellowConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:lime_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::LimeDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::LimeConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:pink_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::PinkDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::PinkConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:gray_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::GrayDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::GrayConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:light_gray_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::LightGrayDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::LightGrayConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:cyan_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::CyanDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::CyanConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:purple_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::PurpleDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::PurpleConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:blue_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::BlueDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::BlueConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:brown_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::BrownDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::BrownConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:green_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::GreenDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::GreenConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:red_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::RedDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::RedConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:black_concrete_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::BlackDye],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Sand],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+            &[Item::Gravel],
+        ],
+        result: Item::BlackConcretePowder,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:blue_ice",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+            &[Item::PackedIce],
+        ],
+        result: Item::BlueIce,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:conduit",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+            &[Item::HeartOfTheSea],
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+            &[Item::NautilusShell],
+        ],
+        result: Item::Conduit,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:polished_granite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PolishedGranite],
+            &[],
+            &[],
+            &[Item::PolishedGranite],
+            &[Item::PolishedGranite],
+            &[],
+            &[Item::PolishedGranite],
+            &[Item::PolishedGranite],
+            &[Item::PolishedGranite],
+        ],
+        result: Item::PolishedGraniteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:smooth_red_sandstone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SmoothRedSandstone],
+            &[],
+            &[],
+            &[Item::SmoothRedSandstone],
+            &[Item::SmoothRedSandstone],
+            &[],
+            &[Item::SmoothRedSandstone],
+            &[Item::SmoothRedSandstone],
+            &[Item::SmoothRedSandstone],
+        ],
+        result: Item::SmoothRedSandstoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:mossy_stone_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::MossyStoneBricks],
+            &[],
+            &[],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+        ],
+        result: Item::MossyStoneBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:polished_diorite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PolishedDiorite],
+            &[],
+            &[],
+            &[Item::PolishedDiorite],
+            &[Item::PolishedDiorite],
+            &[],
+            &[Item::PolishedDiorite],
+            &[Item::PolishedDiorite],
+            &[Item::PolishedDiorite],
+        ],
+        result: Item::PolishedDioriteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:mossy_cobblestone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::MossyCobblestone],
+            &[],
+            &[],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+        ],
+        result: Item::MossyCobblestoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:end_stone_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::EndStoneBricks],
+            &[],
+            &[],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+        ],
+        result: Item::EndStoneBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:stone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stone],
+            &[],
+            &[],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+        ],
+        result: Item::StoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:smooth_sandstone_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SmoothSandstone],
+            &[],
+            &[],
+            &[Item::SmoothSandstone],
+            &[Item::SmoothSandstone],
+            &[],
+            &[Item::SmoothSandstone],
+            &[Item::SmoothSandstone],
+            &[Item::SmoothSandstone],
+        ],
+        result: Item::SmoothSandstoneStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:smooth_quartz_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SmoothQuartz],
+            &[],
+            &[],
+            &[Item::SmoothQuartz],
+            &[Item::SmoothQuartz],
+            &[],
+            &[Item::SmoothQuartz],
+            &[Item::SmoothQuartz],
+            &[Item::SmoothQuartz],
+        ],
+        result: Item::SmoothQuartzStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:granite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Granite],
+            &[],
+            &[],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[],
+            &[Item::Granite],
+            &[Item::Granite],
+            &[Item::Granite],
+        ],
+        result: Item::GraniteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:andesite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Andesite],
+            &[],
+            &[],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[],
+            &[Item::Andesite],
+            &[Item::Andesite],
+            &[Item::Andesite],
+        ],
+        result: Item::AndesiteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:red_nether_brick_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::RedNetherBricks],
+            &[],
+            &[],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+        ],
+        result: Item::RedNetherBrickStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:polished_andesite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PolishedAndesite],
+            &[],
+            &[],
+            &[Item::PolishedAndesite],
+            &[Item::PolishedAndesite],
+            &[],
+            &[Item::PolishedAndesite],
+            &[Item::PolishedAndesite],
+            &[Item::PolishedAndesite],
+        ],
+        result: Item::PolishedAndesiteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:diorite_stairs",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diorite],
+            &[],
+            &[],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[],
+            &[Item::Diorite],
+            &[Item::Diorite],
+            &[Item::Diorite],
+        ],
+        result: Item::DioriteStairs,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:polished_granite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::PolishedGranite],
+            &[Item::PolishedGranite],
+            &[Item::PolishedGranite],
+        ],
+        result: Item::PolishedGraniteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:smooth_red_sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::SmoothRedSandstone],
+            &[Item::SmoothRedSandstone],
+            &[Item::SmoothRedSandstone],
+        ],
+        result: Item::SmoothRedSandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:mossy_stone_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+            &[Item::MossyStoneBricks],
+        ],
+        result: Item::MossyStoneBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:polished_diorite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::PolishedDiorite],
+            &[Item::PolishedDiorite],
+            &[Item::PolishedDiorite],
+        ],
+        result: Item::PolishedDioriteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:mossy_cobblestone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+            &[Item::MossyCobblestone],
+        ],
+        result: Item::MossyCobblestoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:end_stone_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+            &[Item::EndStoneBricks],
+        ],
+        result: Item::EndStoneBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:smooth_sandstone_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::SmoothSandstone],
+            &[Item::SmoothSandstone],
+            &[Item::SmoothSandstone],
+        ],
+        result: Item::SmoothSandstoneSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:smooth_quartz_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::SmoothQuartz],
+            &[Item::SmoothQuartz],
+            &[Item::SmoothQuartz],
+        ],
+        result: Item::SmoothQuartzSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:granite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Granite], &[Item::Granite], &[Item::Granite]],
+        result: Item::GraniteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:andesite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Andesite], &[Item::Andesite], &[Item::Andesite]],
+        result: Item::AndesiteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:red_nether_brick_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+            &[Item::RedNetherBricks],
+        ],
+        result: Item::RedNetherBrickSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:polished_andesite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[
+            &[Item::PolishedAndesite],
+            &[Item::PolishedAndesite],
+            &[Item::PolishedAndesite],
+        ],
+        result: Item::PolishedAndesiteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:diorite_slab",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Diorite], &[Item::Diorite], &[Item::Diorite]],
+        result: Item::DioriteSlab,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:scaffolding",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Bamboo],
+            &[Item::String],
+            &[Item::Bamboo],
+            &[Item::Bamboo],
+            &[],
+            &[Item::Bamboo],
+            &[Item::Bamboo],
+            &[],
+            &[Item::Bamboo],
+        ],
+        result: Item::Scaffolding,
+        count: 6,
+    },
+    Recipe {
+        name: "minecraft:iron_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:oak_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+        ],
+        result: Item::OakDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:spruce_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:birch_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:jungle_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:acacia_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_door",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakDoor,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:repeater",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedstoneTorch],
+            &[Item::Redstone],
+            &[Item::RedstoneTorch],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+        ],
+        result: Item::Repeater,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:comparator",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::RedstoneTorch],
+            &[],
+            &[Item::RedstoneTorch],
+            &[Item::Quartz],
+            &[Item::RedstoneTorch],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+        ],
+        result: Item::Comparator,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:composter",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+        ],
+        result: Item::Composter,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:turtle_helmet",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Scute],
+            &[Item::Scute],
+            &[Item::Scute],
+            &[Item::Scute],
+            &[],
+            &[Item::Scute],
+        ],
+        result: Item::TurtleHelmet,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_shovel",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::IronIngot], &[Item::Stick], &[Item::Stick]],
+        result: Item::IronShovel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_pickaxe",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::IronPickaxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_axe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::IronAxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:flint_and_steel",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::IronIngot], &[Item::Flint]],
+        result: Item::FlintAndSteel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:bow",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::Stick],
+            &[Item::String],
+            &[Item::Stick],
+            &[],
+            &[Item::String],
+            &[],
+            &[Item::Stick],
+            &[Item::String],
+        ],
+        result: Item::Bow,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:arrow",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::Flint], &[Item::Stick], &[Item::Feather]],
+        result: Item::Arrow,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:coal",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::CoalBlock]],
+        result: Item::Coal,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:diamond",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::DiamondBlock]],
+        result: Item::Diamond,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:iron_ingot",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::IronBlock]],
+        result: Item::IronIngot,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:iron_ingot_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+        ],
+        result: Item::IronIngot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gold_ingot",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GoldBlock]],
+        result: Item::GoldIngot,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:gold_ingot_2",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+        ],
+        result: Item::GoldIngot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_sword",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::IronIngot], &[Item::IronIngot], &[Item::Stick]],
+        result: Item::IronSword,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wooden_sword",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Stick],
+        ],
+        result: Item::WoodenSword,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wooden_shovel",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Stick],
+            &[Item::Stick],
+        ],
+        result: Item::WoodenShovel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wooden_pickaxe",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::WoodenPickaxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wooden_axe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::WoodenAxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_sword",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::Cobblestone], &[Item::Cobblestone], &[Item::Stick]],
+        result: Item::StoneSword,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_shovel",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::Cobblestone], &[Item::Stick], &[Item::Stick]],
+        result: Item::StoneShovel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_pickaxe",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::StonePickaxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_axe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::StoneAxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_sword",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::Diamond], &[Item::Diamond], &[Item::Stick]],
+        result: Item::DiamondSword,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_shovel",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::Diamond], &[Item::Stick], &[Item::Stick]],
+        result: Item::DiamondShovel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_pickaxe",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::DiamondPickaxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_axe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::DiamondAxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stick",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Stick,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:stick_2",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 2,
+        },
+        ingredients: &[&[Item::Bamboo], &[Item::Bamboo]],
+        result: Item::Stick,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:bowl",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+        ],
+        result: Item::Bowl,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:mushroom_stew",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BrownMushroom], &[Item::RedMushroom], &[Item::Bowl]],
+        result: Item::MushroomStew,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_sword",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::GoldIngot], &[Item::GoldIngot], &[Item::Stick]],
+        result: Item::GoldenSword,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_shovel",
+        kind: RecipeKind::Shaped {
+            width: 1,
+            height: 3,
+        },
+        ingredients: &[&[Item::GoldIngot], &[Item::Stick], &[Item::Stick]],
+        result: Item::GoldenShovel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_pickaxe",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::GoldenPickaxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_axe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::GoldenAxe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wooden_hoe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::WoodenHoe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_hoe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::StoneHoe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_hoe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::IronHoe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_hoe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::DiamondHoe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_hoe",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+        ],
+        result: Item::GoldenHoe,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:wheat",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::HayBlock]],
+        result: Item::Wheat,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:bread",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Wheat], &[Item::Wheat], &[Item::Wheat]],
+        result: Item::Bread,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather_helmet",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+        ],
+        result: Item::LeatherHelmet,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather_chestplate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+        ],
+        result: Item::LeatherChestplate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather_leggings",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+        ],
+        result: Item::LeatherLeggings,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather_boots",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+        ],
+        result: Item::LeatherBoots,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_helmet",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronHelmet,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_chestplate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronChestplate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_leggings",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronLeggings,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_boots",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+        ],
+        result: Item::IronBoots,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_helmet",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+        ],
+        result: Item::DiamondHelmet,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_chestplate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+        ],
+        result: Item::DiamondChestplate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_leggings",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+        ],
+        result: Item::DiamondLeggings,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_boots",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+            &[Item::Diamond],
+            &[],
+            &[Item::Diamond],
+        ],
+        result: Item::DiamondBoots,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_helmet",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldenHelmet,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_chestplate",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldenChestplate,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_leggings",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldenLeggings,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_boots",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldenBoots,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:painting",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[
+                Item::WhiteWool,
+                Item::OrangeWool,
+                Item::MagentaWool,
+                Item::LightBlueWool,
+                Item::YellowWool,
+                Item::LimeWool,
+                Item::PinkWool,
+                Item::GrayWool,
+                Item::LightGrayWool,
+                Item::CyanWool,
+                Item::PurpleWool,
+                Item::BlueWool,
+                Item::BrownWool,
+                Item::GreenWool,
+                Item::RedWool,
+                Item::BlackWool,
+            ],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+        ],
+        result: Item::Painting,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_apple",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::Apple],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+            &[Item::GoldIngot],
+        ],
+        result: Item::GoldenApple,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:oak_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::OakSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:spruce_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::SpruceSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:birch_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::BirchSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:jungle_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::JungleSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:acacia_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::AcaciaSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_sign",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::DarkOakSign,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:bucket",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[],
+        ],
+        result: Item::Bucket,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:minecart",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::Minecart,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:redstone",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RedstoneBlock]],
+        result: Item::Redstone,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:oak_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OakPlanks],
+            &[],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+            &[Item::OakPlanks],
+        ],
+        result: Item::OakBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RabbitHide],
+            &[Item::RabbitHide],
+            &[Item::RabbitHide],
+            &[Item::RabbitHide],
+        ],
+        result: Item::Leather,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dried_kelp_block",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+            &[Item::DriedKelp],
+        ],
+        result: Item::DriedKelpBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:paper",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::SugarCane], &[Item::SugarCane], &[Item::SugarCane]],
+        result: Item::Paper,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:book",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Leather],
+        ],
+        result: Item::Book,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:slime_ball",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::SlimeBlock]],
+        result: Item::SlimeBall,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:chest_minecart",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Chest], &[Item::Minecart]],
+        result: Item::ChestMinecart,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:furnace_minecart",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Furnace], &[Item::Minecart]],
+        result: Item::FurnaceMinecart,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:compass",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::Redstone],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[],
+        ],
+        result: Item::Compass,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:fishing_rod",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+            &[Item::String],
+            &[Item::Stick],
+            &[],
+            &[Item::String],
+        ],
+        result: Item::FishingRod,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:clock",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[Item::Redstone],
+            &[Item::GoldIngot],
+            &[],
+            &[Item::GoldIngot],
+            &[],
+        ],
+        result: Item::Clock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Poppy]],
+        result: Item::RedDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RedTulip]],
+        result: Item::RedDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_dye_3",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RoseBush]],
+        result: Item::RedDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:red_dye_4",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Beetroot]],
+        result: Item::RedDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lapis_lazuli",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LapisBlock]],
+        result: Item::LapisLazuli,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:purple_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueDye], &[Item::RedDye]],
+        result: Item::PurpleDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:cyan_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueDye], &[Item::GreenDye]],
+        result: Item::CyanDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:light_gray_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::AzureBluet]],
+        result: Item::LightGrayDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::OxeyeDaisy]],
+        result: Item::LightGrayDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_dye_3",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::WhiteTulip]],
+        result: Item::LightGrayDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_dye_4",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GrayDye], &[Item::WhiteDye]],
+        result: Item::LightGrayDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:light_gray_dye_5",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlackDye], &[Item::WhiteDye], &[Item::WhiteDye]],
+        result: Item::LightGrayDye,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:gray_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlackDye], &[Item::WhiteDye]],
+        result: Item::GrayDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:pink_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::PinkTulip]],
+        result: Item::PinkDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pink_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Peony]],
+        result: Item::PinkDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:pink_dye_3",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RedDye], &[Item::WhiteDye]],
+        result: Item::PinkDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:lime_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GreenDye], &[Item::WhiteDye]],
+        result: Item::LimeDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:yellow_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Dandelion]],
+        result: Item::YellowDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:yellow_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Sunflower]],
+        result: Item::YellowDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:light_blue_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueOrchid]],
+        result: Item::LightBlueDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_blue_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueDye], &[Item::WhiteDye]],
+        result: Item::LightBlueDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:magenta_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Allium]],
+        result: Item::MagentaDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:magenta_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Lilac]],
+        result: Item::MagentaDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:magenta_dye_3",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::PurpleDye], &[Item::PinkDye]],
+        result: Item::MagentaDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:magenta_dye_4",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlueDye], &[Item::RedDye], &[Item::PinkDye]],
+        result: Item::MagentaDye,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:orange_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::OrangeTulip]],
+        result: Item::OrangeDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:orange_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::RedDye], &[Item::YellowDye]],
+        result: Item::OrangeDye,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:bone_meal",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BoneBlock]],
+        result: Item::BoneMeal,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:bone_meal_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Bone]],
+        result: Item::BoneMeal,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:blue_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Cornflower]],
+        result: Item::BlueDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blue_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LapisLazuli]],
+        result: Item::BlueDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brown_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::CocoaBeans]],
+        result: Item::BrownDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::InkSac]],
+        result: Item::BlackDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::WitherRose]],
+        result: Item::BlackDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_dye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BoneMeal]],
+        result: Item::WhiteDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_dye_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::LilyOfTheValley]],
+        result: Item::WhiteDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:sugar",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::SugarCane]],
+        result: Item::Sugar,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:sugar_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::HoneyBottle]],
+        result: Item::Sugar,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:cake",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::MilkBucket],
+            &[Item::MilkBucket],
+            &[Item::MilkBucket],
+            &[Item::Sugar],
+            &[Item::Egg],
+            &[Item::Sugar],
+            &[Item::Wheat],
+            &[Item::Wheat],
+            &[Item::Wheat],
+        ],
+        result: Item::Cake,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::WhiteBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:orange_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::OrangeBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:magenta_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::MagentaBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_blue_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::LightBlueBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:yellow_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::YellowBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lime_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::LimeBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pink_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::PinkBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gray_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::GrayBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::LightGrayBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cyan_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::CyanBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:purple_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::PurpleBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blue_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::BlueBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brown_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::BrownBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:green_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::GreenBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::RedBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_bed",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::BlackBed,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cookie",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 1,
+        },
+        ingredients: &[&[Item::Wheat], &[Item::CocoaBeans], &[Item::Wheat]],
+        result: Item::Cookie,
+        count: 8,
+    },
+    Recipe {
+        name: "minecraft:shears",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[&[], &[Item::IronIngot], &[Item::IronIngot], &[]],
+        result: Item::Shears,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dried_kelp",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::DriedKelpBlock]],
+        result: Item::DriedKelp,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:pumpkin_seeds",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Pumpkin]],
+        result: Item::PumpkinSeeds,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:melon_seeds",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::MelonSlice]],
+        result: Item::MelonSeeds,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gold_nugget",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GoldIngot]],
+        result: Item::GoldNugget,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:gold_nugget_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::GoldIngot]],
+        result: Item::GoldNugget,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:glass_bottle",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[],
+            &[Item::Glass],
+            &[],
+            &[Item::Glass],
+            &[],
+        ],
+        result: Item::GlassBottle,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:fermented_spider_eye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::SpiderEye], &[Item::BrownMushroom], &[Item::Sugar]],
+        result: Item::FermentedSpiderEye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blaze_powder",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlazeRod]],
+        result: Item::BlazePowder,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:magma_cream",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::BlazePowder], &[Item::SlimeBall]],
+        result: Item::MagmaCream,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brewing_stand",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[],
+            &[Item::BlazeRod],
+            &[],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+            &[Item::Cobblestone],
+        ],
+        result: Item::BrewingStand,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cauldron",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+        ],
+        result: Item::Cauldron,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:ender_eye",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::EnderPearl], &[Item::BlazePowder]],
+        result: Item::EnderEye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:glistering_melon_slice",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::MelonSlice],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+        ],
+        result: Item::GlisteringMelonSlice,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:fire_charge",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::Gunpowder],
+            &[Item::BlazePowder],
+            &[Item::Coal, Item::Charcoal],
+        ],
+        result: Item::FireCharge,
+        count: 3,
+    },
+    Recipe {
+        name: "minecraft:writable_book",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Book], &[Item::InkSac], &[Item::Feather]],
+        result: Item::WritableBook,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:emerald",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::EmeraldBlock]],
+        result: Item::Emerald,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:item_frame",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Leather],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+        ],
+        result: Item::ItemFrame,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:flower_pot",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Brick],
+            &[],
+            &[Item::Brick],
+            &[],
+            &[Item::Brick],
+            &[],
+        ],
+        result: Item::FlowerPot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:map",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Compass],
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Paper],
+            &[Item::Paper],
+        ],
+        result: Item::Map,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:golden_carrot",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::Carrot],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+            &[Item::GoldNugget],
+        ],
+        result: Item::GoldenCarrot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:carrot_on_a_stick",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[&[Item::FishingRod], &[], &[], &[Item::Carrot]],
+        result: Item::CarrotOnAStick,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pumpkin_pie",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Pumpkin], &[Item::Sugar], &[Item::Egg]],
+        result: Item::PumpkinPie,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:tnt_minecart",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Tnt], &[Item::Minecart]],
+        result: Item::TntMinecart,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:hopper_minecart",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::Hopper], &[Item::Minecart]],
+        result: Item::HopperMinecart,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:rabbit_stew",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::BakedPotato],
+            &[Item::CookedRabbit],
+            &[Item::Bowl],
+            &[Item::Carrot],
+            &[Item::BrownMushroom, Item::RedMushroom],
+        ],
+        result: Item::RabbitStew,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:armor_stand",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::Stick],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+            &[Item::SmoothStoneSlab],
+            &[Item::Stick],
+        ],
+        result: Item::ArmorStand,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:leather_horse_armor",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[Item::Leather],
+            &[],
+            &[Item::Leather],
+        ],
+        result: Item::LeatherHorseArmor,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lead",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::String],
+            &[Item::String],
+            &[],
+            &[Item::String],
+            &[Item::SlimeBall],
+            &[],
+            &[],
+            &[],
+            &[Item::String],
+        ],
+        result: Item::Lead,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:white_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[Item::WhiteWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::WhiteBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:orange_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[Item::OrangeWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::OrangeBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:magenta_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[Item::MagentaWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::MagentaBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_blue_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[Item::LightBlueWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::LightBlueBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:yellow_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[Item::YellowWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::YellowBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lime_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[Item::LimeWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::LimeBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pink_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[Item::PinkWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::PinkBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gray_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[Item::GrayWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::GrayBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[Item::LightGrayWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::LightGrayBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cyan_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[Item::CyanWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::CyanBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:purple_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[Item::PurpleWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::PurpleBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blue_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[Item::BlueWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::BlueBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brown_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[Item::BrownWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::BrownBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:green_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[Item::GreenWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::GreenBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[Item::RedWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::RedBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_banner",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[Item::BlackWool],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::BlackBanner,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:end_crystal",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::EnderEye],
+            &[Item::Glass],
+            &[Item::Glass],
+            &[Item::GhastTear],
+            &[Item::Glass],
+        ],
+        result: Item::EndCrystal,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:beetroot_soup",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::Bowl],
+            &[Item::Beetroot],
+            &[Item::Beetroot],
+            &[Item::Beetroot],
+            &[Item::Beetroot],
+            &[Item::Beetroot],
+            &[Item::Beetroot],
+        ],
+        result: Item::BeetrootSoup,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spectral_arrow",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::GlowstoneDust],
+            &[],
+            &[Item::GlowstoneDust],
+            &[Item::Arrow],
+            &[Item::GlowstoneDust],
+            &[],
+            &[Item::GlowstoneDust],
+            &[],
+        ],
+        result: Item::SpectralArrow,
+        count: 2,
+    },
+    Recipe {
+        name: "minecraft:shield",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::IronIngot],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+        ],
+        result: Item::Shield,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:spruce_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::SprucePlanks],
+            &[],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+            &[Item::SprucePlanks],
+        ],
+        result: Item::SpruceBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:birch_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::BirchPlanks],
+            &[],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+            &[Item::BirchPlanks],
+        ],
+        result: Item::BirchBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:jungle_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::JunglePlanks],
+            &[],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+            &[Item::JunglePlanks],
+        ],
+        result: Item::JungleBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:acacia_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::AcaciaPlanks],
+            &[],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+            &[Item::AcaciaPlanks],
+        ],
+        result: Item::AcaciaBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dark_oak_boat",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::DarkOakPlanks],
+            &[],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+            &[Item::DarkOakPlanks],
+        ],
+        result: Item::DarkOakBoat,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_nugget",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::IronIngot]],
+        result: Item::IronNugget,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:iron_nugget_2",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[&[Item::IronIngot]],
+        result: Item::IronNugget,
+        count: 9,
+    },
+    Recipe {
+        name: "minecraft:crossbow",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::IronIngot],
+            &[Item::Stick],
+            &[Item::String],
+            &[Item::TripwireHook],
+            &[Item::String],
+            &[],
+            &[Item::Stick],
+            &[],
+        ],
+        result: Item::Crossbow,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:loom",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::String],
+            &[Item::String],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Loom,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:barrel",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Barrel,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smoker",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[Item::Furnace],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[],
+        ],
+        result: Item::Smoker,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blast_furnace",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[Item::Furnace],
+            &[Item::IronIngot],
+            &[Item::SmoothStone],
+            &[Item::SmoothStone],
+            &[Item::SmoothStone],
+        ],
+        result: Item::BlastFurnace,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cartography_table",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Paper],
+            &[Item::Paper],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::CartographyTable,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:fletching_table",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::Flint],
+            &[Item::Flint],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::FletchingTable,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:grindstone",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Stick],
+            &[Item::StoneSlab],
+            &[Item::Stick],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Grindstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lectern",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[],
+            &[Item::Bookshelf],
+            &[],
+            &[],
+            &[
+                Item::OakSlab,
+                Item::SpruceSlab,
+                Item::BirchSlab,
+                Item::JungleSlab,
+                Item::AcaciaSlab,
+                Item::DarkOakSlab,
+            ],
+            &[],
+        ],
+        result: Item::Lectern,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smithing_table",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronIngot],
+            &[Item::IronIngot],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::SmithingTable,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stonecutter",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 2,
+        },
+        ingredients: &[
+            &[],
+            &[Item::IronIngot],
+            &[],
+            &[Item::Stone],
+            &[Item::Stone],
+            &[Item::Stone],
+        ],
+        result: Item::Stonecutter,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lantern",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::Torch],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+            &[Item::IronNugget],
+        ],
+        result: Item::Lantern,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:campfire",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[],
+            &[Item::Stick],
+            &[],
+            &[Item::Stick],
+            &[Item::Coal, Item::Charcoal],
+            &[Item::Stick],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+            &[
+                Item::OakLog,
+                Item::OakWood,
+                Item::StrippedOakLog,
+                Item::StrippedOakWood,
+                Item::SpruceLog,
+                Item::SpruceWood,
+                Item::StrippedSpruceLog,
+                Item::StrippedSpruceWood,
+                Item::BirchLog,
+                Item::BirchWood,
+                Item::StrippedBirchLog,
+                Item::StrippedBirchWood,
+                Item::JungleLog,
+                Item::JungleWood,
+                Item::StrippedJungleLog,
+                Item::StrippedJungleWood,
+                Item::AcaciaLog,
+                Item::AcaciaWood,
+                Item::StrippedAcaciaLog,
+                Item::StrippedAcaciaWood,
+                Item::DarkOakLog,
+                Item::DarkOakWood,
+                Item::StrippedDarkOakLog,
+                Item::StrippedDarkOakWood,
+            ],
+        ],
+        result: Item::Campfire,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:beehive",
+        kind: RecipeKind::Shaped {
+            width: 3,
+            height: 3,
+        },
+        ingredients: &[
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[Item::Honeycomb],
+            &[Item::Honeycomb],
+            &[Item::Honeycomb],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+            &[
+                Item::OakPlanks,
+                Item::SprucePlanks,
+                Item::BirchPlanks,
+                Item::JunglePlanks,
+                Item::AcaciaPlanks,
+                Item::DarkOakPlanks,
+            ],
+        ],
+        result: Item::Beehive,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:honey_bottle",
+        kind: RecipeKind::Shapeless,
+        ingredients: &[
+            &[Item::HoneyBlock],
+            &[Item::GlassBottle],
+            &[Item::GlassBottle],
+            &[Item::GlassBottle],
+            &[Item::GlassBottle],
+        ],
+        result: Item::HoneyBottle,
+        count: 4,
+    },
+    Recipe {
+        name: "minecraft:honey_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::HoneyBottle],
+            &[Item::HoneyBottle],
+            &[Item::HoneyBottle],
+            &[Item::HoneyBottle],
+        ],
+        result: Item::HoneyBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:honeycomb_block",
+        kind: RecipeKind::Shaped {
+            width: 2,
+            height: 2,
+        },
+        ingredients: &[
+            &[Item::Honeycomb],
+            &[Item::Honeycomb],
+            &[Item::Honeycomb],
+            &[Item::Honeycomb],
+        ],
+        result: Item::HoneycombBlock,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_ingot_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.7,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::IronOre]],
+        result: Item::IronIngot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gold_ingot_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 1.0,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::GoldOre]],
+        result: Item::GoldIngot,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:diamond_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 1.0,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::DiamondOre]],
+        result: Item::Diamond,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:emerald_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 1.0,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::EmeraldOre]],
+        result: Item::Emerald,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lapis_lazuli_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.2,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::LapisOre]],
+        result: Item::LapisLazuli,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:redstone_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.7,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::RedstoneOre]],
+        result: Item::Redstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:coal_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::CoalOre]],
+        result: Item::Coal,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:quartz_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.2,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::NetherQuartzOre]],
+        result: Item::Quartz,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:glass_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Sand, Item::RedSand]],
+        result: Item::Glass,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:stone_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Cobblestone]],
+        result: Item::Stone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smooth_stone_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Stone]],
+        result: Item::SmoothStone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smooth_sandstone_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Sandstone]],
+        result: Item::SmoothSandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smooth_red_sandstone_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::RedSandstone]],
+        result: Item::SmoothRedSandstone,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:smooth_quartz_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::QuartzBlock]],
+        result: Item::SmoothQuartz,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cracked_stone_bricks_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::StoneBricks]],
+        result: Item::CrackedStoneBricks,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brick_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.3,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::ClayBall]],
+        result: Item::Brick,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Clay]],
+        result: Item::Terracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:nether_brick_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Netherrack]],
+        result: Item::NetherBrick,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:green_dye_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 1.0,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Cactus]],
+        result: Item::GreenDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lime_dye_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::SeaPickle]],
+        result: Item::LimeDye,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:charcoal_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.15,
+            cooking_time: 200,
+        },
+        ingredients: &[&[
+            Item::OakLog,
+            Item::OakWood,
+            Item::StrippedOakLog,
+            Item::StrippedOakWood,
+            Item::SpruceLog,
+            Item::SpruceWood,
+            Item::StrippedSpruceLog,
+            Item::StrippedSpruceWood,
+            Item::BirchLog,
+            Item::BirchWood,
+            Item::StrippedBirchLog,
+            Item::StrippedBirchWood,
+            Item::JungleLog,
+            Item::JungleWood,
+            Item::StrippedJungleLog,
+            Item::StrippedJungleWood,
+            Item::AcaciaLog,
+            Item::AcaciaWood,
+            Item::StrippedAcaciaLog,
+            Item::StrippedAcaciaWood,
+            Item::DarkOakLog,
+            Item::DarkOakWood,
+            Item::StrippedDarkOakLog,
+            Item::StrippedDarkOakWood,
+        ]],
+        result: Item::Charcoal,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:sponge_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.15,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::WetSponge]],
+        result: Item::Sponge,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:popped_chorus_fruit_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::ChorusFruit]],
+        result: Item::PoppedChorusFruit,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:dried_kelp_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Kelp]],
+        result: Item::DriedKelp,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:baked_potato_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Potato]],
+        result: Item::BakedPotato,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_beef_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Beef]],
+        result: Item::CookedBeef,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_porkchop_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Porkchop]],
+        result: Item::CookedPorkchop,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_chicken_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Chicken]],
+        result: Item::CookedChicken,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_mutton_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Mutton]],
+        result: Item::CookedMutton,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_rabbit_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Rabbit]],
+        result: Item::CookedRabbit,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_cod_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Cod]],
+        result: Item::CookedCod,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cooked_salmon_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.35,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::Salmon]],
+        result: Item::CookedSalmon,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:iron_nugget_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[
+            Item::IronPickaxe,
+            Item::IronShovel,
+            Item::IronAxe,
+            Item::IronHoe,
+            Item::IronSword,
+            Item::IronHelmet,
+            Item::IronChestplate,
+            Item::IronLeggings,
+            Item::IronBoots,
+            Item::IronHorseArmor,
+            Item::ChainmailHelmet,
+            Item::ChainmailChestplate,
+            Item::ChainmailLeggings,
+            Item::ChainmailBoots,
+        ]],
+        result: Item::IronNugget,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gold_nugget_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[
+            Item::GoldenPickaxe,
+            Item::GoldenShovel,
+            Item::GoldenAxe,
+            Item::GoldenHoe,
+            Item::GoldenSword,
+            Item::GoldenHelmet,
+            Item::GoldenChestplate,
+            Item::GoldenLeggings,
+            Item::GoldenBoots,
+            Item::GoldenHorseArmor,
+        ]],
+        result: Item::GoldNugget,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:white_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::WhiteTerracotta]],
+        result: Item::WhiteGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:orange_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::OrangeTerracotta]],
+        result: Item::OrangeGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:magenta_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::MagentaTerracotta]],
+        result: Item::MagentaGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_blue_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::LightBlueTerracotta]],
+        result: Item::LightBlueGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:yellow_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::YellowTerracotta]],
+        result: Item::YellowGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:lime_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::LimeTerracotta]],
+        result: Item::LimeGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:pink_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::PinkTerracotta]],
+        result: Item::PinkGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:gray_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::GrayTerracotta]],
+        result: Item::GrayGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:light_gray_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::LightGrayTerracotta]],
+        result: Item::LightGrayGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:cyan_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::CyanTerracotta]],
+        result: Item::CyanGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:purple_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::PurpleTerracotta]],
+        result: Item::PurpleGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:blue_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::BlueTerracotta]],
+        result: Item::BlueGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:brown_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::BrownTerracotta]],
+        result: Item::BrownGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:green_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::GreenTerracotta]],
+        result: Item::GreenGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:red_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::RedTerracotta]],
+        result: Item::RedGlazedTerracotta,
+        count: 1,
+    },
+    Recipe {
+        name: "minecraft:black_glazed_terracotta_from_smelting",
+        kind: RecipeKind::Smelting {
+            experience: 0.1,
+            cooking_time: 200,
+        },
+        ingredients: &[&[Item::BlackTerracotta]],
+        result: Item::BlackGlazedTerracotta,
+        count: 1,
+    },
+];
@@ -0,0 +1,213 @@
+use std::{
+    fmt::Debug,
+    future::Future,
+    io::{Error, ErrorKind, Result},
+    pin::Pin,
+};
+
+use openssl::hash::MessageDigest;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{config::Config, packet::client::Properties};
+
+pub const MOJANG_SESSION_SERVER: &str = "https://sessionserver.mojang.com";
+
+// the account a player logged in with
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub uuid: Uuid,
+    pub name: String,
+    // skin and cape textures
+    pub properties: Vec<Properties>,
+}
+
+pub type AuthFuture<'a> = Pin<Box<dyn Future<Output = Result<Profile>> + Send + 'a>>;
+
+// decides who a player logging in is
+pub trait Authenticator: Debug + Send + Sync {
+    // server_hash is empty when the login skipped encryption
+    fn authenticate<'a>(&'a self, name: &'a str, server_hash: &'a str) -> AuthFuture<'a>;
+
+    // whether the login has to go through encryption before authenticate
+    fn requires_encryption(&self) -> bool {
+        true
+    }
+}
+
+pub fn create(config: &Config) -> Box<dyn Authenticator> {
+    if config.online_mode {
+        Box::new(MojangAuthenticator::new(&config.session_server))
+    } else {
+        Box::new(OfflineAuthenticator)
+    }
+}
+
+// asks a session server whether the player joined with the hash of this login
+#[derive(Debug)]
+pub struct MojangAuthenticator {
+    session_server: String,
+    client: reqwest::Client,
+}
+
+impl MojangAuthenticator {
+    pub fn new(session_server: &str) -> Self {
+        Self {
+            session_server: session_server.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn has_joined(&self, name: &str, server_hash: &str) -> Result<Profile> {
+        let url = format!(
+            "{}/session/minecraft/hasJoined?username={}&serverId={}",
+            self.session_server, name, server_hash
+        );
+        let response = self.client.get(&url).send().await.map_err(http_error)?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                format!("{} has not joined through the session server", name),
+            ));
+        }
+        let response = response.error_for_status().map_err(http_error)?;
+        let body = response.text().await.map_err(http_error)?;
+        let profile = serde_json::from_str::<JsonProfile>(&body)?;
+        let uuid = Uuid::parse_str(&profile.id)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid uuid in profile"))?;
+        Ok(Profile {
+            uuid,
+            name: profile.name,
+            properties: profile
+                .properties
+                .into_iter()
+                .map(JsonProperty::into_properties)
+                .collect(),
+        })
+    }
+}
+
+impl Authenticator for MojangAuthenticator {
+    fn authenticate<'a>(&'a self, name: &'a str, server_hash: &'a str) -> AuthFuture<'a> {
+        Box::pin(self.has_joined(name, server_hash))
+    }
+}
+
+// believes whatever name the player gives, with the uuid vanilla derives from it
+#[derive(Debug)]
+pub struct OfflineAuthenticator;
+
+impl Authenticator for OfflineAuthenticator {
+    fn authenticate<'a>(&'a self, name: &'a str, _server_hash: &'a str) -> AuthFuture<'a> {
+        Box::pin(async move {
+            Ok(Profile {
+                uuid: offline_uuid(name)?,
+                name: name.to_string(),
+                properties: vec![],
+            })
+        })
+    }
+
+    fn requires_encryption(&self) -> bool {
+        false
+    }
+}
+
+// goes through encryption like mojang but answers from a fixed set of profiles, for
+// testing logins without a session server
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct StubAuthenticator {
+    profiles: std::collections::HashMap<String, Profile>,
+}
+
+#[cfg(test)]
+impl StubAuthenticator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        self.profiles.insert(profile.name.clone(), profile);
+        self
+    }
+}
+
+#[cfg(test)]
+impl Authenticator for StubAuthenticator {
+    fn authenticate<'a>(&'a self, name: &'a str, _server_hash: &'a str) -> AuthFuture<'a> {
+        let profile = self.profiles.get(name).cloned().ok_or_else(|| {
+            Error::new(
+                ErrorKind::PermissionDenied,
+                format!("no profile for {}", name),
+            )
+        });
+        Box::pin(async move { profile })
+    }
+}
+
+// what vanilla gives a player in offline mode: a version 3 uuid of "OfflinePlayer:<name>"
+pub fn offline_uuid(name: &str) -> Result<Uuid> {
+    let digest = openssl::hash::hash(
+        MessageDigest::md5(),
+        format!("OfflinePlayer:{}", name).as_bytes(),
+    )?;
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest);
+    bytes[6] = bytes[6] & 0x0f | 0x30;
+    bytes[8] = bytes[8] & 0x3f | 0x80;
+    Ok(Uuid::from_bytes(bytes))
+}
+
+#[derive(Deserialize)]
+struct JsonProfile {
+    id: String,
+    name: String,
+    #[serde(default)]
+    properties: Vec<JsonProperty>,
+}
+
+// a profile property the way session servers and bungeecord write it
+#[derive(Deserialize)]
+pub struct JsonProperty {
+    name: String,
+    value: String,
+    signature: Option<String>,
+}
+
+impl JsonProperty {
+    pub fn into_properties(self) -> Properties {
+        Properties {
+            name: self.name,
+            value: self.value,
+            is_signed: self.signature.is_some(),
+            signature: self.signature,
+        }
+    }
+}
+
+fn http_error(err: reqwest::Error) -> Error {
+    Error::other(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offline_uuids_match_vanilla() {
+        assert_eq!(
+            offline_uuid("Notch").unwrap(),
+            Uuid::parse_str("b50ad385-829d-3141-a216-7e7d7539ba7f").unwrap()
+        );
+        assert_eq!(
+            offline_uuid("jeb_").unwrap(),
+            Uuid::parse_str("a762f560-4fce-3236-812a-b80efff0b62b").unwrap()
+        );
+        let uuid = offline_uuid("Notch").unwrap();
+        assert_eq!(uuid.get_version_num(), 3);
+        // names are case sensitive offline
+        assert_ne!(offline_uuid("notch").unwrap(), uuid);
+    }
+}
@@ -0,0 +1,665 @@
+use std::{collections::HashMap, io};
+
+use crate::{
+    packet::client::{DeclareCommands, Node},
+    types::chat::{Chat, Color},
+};
+
+pub mod argument;
+
+pub use argument::{Argument, Coordinates, EntitySelector, Parser, StringReader};
+
+pub type CommandHandler<S> = fn(&mut S, &CommandContext) -> CommandResult;
+pub type CommandResult = Result<(), CommandError>;
+
+#[derive(Debug)]
+pub enum CommandError {
+    // shown to the sender in red
+    Message(Box<Chat>),
+    Io(io::Error),
+}
+
+impl From<Chat> for CommandError {
+    fn from(message: Chat) -> Self {
+        CommandError::Message(Box::new(message))
+    }
+}
+
+impl From<io::Error> for CommandError {
+    fn from(err: io::Error) -> Self {
+        CommandError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandSyntaxError {
+    pub message: Box<Chat>,
+    pub input: String,
+    pub cursor: usize,
+}
+
+impl CommandSyntaxError {
+    // the last few characters before the error, like vanilla's "...d @s 1 2<--[HERE]"
+    pub fn context(&self) -> Chat {
+        let cursor = self.cursor.min(self.input.len());
+        let before = &self.input[..cursor];
+        let before = match before.char_indices().rev().nth(9) {
+            Some((index, _)) => format!("...{}", &before[index..]),
+            None => before.to_string(),
+        };
+        let mut after = Chat::text(&self.input[cursor..]).color(Color::Red);
+        after.underlined = Some(true);
+        let mut here = Chat::translate("command.context.here", vec![]).color(Color::Red);
+        here.italic = Some(true);
+
+        let mut context = Chat::text(before).color(Color::Gray);
+        context.extra = vec![after, here];
+        context
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandContext {
+    pub sender: usize,
+    literals: Vec<String>,
+    arguments: HashMap<String, Argument>,
+}
+
+impl CommandContext {
+    // literals in the order they were matched, the command name first
+    pub fn literals(&self) -> &[String] {
+        &self.literals
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&Argument> {
+        self.arguments.get(name)
+    }
+
+    pub fn integer(&self, name: &str) -> Option<i32> {
+        match self.argument(name) {
+            Some(Argument::Integer(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.argument(name) {
+            Some(Argument::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn entity(&self, name: &str) -> Option<&EntitySelector> {
+        match self.argument(name) {
+            Some(Argument::Entity(selector)) => Some(selector),
+            _ => None,
+        }
+    }
+
+    pub fn coordinates(&self, name: &str) -> Option<&Coordinates> {
+        match self.argument(name) {
+            Some(Argument::Vec3(coordinates)) | Some(Argument::BlockPos(coordinates)) => {
+                Some(coordinates)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn time(&self, name: &str) -> Option<i32> {
+        match self.argument(name) {
+            Some(Argument::Time(ticks)) => Some(*ticks),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NodeKind {
+    Root,
+    Literal(String),
+    Argument { name: String, parser: Parser },
+}
+
+struct CommandNode<S> {
+    kind: NodeKind,
+    children: Vec<usize>,
+    handler: Option<CommandHandler<S>>,
+    // the permission level a sender needs to see and use the node
+    permission: u8,
+}
+
+pub struct CommandBuilder<S> {
+    kind: NodeKind,
+    children: Vec<CommandBuilder<S>>,
+    handler: Option<CommandHandler<S>>,
+    permission: u8,
+}
+
+pub fn literal<S>(name: &str) -> CommandBuilder<S> {
+    CommandBuilder {
+        kind: NodeKind::Literal(name.to_string()),
+        children: vec![],
+        handler: None,
+        permission: 0,
+    }
+}
+
+pub fn argument<S>(name: &str, parser: Parser) -> CommandBuilder<S> {
+    CommandBuilder {
+        kind: NodeKind::Argument {
+            name: name.to_string(),
+            parser,
+        },
+        children: vec![],
+        handler: None,
+        permission: 0,
+    }
+}
+
+impl<S> CommandBuilder<S> {
+    pub fn then(mut self, child: CommandBuilder<S>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn executes(mut self, handler: CommandHandler<S>) -> Self {
+        self.handler = Some(handler);
+        self
+    }
+
+    // like vanilla, 2 for cheats, 3 for managing players and 4 for the server itself
+    pub fn requires(mut self, permission: u8) -> Self {
+        self.permission = permission;
+        self
+    }
+}
+
+// brigadier style command tree, node 0 is the root
+pub struct CommandDispatcher<S> {
+    nodes: Vec<CommandNode<S>>,
+}
+
+impl<S> Default for CommandDispatcher<S> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![CommandNode {
+                kind: NodeKind::Root,
+                children: vec![],
+                handler: None,
+                permission: 0,
+            }],
+        }
+    }
+}
+
+impl<S> CommandDispatcher<S> {
+    pub fn register(&mut self, command: CommandBuilder<S>) {
+        self.insert(0, command);
+    }
+
+    fn insert(&mut self, parent: usize, builder: CommandBuilder<S>) {
+        let existing = self.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.nodes[*child].kind == builder.kind);
+        let index = match existing {
+            Some(index) => {
+                if builder.handler.is_some() {
+                    self.nodes[index].handler = builder.handler;
+                }
+                index
+            }
+            None => {
+                self.nodes.push(CommandNode {
+                    kind: builder.kind,
+                    children: vec![],
+                    handler: builder.handler,
+                    permission: builder.permission,
+                });
+                let index = self.nodes.len() - 1;
+                self.nodes[parent].children.push(index);
+                index
+            }
+        };
+        for child in builder.children {
+            self.insert(index, child);
+        }
+    }
+
+    // input is the command without the leading slash, nodes above the sender's
+    // permission level are treated as if they didn't exist
+    pub fn parse(
+        &self,
+        sender: usize,
+        permission_level: u8,
+        input: &str,
+    ) -> Result<(CommandHandler<S>, CommandContext), CommandSyntaxError> {
+        let mut context = CommandContext {
+            sender,
+            literals: vec![],
+            arguments: HashMap::new(),
+        };
+        let handler =
+            self.parse_node(0, StringReader::new(input), permission_level, &mut context)?;
+        Ok((handler, context))
+    }
+
+    fn children(&self, index: usize, permission_level: u8) -> impl Iterator<Item = usize> + '_ {
+        self.nodes[index]
+            .children
+            .iter()
+            .copied()
+            .filter(move |child| self.nodes[*child].permission <= permission_level)
+    }
+
+    fn parse_node(
+        &self,
+        index: usize,
+        mut reader: StringReader,
+        permission_level: u8,
+        context: &mut CommandContext,
+    ) -> Result<CommandHandler<S>, CommandSyntaxError> {
+        let node = &self.nodes[index];
+        match &node.kind {
+            NodeKind::Root => {}
+            NodeKind::Literal(literal) => {
+                let start = reader.cursor();
+                if reader.read_word() != literal {
+                    let key = if start == 0 {
+                        "command.unknown.command"
+                    } else {
+                        "command.unknown.argument"
+                    };
+                    return Err(reader.error_at(start, key, vec![]));
+                }
+                context.literals.push(literal.clone());
+            }
+            NodeKind::Argument { name, parser } => {
+                let argument = parser.parse(&mut reader)?;
+                context.arguments.insert(name.clone(), argument);
+            }
+        }
+
+        if !reader.can_read() {
+            return node
+                .handler
+                .ok_or_else(|| reader.error("command.unknown.command", vec![]));
+        }
+        if node.kind != NodeKind::Root {
+            if reader.peek() != Some(' ') {
+                return Err(reader.error("command.expected.separator", vec![]));
+            }
+            reader.skip();
+        }
+
+        let mut furthest: Option<CommandSyntaxError> = None;
+        for child in self.children(index, permission_level) {
+            let mut child_context = context.clone();
+            match self.parse_node(child, reader.clone(), permission_level, &mut child_context) {
+                Ok(handler) => {
+                    *context = child_context;
+                    return Ok(handler);
+                }
+                Err(err) => {
+                    if furthest.as_ref().is_none_or(|f| err.cursor > f.cursor) {
+                        furthest = Some(err);
+                    }
+                }
+            }
+        }
+        Err(furthest.unwrap_or_else(|| reader.error("command.unknown.argument", vec![])))
+    }
+
+    // start of the token being completed and the candidates for it
+    pub fn suggest(
+        &self,
+        input: &str,
+        permission_level: u8,
+        players: &[String],
+    ) -> (usize, Vec<String>) {
+        let mut start = input.len();
+        let mut suggestions = vec![];
+        self.suggest_node(
+            0,
+            StringReader::new(input),
+            permission_level,
+            players,
+            &mut start,
+            &mut suggestions,
+        );
+        (start, suggestions)
+    }
+
+    fn suggest_node(
+        &self,
+        index: usize,
+        mut reader: StringReader,
+        permission_level: u8,
+        players: &[String],
+        start: &mut usize,
+        suggestions: &mut Vec<String>,
+    ) {
+        if index != 0 {
+            if reader.peek() != Some(' ') {
+                return;
+            }
+            reader.skip();
+        }
+        let token = reader.remaining();
+
+        for child in self.children(index, permission_level) {
+            let mut child_reader = reader.clone();
+            let parsed = match &self.nodes[child].kind {
+                NodeKind::Root => false,
+                NodeKind::Literal(literal) => child_reader.read_word() == literal,
+                NodeKind::Argument { parser, .. } => parser.parse(&mut child_reader).is_ok(),
+            };
+            if parsed && child_reader.can_read() {
+                self.suggest_node(
+                    child,
+                    child_reader,
+                    permission_level,
+                    players,
+                    start,
+                    suggestions,
+                );
+                continue;
+            }
+            if token.contains(' ') {
+                continue;
+            }
+
+            *start = reader.cursor();
+            match &self.nodes[child].kind {
+                NodeKind::Literal(literal) if literal.starts_with(token) => {
+                    suggestions.push(literal.clone());
+                }
+                NodeKind::Argument { parser, .. } if parser.asks_server() => {
+                    let selectors = match parser {
+                        Parser::Entity { .. } => &["@p", "@r", "@a", "@e", "@s"][..],
+                        _ => &[],
+                    };
+                    let candidates = players
+                        .iter()
+                        .map(|name| name.as_str())
+                        .chain(selectors.iter().copied());
+                    for candidate in candidates {
+                        if candidate.to_lowercase().starts_with(&token.to_lowercase()) {
+                            suggestions.push(candidate.to_string());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // the tree as the sender sees it, nodes they may not use are left unreachable
+    pub fn declare_commands(&self, permission_level: u8) -> DeclareCommands {
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let executable = if node.handler.is_some() { 0x04 } else { 0 };
+                let children = self
+                    .children(index, permission_level)
+                    .map(|i| (i as i32).into())
+                    .collect();
+                match &node.kind {
+                    NodeKind::Root => Node {
+                        flags: 0,
+                        children,
+                        redirect_node: None,
+                        name: None,
+                        parser: None,
+                        suggestions_type: None,
+                    },
+                    NodeKind::Literal(literal) => Node {
+                        flags: 1 | executable,
+                        children,
+                        redirect_node: None,
+                        name: Some(literal.clone()),
+                        parser: None,
+                        suggestions_type: None,
+                    },
+                    NodeKind::Argument { name, parser } => {
+                        let suggestions_type = if parser.asks_server() {
+                            Some("minecraft:ask_server".to_string())
+                        } else {
+                            None
+                        };
+                        let has_suggestions = if suggestions_type.is_some() { 0x10 } else { 0 };
+                        Node {
+                            flags: 2 | executable | has_suggestions,
+                            children,
+                            redirect_node: None,
+                            name: Some(name.clone()),
+                            parser: Some(parser.clone()),
+                            suggestions_type,
+                        }
+                    }
+                }
+            })
+            .collect();
+        DeclareCommands {
+            nodes,
+            root_index: 0.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kareki_data::{block::Block, item::Item};
+
+    use crate::state::Coordinate;
+
+    use super::*;
+
+    fn ok(_: &mut (), _: &CommandContext) -> CommandResult {
+        Ok(())
+    }
+
+    fn dispatcher() -> CommandDispatcher<()> {
+        let mut dispatcher = CommandDispatcher::default();
+        dispatcher.register(
+            literal("give").requires(2).then(
+                argument(
+                    "targets",
+                    Parser::Entity {
+                        single: false,
+                        players_only: true,
+                    },
+                )
+                .then(
+                    argument("item", Parser::ItemStack).executes(ok).then(
+                        argument(
+                            "count",
+                            Parser::Integer {
+                                min: Some(1),
+                                max: Some(6400),
+                            },
+                        )
+                        .executes(ok),
+                    ),
+                ),
+            ),
+        );
+        dispatcher.register(literal("tp").then(argument("location", Parser::Vec3).executes(ok)));
+        dispatcher.register(
+            literal("setblock").then(
+                argument("pos", Parser::BlockPos)
+                    .then(argument("block", Parser::BlockState).executes(ok)),
+            ),
+        );
+        dispatcher.register(
+            literal("time").then(literal("add").then(argument("time", Parser::Time).executes(ok))),
+        );
+        dispatcher.register(literal("say").then(argument("message", Parser::Message).executes(ok)));
+        dispatcher
+    }
+
+    fn parse(input: &str) -> Result<CommandContext, CommandSyntaxError> {
+        dispatcher().parse(0, 4, input).map(|(_, context)| context)
+    }
+
+    fn error_key(input: &str) -> (String, usize) {
+        let err = parse(input).unwrap_err();
+        (err.message.translate.unwrap(), err.cursor)
+    }
+
+    #[test]
+    fn parses_arguments_along_the_tree() {
+        let context = parse("give @a minecraft:stone 64").unwrap();
+        assert_eq!(context.literals(), ["give"]);
+        assert_eq!(context.entity("targets"), Some(&EntitySelector::AllPlayers));
+        assert_eq!(
+            context.argument("item"),
+            Some(&Argument::ItemStack(Item::Stone))
+        );
+        assert_eq!(context.integer("count"), Some(64));
+
+        let context = parse("give Notch stone").unwrap();
+        assert_eq!(
+            context.entity("targets"),
+            Some(&EntitySelector::Player("Notch".to_string()))
+        );
+        assert_eq!(context.integer("count"), None);
+
+        let context = parse("say hello  there").unwrap();
+        assert_eq!(context.string("message"), Some("hello  there"));
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        assert_eq!(error_key("nope"), ("command.unknown.command".into(), 0));
+        assert_eq!(error_key("give"), ("command.unknown.command".into(), 4));
+        assert_eq!(
+            error_key("give @a stone 6401"),
+            ("argument.integer.big".into(), 14)
+        );
+        assert_eq!(
+            error_key("give @a stone 0"),
+            ("argument.integer.low".into(), 14)
+        );
+        assert_eq!(
+            error_key("give @a dirtt"),
+            ("argument.item.id.invalid".into(), 8)
+        );
+        assert_eq!(
+            error_key("give @e stone"),
+            ("argument.player.entities".into(), 5)
+        );
+        assert_eq!(
+            error_key("give @a stone 1x"),
+            ("command.expected.separator".into(), 15)
+        );
+    }
+
+    #[test]
+    fn parses_coordinates() {
+        let context = parse("tp 1 64 -2.5").unwrap();
+        let origin = Coordinate {
+            x: 10.0,
+            y: 70.0,
+            z: 10.0,
+        };
+        // whole numbers point at the middle of the block horizontally
+        let resolved = context
+            .coordinates("location")
+            .unwrap()
+            .resolve(origin, Default::default());
+        assert_eq!((resolved.x, resolved.y, resolved.z), (1.5, 64.0, -2.5));
+
+        let context = parse("tp ~ ~1 ~-3").unwrap();
+        let resolved = context
+            .coordinates("location")
+            .unwrap()
+            .resolve(origin, Default::default());
+        assert_eq!((resolved.x, resolved.y, resolved.z), (10.0, 71.0, 7.0));
+
+        assert_eq!(error_key("tp ^ ~ ^"), ("argument.pos.mixed".into(), 5));
+        assert_eq!(error_key("tp 1 2"), ("argument.pos3d.incomplete".into(), 6));
+    }
+
+    #[test]
+    fn parses_block_states() {
+        let context = parse("setblock 0 0 0 minecraft:oak_stairs[facing=east,half=top]").unwrap();
+        let state = match context.argument("block") {
+            Some(Argument::BlockState(state)) => *state,
+            _ => panic!("no block state"),
+        };
+        assert_eq!(state.block(), Block::OakStairs);
+        assert_eq!(state.property("facing"), Some("east"));
+        assert_eq!(state.property("half"), Some("top"));
+
+        assert_eq!(
+            error_key("setblock 0 0 0 oak_stairs[color=red]"),
+            ("argument.block.property.unknown".into(), 26)
+        );
+        assert_eq!(
+            error_key("setblock 0 0 0 oak_stairs[half=top,half=top]"),
+            ("argument.block.property.duplicate".into(), 35)
+        );
+    }
+
+    #[test]
+    fn parses_time_units() {
+        for (input, ticks) in [
+            ("1d", 24000),
+            ("3s", 60),
+            ("5t", 5),
+            ("7", 7),
+            ("0.5d", 12000),
+        ]
+        .iter()
+        {
+            let context = parse(&format!("time add {}", input)).unwrap();
+            assert_eq!(context.time("time"), Some(*ticks));
+        }
+        assert_eq!(
+            error_key("time add 1y"),
+            ("argument.time.invalid_unit".into(), 10)
+        );
+    }
+
+    #[test]
+    fn hides_commands_above_the_permission_level() {
+        let dispatcher = dispatcher();
+        let err = dispatcher.parse(0, 0, "give @a stone").unwrap_err();
+        assert_eq!(
+            err.message.translate.as_deref(),
+            Some("command.unknown.command")
+        );
+        assert!(dispatcher.parse(0, 2, "give @a stone").is_ok());
+
+        let (_, suggestions) = dispatcher.suggest("", 0, &[]);
+        assert!(!suggestions.contains(&"give".to_string()));
+        assert!(suggestions.contains(&"tp".to_string()));
+        let (_, suggestions) = dispatcher.suggest("gi", 2, &[]);
+        assert_eq!(suggestions, ["give"]);
+
+        let names = |permission_level| {
+            let declared = dispatcher.declare_commands(permission_level);
+            declared.nodes[0]
+                .children
+                .iter()
+                .map(|child| declared.nodes[child.0 as usize].name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert!(!names(0).contains(&"give".to_string()));
+        assert!(names(2).contains(&"give".to_string()));
+    }
+
+    #[test]
+    fn suggests_players_and_selectors() {
+        let players = ["Notch".to_string(), "jeb_".to_string()];
+        let (start, suggestions) = dispatcher().suggest("give n", 4, &players);
+        assert_eq!(start, 5);
+        assert_eq!(suggestions, ["Notch"]);
+        let (_, suggestions) = dispatcher().suggest("give @", 4, &players);
+        assert_eq!(suggestions, ["@p", "@r", "@a", "@e", "@s"]);
+    }
+}
@@ -0,0 +1,634 @@
+use std::io::{self, Write};
+
+use kareki_data::{block::Block, block_state::BlockState, item::Item};
+
+use crate::{
+    protocol::ProtocolWrite,
+    state::{Coordinate, Rotation},
+    types::{chat::Chat, position::Position, Var},
+};
+
+use super::CommandSyntaxError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Parser {
+    Bool,
+    Double { min: Option<f64>, max: Option<f64> },
+    Float { min: Option<f32>, max: Option<f32> },
+    Integer { min: Option<i32>, max: Option<i32> },
+    String(StringType),
+    Entity { single: bool, players_only: bool },
+    GameProfile,
+    BlockPos,
+    Vec3,
+    BlockState,
+    ItemStack,
+    Message,
+    Time,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringType {
+    SingleWord,
+    QuotablePhrase,
+    GreedyPhrase,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    Bool(bool),
+    Double(f64),
+    Float(f32),
+    Integer(i32),
+    String(String),
+    Entity(EntitySelector),
+    BlockPos(Coordinates),
+    Vec3(Coordinates),
+    BlockState(BlockState),
+    ItemStack(Item),
+    Time(i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntitySelector {
+    Player(String),
+    NearestPlayer,
+    RandomPlayer,
+    AllPlayers,
+    AllEntities,
+    Sender,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coordinates {
+    // absolute or relative (~) to the sender
+    World([WorldCoordinate; 3]),
+    // left, up, forwards (^) from where the sender is looking
+    Local([f64; 3]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldCoordinate {
+    pub value: f64,
+    pub relative: bool,
+}
+
+impl Parser {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Parser::Bool => "brigadier:bool",
+            Parser::Double { .. } => "brigadier:double",
+            Parser::Float { .. } => "brigadier:float",
+            Parser::Integer { .. } => "brigadier:integer",
+            Parser::String(_) => "brigadier:string",
+            Parser::Entity { .. } => "minecraft:entity",
+            Parser::GameProfile => "minecraft:game_profile",
+            Parser::BlockPos => "minecraft:block_pos",
+            Parser::Vec3 => "minecraft:vec3",
+            Parser::BlockState => "minecraft:block_state",
+            Parser::ItemStack => "minecraft:item_stack",
+            Parser::Message => "minecraft:message",
+            Parser::Time => "minecraft:time",
+        }
+    }
+
+    // whether the client has to ask the server for suggestions
+    pub fn asks_server(&self) -> bool {
+        matches!(self, Parser::Entity { .. } | Parser::GameProfile)
+    }
+
+    pub fn parse(&self, reader: &mut StringReader) -> Result<Argument, CommandSyntaxError> {
+        match self {
+            Parser::Bool => {
+                let start = reader.cursor();
+                match reader.read_unquoted() {
+                    "true" => Ok(Argument::Bool(true)),
+                    "false" => Ok(Argument::Bool(false)),
+                    "" => Err(reader.error_at(start, "parsing.bool.expected", vec![])),
+                    value => {
+                        Err(reader.error_at(start, "parsing.bool.invalid", vec![value.into()]))
+                    }
+                }
+            }
+            Parser::Double { min, max } => {
+                let start = reader.cursor();
+                let value = reader.read_number::<f64>("double")?;
+                check_range(reader, start, "double", value, *min, *max)?;
+                Ok(Argument::Double(value))
+            }
+            Parser::Float { min, max } => {
+                let start = reader.cursor();
+                let value = reader.read_number::<f32>("float")?;
+                check_range(reader, start, "float", value, *min, *max)?;
+                Ok(Argument::Float(value))
+            }
+            Parser::Integer { min, max } => {
+                let start = reader.cursor();
+                let value = reader.read_number::<i32>("int")?;
+                check_range(reader, start, "integer", value, *min, *max)?;
+                Ok(Argument::Integer(value))
+            }
+            Parser::String(StringType::SingleWord) => {
+                Ok(Argument::String(reader.read_unquoted().to_string()))
+            }
+            Parser::String(StringType::QuotablePhrase) => {
+                Ok(Argument::String(reader.read_string()?))
+            }
+            Parser::String(StringType::GreedyPhrase) | Parser::Message => {
+                Ok(Argument::String(reader.read_remaining().to_string()))
+            }
+            Parser::Entity {
+                single,
+                players_only,
+            } => {
+                let start = reader.cursor();
+                let selector = parse_entity_selector(reader)?;
+                let many = matches!(
+                    selector,
+                    EntitySelector::AllPlayers | EntitySelector::AllEntities
+                );
+                if *single && many {
+                    return Err(reader.error_at(start, "argument.entity.toomany", vec![]));
+                }
+                if *players_only && selector == EntitySelector::AllEntities {
+                    return Err(reader.error_at(start, "argument.player.entities", vec![]));
+                }
+                Ok(Argument::Entity(selector))
+            }
+            Parser::GameProfile => Ok(Argument::Entity(parse_entity_selector(reader)?)),
+            Parser::BlockPos => Ok(Argument::BlockPos(parse_coordinates(reader, true)?)),
+            Parser::Vec3 => Ok(Argument::Vec3(parse_coordinates(reader, false)?)),
+            Parser::BlockState => parse_block_state(reader),
+            Parser::ItemStack => {
+                let start = reader.cursor();
+                let name = reader.read_resource_location();
+                let item = strip_namespace(name)
+                    .and_then(Item::from_name)
+                    .ok_or_else(|| {
+                        reader.error_at(start, "argument.item.id.invalid", vec![name.into()])
+                    })?;
+                if reader.peek() == Some('{') {
+                    return Err(reader.error_text("item NBT is not supported"));
+                }
+                Ok(Argument::ItemStack(item))
+            }
+            Parser::Time => {
+                let value = reader.read_number::<f32>("float")?;
+                let multiplier = match reader.peek() {
+                    Some('d') => 24000.0,
+                    Some('s') => 20.0,
+                    Some('t') => 1.0,
+                    Some(' ') | None => 1.0,
+                    Some(_) => return Err(reader.error("argument.time.invalid_unit", vec![])),
+                };
+                if matches!(reader.peek(), Some('d') | Some('s') | Some('t')) {
+                    reader.skip();
+                }
+                let ticks = (value * multiplier).round() as i32;
+                if ticks < 0 {
+                    return Err(reader.error("argument.time.invalid_tick_count", vec![]));
+                }
+                Ok(Argument::Time(ticks))
+            }
+        }
+    }
+}
+
+impl ProtocolWrite for Parser {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        String::proto_encode(&value.identifier().to_string(), dst)?;
+        match value {
+            Parser::Double { min, max } => {
+                u8::proto_encode(&range_flags(min, max), dst)?;
+                Option::proto_encode(min, dst)?;
+                Option::proto_encode(max, dst)?;
+            }
+            Parser::Float { min, max } => {
+                u8::proto_encode(&range_flags(min, max), dst)?;
+                Option::proto_encode(min, dst)?;
+                Option::proto_encode(max, dst)?;
+            }
+            Parser::Integer { min, max } => {
+                u8::proto_encode(&range_flags(min, max), dst)?;
+                Option::proto_encode(min, dst)?;
+                Option::proto_encode(max, dst)?;
+            }
+            Parser::String(string_type) => {
+                let string_type = match string_type {
+                    StringType::SingleWord => 0,
+                    StringType::QuotablePhrase => 1,
+                    StringType::GreedyPhrase => 2,
+                };
+                <Var<i32>>::proto_encode(&Var(string_type), dst)?;
+            }
+            Parser::Entity {
+                single,
+                players_only,
+            } => {
+                let flags = (*single as u8) | (*players_only as u8) << 1;
+                u8::proto_encode(&flags, dst)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn range_flags<T>(min: &Option<T>, max: &Option<T>) -> u8 {
+    (min.is_some() as u8) | (max.is_some() as u8) << 1
+}
+
+fn check_range<T: PartialOrd + ToString + Copy>(
+    reader: &StringReader,
+    start: usize,
+    kind: &str,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), CommandSyntaxError> {
+    if let Some(min) = min.filter(|min| value < *min) {
+        return Err(reader.error_at(
+            start,
+            &format!("argument.{}.low", kind),
+            vec![min.to_string().into(), value.to_string().into()],
+        ));
+    }
+    if let Some(max) = max.filter(|max| value > *max) {
+        return Err(reader.error_at(
+            start,
+            &format!("argument.{}.big", kind),
+            vec![max.to_string().into(), value.to_string().into()],
+        ));
+    }
+    Ok(())
+}
+
+fn strip_namespace(name: &str) -> Option<&str> {
+    match name.find(':') {
+        Some(_) => name.strip_prefix("minecraft:"),
+        None => Some(name),
+    }
+}
+
+fn parse_entity_selector(reader: &mut StringReader) -> Result<EntitySelector, CommandSyntaxError> {
+    let start = reader.cursor();
+    if reader.peek() != Some('@') {
+        let name = reader.read_word();
+        if name.is_empty() || name.len() > 16 {
+            return Err(reader.error_at(start, "argument.entity.invalid", vec![]));
+        }
+        return Ok(EntitySelector::Player(name.to_string()));
+    }
+
+    reader.skip();
+    let selector = match reader.peek() {
+        Some('p') => EntitySelector::NearestPlayer,
+        Some('r') => EntitySelector::RandomPlayer,
+        Some('a') => EntitySelector::AllPlayers,
+        Some('e') => EntitySelector::AllEntities,
+        Some('s') => EntitySelector::Sender,
+        _ => {
+            let selector_type = reader.read_word().to_string();
+            return Err(reader.error_at(
+                start,
+                "argument.entity.selector.unknown",
+                vec![selector_type.into()],
+            ));
+        }
+    };
+    reader.skip();
+    if reader.peek() == Some('[') {
+        return Err(reader.error_text("selector arguments are not supported"));
+    }
+    if reader.peek().is_some_and(|c| c != ' ') {
+        return Err(reader.error("argument.entity.invalid", vec![]));
+    }
+    Ok(selector)
+}
+
+fn parse_coordinates(
+    reader: &mut StringReader,
+    integer: bool,
+) -> Result<Coordinates, CommandSyntaxError> {
+    let start = reader.cursor();
+    if reader.peek() == Some('^') {
+        let mut values = [0.0; 3];
+        for (index, value) in values.iter_mut().enumerate() {
+            if index > 0 {
+                reader.expect_separator("argument.pos3d.incomplete")?;
+            }
+            if reader.peek() != Some('^') {
+                return Err(reader.error("argument.pos.mixed", vec![]));
+            }
+            reader.skip();
+            if reader.peek().is_some_and(|c| c != ' ') {
+                *value = reader.read_number::<f64>("double")?;
+            }
+        }
+        return Ok(Coordinates::Local(values));
+    }
+
+    let mut coordinates = [WorldCoordinate {
+        value: 0.0,
+        relative: false,
+    }; 3];
+    for (index, coordinate) in coordinates.iter_mut().enumerate() {
+        if index > 0 {
+            reader.expect_separator("argument.pos3d.incomplete")?;
+        }
+        match reader.peek() {
+            Some('^') => return Err(reader.error("argument.pos.mixed", vec![])),
+            Some('~') => {
+                reader.skip();
+                coordinate.relative = true;
+                if reader.peek().is_some_and(|c| c != ' ') {
+                    coordinate.value = reader.read_number::<f64>("double")?;
+                }
+            }
+            None | Some(' ') => {
+                let key = if integer {
+                    "argument.pos.missing.int"
+                } else {
+                    "argument.pos.missing.double"
+                };
+                return Err(reader.error(key, vec![]));
+            }
+            Some(_) if integer => {
+                coordinate.value = reader.read_number::<i32>("int")? as f64;
+            }
+            Some(_) => {
+                let number_start = reader.cursor();
+                coordinate.value = reader.read_number::<f64>("double")?;
+                // whole block coordinates point at the center of the block, except for y
+                let is_integer = !reader.input[number_start..reader.cursor()].contains('.');
+                if is_integer && index != 1 {
+                    coordinate.value += 0.5;
+                }
+            }
+        }
+    }
+    if reader.cursor() == start {
+        return Err(reader.error("argument.pos3d.incomplete", vec![]));
+    }
+    Ok(Coordinates::World(coordinates))
+}
+
+fn parse_block_state(reader: &mut StringReader) -> Result<Argument, CommandSyntaxError> {
+    let start = reader.cursor();
+    let name = reader.read_resource_location();
+    let block = strip_namespace(name)
+        .and_then(Block::from_name)
+        .ok_or_else(|| reader.error_at(start, "argument.block.id.invalid", vec![name.into()]))?;
+    let name = name.to_string();
+
+    let mut state = BlockState::new(block);
+    let mut properties = vec![];
+    if reader.peek() == Some('[') {
+        reader.skip();
+        loop {
+            if reader.peek() == Some(']') {
+                reader.skip();
+                break;
+            }
+            let key_start = reader.cursor();
+            let key = reader.read_unquoted().to_string();
+            let values = block
+                .properties()
+                .iter()
+                .find(|(property, _)| *property == key)
+                .map(|(_, values)| *values)
+                .ok_or_else(|| {
+                    reader.error_at(
+                        key_start,
+                        "argument.block.property.unknown",
+                        vec![name.as_str().into(), key.as_str().into()],
+                    )
+                })?;
+            if properties.contains(&key) {
+                return Err(reader.error_at(
+                    key_start,
+                    "argument.block.property.duplicate",
+                    vec![key.as_str().into(), name.as_str().into()],
+                ));
+            }
+            if reader.peek() != Some('=') {
+                return Err(reader.error(
+                    "argument.block.property.novalue",
+                    vec![key.as_str().into(), name.as_str().into()],
+                ));
+            }
+            reader.skip();
+            let value_start = reader.cursor();
+            let value = reader.read_unquoted().to_string();
+            if !values.contains(&value.as_str()) {
+                return Err(reader.error_at(
+                    value_start,
+                    "argument.block.property.invalid",
+                    vec![name.as_str().into(), value.into(), key.into()],
+                ));
+            }
+            state.set_property(&key, &value);
+            properties.push(key);
+            match reader.peek() {
+                Some(',') => reader.skip(),
+                Some(']') => {}
+                _ => return Err(reader.error("argument.block.property.unclosed", vec![])),
+            }
+        }
+    }
+    if reader.peek() == Some('{') {
+        return Err(reader.error_text("block entity NBT is not supported"));
+    }
+
+    Ok(Argument::BlockState(state))
+}
+
+impl Coordinates {
+    pub fn resolve(&self, origin: Coordinate, rotation: Rotation) -> Coordinate {
+        match self {
+            Coordinates::World([x, y, z]) => {
+                let resolve = |coordinate: &WorldCoordinate, origin: f64| {
+                    if coordinate.relative {
+                        origin + coordinate.value
+                    } else {
+                        coordinate.value
+                    }
+                };
+                Coordinate {
+                    x: resolve(x, origin.x),
+                    y: resolve(y, origin.y),
+                    z: resolve(z, origin.z),
+                }
+            }
+            Coordinates::Local([left, up, forwards]) => {
+                let yaw = (rotation.yaw as f64 + 90.0).to_radians();
+                let pitch = -(rotation.pitch as f64).to_radians();
+                let pitch_up = pitch + 90f64.to_radians();
+                let forwards_axis = [
+                    yaw.cos() * pitch.cos(),
+                    pitch.sin(),
+                    yaw.sin() * pitch.cos(),
+                ];
+                let up_axis = [
+                    yaw.cos() * pitch_up.cos(),
+                    pitch_up.sin(),
+                    yaw.sin() * pitch_up.cos(),
+                ];
+                // -(forwards x up)
+                let left_axis = [
+                    -(forwards_axis[1] * up_axis[2] - forwards_axis[2] * up_axis[1]),
+                    -(forwards_axis[2] * up_axis[0] - forwards_axis[0] * up_axis[2]),
+                    -(forwards_axis[0] * up_axis[1] - forwards_axis[1] * up_axis[0]),
+                ];
+                let offset = |axis: usize| {
+                    forwards_axis[axis] * forwards + up_axis[axis] * up + left_axis[axis] * left
+                };
+                Coordinate {
+                    x: origin.x + offset(0),
+                    y: origin.y + offset(1),
+                    z: origin.z + offset(2),
+                }
+            }
+        }
+    }
+
+    pub fn resolve_block(&self, origin: Coordinate, rotation: Rotation) -> Position {
+        let Coordinate { x, y, z } = self.resolve(origin, rotation);
+        Position {
+            x: x.floor() as i32,
+            y: y.floor() as i16,
+            z: z.floor() as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StringReader<'a> {
+    input: &'a str,
+    cursor: usize,
+}
+
+impl<'a> StringReader<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input, cursor: 0 }
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn can_read(&self) -> bool {
+        self.cursor < self.input.len()
+    }
+
+    pub fn remaining(&self) -> &'a str {
+        &self.input[self.cursor..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.remaining().chars().next()
+    }
+
+    pub fn skip(&mut self) {
+        if let Some(c) = self.peek() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    fn read_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let start = self.cursor;
+        while self.peek().is_some_and(&predicate) {
+            self.skip();
+        }
+        &self.input[start..self.cursor]
+    }
+
+    // up to the next space
+    pub fn read_word(&mut self) -> &'a str {
+        self.read_while(|c| c != ' ')
+    }
+
+    pub fn read_unquoted(&mut self) -> &'a str {
+        self.read_while(|c| c.is_ascii_alphanumeric() || "_-.+".contains(c))
+    }
+
+    pub fn read_remaining(&mut self) -> &'a str {
+        let remaining = self.remaining();
+        self.cursor = self.input.len();
+        remaining
+    }
+
+    fn read_resource_location(&mut self) -> &'a str {
+        self.read_while(|c| c.is_ascii_alphanumeric() || "_-.:/".contains(c))
+    }
+
+    pub fn read_string(&mut self) -> Result<String, CommandSyntaxError> {
+        let quote = match self.peek() {
+            Some(quote @ '"') | Some(quote @ '\'') => quote,
+            _ => return Ok(self.read_unquoted().to_string()),
+        };
+        self.skip();
+        let mut value = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.peek() {
+            self.skip();
+            if escaped {
+                if c != quote && c != '\\' {
+                    return Err(self.error("parsing.quote.escape", vec![c.to_string().into()]));
+                }
+                value.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                return Ok(value);
+            } else {
+                value.push(c);
+            }
+        }
+        Err(self.error("parsing.quote.expected.end", vec![]))
+    }
+
+    fn read_number<T: std::str::FromStr>(&mut self, kind: &str) -> Result<T, CommandSyntaxError> {
+        let start = self.cursor;
+        let number = self.read_while(|c| c.is_ascii_digit() || c == '.' || c == '-');
+        if number.is_empty() {
+            return Err(self.error(&format!("parsing.{}.expected", kind), vec![]));
+        }
+        number.parse().map_err(|_| {
+            self.error_at(
+                start,
+                &format!("parsing.{}.invalid", kind),
+                vec![number.into()],
+            )
+        })
+    }
+
+    fn expect_separator(&mut self, key: &str) -> Result<(), CommandSyntaxError> {
+        if self.peek() != Some(' ') {
+            return Err(self.error(key, vec![]));
+        }
+        self.skip();
+        Ok(())
+    }
+
+    pub fn error(&self, key: &str, with: Vec<Chat>) -> CommandSyntaxError {
+        self.error_at(self.cursor, key, with)
+    }
+
+    pub fn error_at(&self, cursor: usize, key: &str, with: Vec<Chat>) -> CommandSyntaxError {
+        CommandSyntaxError {
+            message: Box::new(Chat::translate(key, with)),
+            input: self.input.to_string(),
+            cursor,
+        }
+    }
+
+    fn error_text(&self, text: &str) -> CommandSyntaxError {
+        CommandSyntaxError {
+            message: Box::new(Chat::text(text)),
+            input: self.input.to_string(),
+            cursor: self.cursor,
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{auth::MOJANG_SESSION_SERVER, forwarding::Forwarding, state::GameMode};
+
+// settings from server.properties, keys it doesn't set keep their defaults
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub server_ip: String,
+    pub server_port: u16,
+    pub motd: String,
+    pub max_players: u32,
+    // packets at least this long are compressed, negative turns compression off
+    pub network_compression_threshold: i32,
+    pub online_mode: bool,
+    // where online mode checks that players are who they say they are
+    pub session_server: String,
+    pub view_distance: u8,
+    // what players join the server in
+    pub gamemode: GameMode,
+    // set when a proxy authenticates players, online-mode is ignored then
+    pub proxy_forwarding: Forwarding,
+    // the secret shared with velocity
+    pub forwarding_secret: String,
+    pub level_type: String,
+    pub generator_settings: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            server_ip: String::new(),
+            server_port: 25565,
+            motd: "A Minecraft Server".to_string(),
+            max_players: 20,
+            network_compression_threshold: 256,
+            online_mode: true,
+            session_server: MOJANG_SESSION_SERVER.to_string(),
+            view_distance: 10,
+            gamemode: GameMode::Survival,
+            proxy_forwarding: Forwarding::None,
+            forwarding_secret: String::new(),
+            level_type: "default".to_string(),
+            generator_settings: String::new(),
+        }
+    }
+}
+
+impl Config {
+    // a missing file is created with the defaults
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut config = Self::default();
+        if !path.exists() {
+            config.save(path)?;
+            return Ok(config);
+        }
+        for (key, value) in parse_properties(&fs::read_to_string(path)?) {
+            let (key, value) = (key.as_str(), value.trim());
+            match key {
+                "server-ip" => config.server_ip = value.to_string(),
+                "server-port" => config.server_port = parse(key, value)?,
+                "motd" => config.motd = value.to_string(),
+                "max-players" => config.max_players = parse(key, value)?,
+                "network-compression-threshold" => {
+                    config.network_compression_threshold = parse(key, value)?
+                }
+                "online-mode" => config.online_mode = parse(key, value)?,
+                "session-server" => config.session_server = value.to_string(),
+                "view-distance" => config.view_distance = parse::<u8>(key, value)?.clamp(3, 32),
+                "gamemode" => config.gamemode = parse(key, value)?,
+                "proxy-forwarding" => config.proxy_forwarding = parse(key, value)?,
+                "forwarding-secret" => config.forwarding_secret = value.to_string(),
+                "level-type" => config.level_type = value.to_string(),
+                "generator-settings" => config.generator_settings = value.to_string(),
+                _ => {}
+            }
+        }
+        // an empty secret would let anyone sign forwarded player info
+        if config.proxy_forwarding == Forwarding::Velocity && config.forwarding_secret.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "forwarding-secret must be set for velocity forwarding",
+            ));
+        }
+        Ok(config)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let properties = [
+            ("server-ip", self.server_ip.clone()),
+            ("server-port", self.server_port.to_string()),
+            ("motd", self.motd.clone()),
+            ("max-players", self.max_players.to_string()),
+            (
+                "network-compression-threshold",
+                self.network_compression_threshold.to_string(),
+            ),
+            ("online-mode", self.online_mode.to_string()),
+            ("session-server", self.session_server.clone()),
+            ("view-distance", self.view_distance.to_string()),
+            ("gamemode", self.gamemode.name().to_string()),
+            ("proxy-forwarding", self.proxy_forwarding.to_string()),
+            ("forwarding-secret", self.forwarding_secret.clone()),
+            ("level-type", self.level_type.clone()),
+            ("generator-settings", self.generator_settings.clone()),
+        ];
+        let mut text = "#Minecraft server properties\n".to_string();
+        for (key, value) in properties.iter() {
+            text.push_str(&format!("{}={}\n", escape(key, true), escape(value, false)));
+        }
+        fs::write(path, text)
+    }
+
+    // an empty server-ip listens on every interface
+    pub fn bind_address(&self) -> String {
+        let ip = if self.server_ip.is_empty() {
+            "0.0.0.0"
+        } else {
+            &self.server_ip
+        };
+        format!("{}:{}", ip, self.server_port)
+    }
+
+    pub fn compression_threshold(&self) -> Option<usize> {
+        if self.network_compression_threshold < 0 {
+            None
+        } else {
+            Some(self.network_compression_threshold as usize)
+        }
+    }
+}
+
+fn parse<T: FromStr>(key: &str, value: &str) -> Result<T> {
+    value.parse().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid value for {}: {:?}", key, value),
+        )
+    })
+}
+
+// key value pairs the way java.util.Properties reads them: # and ! comments, =, : or
+// whitespace between key and value, backslash line continuations and escapes
+fn parse_properties(text: &str) -> Vec<(String, String)> {
+    let mut properties = vec![];
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let mut line = line.trim_start().to_string();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        // an odd number of trailing backslashes joins the next line, minus its indent
+        while line.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+            line.pop();
+            match lines.next() {
+                Some(next) => line.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+
+        let mut key_end = line.len();
+        let mut escaped = false;
+        for (index, c) in line.char_indices() {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '=' || c == ':' || c.is_whitespace() {
+                key_end = index;
+                break;
+            }
+        }
+        let (key, rest) = line.split_at(key_end);
+        let rest = rest.trim_start();
+        let rest = rest
+            .strip_prefix(|c| c == '=' || c == ':')
+            .unwrap_or(rest)
+            .trim_start();
+        properties.push((unescape(key), unescape(rest)));
+    }
+    properties
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    // \\u escapes are utf-16, characters past it take two of them
+    let mut units = vec![];
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.as_str().starts_with('u') {
+            let hex = chars.as_str().get(1..5).unwrap_or_default();
+            let is_hex = hex.len() == 4 && hex.bytes().all(|b| b.is_ascii_hexdigit());
+            if let (true, Ok(unit)) = (is_hex, u16::from_str_radix(hex, 16)) {
+                units.push(unit);
+                chars.nth(4);
+                continue;
+            }
+        }
+        unescaped.push_str(&String::from_utf16_lossy(&units));
+        units.clear();
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('f') => unescaped.push('\u{c}'),
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+    unescaped.push_str(&String::from_utf16_lossy(&units));
+    unescaped
+}
+
+// what java.util.Properties writes, keys escape every space and values a leading one
+fn escape(text: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (index, c) in text.chars().enumerate() {
+        match c {
+            ' ' if is_key || index == 0 => escaped.push_str("\\ "),
+            '\\' | '=' | ':' | '#' | '!' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{c}' => escaped.push_str("\\f"),
+            ' '..='~' => escaped.push(c),
+            _ => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("\\u{:04X}", unit));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(text: &str) -> Vec<(String, String)> {
+        parse_properties(text)
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn reads_what_vanilla_writes() {
+        let text = "#Minecraft server properties\n\
+                    #Sun Oct 18 12:00:00 UTC 2026\n\
+                    motd=\\u00A7aHello \\u00E9t\\u00E9\n\
+                    resource-pack=https\\://example.com/pack.zip\n\
+                    level-name=world\n";
+        assert_eq!(
+            parsed(text),
+            vec![
+                pair("motd", "\u{a7}aHello été"),
+                pair("resource-pack", "https://example.com/pack.zip"),
+                pair("level-name", "world"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_every_separator() {
+        assert_eq!(
+            parsed("a=1\nb:2\nc 3\nd = 4\n  e  :  5\nf\n! comment\n"),
+            vec![
+                pair("a", "1"),
+                pair("b", "2"),
+                pair("c", "3"),
+                pair("d", "4"),
+                pair("e", "5"),
+                pair("f", ""),
+            ]
+        );
+        // only the first separator counts
+        assert_eq!(parsed("a=b=c"), vec![pair("a", "b=c")]);
+        assert_eq!(parsed("a\\=b=c"), vec![pair("a=b", "c")]);
+    }
+
+    #[test]
+    fn joins_continued_lines() {
+        assert_eq!(
+            parsed("motd=first \\\n    second\nnext=1"),
+            vec![pair("motd", "first second"), pair("next", "1")]
+        );
+        // an escaped backslash doesn't continue the line
+        assert_eq!(
+            parsed("path=c:\\\\\nnext=1"),
+            vec![pair("path", "c:\\"), pair("next", "1")]
+        );
+    }
+
+    #[test]
+    fn escapes_round_trip() {
+        for value in [
+            "\u{a7}aHello",
+            "https://example.com/a=b#c",
+            " leading space",
+            "tab\tand\\backslash",
+            "日本 🎮",
+        ]
+        .iter()
+        {
+            let line = format!("{}={}", escape("key", true), escape(value, false));
+            assert!(line.is_ascii());
+            assert_eq!(parsed(&line), vec![pair("key", value)]);
+        }
+        assert_eq!(escape("\u{a7}a", false), "\\u00A7a");
+    }
+
+    #[test]
+    fn saved_config_loads_the_same() {
+        let path =
+            std::env::temp_dir().join(format!("kareki-server-{}.properties", std::process::id()));
+        let config = Config {
+            motd: "\u{a7}6Gold: a=b #1".to_string(),
+            view_distance: 12,
+            ..Config::default()
+        };
+        config.save(&path).unwrap();
+        let loaded = Config::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.motd, config.motd);
+        assert_eq!(loaded.view_distance, 12);
+    }
+
+    #[test]
+    fn clamps_the_view_distance() {
+        let path = std::env::temp_dir().join(format!(
+            "kareki-view-distance-{}.properties",
+            std::process::id()
+        ));
+        fs::write(&path, "view-distance=0\n").unwrap();
+        assert_eq!(Config::load(&path).unwrap().view_distance, 3);
+        fs::write(&path, "view-distance=64\n").unwrap();
+        assert_eq!(Config::load(&path).unwrap().view_distance, 32);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+use kareki_data::{
+    item::Item,
+    recipe::{Recipe, RecipeKind, RECIPES},
+};
+
+use crate::types::slot::Slot;
+
+// the crafting recipe a size by size grid makes, shaped recipes may sit anywhere in the
+// grid and be mirrored
+pub fn find_recipe(grid: &[Option<Slot>], size: usize) -> Option<&'static Recipe> {
+    let mut items = Vec::with_capacity(grid.len());
+    for slot in grid {
+        items.push(match slot {
+            Some(stack) => Some(Item::from_id(stack.item_id.0 as u32)?),
+            None => None,
+        });
+    }
+    if items.iter().all(Option::is_none) {
+        return None;
+    }
+    RECIPES.iter().find(|recipe| match recipe.kind {
+        RecipeKind::Shaped { width, height } => {
+            matches_shaped(recipe, width as usize, height as usize, &items, size)
+        }
+        RecipeKind::Shapeless => matches_shapeless(recipe, &items),
+        RecipeKind::Smelting { .. } => false,
+    })
+}
+
+pub fn recipe_by_name(name: &str) -> Option<&'static Recipe> {
+    RECIPES.iter().find(|recipe| recipe.name == name)
+}
+
+pub fn smelting_recipe(item: Item) -> Option<&'static Recipe> {
+    RECIPES.iter().find(|recipe| {
+        matches!(recipe.kind, RecipeKind::Smelting { .. }) && recipe.ingredients[0].contains(&item)
+    })
+}
+
+// ticks an item keeps a furnace burning, None for what doesn't burn
+pub fn burn_time(item: Item) -> Option<u16> {
+    let name = item.name();
+    let wooden = WOODS
+        .iter()
+        .any(|wood| name.trim_start_matches("stripped_").starts_with(wood));
+    let ticks = match name.as_str() {
+        "lava_bucket" => 20000,
+        "coal_block" => 16000,
+        "dried_kelp_block" => 4001,
+        "blaze_rod" => 2400,
+        "coal" | "charcoal" => 1600,
+        "scaffolding" => 400,
+        "crafting_table" | "chest" | "trapped_chest" | "bookshelf" | "jukebox" | "note_block"
+        | "ladder" | "bow" | "crossbow" | "fishing_rod" | "lectern" | "composter" | "barrel"
+        | "loom" | "cartography_table" | "fletching_table" | "smithing_table"
+        | "daylight_detector" => 300,
+        "stick" | "bowl" => 100,
+        "bamboo" => 50,
+        _ if name.ends_with("_boat") => 1200,
+        _ if name.ends_with("_banner") => 300,
+        _ if name.starts_with("wooden_") => 200,
+        _ if name.ends_with("_wool") => 100,
+        _ if name.ends_with("_carpet") => 67,
+        _ if wooden && name.ends_with("_slab") => 150,
+        _ if wooden && (name.ends_with("_door") || name.ends_with("_sign")) => 200,
+        _ if wooden && (name.ends_with("_button") || name.ends_with("_sapling")) => 100,
+        _ if wooden && !name.ends_with("_leaves") => 300,
+        _ => return None,
+    };
+    Some(ticks)
+}
+
+const WOODS: &[&str] = &[
+    "oak_",
+    "spruce_",
+    "birch_",
+    "jungle_",
+    "acacia_",
+    "dark_oak_",
+];
+
+// what an ingredient leaves behind in the grid
+pub fn remainder(item: Item) -> Option<Item> {
+    match item {
+        Item::WaterBucket | Item::LavaBucket | Item::MilkBucket => Some(Item::Bucket),
+        Item::HoneyBottle | Item::DragonBreath => Some(Item::GlassBottle),
+        _ => None,
+    }
+}
+
+fn matches_shaped(
+    recipe: &Recipe,
+    width: usize,
+    height: usize,
+    items: &[Option<Item>],
+    size: usize,
+) -> bool {
+    if width > size || height > size {
+        return false;
+    }
+    for offset_x in 0..=size - width {
+        for offset_y in 0..=size - height {
+            for &mirrored in &[false, true] {
+                let matches = (0..size).all(|y| {
+                    (0..size).all(|x| {
+                        let item = items[y * size + x];
+                        let (rx, ry) = (x.wrapping_sub(offset_x), y.wrapping_sub(offset_y));
+                        if rx >= width || ry >= height {
+                            return item.is_none();
+                        }
+                        let rx = if mirrored { width - 1 - rx } else { rx };
+                        let ingredient = recipe.ingredients[ry * width + rx];
+                        match item {
+                            Some(item) => ingredient.contains(&item),
+                            None => ingredient.is_empty(),
+                        }
+                    })
+                });
+                if matches {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn matches_shapeless(recipe: &Recipe, items: &[Option<Item>]) -> bool {
+    let items = items.iter().flatten().copied().collect::<Vec<_>>();
+    if items.len() != recipe.ingredients.len() {
+        return false;
+    }
+    let mut used = vec![false; items.len()];
+    assign(recipe.ingredients, &items, &mut used)
+}
+
+// give every ingredient its own item, trying each candidate in turn
+fn assign(ingredients: &[&[Item]], items: &[Item], used: &mut [bool]) -> bool {
+    let (ingredient, rest) = match ingredients.split_first() {
+        Some(split) => split,
+        None => return true,
+    };
+    for (index, item) in items.iter().enumerate() {
+        if !used[index] && ingredient.contains(item) {
+            used[index] = true;
+            if assign(rest, items, used) {
+                return true;
+            }
+            used[index] = false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a grid from a row by row pattern, with the items for its letters
+    fn grid(pattern: &str, keys: &[(char, Item)]) -> Vec<Option<Slot>> {
+        pattern
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                let item = keys.iter().find(|(key, _)| *key == c)?.1;
+                Some(Slot {
+                    item_id: (item.id() as i32).into(),
+                    item_count: 1,
+                    meta: None,
+                })
+            })
+            .collect()
+    }
+
+    fn result(grid: &[Option<Slot>], size: usize) -> Option<Item> {
+        find_recipe(grid, size).map(|recipe| recipe.result)
+    }
+
+    const AXE: &[(char, Item)] = &[('#', Item::OakPlanks), ('/', Item::Stick)];
+
+    #[test]
+    fn matches_shaped_recipes_anywhere_in_the_grid() {
+        let torch = &[('c', Item::Coal), ('/', Item::Stick)];
+        assert_eq!(result(&grid("c. /.", torch), 2), Some(Item::Torch));
+        assert_eq!(result(&grid(".c ./", torch), 2), Some(Item::Torch));
+        assert_eq!(result(&grid("... ..c ../", torch), 3), Some(Item::Torch));
+        // upside down is a different shape
+        assert_eq!(result(&grid("/. c.", torch), 2), None);
+        // and nothing else may be in the grid
+        assert_eq!(result(&grid("c/ /.", torch), 2), None);
+    }
+
+    #[test]
+    fn matches_mirrored_shaped_recipes() {
+        assert_eq!(result(&grid("##. #/. ./.", AXE), 3), Some(Item::WoodenAxe));
+        assert_eq!(result(&grid(".## ./# ./.", AXE), 3), Some(Item::WoodenAxe));
+        // too tall for the player's grid
+        assert_eq!(result(&grid("## #/", AXE), 2), None);
+    }
+
+    #[test]
+    fn matches_shapeless_recipes_in_any_order() {
+        let keys = &[('i', Item::IronIngot), ('f', Item::Flint)];
+        assert_eq!(result(&grid("i. .f", keys), 2), Some(Item::FlintAndSteel));
+        assert_eq!(
+            result(&grid("... f.. ..i", keys), 3),
+            Some(Item::FlintAndSteel)
+        );
+        assert_eq!(result(&grid("if f.", keys), 2), None);
+        assert_eq!(result(&grid("ff ..", keys), 2), None);
+    }
+
+    #[test]
+    fn empty_grids_make_nothing() {
+        assert_eq!(result(&grid("....", &[]), 2), None);
+    }
+}
@@ -0,0 +1,231 @@
+use kareki_data::{
+    block::Block,
+    block_state::{BlockState, Half, Kind, Part},
+    item::Item,
+};
+use rand::Rng;
+
+use crate::types::slot::Slot;
+
+// vanilla's chance for each of the three extra seeds of a grown crop
+const CROP_BONUS_CHANCE: f64 = 0.571_428_6;
+
+// how far a digger gets into the block each tick, it breaks once this adds up to 1
+pub fn break_progress(block: Block, tool: Option<Item>, on_ground: bool) -> f32 {
+    let hardness = match block.hardness() {
+        Some(hardness) => hardness,
+        None => return 0.0,
+    };
+    if hardness == 0.0 {
+        return 1.0;
+    }
+
+    let mut speed = tool.map_or(1.0, |tool| destroy_speed(tool, block.material()));
+    if !on_ground {
+        speed /= 5.0;
+    }
+
+    speed
+        / hardness
+        / if can_harvest(block, tool) {
+            30.0
+        } else {
+            100.0
+        }
+}
+
+// whether breaking the block with the tool drops anything
+pub fn can_harvest(block: Block, tool: Option<Item>) -> bool {
+    match block.harvest_tools() {
+        Some(tools) => tool.is_some_and(|tool| tools.contains(&tool.id())),
+        None => true,
+    }
+}
+
+// what breaking the block drops without enchantments, following the vanilla loot tables
+pub fn drops<R: Rng>(state: BlockState, rng: &mut R) -> Vec<Slot> {
+    // doors and tall plants drop from their lower half, beds from their head
+    if state.half() == Some(Half::Upper) || state.part() == Some(Part::Foot) {
+        return vec![];
+    }
+    let block = state.block();
+    let age = state.age().unwrap_or(0);
+    let each = |count: u32| -> Vec<(u32, u32)> {
+        block.drops().iter().map(|item| (*item, count)).collect()
+    };
+    let crop_bonus =
+        |rng: &mut R| (0..3).filter(|_| rng.gen_bool(CROP_BONUS_CHANCE)).count() as u32;
+
+    let drops = match block {
+        Block::Glowstone => each(rng.gen_range(2, 5)),
+        Block::Melon => each(rng.gen_range(3, 8)),
+        Block::Clay | Block::SnowBlock => each(4),
+        Block::Bookshelf => each(3),
+        Block::RedstoneOre => each(rng.gen_range(4, 6)),
+        Block::LapisOre => each(rng.gen_range(4, 10)),
+        Block::Snow => each(state.layers().unwrap_or(1) as u32),
+        Block::SeaPickle => each(state.pickles().unwrap_or(1) as u32),
+        Block::DeadBush => each(rng.gen_range(0, 3)),
+        Block::NetherWart if age == 3 => each(rng.gen_range(2, 5)),
+        Block::Cocoa if age == 2 => each(3),
+        Block::SweetBerryBush => match age {
+            3 => each(rng.gen_range(2, 4)),
+            2 => each(rng.gen_range(1, 3)),
+            _ => vec![],
+        },
+        Block::Wheat if age == 7 => vec![
+            (Item::Wheat.id(), 1),
+            (Item::WheatSeeds.id(), 1 + crop_bonus(rng)),
+        ],
+        Block::Beetroots if age == 3 => vec![
+            (Item::Beetroot.id(), 1),
+            (Item::BeetrootSeeds.id(), 1 + crop_bonus(rng)),
+        ],
+        Block::Carrots | Block::Potatoes if age == 7 => {
+            let mut drops = each(2 + crop_bonus(rng));
+            if block == Block::Potatoes && rng.gen_bool(0.02) {
+                drops.push((Item::PoisonousPotato.id(), 1));
+            }
+            drops
+        }
+        Block::Gravel if rng.gen_bool(0.1) => vec![(Item::Flint.id(), 1)],
+        Block::Grass | Block::Fern | Block::TallGrass | Block::LargeFern => {
+            if rng.gen_bool(0.125) {
+                vec![(Item::WheatSeeds.id(), 1)]
+            } else {
+                vec![]
+            }
+        }
+        Block::BrownMushroomBlock | Block::RedMushroomBlock => {
+            let mushroom = if block == Block::BrownMushroomBlock {
+                Item::BrownMushroom
+            } else {
+                Item::RedMushroom
+            };
+            vec![(mushroom.id(), rng.gen_range(-6, 3).max(0) as u32)]
+        }
+        Block::MushroomStem => vec![],
+        Block::OakLeaves
+        | Block::SpruceLeaves
+        | Block::BirchLeaves
+        | Block::JungleLeaves
+        | Block::AcaciaLeaves
+        | Block::DarkOakLeaves => {
+            let sapling_chance = if block == Block::JungleLeaves {
+                0.025
+            } else {
+                0.05
+            };
+            let mut drops = each(rng.gen_bool(sapling_chance) as u32);
+            if rng.gen_bool(0.02) {
+                drops.push((Item::Stick.id(), rng.gen_range(1, 3)));
+            }
+            let drops_apples = matches!(block, Block::OakLeaves | Block::DarkOakLeaves);
+            if drops_apples && rng.gen_bool(0.005) {
+                drops.push((Item::Apple.id(), 1));
+            }
+            drops
+        }
+        // both halves of a double slab
+        _ if state.kind() == Some(Kind::Double) => each(2),
+        _ => each(1),
+    };
+    drops
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(item, count)| Slot {
+            item_id: (item as i32).into(),
+            item_count: count as u8,
+            meta: None,
+        })
+        .collect()
+}
+
+pub fn destroy_speed(tool: Item, material: &str) -> f32 {
+    let name = tool.name();
+    if name == "shears" {
+        return match material {
+            "web" | "leaves" => 15.0,
+            "wool" => 5.0,
+            _ => 1.0,
+        };
+    }
+
+    let (tier, kind) = match name.rsplit_once('_') {
+        Some(split) => split,
+        None => return 1.0,
+    };
+    let tier_speed = match tier {
+        "wooden" => 2.0,
+        "stone" => 4.0,
+        "iron" => 6.0,
+        "diamond" => 8.0,
+        "golden" => 12.0,
+        _ => return 1.0,
+    };
+    match (kind, material) {
+        ("pickaxe", "rock") | ("pickaxe", "iron") => tier_speed,
+        ("axe", "wood") | ("axe", "plant") | ("axe", "gourd") => tier_speed,
+        ("shovel", "dirt") => tier_speed,
+        ("sword", "web") => 15.0,
+        ("sword", "plant") | ("sword", "leaves") | ("sword", "gourd") => 1.5,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(state: BlockState) -> Vec<(i32, u8)> {
+        drops(state, &mut rand::thread_rng())
+            .into_iter()
+            .map(|slot| (slot.item_id.0, slot.item_count))
+            .collect()
+    }
+
+    #[test]
+    fn drops_vanilla_counts() {
+        let item = |item: Item| item.id() as i32;
+        assert_eq!(
+            counts(BlockState::new(Block::Stone)),
+            [(item(Item::Cobblestone), 1)]
+        );
+        assert_eq!(
+            counts(BlockState::new(Block::Clay)),
+            [(item(Item::ClayBall), 4)]
+        );
+        assert_eq!(counts(BlockState::new(Block::Glass)), []);
+
+        let mut slab = BlockState::new(Block::OakSlab);
+        slab.set_kind(Kind::Double);
+        assert_eq!(counts(slab), [(item(Item::OakSlab), 2)]);
+
+        let mut snow = BlockState::new(Block::Snow);
+        snow.set_layers(5);
+        assert_eq!(counts(snow), [(item(Item::Snowball), 5)]);
+
+        let mut wheat = BlockState::new(Block::Wheat);
+        assert_eq!(counts(wheat), [(item(Item::WheatSeeds), 1)]);
+        wheat.set_age(7);
+        for _ in 0..20 {
+            let drops = counts(wheat);
+            assert_eq!(drops[0], (item(Item::Wheat), 1));
+            assert!((1..=4).contains(&drops[1].1));
+        }
+        let mut door = BlockState::new(Block::OakDoor);
+        assert_eq!(counts(door), [(item(Item::OakDoor), 1)]);
+        door.set_half(Half::Upper);
+        assert_eq!(counts(door), []);
+        let mut bed = BlockState::new(Block::RedBed);
+        bed.set_part(Part::Head);
+        assert_eq!(counts(bed), [(item(Item::RedBed), 1)]);
+        bed.set_part(Part::Foot);
+        assert_eq!(counts(bed), []);
+
+        for _ in 0..20 {
+            let drops = counts(BlockState::new(Block::Glowstone));
+            assert!((2..=4).contains(&drops[0].1));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::io::Result;
+
+use kareki_data::item::Item;
+use uuid::Uuid;
+
+use crate::{state::Coordinate, types::slot::Slot, world::World};
+
+// entity type id of minecraft:item
+pub const ITEM_ENTITY_TYPE: i32 = 35;
+pub const DROPPED_PICKUP_DELAY: u32 = 10;
+pub const THROWN_PICKUP_DELAY: u32 = 40;
+// five minutes
+const DESPAWN_TICKS: u32 = 20 * 60 * 5;
+
+#[derive(Debug, Clone)]
+pub struct ItemEntity {
+    pub entity_id: i32,
+    pub uuid: Uuid,
+    pub position: Coordinate,
+    // blocks per tick
+    pub velocity: Coordinate,
+    pub on_ground: bool,
+    pub stack: Slot,
+    pub pickup_delay: u32,
+    pub age: u32,
+}
+
+impl ItemEntity {
+    pub fn new(
+        entity_id: i32,
+        position: Coordinate,
+        velocity: Coordinate,
+        stack: Slot,
+        pickup_delay: u32,
+    ) -> Self {
+        Self {
+            entity_id,
+            uuid: Uuid::new_v4(),
+            position,
+            velocity,
+            on_ground: false,
+            stack,
+            pickup_delay,
+            age: 0,
+        }
+    }
+
+    pub fn tick(&mut self, world: &mut World) -> Result<()> {
+        self.age += 1;
+        self.pickup_delay = self.pickup_delay.saturating_sub(1);
+
+        self.velocity.y -= 0.04;
+        self.move_by_velocity(world)?;
+
+        let friction = if self.on_ground { 0.6 * 0.98 } else { 0.98 };
+        self.velocity.x *= friction;
+        self.velocity.y *= 0.98;
+        self.velocity.z *= friction;
+        Ok(())
+    }
+
+    pub fn chunk(&self) -> (i32, i32) {
+        (
+            self.position.x.floor() as i32 >> 4,
+            self.position.z.floor() as i32 >> 4,
+        )
+    }
+
+    pub fn is_despawned(&self) -> bool {
+        self.age >= DESPAWN_TICKS || self.position.y < -64.0
+    }
+
+    // whether other can be added to this stack, items close enough touch each other
+    pub fn can_merge(&self, other: &ItemEntity) -> bool {
+        let stack_size =
+            Item::from_id(self.stack.item_id.0 as u32).map_or(1, |item| item.stack_size());
+        self.stack.item_id.0 == other.stack.item_id.0
+            && self.stack.meta.is_none()
+            && other.stack.meta.is_none()
+            && (self.stack.item_count as u32 + other.stack.item_count as u32) <= stack_size
+            && (self.position.x - other.position.x).abs() <= 0.75
+            && (self.position.y - other.position.y).abs() <= 0.25
+            && (self.position.z - other.position.z).abs() <= 0.75
+    }
+
+    pub fn merge(&mut self, other: &ItemEntity) {
+        self.stack.item_count += other.stack.item_count;
+        self.pickup_delay = self.pickup_delay.max(other.pickup_delay);
+        self.age = self.age.min(other.age);
+    }
+
+    // one axis at a time against full blocks
+    fn move_by_velocity(&mut self, world: &mut World) -> Result<()> {
+        let Coordinate { x, y, z } = self.position;
+        let velocity = self.velocity;
+
+        if is_solid(world, x, y + velocity.y, z)? {
+            if velocity.y < 0.0 {
+                self.position.y = (y + velocity.y).floor() + 1.0;
+                self.on_ground = true;
+            }
+            self.velocity.y = 0.0;
+        } else {
+            self.position.y += velocity.y;
+            self.on_ground = false;
+        }
+
+        if is_solid(world, x + velocity.x, self.position.y, z)? {
+            self.velocity.x = 0.0;
+        } else {
+            self.position.x += velocity.x;
+        }
+        if is_solid(world, self.position.x, self.position.y, z + velocity.z)? {
+            self.velocity.z = 0.0;
+        } else {
+            self.position.z += velocity.z;
+        }
+        Ok(())
+    }
+}
+
+fn is_solid(world: &mut World, x: f64, y: f64, z: f64) -> Result<bool> {
+    let (x, z) = (x.floor() as i32, z.floor() as i32);
+    // unloaded chunks stop items like a wall until they are loaded
+    if world.get_chunk(x >> 4, z >> 4)?.is_none() {
+        return Ok(true);
+    }
+    if !(0.0..256.0).contains(&y) {
+        return Ok(false);
+    }
+    let state = world.get_block_state(x as usize, y as usize, z as usize)?;
+    Ok(state.is_some_and(|state| state.block().solid()))
+}
@@ -0,0 +1,180 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{Cursor, Error, ErrorKind, Result},
+    net::IpAddr,
+    str::FromStr,
+};
+
+use openssl::{hash::MessageDigest, memcmp, pkey::PKey, sign::Signer};
+use uuid::Uuid;
+
+use crate::{
+    auth::JsonProperty,
+    packet::client::Properties,
+    protocol::ProtocolRead,
+    types::{Arr, Var},
+};
+
+pub const VELOCITY_CHANNEL: &str = "velocity:player_info";
+const VELOCITY_VERSION: i32 = 1;
+const SIGNATURE_LENGTH: usize = 32;
+
+// how a proxy in front of the server passes on who is connecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forwarding {
+    None,
+    // legacy forwarding in the handshake's server address
+    BungeeCord,
+    // modern forwarding over a login plugin message signed with a shared secret
+    Velocity,
+}
+
+impl FromStr for Forwarding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "none" => Ok(Self::None),
+            "bungeecord" => Ok(Self::BungeeCord),
+            "velocity" => Ok(Self::Velocity),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unknown forwarding {:?}", s),
+            )),
+        }
+    }
+}
+
+impl Display for Forwarding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::None => "none",
+            Self::BungeeCord => "bungeecord",
+            Self::Velocity => "velocity",
+        })
+    }
+}
+
+// the player as the proxy saw them
+#[derive(Debug, Clone)]
+pub struct ForwardedPlayer {
+    pub address: IpAddr,
+    pub uuid: Uuid,
+    // bungeecord leaves the name to login start
+    pub name: Option<String>,
+    pub properties: Vec<Properties>,
+}
+
+// host\0ip\0uuid\0properties, the properties being optional
+pub fn parse_bungeecord(server_address: &str) -> Result<ForwardedPlayer> {
+    let parts = server_address.split('\0').collect::<Vec<_>>();
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(invalid("server address has no forwarded player"));
+    }
+    let address = parts[1]
+        .parse()
+        .map_err(|_| invalid("invalid forwarded address"))?;
+    let uuid = Uuid::parse_str(parts[2]).map_err(|_| invalid("invalid forwarded uuid"))?;
+    let properties = match parts.get(3) {
+        Some(json) => serde_json::from_str::<Vec<JsonProperty>>(json)?
+            .into_iter()
+            .map(JsonProperty::into_properties)
+            .collect(),
+        None => vec![],
+    };
+    Ok(ForwardedPlayer {
+        address,
+        uuid,
+        name: None,
+        properties,
+    })
+}
+
+// an hmac-sha256 of the rest with the forwarding secret, then the player info
+pub fn parse_velocity(data: &[u8], secret: &[u8]) -> Result<ForwardedPlayer> {
+    if secret.is_empty() {
+        return Err(invalid("no forwarding secret is set"));
+    }
+    if data.len() < SIGNATURE_LENGTH {
+        return Err(invalid("forwarded player info is too short"));
+    }
+    let (signature, payload) = data.split_at(SIGNATURE_LENGTH);
+    let key = PKey::hmac(secret)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(payload)?;
+    if !memcmp::eq(signature, &signer.sign_to_vec()?) {
+        return Err(invalid("forwarded player info has a bad signature"));
+    }
+
+    let mut src = Cursor::new(payload);
+    let version = i32::from(<Var<i32>>::proto_decode(&mut src)?);
+    if version != VELOCITY_VERSION {
+        return Err(invalid("unsupported forwarding version"));
+    }
+    let address = String::proto_decode(&mut src)?
+        .parse()
+        .map_err(|_| invalid("invalid forwarded address"))?;
+    let uuid = Uuid::proto_decode(&mut src)?;
+    let name = String::proto_decode(&mut src)?;
+    let properties = <Arr<Var<i32>, Properties>>::proto_decode(&mut src)?;
+    Ok(ForwardedPlayer {
+        address,
+        uuid,
+        name: Some(name),
+        properties,
+    })
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::ProtocolWrite;
+
+    const SECRET: &[u8] = b"secret";
+
+    fn player_info() -> Vec<u8> {
+        let mut payload = vec![];
+        <Var<i32>>::proto_encode(&Var(VELOCITY_VERSION), &mut payload).unwrap();
+        String::proto_encode(&"127.0.0.1".to_string(), &mut payload).unwrap();
+        Uuid::proto_encode(&Uuid::nil(), &mut payload).unwrap();
+        String::proto_encode(&"Notch".to_string(), &mut payload).unwrap();
+        <Arr<Var<i32>, Properties>>::proto_encode(&vec![], &mut payload).unwrap();
+        payload
+    }
+
+    fn sign(secret: &[u8], payload: &[u8]) -> Vec<u8> {
+        let key = PKey::hmac(secret).unwrap();
+        let mut signer = Signer::new(MessageDigest::sha256(), &key).unwrap();
+        signer.update(payload).unwrap();
+        let mut data = signer.sign_to_vec().unwrap();
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn accepts_signed_player_info() {
+        let player = parse_velocity(&sign(SECRET, &player_info()), SECRET).unwrap();
+        assert_eq!(player.address, "127.0.0.1".parse::<IpAddr>().unwrap());
+        assert_eq!(player.uuid, Uuid::nil());
+        assert_eq!(player.name.as_deref(), Some("Notch"));
+        assert!(player.properties.is_empty());
+    }
+
+    #[test]
+    fn rejects_a_bad_signature() {
+        assert!(parse_velocity(&sign(b"other", &player_info()), SECRET).is_err());
+        let mut data = sign(SECRET, &player_info());
+        *data.last_mut().unwrap() ^= 1;
+        assert!(parse_velocity(&data, SECRET).is_err());
+        assert!(parse_velocity(&data[..SIGNATURE_LENGTH - 1], SECRET).is_err());
+    }
+
+    #[test]
+    fn rejects_an_empty_secret() {
+        assert!(parse_velocity(&sign(SECRET, &player_info()), b"").is_err());
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind, Result},
+};
+
+use nbt::{Map, Value};
+
+use crate::types::chunk::Chunk;
+
+mod biome;
+mod flat;
+mod noise;
+mod terrain;
+
+use flat::{FlatGenerator, DEFAULT_PRESET};
+use terrain::TerrainGenerator;
+
+// y of the topmost water block in oceans
+pub const SEA_LEVEL: usize = 62;
+
+// fills in chunks that aren't on disk yet. the same coordinates always give the
+// same chunk, and chunks may be generated from several threads
+pub trait ChunkGenerator: Debug + Send + Sync {
+    fn generate(&self, chunk_x: i32, chunk_z: i32) -> Result<Chunk>;
+
+    // what Join Game tells the client, which draws the horizon lower for flat
+    fn level_type(&self) -> &'static str {
+        "default"
+    }
+
+    // what level.dat keeps as generatorOptions to make the same chunks again
+    fn options(&self) -> Map<String, Value> {
+        Map::new()
+    }
+}
+
+// the generator for server.properties' `level-type` and `generator-settings`
+pub fn create(level_type: &str, settings: &str, seed: i64) -> Result<Box<dyn ChunkGenerator>> {
+    match level_type.to_lowercase().as_str() {
+        "default" => Ok(Box::new(TerrainGenerator::new(seed))),
+        "flat" => {
+            let preset = if settings.trim().is_empty() {
+                DEFAULT_PRESET
+            } else {
+                settings
+            };
+            Ok(Box::new(FlatGenerator::from_preset(preset)?))
+        }
+        level_type => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown level-type {:?}", level_type),
+        )),
+    }
+}
+
+// the generator an existing world was created with, from its level.dat
+pub fn load(
+    generator_name: &str,
+    options: &Map<String, Value>,
+    seed: i64,
+) -> Result<Box<dyn ChunkGenerator>> {
+    match generator_name.to_lowercase().as_str() {
+        "flat" => Ok(Box::new(FlatGenerator::from_options(options)?)),
+        generator_name => create(generator_name, "", seed),
+    }
+}
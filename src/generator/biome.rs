@@ -0,0 +1,93 @@
+use kareki_data::block::Block;
+
+// the biomes the terrain generator places, with their 1.15 ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Biome {
+    Ocean,
+    Plains,
+    Desert,
+    Mountains,
+    Forest,
+    Taiga,
+    Swamp,
+    SnowyTundra,
+    Beach,
+    DeepOcean,
+    Savanna,
+}
+
+impl Biome {
+    pub fn id(self) -> i32 {
+        match self {
+            Biome::Ocean => 0,
+            Biome::Plains => 1,
+            Biome::Desert => 2,
+            Biome::Mountains => 3,
+            Biome::Forest => 4,
+            Biome::Taiga => 5,
+            Biome::Swamp => 6,
+            Biome::SnowyTundra => 12,
+            Biome::Beach => 16,
+            Biome::DeepOcean => 24,
+            Biome::Savanna => 35,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Biome::Ocean => "ocean",
+            Biome::Plains => "plains",
+            Biome::Desert => "desert",
+            Biome::Mountains => "mountains",
+            Biome::Forest => "forest",
+            Biome::Taiga => "taiga",
+            Biome::Swamp => "swamp",
+            Biome::SnowyTundra => "snowy_tundra",
+            Biome::Beach => "beach",
+            Biome::DeepOcean => "deep_ocean",
+            Biome::Savanna => "savanna",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let biome = match name.strip_prefix("minecraft:").unwrap_or(name) {
+            "ocean" => Biome::Ocean,
+            "plains" => Biome::Plains,
+            "desert" => Biome::Desert,
+            "mountains" => Biome::Mountains,
+            "forest" => Biome::Forest,
+            "taiga" => Biome::Taiga,
+            "swamp" => Biome::Swamp,
+            "snowy_tundra" => Biome::SnowyTundra,
+            "beach" => Biome::Beach,
+            "deep_ocean" => Biome::DeepOcean,
+            "savanna" => Biome::Savanna,
+            _ => return None,
+        };
+        Some(biome)
+    }
+
+    // the block on top of a column above water
+    pub fn top_block(self) -> Block {
+        match self {
+            Biome::Desert | Biome::Beach => Block::Sand,
+            Biome::Mountains => Block::Stone,
+            Biome::Ocean | Biome::DeepOcean => Block::Gravel,
+            _ => Block::GrassBlock,
+        }
+    }
+
+    // the few blocks between the top block and stone
+    pub fn filler_block(self) -> Block {
+        match self {
+            Biome::Desert | Biome::Beach => Block::Sand,
+            Biome::Mountains => Block::Stone,
+            Biome::Ocean | Biome::DeepOcean => Block::Gravel,
+            _ => Block::Dirt,
+        }
+    }
+
+    pub fn is_snowy(self) -> bool {
+        self == Biome::SnowyTundra
+    }
+}
@@ -0,0 +1,206 @@
+use std::io::{Error, ErrorKind, Result};
+
+use kareki_data::block::Block;
+use nbt::{Map, Value};
+
+use crate::types::chunk::Chunk;
+
+use super::{biome::Biome, ChunkGenerator};
+
+// vanilla's classic flat
+pub const DEFAULT_PRESET: &str =
+    "minecraft:bedrock,2*minecraft:dirt,minecraft:grass_block;minecraft:plains";
+
+// the same stack of layers everywhere, from the bottom of the world up
+#[derive(Debug, Clone)]
+pub struct FlatGenerator {
+    layers: Vec<Block>,
+    biome: Biome,
+}
+
+impl FlatGenerator {
+    // `[version;]layers[;biome[;structures]]`, where layers are comma separated
+    // `[count*]block` from the bottom up. structures are ignored
+    pub fn from_preset(preset: &str) -> Result<Self> {
+        let mut parts = preset.trim().split(';').peekable();
+        if parts.peek().is_some_and(|part| part.parse::<u32>().is_ok()) {
+            parts.next();
+        }
+
+        let mut layers = vec![];
+        for layer in parts.next().unwrap_or("").split(',') {
+            let layer = layer.trim();
+            if layer.is_empty() {
+                continue;
+            }
+            let (count, name) = match layer.split_once('*') {
+                Some((count, name)) => {
+                    let count = count.parse::<usize>().map_err(|_| {
+                        invalid_preset(format!("invalid layer count in {:?}", layer))
+                    })?;
+                    (count, name)
+                }
+                None => (1, layer),
+            };
+            add_layers(&mut layers, count, block_from_name(name)?)?;
+        }
+
+        let biome = match parts.next().map(str::trim) {
+            Some(name) if !name.is_empty() => Biome::from_name(name)
+                .ok_or_else(|| invalid_preset(format!("unknown biome {:?}", name)))?,
+            _ => Biome::Plains,
+        };
+
+        Ok(Self { layers, biome })
+    }
+
+    // the generatorOptions compound of a flat world's level.dat
+    pub fn from_options(options: &Map<String, Value>) -> Result<Self> {
+        if options.is_empty() {
+            return Self::from_preset(DEFAULT_PRESET);
+        }
+        let mut layers = vec![];
+        if let Some(Value::List(list)) = options.get("layers") {
+            for layer in list {
+                let layer = match layer {
+                    Value::Compound(layer) => layer,
+                    _ => return Err(invalid_preset("layers must be compounds".to_string())),
+                };
+                let count = match layer.get("height") {
+                    Some(Value::Int(height)) => *height as usize,
+                    Some(Value::Short(height)) => *height as usize,
+                    Some(Value::Byte(height)) => *height as usize,
+                    _ => 1,
+                };
+                let name = match layer.get("block") {
+                    Some(Value::String(name)) => name,
+                    _ => return Err(invalid_preset("layer without a block".to_string())),
+                };
+                add_layers(&mut layers, count, block_from_name(name)?)?;
+            }
+        }
+        let biome = match options.get("biome") {
+            Some(Value::String(name)) => Biome::from_name(name)
+                .ok_or_else(|| invalid_preset(format!("unknown biome {:?}", name)))?,
+            _ => Biome::Plains,
+        };
+        Ok(Self { layers, biome })
+    }
+}
+
+impl ChunkGenerator for FlatGenerator {
+    fn generate(&self, _chunk_x: i32, _chunk_z: i32) -> Result<Chunk> {
+        let mut chunk = Chunk::empty();
+        for (y, block) in self.layers.iter().enumerate() {
+            if *block == Block::Air {
+                continue;
+            }
+            for x in 0..16 {
+                for z in 0..16 {
+                    chunk.set_block(x, y, z, *block)?;
+                }
+            }
+        }
+        for biome in chunk.biomes.iter_mut() {
+            *biome = self.biome.id();
+        }
+        Ok(chunk)
+    }
+
+    fn level_type(&self) -> &'static str {
+        "flat"
+    }
+
+    fn options(&self) -> Map<String, Value> {
+        let mut layers: Vec<(Block, i32)> = vec![];
+        for block in self.layers.iter() {
+            match layers.last_mut() {
+                Some((last, height)) if last == block => *height += 1,
+                _ => layers.push((*block, 1)),
+            }
+        }
+        let layers = layers
+            .into_iter()
+            .map(|(block, height)| {
+                let mut layer = Map::new();
+                layer.insert(
+                    "block".to_string(),
+                    Value::String(format!("minecraft:{}", block.name())),
+                );
+                layer.insert("height".to_string(), Value::Int(height));
+                Value::Compound(layer)
+            })
+            .collect();
+
+        let mut options = Map::new();
+        options.insert("layers".to_string(), Value::List(layers));
+        options.insert(
+            "biome".to_string(),
+            Value::String(format!("minecraft:{}", self.biome.name())),
+        );
+        options.insert("structures".to_string(), Value::Compound(Map::new()));
+        options
+    }
+}
+
+fn block_from_name(name: &str) -> Result<Block> {
+    let name = name.strip_prefix("minecraft:").unwrap_or(name);
+    Block::from_name(name).ok_or_else(|| invalid_preset(format!("unknown block {:?}", name)))
+}
+
+// stack count more of block on top, as long as the world is tall enough
+fn add_layers(layers: &mut Vec<Block>, count: usize, block: Block) -> Result<()> {
+    let height = layers
+        .len()
+        .checked_add(count)
+        .filter(|height| *height <= 256)
+        .ok_or_else(|| invalid_preset("the layers don't fit in the world".to_string()))?;
+    layers.resize(height, block);
+    Ok(())
+}
+
+fn invalid_preset(message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("superflat preset: {}", message),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_presets() {
+        let generator = FlatGenerator::from_preset(DEFAULT_PRESET).unwrap();
+        assert_eq!(
+            generator.layers,
+            [Block::Bedrock, Block::Dirt, Block::Dirt, Block::GrassBlock]
+        );
+        assert_eq!(generator.biome, Biome::Plains);
+
+        let generator = FlatGenerator::from_preset("3;stone,3*sand;desert;village").unwrap();
+        assert_eq!(generator.layers.len(), 4);
+        assert_eq!(generator.biome, Biome::Desert);
+    }
+
+    #[test]
+    fn rejects_too_many_layers() {
+        assert!(FlatGenerator::from_preset("256*stone").is_ok());
+        assert!(FlatGenerator::from_preset("256*stone,dirt").is_err());
+        assert!(FlatGenerator::from_preset("stone,18446744073709551615*dirt").is_err());
+    }
+
+    #[test]
+    fn options_round_trip() {
+        let generator = FlatGenerator::from_preset("bedrock,60*stone,dirt;desert").unwrap();
+        let options = generator.options();
+        match options.get("layers") {
+            Some(Value::List(layers)) => assert_eq!(layers.len(), 3),
+            _ => panic!("no layers"),
+        }
+        let loaded = FlatGenerator::from_options(&options).unwrap();
+        assert_eq!(loaded.layers, generator.layers);
+        assert_eq!(loaded.biome, Biome::Desert);
+    }
+}
@@ -0,0 +1,125 @@
+use rand::{seq::SliceRandom, Rng};
+
+// Ken Perlin's improved noise, seeded by shuffling the permutation table
+#[derive(Debug, Clone)]
+pub struct Perlin {
+    permutation: Vec<u8>,
+    offset: (f64, f64, f64),
+}
+
+impl Perlin {
+    pub fn new<R: Rng>(rng: &mut R) -> Self {
+        let mut permutation = (0..=255).collect::<Vec<u8>>();
+        permutation.shuffle(rng);
+        permutation.extend_from_within(..);
+        Self {
+            permutation,
+            offset: (
+                rng.gen_range(0.0, 256.0),
+                rng.gen_range(0.0, 256.0),
+                rng.gen_range(0.0, 256.0),
+            ),
+        }
+    }
+
+    // roughly -1 to 1
+    pub fn noise3(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (x, y, z) = (x + self.offset.0, y + self.offset.1, z + self.offset.2);
+        let (cell_x, cell_y, cell_z) = (x.floor(), y.floor(), z.floor());
+        let (x, y, z) = (x - cell_x, y - cell_y, z - cell_z);
+        let (i, j, k) = (
+            (cell_x as i64 & 255) as usize,
+            (cell_y as i64 & 255) as usize,
+            (cell_z as i64 & 255) as usize,
+        );
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let p = &self.permutation;
+        let a = p[i] as usize + j;
+        let aa = p[a] as usize + k;
+        let ab = p[a + 1] as usize + k;
+        let b = p[i + 1] as usize + j;
+        let ba = p[b] as usize + k;
+        let bb = p[b + 1] as usize + k;
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(p[aa], x, y, z), grad(p[ba], x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(p[ab], x, y - 1.0, z),
+                    grad(p[bb], x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(p[aa + 1], x, y, z - 1.0),
+                    grad(p[ba + 1], x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(p[ab + 1], x, y - 1.0, z - 1.0),
+                    grad(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+}
+
+// several octaves of Perlin noise, each twice the frequency and half the amplitude
+#[derive(Debug, Clone)]
+pub struct Octaves {
+    octaves: Vec<Perlin>,
+}
+
+impl Octaves {
+    pub fn new<R: Rng>(rng: &mut R, count: usize) -> Self {
+        Self {
+            octaves: (0..count).map(|_| Perlin::new(rng)).collect(),
+        }
+    }
+
+    // normalized to roughly -1 to 1
+    pub fn noise3(&self, x: f64, y: f64, z: f64) -> f64 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max = 0.0;
+        for octave in self.octaves.iter() {
+            total += octave.noise3(x * frequency, y * frequency, z * frequency) * amplitude;
+            max += amplitude;
+            amplitude /= 2.0;
+            frequency *= 2.0;
+        }
+        total / max
+    }
+
+    pub fn noise2(&self, x: f64, z: f64) -> f64 {
+        self.noise3(x, 0.0, z)
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+fn grad(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
@@ -0,0 +1,205 @@
+use std::io::Result;
+
+use kareki_data::block::Block;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::types::chunk::Chunk;
+
+use super::{biome::Biome, noise::Octaves, ChunkGenerator, SEA_LEVEL};
+
+// veins per chunk, blocks per vein and the height they stay below
+const ORES: [(Block, u32, u32, usize); 6] = [
+    (Block::CoalOre, 20, 17, 128),
+    (Block::IronOre, 20, 9, 64),
+    (Block::GoldOre, 2, 9, 32),
+    (Block::RedstoneOre, 8, 8, 16),
+    (Block::DiamondOre, 1, 8, 16),
+    (Block::LapisOre, 1, 7, 32),
+];
+// caves below this fill with lava
+const LAVA_LEVEL: usize = 10;
+
+struct Column {
+    height: usize,
+    biome: Biome,
+}
+
+// rolling hills, oceans and the odd mountain range out of layered Perlin noise,
+// with tunnels where two noise fields are both close to zero
+#[derive(Debug)]
+pub struct TerrainGenerator {
+    seed: i64,
+    continents: Octaves,
+    hills: Octaves,
+    mountains: Octaves,
+    temperature: Octaves,
+    humidity: Octaves,
+    tunnels: (Octaves, Octaves),
+}
+
+impl TerrainGenerator {
+    pub fn new(seed: i64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed as u64);
+        Self {
+            seed,
+            continents: Octaves::new(&mut rng, 4),
+            hills: Octaves::new(&mut rng, 4),
+            mountains: Octaves::new(&mut rng, 3),
+            temperature: Octaves::new(&mut rng, 2),
+            humidity: Octaves::new(&mut rng, 2),
+            tunnels: (Octaves::new(&mut rng, 2), Octaves::new(&mut rng, 2)),
+        }
+    }
+
+    fn column(&self, x: i32, z: i32) -> Column {
+        let (x, z) = (x as f64, z as f64);
+        let continent = self.continents.noise2(x / 512.0, z / 512.0);
+        let hills = self.hills.noise2(x / 96.0, z / 96.0);
+        let mountain = (self.mountains.noise2(x / 256.0, z / 256.0) - 0.2).max(0.0);
+        let height = 64.0 + continent * 40.0 + hills * 10.0 + mountain * 120.0;
+        let height = (height as usize).clamp(8, 250);
+
+        let temperature = self.temperature.noise2(x / 600.0, z / 600.0);
+        let humidity = self.humidity.noise2(x / 600.0, z / 600.0);
+        let biome = if height < 45 {
+            Biome::DeepOcean
+        } else if height <= SEA_LEVEL {
+            Biome::Ocean
+        } else if height <= SEA_LEVEL + 2 && temperature > -0.25 {
+            Biome::Beach
+        } else if height > 100 {
+            Biome::Mountains
+        } else if temperature < -0.25 {
+            if humidity > 0.0 {
+                Biome::Taiga
+            } else {
+                Biome::SnowyTundra
+            }
+        } else if temperature > 0.25 {
+            if humidity < 0.0 {
+                Biome::Desert
+            } else {
+                Biome::Savanna
+            }
+        } else if humidity > 0.3 && height < SEA_LEVEL + 6 {
+            Biome::Swamp
+        } else if humidity > 0.0 {
+            Biome::Forest
+        } else {
+            Biome::Plains
+        };
+        Column { height, biome }
+    }
+
+    fn is_tunnel(&self, x: i32, y: usize, z: i32) -> bool {
+        let (x, y, z) = (x as f64 / 48.0, y as f64 / 24.0, z as f64 / 48.0);
+        self.tunnels.0.noise3(x, y, z).abs() < 0.06 && self.tunnels.1.noise3(x, y, z).abs() < 0.06
+    }
+
+    fn chunk_rng(&self, chunk_x: i32, chunk_z: i32) -> StdRng {
+        StdRng::seed_from_u64(
+            self.seed as u64
+                ^ (chunk_x as u64).wrapping_mul(341_873_128_712)
+                ^ (chunk_z as u64).wrapping_mul(132_897_987_541),
+        )
+    }
+
+    fn place_ores(chunk: &mut Chunk, rng: &mut StdRng) -> Result<()> {
+        for &(ore, veins, size, max_y) in ORES.iter() {
+            for _ in 0..veins {
+                let (mut x, mut y, mut z) = (
+                    rng.gen_range(0, 16),
+                    rng.gen_range(0, max_y),
+                    rng.gen_range(0, 16),
+                );
+                for _ in 0..size {
+                    if chunk.get_block(x, y, z) == Some(Block::Stone.default_state() as u16) {
+                        chunk.set_block(x, y, z, ore)?;
+                    }
+                    // wander off to a neighbour, staying inside the chunk
+                    match rng.gen_range(0, 6) {
+                        0 if x > 0 => x -= 1,
+                        1 if x < 15 => x += 1,
+                        2 if y > 0 => y -= 1,
+                        3 if y < max_y => y += 1,
+                        4 if z > 0 => z -= 1,
+                        5 if z < 15 => z += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ChunkGenerator for TerrainGenerator {
+    fn generate(&self, chunk_x: i32, chunk_z: i32) -> Result<Chunk> {
+        let mut chunk = Chunk::empty();
+        let mut rng = self.chunk_rng(chunk_x, chunk_z);
+        let (base_x, base_z) = (chunk_x * 16, chunk_z * 16);
+
+        // indexed x + z * 16
+        let columns = (0..256)
+            .map(|index| self.column(base_x + index % 16, base_z + index / 16))
+            .collect::<Vec<_>>();
+
+        for x in 0..16 {
+            for z in 0..16 {
+                let Column { height, biome } = columns[x + z * 16];
+                let (world_x, world_z) = (base_x + x as i32, base_z + z as i32);
+                let bedrock = rng.gen_range(1, 5);
+                // keep the sea floor sealed so tunnels don't drain into air pockets
+                let tunnel_roof = if height > SEA_LEVEL {
+                    height
+                } else {
+                    height - 4
+                };
+
+                for y in 0..=height.max(SEA_LEVEL) {
+                    let block = if y < bedrock {
+                        Block::Bedrock
+                    } else if y > height {
+                        if y == SEA_LEVEL && biome.is_snowy() {
+                            Block::Ice
+                        } else {
+                            Block::Water
+                        }
+                    } else if y <= tunnel_roof && self.is_tunnel(world_x, y, world_z) {
+                        if y <= LAVA_LEVEL {
+                            Block::Lava
+                        } else {
+                            continue;
+                        }
+                    } else if y == height {
+                        biome.top_block()
+                    } else if y + 4 > height {
+                        biome.filler_block()
+                    } else {
+                        Block::Stone
+                    };
+                    chunk.set_block(x, y, z, block)?;
+                }
+                let has_top = chunk
+                    .get_block(x, height, z)
+                    .is_some_and(|block| block != 0);
+                if biome.is_snowy() && height > SEA_LEVEL && has_top {
+                    chunk.set_block(x, height + 1, z, Block::Snow)?;
+                }
+            }
+        }
+
+        // one biome per 4x4 column of cells, sampled at its centre
+        for cell_x in 0..4 {
+            for cell_z in 0..4 {
+                let biome = columns[cell_x * 4 + 2 + (cell_z * 4 + 2) * 16].biome;
+                for cell_y in 0..64 {
+                    chunk.biomes[cell_y << 4 | cell_z << 2 | cell_x] = biome.id();
+                }
+            }
+        }
+
+        Self::place_ores(&mut chunk, &mut rng)?;
+        Ok(chunk)
+    }
+}
@@ -0,0 +1,372 @@
+use kareki_data::{block::Block, block_state::BlockState};
+
+pub const MAX_HEALTH: f32 = 20.0;
+pub const MAX_FOOD: i32 = 20;
+pub const MAX_AIR: i32 = 300;
+// below this players take void damage every tick
+pub const VOID_Y: f64 = -64.0;
+
+// exhaustion for what players do, 4 of it costs a point of saturation or food
+pub const SPRINT_EXHAUSTION: f32 = 0.1;
+pub const SWIM_EXHAUSTION: f32 = 0.01;
+pub const JUMP_EXHAUSTION: f32 = 0.05;
+pub const SPRINT_JUMP_EXHAUSTION: f32 = 0.2;
+pub const BREAK_EXHAUSTION: f32 = 0.005;
+
+// ticks after being hurt in which only harder hits do damage, counting down from 20
+const INVULNERABLE_TICKS: u32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageCause {
+    Fall,
+    Void,
+    Drown,
+    Starve,
+}
+
+impl DamageCause {
+    // the death message, with the player's name filled in
+    pub fn death_message(self) -> &'static str {
+        match self {
+            DamageCause::Fall => "death.attack.fall",
+            DamageCause::Void => "death.attack.outOfWorld",
+            DamageCause::Drown => "death.attack.drown",
+            DamageCause::Starve => "death.attack.starve",
+        }
+    }
+
+    // the entity status others see the player hurt with
+    pub fn hurt_status(self) -> i8 {
+        match self {
+            DamageCause::Drown => 36,
+            _ => 2,
+        }
+    }
+
+    // hurts players in any game mode, even while they recover from a hit
+    pub fn bypasses_invulnerability(self) -> bool {
+        self == DamageCause::Void
+    }
+}
+
+// health, hunger and breath of a player, normal difficulty rules
+#[derive(Debug, Clone)]
+pub struct Health {
+    pub health: f32,
+    pub food: i32,
+    pub saturation: f32,
+    pub exhaustion: f32,
+    // ticks towards the next point healed or starved
+    food_timer: u32,
+    // blocks fallen since last standing on something
+    pub fall_distance: f32,
+    pub air: i32,
+    invulnerable: u32,
+    last_damage: f32,
+    // what the client was last sent, health, food and whether saturation ran out
+    sent: Option<(f32, i32, bool)>,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            health: MAX_HEALTH,
+            food: MAX_FOOD,
+            saturation: 5.0,
+            exhaustion: 0.0,
+            food_timer: 0,
+            fall_distance: 0.0,
+            air: MAX_AIR,
+            invulnerable: 0,
+            last_damage: 0.0,
+            sent: None,
+        }
+    }
+}
+
+impl Health {
+    pub fn is_dead(&self) -> bool {
+        self.health <= 0.0
+    }
+
+    // whether the client needs an update health since the last time this was asked
+    pub fn changed(&mut self) -> bool {
+        let current = (self.health, self.food, self.saturation == 0.0);
+        let changed = self.sent != Some(current);
+        self.sent = Some(current);
+        changed
+    }
+
+    // take damage unless still recovering from a harder hit, true when it hurt
+    pub fn damage(&mut self, cause: DamageCause, amount: f32) -> bool {
+        if self.is_dead() || amount <= 0.0 {
+            return false;
+        }
+        let amount =
+            if self.invulnerable > INVULNERABLE_TICKS / 2 && !cause.bypasses_invulnerability() {
+                if amount <= self.last_damage {
+                    return false;
+                }
+                // only what the harder hit adds
+                let extra = amount - self.last_damage;
+                self.last_damage = amount;
+                extra
+            } else {
+                self.last_damage = amount;
+                self.invulnerable = INVULNERABLE_TICKS;
+                amount
+            };
+        self.health = (self.health - amount).max(0.0);
+        true
+    }
+
+    pub fn heal(&mut self, amount: f32) {
+        if !self.is_dead() {
+            self.health = (self.health + amount).min(MAX_HEALTH);
+        }
+    }
+
+    pub fn exhaust(&mut self, amount: f32) {
+        self.exhaustion = (self.exhaustion + amount).min(40.0);
+    }
+
+    // the damage a player takes landing after falling this far
+    pub fn land(&mut self) -> f32 {
+        let damage = (self.fall_distance - 3.0).ceil().max(0.0);
+        self.fall_distance = 0.0;
+        damage
+    }
+
+    // one tick of hunger, healing from a full stomach and starving on an empty one.
+    // returns the starvation damage to apply
+    pub fn tick(&mut self, natural_regeneration: bool) -> Option<f32> {
+        self.invulnerable = self.invulnerable.saturating_sub(1);
+
+        if self.exhaustion > 4.0 {
+            self.exhaustion -= 4.0;
+            if self.saturation > 0.0 {
+                self.saturation = (self.saturation - 1.0).max(0.0);
+            } else {
+                self.food = (self.food - 1).max(0);
+            }
+        }
+
+        let hurt = self.health > 0.0 && self.health < MAX_HEALTH;
+        if natural_regeneration && self.saturation > 0.0 && hurt && self.food >= MAX_FOOD {
+            self.food_timer += 1;
+            if self.food_timer >= 10 {
+                let amount = self.saturation.min(6.0);
+                self.heal(amount / 6.0);
+                self.exhaust(amount);
+                self.food_timer = 0;
+            }
+        } else if natural_regeneration && self.food >= 18 && hurt {
+            self.food_timer += 1;
+            if self.food_timer >= 80 {
+                self.heal(1.0);
+                self.exhaust(6.0);
+                self.food_timer = 0;
+            }
+        } else if self.food <= 0 {
+            self.food_timer += 1;
+            if self.food_timer >= 80 {
+                self.food_timer = 0;
+                // normal difficulty starves down to half a heart
+                if self.health > 1.0 {
+                    return Some(1.0);
+                }
+            }
+        } else {
+            self.food_timer = 0;
+        }
+        None
+    }
+
+    // one tick of holding breath, returns the drowning damage to apply
+    pub fn breathe(&mut self, under_water: bool) -> Option<f32> {
+        if !under_water {
+            self.air = (self.air + 4).min(MAX_AIR);
+            return None;
+        }
+        self.air -= 1;
+        if self.air <= -20 {
+            self.air = 0;
+            return Some(2.0);
+        }
+        None
+    }
+}
+
+// water a player can drown in
+pub fn is_water(state: BlockState) -> bool {
+    match state.block() {
+        Block::Water
+        | Block::BubbleColumn
+        | Block::Seagrass
+        | Block::TallSeagrass
+        | Block::Kelp
+        | Block::KelpPlant => true,
+        _ => state.waterlogged() == Some(true),
+    }
+}
+
+// blocks that catch a falling player so they take no fall damage
+pub fn breaks_fall(state: BlockState) -> bool {
+    match state.block() {
+        Block::Lava
+        | Block::Ladder
+        | Block::Vine
+        | Block::Scaffolding
+        | Block::Cobweb
+        | Block::SweetBerryBush => true,
+        _ => is_water(state),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hurt(health: f32) -> Health {
+        Health {
+            health,
+            ..Health::default()
+        }
+    }
+
+    #[test]
+    fn weaker_hits_are_ignored_while_recovering() {
+        let mut health = Health::default();
+        assert!(health.damage(DamageCause::Fall, 4.0));
+        assert!(!health.damage(DamageCause::Fall, 2.0));
+        assert_eq!(health.health, 16.0);
+        // a harder hit only adds what it has over the first
+        assert!(health.damage(DamageCause::Fall, 6.0));
+        assert_eq!(health.health, 14.0);
+
+        // half the invulnerable ticks later any hit counts again
+        for _ in 0..INVULNERABLE_TICKS / 2 {
+            health.tick(false);
+        }
+        assert!(health.damage(DamageCause::Fall, 1.0));
+        assert_eq!(health.health, 13.0);
+    }
+
+    #[test]
+    fn void_bypasses_invulnerability() {
+        let mut health = Health::default();
+        health.damage(DamageCause::Fall, 5.0);
+        assert!(health.damage(DamageCause::Void, 4.0));
+        assert_eq!(health.health, 11.0);
+        assert!(DamageCause::Void.bypasses_invulnerability());
+        assert!(!DamageCause::Drown.bypasses_invulnerability());
+    }
+
+    #[test]
+    fn the_dead_take_no_damage() {
+        let mut health = hurt(1.0);
+        assert!(health.damage(DamageCause::Void, 4.0));
+        assert_eq!(health.health, 0.0);
+        assert!(health.is_dead());
+        assert!(!health.damage(DamageCause::Void, 4.0));
+        health.heal(5.0);
+        assert!(health.is_dead());
+    }
+
+    #[test]
+    fn falls_past_three_blocks_hurt() {
+        let mut health = Health {
+            fall_distance: 4.0,
+            ..Health::default()
+        };
+        assert_eq!(health.land(), 1.0);
+        assert_eq!(health.fall_distance, 0.0);
+        health.fall_distance = 3.0;
+        assert_eq!(health.land(), 0.0);
+        health.fall_distance = 10.5;
+        assert_eq!(health.land(), 8.0);
+    }
+
+    #[test]
+    fn starvation_stops_at_half_a_heart() {
+        let mut health = hurt(3.0);
+        health.food = 0;
+        health.saturation = 0.0;
+        let mut starved = 0;
+        for _ in 0..80 * 10 {
+            if let Some(damage) = health.tick(true) {
+                health.damage(DamageCause::Starve, damage);
+                starved += 1;
+            }
+        }
+        assert_eq!(starved, 2);
+        assert_eq!(health.health, 1.0);
+    }
+
+    #[test]
+    fn saturation_heals_fast_on_a_full_stomach() {
+        let mut health = hurt(10.0);
+        for _ in 0..9 {
+            health.tick(true);
+        }
+        assert_eq!(health.health, 10.0);
+        health.tick(true);
+        // saturation 5 heals 5/6 of a point and costs 5 exhaustion
+        assert!((health.health - (10.0 + 5.0 / 6.0)).abs() < 1e-6);
+        assert_eq!(health.exhaustion, 5.0);
+    }
+
+    #[test]
+    fn food_heals_slowly_without_saturation() {
+        let mut health = hurt(10.0);
+        health.food = 18;
+        health.saturation = 0.0;
+        for _ in 0..79 {
+            health.tick(true);
+        }
+        assert_eq!(health.health, 10.0);
+        health.tick(true);
+        assert_eq!(health.health, 11.0);
+        assert_eq!(health.exhaustion, 6.0);
+
+        // and not at all with the game rule off
+        let mut health = hurt(10.0);
+        for _ in 0..200 {
+            health.tick(false);
+        }
+        assert_eq!(health.health, 10.0);
+    }
+
+    #[test]
+    fn exhaustion_costs_saturation_then_food() {
+        let mut health = Health {
+            saturation: 1.0,
+            ..Health::default()
+        };
+        health.exhaust(4.5);
+        health.tick(false);
+        assert_eq!((health.saturation, health.food), (0.0, MAX_FOOD));
+        health.exhaust(4.0);
+        health.tick(false);
+        assert_eq!((health.saturation, health.food), (0.0, MAX_FOOD - 1));
+    }
+
+    #[test]
+    fn air_runs_out_before_drowning() {
+        let mut health = Health::default();
+        for _ in 0..MAX_AIR + 19 {
+            assert_eq!(health.breathe(true), None);
+        }
+        assert_eq!(health.air, -19);
+        assert_eq!(health.breathe(true), Some(2.0));
+        assert_eq!(health.air, 0);
+
+        // and comes back four a tick
+        assert_eq!(health.breathe(false), None);
+        assert_eq!(health.air, 4);
+        for _ in 0..100 {
+            health.breathe(false);
+        }
+        assert_eq!(health.air, MAX_AIR);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufReader, BufWriter, Error, ErrorKind, Result, Write},
+    path::Path,
+};
+
+use nbt::{Blob, Map, Value};
+
+use crate::types::position::Position;
+
+// 1.15.2
+const DATA_VERSION: i32 = 2230;
+const VERSION_NAME: &str = "1.15.2";
+// anvil
+const STORAGE_VERSION: i32 = 19133;
+
+// contents of `level.dat`
+#[derive(Debug, Clone)]
+pub struct Level {
+    pub name: String,
+    pub spawn: Position,
+    pub seed: i64,
+    pub time: i64,
+    pub day_time: i64,
+    pub game_rules: HashMap<String, String>,
+    // the level type the world was created with and its settings
+    pub generator_name: String,
+    pub generator_options: Map<String, Value>,
+    // the whole `Data` compound, so fields kareki doesn't know about survive a save
+    raw: Map<String, Value>,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self {
+            name: "world".to_string(),
+            spawn: Position { x: 0, y: 64, z: 0 },
+            seed: 0,
+            time: 0,
+            day_time: 0,
+            game_rules: HashMap::new(),
+            generator_name: "default".to_string(),
+            generator_options: Map::new(),
+            raw: Map::new(),
+        }
+    }
+}
+
+impl Level {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Option<Self>> {
+        if !path.as_ref().exists() {
+            return Ok(None);
+        }
+        let mut reader = BufReader::new(File::open(path)?);
+        let blob = Blob::from_gzip_reader(&mut reader).map_err(Error::from)?;
+        let raw = match blob.get("Data") {
+            Some(Value::Compound(data)) => data.clone(),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "level.dat has no Data compound",
+                ))
+            }
+        };
+
+        let default = Self::default();
+        let game_rules = match raw.get("GameRules") {
+            Some(Value::Compound(rules)) => rules
+                .iter()
+                .filter_map(|(name, value)| match value {
+                    Value::String(value) => Some((name.clone(), value.clone())),
+                    _ => None,
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        let generator_options = match raw.get("generatorOptions") {
+            Some(Value::Compound(options)) => options.clone(),
+            _ => Map::new(),
+        };
+
+        Ok(Some(Self {
+            name: get_string(&raw, "LevelName").unwrap_or(default.name),
+            spawn: Position {
+                x: get_int(&raw, "SpawnX").unwrap_or(default.spawn.x),
+                y: get_int(&raw, "SpawnY").map_or(default.spawn.y, |y| y as i16),
+                z: get_int(&raw, "SpawnZ").unwrap_or(default.spawn.z),
+            },
+            seed: get_long(&raw, "RandomSeed").unwrap_or(default.seed),
+            time: get_long(&raw, "Time").unwrap_or(default.time),
+            day_time: get_long(&raw, "DayTime").unwrap_or(default.day_time),
+            game_rules,
+            generator_name: get_string(&raw, "generatorName").unwrap_or(default.generator_name),
+            generator_options,
+            raw,
+        }))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut data = self.raw.clone();
+        if data.is_empty() {
+            // a fresh world, fill in what vanilla needs to open it
+            let mut version = Map::new();
+            version.insert("Id".to_string(), Value::Int(DATA_VERSION));
+            version.insert("Name".to_string(), Value::String(VERSION_NAME.to_string()));
+            version.insert("Snapshot".to_string(), Value::Byte(0));
+            data.insert("Version".to_string(), Value::Compound(version));
+            data.insert("DataVersion".to_string(), Value::Int(DATA_VERSION));
+            data.insert("version".to_string(), Value::Int(STORAGE_VERSION));
+            data.insert("initialized".to_string(), Value::Byte(1));
+        }
+        data.insert(
+            "generatorName".to_string(),
+            Value::String(self.generator_name.clone()),
+        );
+        if !self.generator_options.is_empty() {
+            data.insert(
+                "generatorOptions".to_string(),
+                Value::Compound(self.generator_options.clone()),
+            );
+        }
+        data.insert("LevelName".to_string(), Value::String(self.name.clone()));
+        data.insert("SpawnX".to_string(), Value::Int(self.spawn.x));
+        data.insert("SpawnY".to_string(), Value::Int(self.spawn.y as i32));
+        data.insert("SpawnZ".to_string(), Value::Int(self.spawn.z));
+        data.insert("RandomSeed".to_string(), Value::Long(self.seed));
+        data.insert("Time".to_string(), Value::Long(self.time));
+        data.insert("DayTime".to_string(), Value::Long(self.day_time));
+        let game_rules = self
+            .game_rules
+            .iter()
+            .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+            .collect();
+        data.insert("GameRules".to_string(), Value::Compound(game_rules));
+
+        let mut blob = Blob::new();
+        blob.insert("Data", Value::Compound(data))
+            .map_err(Error::from)?;
+
+        // write to a temporary file first so a crash never leaves a broken level.dat
+        let path = path.as_ref();
+        let new_path = path.with_extension("dat_new");
+        {
+            let mut writer = BufWriter::new(File::create(&new_path)?);
+            blob.to_gzip_writer(&mut writer).map_err(Error::from)?;
+            writer.flush()?;
+        }
+        fs::rename(new_path, path)?;
+
+        Ok(())
+    }
+
+    pub fn game_rule_bool(&self, name: &str, default: bool) -> bool {
+        match self.game_rules.get(name).map(|value| value.as_str()) {
+            Some("true") => true,
+            Some("false") => false,
+            _ => default,
+        }
+    }
+}
+
+fn get_string(data: &Map<String, Value>, name: &str) -> Option<String> {
+    match data.get(name) {
+        Some(Value::String(value)) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn get_int(data: &Map<String, Value>, name: &str) -> Option<i32> {
+    match data.get(name) {
+        Some(Value::Int(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+fn get_long(data: &Map<String, Value>, name: &str) -> Option<i64> {
+    match data.get(name) {
+        Some(Value::Long(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_generator() {
+        let path = env::temp_dir().join(format!("kareki-level-{}.dat", process::id()));
+        let mut options = Map::new();
+        options.insert(
+            "biome".to_string(),
+            Value::String("minecraft:desert".to_string()),
+        );
+        let level = Level {
+            seed: 42,
+            generator_name: "flat".to_string(),
+            generator_options: options.clone(),
+            ..Default::default()
+        };
+        level.save(&path).unwrap();
+
+        let loaded = Level::load(&path).unwrap().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.seed, 42);
+        assert_eq!(loaded.generator_name, "flat");
+        assert_eq!(loaded.generator_options, options);
+    }
+}
@@ -1,108 +1,205 @@
 use std::io::{self, Error};
-use std::str::FromStr;
 
-use crate::packet::client::{self, EncryptionRequest, LoginSuccess, SetCompression};
-use crate::packet::server::{EncryptionResponse, LoginStart};
+use crate::auth::Profile;
+use crate::forwarding::{self, ForwardedPlayer, VELOCITY_CHANNEL};
+use crate::packet::client::{
+    self, Disconnect, EncryptionRequest, LoginPluginRequest, LoginSuccess, SetCompression,
+};
+use crate::packet::server::{EncryptionResponse, LoginPacket, LoginStart};
 use crate::server::Worker;
+use crate::types::chat::Chat;
 
-use openssl::rsa::Padding;
-use uuid::Uuid;
+use openssl::{
+    memcmp,
+    pkey::Private,
+    rsa::{Padding, Rsa},
+};
+use rand::RngCore;
 
-pub async fn crack_login_start(worker: &mut Worker, _login_start: LoginStart) -> Result<(), Error> {
-    // crack
-    let uuid = Uuid::from_str("af6c5ee2-8eeb-8099-b8ce-253c50b0d8a8").unwrap();
-    login_success(worker, uuid.to_string(), "todo".to_string()).await?;
-    worker.state.uuid = Some(uuid);
-    worker.state.name = Some("todo".to_string());
+const VERIFY_TOKEN_LENGTH: usize = 4;
+const SHARED_SECRET_LENGTH: usize = 16;
+
+// bungeecord already sent everything but the name in the handshake
+pub async fn bungeecord_login_start(
+    worker: &mut Worker,
+    login_start: LoginStart,
+    player: ForwardedPlayer,
+) -> Result<(), Error> {
+    forwarded_login(worker, login_start.name, player).await
+}
+
+// ask velocity for the player it authenticated. false when the connection didn't come
+// through it and was disconnected
+pub async fn velocity_login_start(
+    worker: &mut Worker,
+    login_start: LoginStart,
+) -> Result<bool, Error> {
+    let message_id = 0;
+    let packet = client::LoginPacket::LoginPluginRequest(LoginPluginRequest {
+        message_id: message_id.into(),
+        channel: VELOCITY_CHANNEL.to_string(),
+        data: vec![],
+    });
+    worker.write_packet(packet).await?;
+
+    let response = match worker.read_packet_exact().await? {
+        LoginPacket::LoginPluginResponse(response)
+            if i32::from(response.message_id) == message_id =>
+        {
+            response
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "expected a login plugin response",
+            ))
+        }
+    };
+    if !response.successful {
+        let reason = Chat::text("This server requires you to connect with Velocity.");
+        disconnect(worker, reason).await?;
+        return Ok(false);
+    }
+    let secret = worker.config.forwarding_secret.as_bytes();
+    let player = match forwarding::parse_velocity(&response.data, secret) {
+        Ok(player) => player,
+        Err(err) => {
+            println!("velocity forwarding failed: {:?}", err);
+            disconnect(worker, Chat::text("Unable to verify player details.")).await?;
+            return Ok(false);
+        }
+    };
+    let name = player.name.clone().unwrap_or(login_start.name);
+    forwarded_login(worker, name, player).await?;
+    Ok(true)
+}
+
+async fn forwarded_login(
+    worker: &mut Worker,
+    name: String,
+    player: ForwardedPlayer,
+) -> Result<(), Error> {
+    println!(
+        "forwarded login: {} ({}) from {}",
+        name, player.uuid, player.address
+    );
+    worker.state.address = Some(player.address);
+    let profile = Profile {
+        uuid: player.uuid,
+        name,
+        properties: player.properties,
+    };
+    unauthenticated_login(worker, profile).await
+}
+
+// skip encryption, someone else vouched for the player or nobody has to
+async fn unauthenticated_login(worker: &mut Worker, profile: Profile) -> Result<(), Error> {
+    set_compression(worker).await?;
+    login_success(worker, profile.uuid.to_string(), profile.name.clone()).await?;
+    worker.state.uuid = Some(profile.uuid);
+    worker.state.name = Some(profile.name);
+    worker.state.properties = profile.properties;
     worker.state.crack = true;
 
-    return Ok(());
+    Ok(())
+}
+
+// 1 to 16 letters, digits and underscores
+pub fn is_valid_username(name: &str) -> bool {
+    (1..=16).contains(&name.len()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
 pub async fn login_start(worker: &mut Worker, login_start: LoginStart) -> Result<(), Error> {
-    worker.state.rsa = Some(openssl::rsa::Rsa::generate(1024).unwrap());
-    let public_key = worker
-        .state
-        .rsa
-        .as_ref()
-        .expect("maybe not in login mode")
-        .public_key_to_der()?;
-    let verify_token = vec![0u8, 123, 212, 123];
+    let authenticator = worker.authenticator.clone();
+    if !authenticator.requires_encryption() {
+        let profile = authenticator.authenticate(&login_start.name, "").await?;
+        println!("offline login: {} ({})", profile.name, profile.uuid);
+        return unauthenticated_login(worker, profile).await;
+    }
+
+    let public_key = worker.keypair.public_key_to_der()?;
+    let mut verify_token = vec![0; VERIFY_TOKEN_LENGTH];
+    rand::thread_rng().fill_bytes(&mut verify_token);
     println!("login attempt: {}", login_start.name);
     worker.state.name = Some(login_start.name);
+    worker.state.verify_token = verify_token.clone();
     encryption_request(worker, public_key, verify_token).await?;
 
     return Ok(());
 }
 
+// false when the client failed encryption or authentication and was disconnected
 pub async fn encryption_response(
     worker: &mut Worker,
     encryption_response: EncryptionResponse,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     println!("receive encryption response");
-    // use mojang_api::ServerAuthResponse;
-
-    let mut decoded_shared_secret = vec![0; worker.state.rsa.as_ref().unwrap().size() as usize];
-    worker.state.rsa.as_ref().unwrap().private_decrypt(
-        &encryption_response.shared_secret,
-        &mut decoded_shared_secret,
-        Padding::PKCS1,
-    )?;
-    let mut decoded_verify_token = vec![0; worker.state.rsa.as_ref().unwrap().size() as usize];
-    worker.state.rsa.as_ref().unwrap().private_decrypt(
-        &encryption_response.verify_token,
-        &mut decoded_verify_token,
-        Padding::PKCS1,
-    )?;
-    println!("shared_secret: {:?}", decoded_shared_secret);
-    println!("verify_token:  {:?}", decoded_verify_token);
-
-    let mut key = [0u8; 16];
-    for (i, x) in decoded_shared_secret[..16].iter().enumerate() {
-        key[i] = *x;
+
+    let keypair = worker.keypair.clone();
+    let decoded_shared_secret = decrypt(&keypair, &encryption_response.shared_secret);
+    let decoded_shared_secret = match decoded_shared_secret {
+        Ok(secret) if secret.len() == SHARED_SECRET_LENGTH => secret,
+        _ => {
+            disconnect(worker, Chat::text("Invalid shared secret")).await?;
+            return Ok(false);
+        }
+    };
+    let mut key = [0u8; SHARED_SECRET_LENGTH];
+    key.copy_from_slice(&decoded_shared_secret);
+    // the client encrypts everything after its response, disconnects included
+    worker.set_key(&key);
+
+    let decoded_verify_token = decrypt(&keypair, &encryption_response.verify_token);
+    let token_matches = decoded_verify_token.is_ok_and(|token| {
+        token.len() == worker.state.verify_token.len()
+            && memcmp::eq(&token, &worker.state.verify_token)
+    });
+    if !token_matches {
+        disconnect(worker, Chat::text("Invalid verify token")).await?;
+        return Ok(false);
     }
 
-    let server_hash = mojang_api::server_hash(
-        "",
-        key,
-        &worker.state.rsa.as_ref().unwrap().public_key_to_der()?,
-    );
-    let auth_result =
-        mojang_api::server_auth(&server_hash, &worker.state.name.as_ref().unwrap()).await;
+    let server_hash = mojang_api::server_hash("", key, &keypair.public_key_to_der()?);
+    let authenticator = worker.authenticator.clone();
+    let auth_result = authenticator
+        .authenticate(worker.state.name.as_ref().unwrap(), &server_hash)
+        .await;
 
-    let (name, uuid, props) = match auth_result {
-        Ok(auth) => (auth.name, auth.id, auth.properties),
+    let profile = match auth_result {
+        Ok(profile) => profile,
         Err(e) => {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("auth failed {:?}", e),
-            ))
+            println!("auth failed {:?}", e);
+            let reason = Chat::translate("multiplayer.disconnect.unverified_username", vec![]);
+            disconnect(worker, reason).await?;
+            return Ok(false);
         }
     };
 
-    println!(
-        "name: {}, id: {}, props: {:?}, key: {:?}",
-        name, uuid, props, key
-    );
-    worker.set_key(&key);
+    println!("online login: {} ({})", profile.name, profile.uuid);
 
     set_compression(worker).await?;
-    worker.state.name = Some(name.clone());
-    login_success(worker, uuid.to_string(), name).await?;
-    worker.state.uuid = Some(uuid);
+    worker.state.name = Some(profile.name.clone());
+    login_success(worker, profile.uuid.to_string(), profile.name).await?;
+    worker.state.uuid = Some(profile.uuid);
+    worker.state.properties = profile.properties;
 
-    Ok(())
+    Ok(true)
 }
 
-// pub fn disconnect(stream: &mut McStream) -> Result<(), Error> {
-//     let disconnect = Disconnect {
-//         chat: r#"{"text": "konaide ( ; _ ; )"}"#.to_string(),
-//     };
-//     disconnect.packet_write(stream)?;
+fn decrypt(keypair: &Rsa<Private>, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut decrypted = vec![0; keypair.size() as usize];
+    let length = keypair.private_decrypt(data, &mut decrypted, Padding::PKCS1)?;
+    decrypted.truncate(length);
+    Ok(decrypted)
+}
 
-//     println!("disconnected");
-//     Ok(())
-// }
+pub async fn disconnect(worker: &mut Worker, reason: Chat) -> Result<(), Error> {
+    let packet = client::LoginPacket::Disconnect(Disconnect { chat: reason });
+    worker.write_packet(packet).await?;
+
+    println!("disconnected");
+    Ok(())
+}
 
 pub async fn encryption_request(
     worker: &mut Worker,
@@ -121,7 +218,10 @@ pub async fn encryption_request(
 }
 
 pub async fn set_compression(worker: &mut Worker) -> Result<(), Error> {
-    let threshold = 256;
+    let threshold = match worker.config.compression_threshold() {
+        Some(threshold) => threshold,
+        None => return Ok(()),
+    };
     let packet = client::LoginPacket::SetCompression(SetCompression {
         thresshold: (threshold as i32).into(),
     });
@@ -142,3 +242,118 @@ pub async fn login_success(
     println!("login successful");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicUsize, Arc};
+
+    use tokio::net::TcpStream;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::auth::{Authenticator, StubAuthenticator};
+    use crate::config::Config;
+
+    // a worker for the server end of a local connection, with the client end to keep
+    // it open
+    fn connect(authenticator: impl Authenticator + 'static) -> (Worker, std::net::TcpStream) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+        let worker = Worker::new(
+            TcpStream::from_std(stream).unwrap(),
+            Arc::new(Config::default()),
+            Arc::new(authenticator),
+            Arc::new(Rsa::generate(1024).unwrap()),
+            Arc::new(AtomicUsize::new(0)),
+        );
+        (worker, client)
+    }
+
+    fn encrypt(keypair: &Rsa<Private>, data: &[u8]) -> Vec<u8> {
+        let mut encrypted = vec![0; keypair.size() as usize];
+        let length = keypair
+            .public_encrypt(data, &mut encrypted, Padding::PKCS1)
+            .unwrap();
+        encrypted.truncate(length);
+        encrypted
+    }
+
+    fn response(worker: &Worker, verify_token: &[u8]) -> EncryptionResponse {
+        EncryptionResponse {
+            shared_secret: encrypt(&worker.keypair, &[7; SHARED_SECRET_LENGTH]),
+            verify_token: encrypt(&worker.keypair, verify_token),
+        }
+    }
+
+    fn login_start_packet(name: &str) -> LoginStart {
+        LoginStart {
+            name: name.to_string(),
+        }
+    }
+
+    fn profile(name: &str) -> Profile {
+        Profile {
+            uuid: Uuid::from_u128(1),
+            name: name.to_string(),
+            properties: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn logs_in_a_known_profile() {
+        let (mut worker, _client) =
+            connect(StubAuthenticator::new().with_profile(profile("Notch")));
+        login_start(&mut worker, login_start_packet("Notch"))
+            .await
+            .unwrap();
+        assert_eq!(worker.state.verify_token.len(), VERIFY_TOKEN_LENGTH);
+
+        let packet = response(&worker, &worker.state.verify_token.clone());
+        assert!(encryption_response(&mut worker, packet).await.unwrap());
+        assert_eq!(worker.state.uuid, Some(Uuid::from_u128(1)));
+        assert_eq!(worker.state.name.as_deref(), Some("Notch"));
+    }
+
+    #[tokio::test]
+    async fn disconnects_unknown_profiles() {
+        let (mut worker, _client) =
+            connect(StubAuthenticator::new().with_profile(profile("Notch")));
+        login_start(&mut worker, login_start_packet("jeb_"))
+            .await
+            .unwrap();
+
+        let packet = response(&worker, &worker.state.verify_token.clone());
+        assert!(!encryption_response(&mut worker, packet).await.unwrap());
+        assert_eq!(worker.state.uuid, None);
+    }
+
+    #[tokio::test]
+    async fn disconnects_a_wrong_verify_token() {
+        let (mut worker, _client) =
+            connect(StubAuthenticator::new().with_profile(profile("Notch")));
+        login_start(&mut worker, login_start_packet("Notch"))
+            .await
+            .unwrap();
+
+        let mut verify_token = worker.state.verify_token.clone();
+        verify_token[0] ^= 1;
+        let packet = response(&worker, &verify_token);
+        assert!(!encryption_response(&mut worker, packet).await.unwrap());
+        assert_eq!(worker.state.uuid, None);
+    }
+
+    #[tokio::test]
+    async fn disconnects_an_undecryptable_shared_secret() {
+        let (mut worker, _client) =
+            connect(StubAuthenticator::new().with_profile(profile("Notch")));
+        login_start(&mut worker, login_start_packet("Notch"))
+            .await
+            .unwrap();
+
+        let mut packet = response(&worker, &worker.state.verify_token.clone());
+        packet.shared_secret = vec![0; 128];
+        assert!(!encryption_response(&mut worker, packet).await.unwrap());
+        assert_eq!(worker.state.uuid, None);
+    }
+}
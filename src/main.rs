@@ -2,16 +2,36 @@ pub mod packet;
 mod protocol;
 mod types;
 
+mod forwarding;
 mod login;
 mod play;
 mod slp;
 
+mod auth;
 mod client;
+mod command;
+mod config;
+mod crafting;
+mod digging;
+mod entity;
+mod generator;
+mod health;
+mod ops;
+mod placement;
 mod server;
 mod state;
+mod window;
 
+mod level;
+mod region;
 mod world;
 
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use config::Config;
 pub use packet::server::{HandshakePacket, NextState};
 use server::Server;
 
@@ -22,8 +42,22 @@ pub type AesCfb8 = Cfb8<Aes128>;
 
 #[tokio::main]
 async fn main() {
-    let mut server = Server::new("0.0.0.0:25565".to_string()).await;
-    loop {
+    let config = Config::load("server.properties").expect("failed to read server.properties");
+    let mut server = Server::new(config).await;
+
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let running = running.clone();
+        tokio::spawn(async move {
+            tokio::signal::ctrl_c()
+                .await
+                .expect("failed to listen for ctrl-c");
+            running.store(false, Ordering::SeqCst);
+        });
+    }
+
+    while running.load(Ordering::SeqCst) {
         server.loop_once().unwrap();
     }
+    server.shutdown().unwrap();
 }
@@ -0,0 +1,61 @@
+use std::{fs, io::Result, path::Path};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// players allowed to run commands, in the format of vanilla's ops.json
+#[derive(Debug, Clone, Default)]
+pub struct Ops {
+    entries: Vec<Op>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Op {
+    uuid: String,
+    name: String,
+    level: u8,
+    #[serde(default)]
+    bypasses_player_limit: bool,
+}
+
+impl Ops {
+    // a missing file is created without anyone in it
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            fs::write(path, "[]")?;
+            return Ok(Self::default());
+        }
+        let entries = serde_json::from_str(&fs::read_to_string(path)?)?;
+        Ok(Self { entries })
+    }
+
+    // 0 for everyone who isn't an op
+    pub fn permission_level(&self, uuid: Uuid) -> u8 {
+        self.entries
+            .iter()
+            .find(|op| Uuid::parse_str(&op.uuid) == Ok(uuid))
+            .map_or(0, |op| op.level.min(4))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_vanilla_ops() {
+        let entries = serde_json::from_str(
+            r#"[{"uuid":"069a79f4-44e9-4726-a5be-fca90e38aaf5","name":"Notch","level":4,"bypassesPlayerLimit":false},
+                {"uuid":"853c80ef-3c37-49fd-aa49-938b674adae6","name":"jeb_","level":2}]"#,
+        )
+        .unwrap();
+        let ops = Ops { entries };
+        let notch = Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+        let jeb = Uuid::parse_str("853c80ef3c3749fdaa49938b674adae6").unwrap();
+        assert_eq!(ops.permission_level(notch), 4);
+        assert_eq!(ops.permission_level(jeb), 2);
+        assert_eq!(ops.permission_level(Uuid::nil()), 0);
+    }
+}
@@ -1,12 +1,20 @@
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 
 use kareki_macros::{PacketWrite, ProtocolWrite};
+use nbt::Blob;
 use uuid::Uuid;
 
 use crate::{
-    protocol::ProtocolWrite,
+    command::Parser,
+    protocol::{ProtocolRead, ProtocolWrite},
     types::{
-        block_entity::BlockEntity, heightmap::Heightmaps, nbt::Nbt, position::Position, Arr, Var,
+        chat::{Chat, ChatPosition},
+        digging_status::DiggingStatus,
+        heightmap::Heightmaps,
+        nbt::Nbt,
+        position::Position,
+        slot::Slot,
+        Arr, Var,
     },
 };
 
@@ -44,6 +52,7 @@ pub enum LoginPacket {
     EncryptionRequest(EncryptionRequest),
     LoginSuccess(LoginSuccess),
     SetCompression(SetCompression),
+    LoginPluginRequest(LoginPluginRequest),
 }
 impl PacketWriteEnum for LoginPacket {
     fn packet_write<D: Write>(&self, dst: &mut D) -> io::Result<()> {
@@ -52,6 +61,7 @@ impl PacketWriteEnum for LoginPacket {
             LoginPacket::EncryptionRequest(packet) => packet.packet_write(dst),
             LoginPacket::LoginSuccess(packet) => packet.packet_write(dst),
             LoginPacket::SetCompression(packet) => packet.packet_write(dst),
+            LoginPacket::LoginPluginRequest(packet) => packet.packet_write(dst),
         }
     }
 }
@@ -59,7 +69,7 @@ impl PacketWriteEnum for LoginPacket {
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x00]
 pub struct Disconnect {
-    pub chat: String,
+    pub chat: Chat,
 }
 
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
@@ -83,51 +93,186 @@ pub struct SetCompression {
     pub thresshold: Var<i32>,
 }
 
+#[derive(Debug, Clone, PacketWrite)]
+#[packet_id = 0x04]
+pub struct LoginPluginRequest {
+    pub message_id: Var<i32>,
+    pub channel: String,
+    // the rest of the packet, not length prefixed
+    pub data: Vec<u8>,
+}
+
+impl ProtocolWrite for LoginPluginRequest {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        <Var<i32>>::proto_encode(&value.message_id, dst)?;
+        String::proto_encode(&value.channel, dst)?;
+        dst.write_all(&value.data)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
 pub enum PlayPacket {
-    BlockChange(BlockChange),                     // 0x0C
-    DeclareCommands(DeclareCommands),             // 0x12
-    Disconnect(PlayDisconnect),                   // 0x1B
-    EntityStatus(EntityStatus),                   // 0x1C
-    UnloadChunk(UnloadChunk),                     // 0x1E
-    KeepAlive(KeepAlive),                         // 0x21
-    ChunkData(ChunkData),                         // 0x22
-    UpdateLight(UpdateLight),                     // 0x25
-    JoinGame(JoinGame),                           // 0x26
-    PlayerInfo(PlayerInfo),                       // 0x34
-    PlayerPositionAndLook(PlayerPositionAndLook), // 0x36
-    UnlockRecipes(UnlockRecipes),                 // 0x37
-    WorldBorder(WorldBorder),                     // 0x3E
-    HeldItemChange(HeldItemChange),               // 0x40
-    UpdateViewPosition(UpdateViewPosition),       // 0x41
-    SpawnPosition(SpawnPosition),                 // 0x4E
-    DeclareRecipes(DeclareRecipes),               // 0x5B
-    Tags(Tags),                                   // 0x5C
+    SpawnObject(SpawnObject),                             // 0x00
+    SpawnPlayer(SpawnPlayer),                             // 0x05
+    AcknowledgePlayerDigging(AcknowledgePlayerDigging),   // 0x08
+    BlockBreakAnimation(BlockBreakAnimation),             // 0x09
+    BlockEntityData(BlockEntityData),                     // 0x0A
+    BlockChange(BlockChange),                             // 0x0C
+    ChatMessage(ChatMessage),                             // 0x0F
+    MultiBlockChange(MultiBlockChange),                   // 0x10
+    TabComplete(TabComplete),                             // 0x11
+    DeclareCommands(DeclareCommands),                     // 0x12
+    ConfirmTransaction(ConfirmTransaction),               // 0x13
+    CloseWindow(CloseWindow),                             // 0x14
+    WindowItems(WindowItems),                             // 0x15
+    WindowProperty(WindowProperty),                       // 0x16
+    SetSlot(SetSlot),                                     // 0x17
+    Disconnect(PlayDisconnect),                           // 0x1B
+    EntityStatus(EntityStatus),                           // 0x1C
+    UnloadChunk(UnloadChunk),                             // 0x1E
+    ChangeGameState(ChangeGameState),                     // 0x1F
+    KeepAlive(KeepAlive),                                 // 0x21
+    ChunkData(ChunkData),                                 // 0x22
+    UpdateLight(UpdateLight),                             // 0x25
+    JoinGame(JoinGame),                                   // 0x26
+    EntityPosition(EntityPosition),                       // 0x29
+    EntityPositionAndRotation(EntityPositionAndRotation), // 0x2A
+    EntityRotation(EntityRotation),                       // 0x2B
+    OpenWindow(OpenWindow),                               // 0x2F
+    OpenSignEditor(OpenSignEditor),                       // 0x30
+    CraftRecipeResponse(CraftRecipeResponse),             // 0x31
+    PlayerAbilities(PlayerAbilities),                     // 0x32
+    CombatEvent(CombatEvent),                             // 0x33
+    PlayerInfo(PlayerInfo),                               // 0x34
+    PlayerPositionAndLook(PlayerPositionAndLook),         // 0x36
+    UnlockRecipes(UnlockRecipes),                         // 0x37
+    DestroyEntities(DestroyEntities),                     // 0x38
+    Respawn(Respawn),                                     // 0x3B
+    EntityHeadLook(EntityHeadLook),                       // 0x3C
+    WorldBorder(WorldBorder),                             // 0x3E
+    HeldItemChange(HeldItemChange),                       // 0x40
+    UpdateViewPosition(UpdateViewPosition),               // 0x41
+    EntityMetadata(EntityMetadata),                       // 0x44
+    UpdateHealth(UpdateHealth),                           // 0x49
+    SpawnPosition(SpawnPosition),                         // 0x4E
+    TimeUpdate(TimeUpdate),                               // 0x4F
+    CollectItem(CollectItem),                             // 0x56
+    EntityTeleport(EntityTeleport),                       // 0x57
+    DeclareRecipes(DeclareRecipes),                       // 0x5B
+    Tags(Tags),                                           // 0x5C
 }
 impl PacketWriteEnum for PlayPacket {
     fn packet_write<D: Write>(&self, dst: &mut D) -> io::Result<()> {
         match self {
+            PlayPacket::SpawnObject(packet) => packet.packet_write(dst),
+            PlayPacket::SpawnPlayer(packet) => packet.packet_write(dst),
+            PlayPacket::AcknowledgePlayerDigging(packet) => packet.packet_write(dst),
+            PlayPacket::BlockBreakAnimation(packet) => packet.packet_write(dst),
+            PlayPacket::BlockEntityData(packet) => packet.packet_write(dst),
             PlayPacket::BlockChange(packet) => packet.packet_write(dst),
+            PlayPacket::ChatMessage(packet) => packet.packet_write(dst),
+            PlayPacket::MultiBlockChange(packet) => packet.packet_write(dst),
+            PlayPacket::TabComplete(packet) => packet.packet_write(dst),
             PlayPacket::DeclareCommands(packet) => packet.packet_write(dst),
+            PlayPacket::ConfirmTransaction(packet) => packet.packet_write(dst),
+            PlayPacket::CloseWindow(packet) => packet.packet_write(dst),
+            PlayPacket::WindowItems(packet) => packet.packet_write(dst),
+            PlayPacket::WindowProperty(packet) => packet.packet_write(dst),
+            PlayPacket::SetSlot(packet) => packet.packet_write(dst),
             PlayPacket::Disconnect(packet) => packet.packet_write(dst),
             PlayPacket::EntityStatus(packet) => packet.packet_write(dst),
             PlayPacket::UnloadChunk(packet) => packet.packet_write(dst),
+            PlayPacket::ChangeGameState(packet) => packet.packet_write(dst),
             PlayPacket::KeepAlive(packet) => packet.packet_write(dst),
             PlayPacket::ChunkData(packet) => packet.packet_write(dst),
             PlayPacket::UpdateLight(packet) => packet.packet_write(dst),
             PlayPacket::JoinGame(packet) => packet.packet_write(dst),
+            PlayPacket::EntityPosition(packet) => packet.packet_write(dst),
+            PlayPacket::EntityPositionAndRotation(packet) => packet.packet_write(dst),
+            PlayPacket::EntityRotation(packet) => packet.packet_write(dst),
+            PlayPacket::OpenWindow(packet) => packet.packet_write(dst),
+            PlayPacket::OpenSignEditor(packet) => packet.packet_write(dst),
+            PlayPacket::CraftRecipeResponse(packet) => packet.packet_write(dst),
+            PlayPacket::PlayerAbilities(packet) => packet.packet_write(dst),
+            PlayPacket::CombatEvent(packet) => packet.packet_write(dst),
             PlayPacket::PlayerInfo(packet) => packet.packet_write(dst),
             PlayPacket::PlayerPositionAndLook(packet) => packet.packet_write(dst),
             PlayPacket::UnlockRecipes(packet) => packet.packet_write(dst),
+            PlayPacket::DestroyEntities(packet) => packet.packet_write(dst),
+            PlayPacket::Respawn(packet) => packet.packet_write(dst),
+            PlayPacket::EntityHeadLook(packet) => packet.packet_write(dst),
             PlayPacket::WorldBorder(packet) => packet.packet_write(dst),
             PlayPacket::HeldItemChange(packet) => packet.packet_write(dst),
             PlayPacket::UpdateViewPosition(packet) => packet.packet_write(dst),
+            PlayPacket::EntityMetadata(packet) => packet.packet_write(dst),
+            PlayPacket::UpdateHealth(packet) => packet.packet_write(dst),
             PlayPacket::SpawnPosition(packet) => packet.packet_write(dst),
+            PlayPacket::TimeUpdate(packet) => packet.packet_write(dst),
+            PlayPacket::CollectItem(packet) => packet.packet_write(dst),
+            PlayPacket::EntityTeleport(packet) => packet.packet_write(dst),
             PlayPacket::DeclareRecipes(packet) => packet.packet_write(dst),
             PlayPacket::Tags(packet) => packet.packet_write(dst),
         }
     }
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x00]
+pub struct SpawnObject {
+    pub entity_id: Var<i32>,
+    pub object_uuid: Uuid,
+    pub object_type: Var<i32>,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: u8,
+    pub yaw: u8,
+    pub data: i32,
+    // in 1/8000 of a block per tick
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub velocity_z: i16,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x05]
+pub struct SpawnPlayer {
+    pub entity_id: Var<i32>,
+    pub uuid: Uuid,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: u8,
+    pub pitch: u8,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x08]
+pub struct AcknowledgePlayerDigging {
+    pub location: Position,
+    pub block: Var<i32>,
+    pub status: DiggingStatus,
+    pub successful: bool,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x09]
+pub struct BlockBreakAnimation {
+    pub entity_id: Var<i32>,
+    pub location: Position,
+    // 0 to 9, anything else removes the animation
+    pub destroy_stage: i8,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x0A]
+pub struct BlockEntityData {
+    pub location: Position,
+    pub action: u8,
+    pub nbt: Nbt<Blob>,
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x0C]
 pub struct BlockChange {
@@ -135,6 +280,63 @@ pub struct BlockChange {
     pub block_id: Var<i32>,
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x0F]
+pub struct ChatMessage {
+    pub json_data: Chat,
+    pub position: ChatPosition,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x10]
+pub struct MultiBlockChange {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub records: Vec<BlockChangeRecord>,
+}
+
+#[derive(Debug, Clone, ProtocolWrite)]
+pub struct BlockChangeRecord {
+    // x << 4 | z, relative to the chunk
+    pub horizontal_position: u8,
+    pub y: u8,
+    pub block_id: Var<i32>,
+}
+
+#[derive(Debug, Clone, PacketWrite)]
+#[packet_id = 0x11]
+pub struct TabComplete {
+    pub id: Var<i32>,
+    pub start: Var<i32>,
+    pub length: Var<i32>,
+    pub matches: Vec<TabCompleteMatch>,
+}
+
+impl ProtocolWrite for TabComplete {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        <Var<i32>>::proto_encode(&value.id, dst)?;
+        <Var<i32>>::proto_encode(&value.start, dst)?;
+        <Var<i32>>::proto_encode(&value.length, dst)?;
+        <Arr<Var<i32>, TabCompleteMatch>>::proto_encode(&value.matches, dst)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TabCompleteMatch {
+    pub text: String,
+    pub tooltip: Option<Chat>,
+}
+
+impl ProtocolWrite for TabCompleteMatch {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        String::proto_encode(&value.text, dst)?;
+        bool::proto_encode(&value.tooltip.is_some(), dst)?;
+        Option::proto_encode(&value.tooltip, dst)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x12]
 pub struct DeclareCommands {
@@ -148,8 +350,7 @@ pub struct Node {
     pub children: Vec<Var<i32>>,
     pub redirect_node: Option<Var<i32>>,
     pub name: Option<String>,
-    pub parser: Option<String>,
-    // TODO: props
+    pub parser: Option<Parser>,
     pub suggestions_type: Option<String>,
 }
 
@@ -165,10 +366,73 @@ impl ProtocolWrite for Node {
     }
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x13]
+pub struct ConfirmTransaction {
+    pub window_id: i8,
+    pub action_number: i16,
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x14]
+pub struct CloseWindow {
+    pub window_id: u8,
+}
+
+#[derive(Debug, Clone, PacketWrite)]
+#[packet_id = 0x15]
+pub struct WindowItems {
+    pub window_id: u8,
+    pub slot_data: Vec<Option<Slot>>,
+}
+
+impl ProtocolWrite for WindowItems {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        u8::proto_encode(&value.window_id, dst)?;
+        i16::proto_encode(&(value.slot_data.len() as i16), dst)?;
+        for slot in &value.slot_data {
+            write_slot(slot, dst)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x16]
+pub struct WindowProperty {
+    pub window_id: u8,
+    pub property: i16,
+    pub value: i16,
+}
+
+#[derive(Debug, Clone, PacketWrite)]
+#[packet_id = 0x17]
+pub struct SetSlot {
+    pub window_id: i8,
+    pub slot: i16,
+    pub slot_data: Option<Slot>,
+}
+
+impl ProtocolWrite for SetSlot {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        i8::proto_encode(&value.window_id, dst)?;
+        i16::proto_encode(&value.slot, dst)?;
+        write_slot(&value.slot_data, dst)?;
+        Ok(())
+    }
+}
+
+// slots are sent behind a present flag
+fn write_slot<D: Write>(slot: &Option<Slot>, dst: &mut D) -> io::Result<()> {
+    bool::proto_encode(&slot.is_some(), dst)?;
+    Option::proto_encode(slot, dst)
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x1B]
 pub struct PlayDisconnect {
-    pub reason: String,
+    pub reason: Chat,
 }
 
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
@@ -185,6 +449,13 @@ pub struct UnloadChunk {
     pub chunk_z: i32,
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x1F]
+pub struct ChangeGameState {
+    pub reason: u8,
+    pub value: f32,
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x21]
 pub struct KeepAlive {
@@ -201,7 +472,7 @@ pub struct ChunkData {
     pub heightmaps: Nbt<Heightmaps>,
     pub biomes: Option<Vec<i32>>,
     pub data: Vec<u8>,
-    pub block_entities: Vec<Nbt<BlockEntity>>,
+    pub block_entities: Vec<Nbt<Blob>>,
 }
 
 impl ProtocolWrite for ChunkData {
@@ -219,7 +490,7 @@ impl ProtocolWrite for ChunkData {
         <Arr<Var<i32>, u8>>::proto_encode(&value.data, dst)?;
         // <Var<i32>>::proto_encode(&((value.data.len() as i32).into()), dst)?;
         // dst.write_all(&value.data)?; // maybe just write bytes?
-        <Arr<Var<i32>, Nbt<BlockEntity>>>::proto_encode(&value.block_entities, dst)?;
+        <Arr<Var<i32>, Nbt<Blob>>>::proto_encode(&value.block_entities, dst)?;
 
         Ok(())
     }
@@ -271,6 +542,118 @@ pub struct JoinGame {
     pub enable_respawn_screen: bool,
 }
 
+// delta is (current * 32 - prev * 32) * 128
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x29]
+pub struct EntityPosition {
+    pub entity_id: Var<i32>,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x2A]
+pub struct EntityPositionAndRotation {
+    pub entity_id: Var<i32>,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x2B]
+pub struct EntityRotation {
+    pub entity_id: Var<i32>,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x2F]
+pub struct OpenWindow {
+    pub window_id: Var<i32>,
+    pub window_type: Var<i32>,
+    pub window_title: Chat,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x30]
+pub struct OpenSignEditor {
+    pub location: Position,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x31]
+pub struct CraftRecipeResponse {
+    pub window_id: i8,
+    pub recipe: String,
+}
+
+// flags are 0x01 invulnerable, 0x02 flying, 0x04 allow flying and 0x08 instant break
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x32]
+pub struct PlayerAbilities {
+    pub flags: u8,
+    pub flying_speed: f32,
+    pub field_of_view_modifier: f32,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x33]
+pub struct CombatEvent {
+    pub event: CombatEventKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum CombatEventKind {
+    EnterCombat,
+    EndCombat {
+        duration: Var<i32>,
+        entity_id: i32,
+    },
+    // opens the death screen of the player
+    EntityDead {
+        player_id: Var<i32>,
+        entity_id: i32,
+        message: Chat,
+    },
+}
+
+impl ProtocolWrite for CombatEventKind {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        match value {
+            CombatEventKind::EnterCombat => {
+                <Var<i32>>::proto_encode(&0.into(), dst)?;
+            }
+            CombatEventKind::EndCombat {
+                duration,
+                entity_id,
+            } => {
+                <Var<i32>>::proto_encode(&1.into(), dst)?;
+                <Var<i32>>::proto_encode(duration, dst)?;
+                i32::proto_encode(entity_id, dst)?;
+            }
+            CombatEventKind::EntityDead {
+                player_id,
+                entity_id,
+                message,
+            } => {
+                <Var<i32>>::proto_encode(&2.into(), dst)?;
+                <Var<i32>>::proto_encode(player_id, dst)?;
+                i32::proto_encode(entity_id, dst)?;
+                Chat::proto_encode(message, dst)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x34]
 pub struct PlayerInfo {
@@ -295,6 +678,26 @@ impl ProtocolWrite for Properties {
     }
 }
 
+// proxies forward properties in the same layout
+impl ProtocolRead for Properties {
+    fn proto_decode<S: Read>(src: &mut S) -> io::Result<Self> {
+        let name = String::proto_decode(src)?;
+        let value = String::proto_decode(src)?;
+        let is_signed = bool::proto_decode(src)?;
+        let signature = if is_signed {
+            Some(String::proto_decode(src)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            name,
+            value,
+            is_signed,
+            signature,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AddPlayer {
     pub uuid: Uuid,
@@ -393,8 +796,8 @@ pub struct UnlockRecipes {
     pub crafting_recipe_book_filter_active: bool,
     pub smelting_recipe_book_open: bool,
     pub smelting_recipe_book_filter_active: bool,
-    pub recipe_ids: Vec<Var<i32>>,
-    pub additional_recipe_ids: Option<Vec<Var<i32>>>,
+    pub recipe_ids: Vec<String>,
+    pub additional_recipe_ids: Option<Vec<String>>,
 }
 
 impl ProtocolWrite for UnlockRecipes {
@@ -404,16 +807,37 @@ impl ProtocolWrite for UnlockRecipes {
         bool::proto_encode(&value.crafting_recipe_book_filter_active, dst)?;
         bool::proto_encode(&value.smelting_recipe_book_open, dst)?;
         bool::proto_encode(&value.smelting_recipe_book_filter_active, dst)?;
-        <Arr<Var<i32>, Var<i32>>>::proto_encode(&value.recipe_ids, dst)?;
+        <Arr<Var<i32>, String>>::proto_encode(&value.recipe_ids, dst)?;
         // TODO: Optional Array
-        match &value.additional_recipe_ids {
-            Some(v) => <Arr<Var<i32>, Var<i32>>>::proto_encode(v, dst)?,
-            None => {}
-        };
+        if let Some(v) = &value.additional_recipe_ids {
+            <Arr<Var<i32>, String>>::proto_encode(v, dst)?;
+        }
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x38]
+pub struct DestroyEntities {
+    pub entity_ids: Vec<Var<i32>>,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x3B]
+pub struct Respawn {
+    pub dimension: i32,
+    pub hashed_seed: u64,
+    pub gamemode: u8,
+    pub level_type: String,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x3C]
+pub struct EntityHeadLook {
+    pub entity_id: Var<i32>,
+    pub head_yaw: u8,
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x3E]
 pub struct WorldBorder {
@@ -520,12 +944,78 @@ pub struct UpdateViewPosition {
     pub chunk_z: Var<i32>,
 }
 
+#[derive(Debug, Clone, PacketWrite)]
+#[packet_id = 0x44]
+pub struct EntityMetadata {
+    pub entity_id: Var<i32>,
+    pub metadata: Vec<(u8, MetadataValue)>,
+}
+
+impl ProtocolWrite for EntityMetadata {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        <Var<i32>>::proto_encode(&value.entity_id, dst)?;
+        for (index, metadata) in value.metadata.iter() {
+            u8::proto_encode(index, dst)?;
+            match metadata {
+                MetadataValue::Slot(slot) => {
+                    <Var<i32>>::proto_encode(&Var(6), dst)?;
+                    bool::proto_encode(&slot.is_some(), dst)?;
+                    Option::proto_encode(slot, dst)?;
+                }
+            }
+        }
+        u8::proto_encode(&0xff, dst)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MetadataValue {
+    Slot(Option<Slot>),
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x49]
+pub struct UpdateHealth {
+    pub health: f32,
+    pub food: Var<i32>,
+    pub food_saturation: f32,
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x4E]
 pub struct SpawnPosition {
     pub location: Position,
 }
 
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x4F]
+pub struct TimeUpdate {
+    pub world_age: i64,
+    // negative stops the client from advancing the day cycle
+    pub time_of_day: i64,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x56]
+pub struct CollectItem {
+    pub collected_entity_id: Var<i32>,
+    pub collector_entity_id: Var<i32>,
+    pub pickup_item_count: Var<i32>,
+}
+
+#[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
+#[packet_id = 0x57]
+pub struct EntityTeleport {
+    pub entity_id: Var<i32>,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: u8,
+    pub pitch: u8,
+    pub on_ground: bool,
+}
+
 #[derive(Debug, Clone, ProtocolWrite, PacketWrite)]
 #[packet_id = 0x5B]
 pub struct DeclareRecipes {
@@ -534,14 +1024,92 @@ pub struct DeclareRecipes {
 
 #[derive(Debug, Clone)]
 pub struct Recipe {
-    recipe_type: String,
-    recipe_id: String,
+    pub recipe_type: String,
+    pub recipe_id: String,
+    pub data: RecipeData,
 }
 
+#[derive(Debug, Clone)]
+pub enum RecipeData {
+    Shapeless {
+        group: String,
+        ingredients: Vec<Ingredient>,
+        result: Slot,
+    },
+    // ingredients go row by row
+    Shaped {
+        width: i32,
+        height: i32,
+        group: String,
+        ingredients: Vec<Ingredient>,
+        result: Slot,
+    },
+    Smelting {
+        group: String,
+        ingredient: Ingredient,
+        result: Slot,
+        experience: f32,
+        cooking_time: i32,
+    },
+}
+
+// any one of these items
+#[derive(Debug, Clone)]
+pub struct Ingredient(pub Vec<Slot>);
+
 impl ProtocolWrite for Recipe {
     fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
         String::proto_encode(&value.recipe_type, dst)?;
         String::proto_encode(&value.recipe_id, dst)?;
+        match &value.data {
+            RecipeData::Shapeless {
+                group,
+                ingredients,
+                result,
+            } => {
+                String::proto_encode(group, dst)?;
+                <Arr<Var<i32>, Ingredient>>::proto_encode(ingredients, dst)?;
+                write_slot(&Some(result.clone()), dst)?;
+            }
+            RecipeData::Shaped {
+                width,
+                height,
+                group,
+                ingredients,
+                result,
+            } => {
+                <Var<i32>>::proto_encode(&(*width).into(), dst)?;
+                <Var<i32>>::proto_encode(&(*height).into(), dst)?;
+                String::proto_encode(group, dst)?;
+                for ingredient in ingredients {
+                    Ingredient::proto_encode(ingredient, dst)?;
+                }
+                write_slot(&Some(result.clone()), dst)?;
+            }
+            RecipeData::Smelting {
+                group,
+                ingredient,
+                result,
+                experience,
+                cooking_time,
+            } => {
+                String::proto_encode(group, dst)?;
+                Ingredient::proto_encode(ingredient, dst)?;
+                write_slot(&Some(result.clone()), dst)?;
+                f32::proto_encode(experience, dst)?;
+                <Var<i32>>::proto_encode(&(*cooking_time).into(), dst)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ProtocolWrite for Ingredient {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        <Var<i32>>::proto_encode(&(value.0.len() as i32).into(), dst)?;
+        for item in &value.0 {
+            write_slot(&Some(item.clone()), dst)?;
+        }
         Ok(())
     }
 }
@@ -95,6 +95,7 @@ pub struct Ping {
 pub enum LoginPacket {
     LoginStart(LoginStart),
     EncryptionResponse(EncryptionResponse),
+    LoginPluginResponse(LoginPluginResponse),
 }
 
 impl PacketReadEnum for LoginPacket {
@@ -103,6 +104,7 @@ impl PacketReadEnum for LoginPacket {
         Ok(match packet_id {
             0 => LoginPacket::LoginStart(LoginStart::proto_decode(src)?),
             1 => LoginPacket::EncryptionResponse(EncryptionResponse::proto_decode(src)?),
+            2 => LoginPacket::LoginPluginResponse(LoginPluginResponse::proto_decode(src)?),
             _ => {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -124,19 +126,49 @@ pub struct EncryptionResponse {
     pub verify_token: Vec<u8>,
 }
 
+#[derive(Debug, Clone)]
+pub struct LoginPluginResponse {
+    pub message_id: Var<i32>,
+    pub successful: bool,
+    // the rest of the packet, not length prefixed
+    pub data: Vec<u8>,
+}
+
+impl ProtocolRead for LoginPluginResponse {
+    fn proto_decode<S: Read>(src: &mut S) -> io::Result<Self> {
+        let message_id = <Var<i32>>::proto_decode(src)?;
+        let successful = bool::proto_decode(src)?;
+        let mut data = vec![];
+        src.read_to_end(&mut data)?;
+        Ok(Self {
+            message_id,
+            successful,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PlayPacket {
     /* 0x00 */ TeleportConfirm(TeleportConfirm),
+    /* 0x03 */ ChatMessage(ChatMessage),
+    /* 0x04 */ ClientStatus(ClientStatus),
     /* 0x05 */ ClientSettings(ClientSettings),
+    /* 0x06 */ TabComplete(TabComplete),
+    /* 0x07 */ ConfirmTransaction(ConfirmTransaction),
+    /* 0x09 */ ClickWindow(ClickWindow),
+    /* 0x0A */ CloseWindow(CloseWindow),
     /* 0x0F */ KeepAlive(KeepAlive),
     /* 0x11 */ PlayerPosition(PlayerPosition),
     /* 0x12 */ PlayerPositionAndRotation(PlayerPositionAndRotation),
     /* 0x13 */ PlayerRotation(PlayerRotation),
-    /* 0x1A */ PlayerAbilities(PlayerAbilities),
+    /* 0x18 */ CraftRecipeRequest(CraftRecipeRequest),
+    /* 0x19 */ PlayerAbilities(PlayerAbilities),
     /* 0x1A */ PlayerDigging(PlayerDigging),
     /* 0x1B */ EntityAction(EntityAction),
     /* 0x23 */ HeldItemChange(HeldItemChange),
     /* 0x26 */ CreativeInventoryAction(CreativeInventoryAction),
+    /* 0x29 */ UpdateSign(UpdateSign),
     /* 0x2C */ PlayerBlockPlacement(PlayerBlockPlacement),
 }
 
@@ -145,13 +177,20 @@ impl PacketReadEnum for PlayPacket {
         let packet_id = read_packet_meta(src)?;
         Ok(match packet_id {
             0x00 => PlayPacket::TeleportConfirm(TeleportConfirm::proto_decode(src)?),
+            0x03 => PlayPacket::ChatMessage(ChatMessage::proto_decode(src)?),
+            0x04 => PlayPacket::ClientStatus(ClientStatus::proto_decode(src)?),
             0x05 => PlayPacket::ClientSettings(ClientSettings::proto_decode(src)?),
+            0x06 => PlayPacket::TabComplete(TabComplete::proto_decode(src)?),
+            0x07 => PlayPacket::ConfirmTransaction(ConfirmTransaction::proto_decode(src)?),
+            0x09 => PlayPacket::ClickWindow(ClickWindow::proto_decode(src)?),
+            0x0A => PlayPacket::CloseWindow(CloseWindow::proto_decode(src)?),
             0x0F => PlayPacket::KeepAlive(KeepAlive::proto_decode(src)?),
             0x11 => PlayPacket::PlayerPosition(PlayerPosition::proto_decode(src)?),
             0x12 => {
                 PlayPacket::PlayerPositionAndRotation(PlayerPositionAndRotation::proto_decode(src)?)
             }
             0x13 => PlayPacket::PlayerRotation(PlayerRotation::proto_decode(src)?),
+            0x18 => PlayPacket::CraftRecipeRequest(CraftRecipeRequest::proto_decode(src)?),
             0x19 => PlayPacket::PlayerAbilities(PlayerAbilities::proto_decode(src)?),
             0x1A => PlayPacket::PlayerDigging(PlayerDigging::proto_decode(src)?),
             0x1B => PlayPacket::EntityAction(EntityAction::proto_decode(src)?),
@@ -159,6 +198,7 @@ impl PacketReadEnum for PlayPacket {
             0x26 => {
                 PlayPacket::CreativeInventoryAction(CreativeInventoryAction::proto_decode(src)?)
             }
+            0x29 => PlayPacket::UpdateSign(UpdateSign::proto_decode(src)?),
             0x2C => PlayPacket::PlayerBlockPlacement(PlayerBlockPlacement::proto_decode(src)?),
             _ => {
                 return Err(io::Error::new(
@@ -175,6 +215,17 @@ pub struct TeleportConfirm {
     pub teleport_id: Var<i32>,
 }
 
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct ChatMessage {
+    pub message: String,
+}
+
+// action 0 respawns, 1 requests statistics
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct ClientStatus {
+    pub action_id: Var<i32>,
+}
+
 #[derive(Debug, Clone, ProtocolRead)]
 pub struct ClientSettings {
     pub locale: String,
@@ -185,6 +236,47 @@ pub struct ClientSettings {
     pub main_hand: Var<i32>,
 }
 
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct TabComplete {
+    pub transaction_id: Var<i32>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct ConfirmTransaction {
+    pub window_id: i8,
+    pub action_number: i16,
+    pub accepted: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClickWindow {
+    pub window_id: u8,
+    pub slot: i16,
+    pub button: i8,
+    pub action_number: i16,
+    pub mode: Var<i32>,
+    pub clicked_item: Option<Slot>,
+}
+
+impl ProtocolRead for ClickWindow {
+    fn proto_decode<S: Read>(src: &mut S) -> io::Result<Self> {
+        Ok(Self {
+            window_id: u8::proto_decode(src)?,
+            slot: i16::proto_decode(src)?,
+            button: i8::proto_decode(src)?,
+            action_number: i16::proto_decode(src)?,
+            mode: <Var<i32>>::proto_decode(src)?,
+            clicked_item: read_slot(src)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct CloseWindow {
+    pub window_id: u8,
+}
+
 #[derive(Debug, Clone, ProtocolRead)]
 pub struct KeepAlive {
     pub id: i64,
@@ -215,6 +307,13 @@ pub struct PlayerRotation {
     pub on_ground: bool,
 }
 
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct CraftRecipeRequest {
+    pub window_id: i8,
+    pub recipe: String,
+    pub make_all: bool,
+}
+
 #[derive(Debug, Clone, ProtocolRead)]
 pub struct PlayerAbilities {
     pub flags: u8,
@@ -250,17 +349,31 @@ pub struct CreativeInventoryAction {
 impl ProtocolRead for CreativeInventoryAction {
     fn proto_decode<S: Read>(src: &mut S) -> io::Result<Self> {
         let slot = i16::proto_decode(src)?;
-        let present = bool::proto_decode(src)?;
-        let clicked_item = if present {
-            Some(Slot::proto_decode(src)?)
-        } else {
-            None
-        };
+        let clicked_item = read_slot(src)?;
 
         Ok(Self { slot, clicked_item })
     }
 }
 
+#[derive(Debug, Clone, ProtocolRead)]
+pub struct UpdateSign {
+    pub location: Position,
+    pub line_1: String,
+    pub line_2: String,
+    pub line_3: String,
+    pub line_4: String,
+}
+
+// slots come behind a present flag
+fn read_slot<S: Read>(src: &mut S) -> io::Result<Option<Slot>> {
+    let present = bool::proto_decode(src)?;
+    Ok(if present {
+        Some(Slot::proto_decode(src)?)
+    } else {
+        None
+    })
+}
+
 #[derive(Debug, Clone, ProtocolRead)]
 pub struct PlayerBlockPlacement {
     pub hand: Var<i32>,
@@ -0,0 +1,420 @@
+use kareki_data::{
+    block::Block,
+    block_state::{Axis, BlockState, Face, Facing, Half, Hinge, Kind, Part},
+    item::Item,
+};
+
+use crate::{
+    state::Rotation,
+    types::{block_face::BlockFace, position::Position},
+};
+
+// where a block is going and how the player aimed at it
+pub struct PlacementContext {
+    pub position: Position,
+    pub face: BlockFace,
+    // where the clicked face was hit, relative to position
+    pub hit_x: f64,
+    pub hit_y: f64,
+    pub hit_z: f64,
+    pub rotation: Rotation,
+}
+
+// the block an item places, wall variants for torches, signs, banners, heads and coral fans
+pub fn block_for_item(item: Item, face: BlockFace) -> Option<Block> {
+    let name = item.name();
+    let name = match name.as_str() {
+        "wheat_seeds" => "wheat",
+        "pumpkin_seeds" => "pumpkin_stem",
+        "melon_seeds" => "melon_stem",
+        "beetroot_seeds" => "beetroots",
+        "carrot" => "carrots",
+        "potato" => "potatoes",
+        "cocoa_beans" => "cocoa",
+        "sweet_berries" => "sweet_berry_bush",
+        "redstone" => "redstone_wire",
+        "string" => "tripwire",
+        name => name,
+    };
+    if !is_vertical(face) {
+        let wall_name = match name.rsplit_once('_') {
+            Some((prefix, last)) => format!("{}_wall_{}", prefix, last),
+            None => format!("wall_{}", name),
+        };
+        if let Some(block) = Block::from_name(&wall_name) {
+            return Some(block);
+        }
+    }
+    Block::from_name(name)
+}
+
+pub fn is_replaceable(state: BlockState) -> bool {
+    match state.block() {
+        Block::Air
+        | Block::CaveAir
+        | Block::VoidAir
+        | Block::Water
+        | Block::Lava
+        | Block::Grass
+        | Block::Fern
+        | Block::DeadBush
+        | Block::TallGrass
+        | Block::LargeFern
+        | Block::Seagrass
+        | Block::TallSeagrass
+        | Block::Vine
+        | Block::Fire
+        | Block::StructureVoid => true,
+        Block::Snow => state.layers() == Some(1),
+        _ => false,
+    }
+}
+
+// a slab placed onto the empty half of the same slab fills the block. clicking the
+// slab itself only works on its open face
+pub fn double_slab(
+    existing: BlockState,
+    block: Block,
+    face: BlockFace,
+    clicked: bool,
+) -> Option<BlockState> {
+    if existing.block() != block || !block.name().ends_with("_slab") {
+        return None;
+    }
+    let fills = match existing.kind()? {
+        Kind::Bottom => !clicked || face == BlockFace::Top,
+        Kind::Top => !clicked || face == BlockFace::Bottom,
+        _ => false,
+    };
+    let mut state = existing;
+    if fills && state.set_kind(Kind::Double) {
+        Some(state)
+    } else {
+        None
+    }
+}
+
+// every block the placement sets, doors, tall plants and beds take two
+pub fn place(block: Block, context: &PlacementContext) -> Vec<(Position, BlockState)> {
+    let mut state = BlockState::new(block);
+    let face = context.face;
+    let player_facing = horizontal_facing(context.rotation.yaw);
+    let looking = looking_direction(context.rotation);
+    let name = block.name();
+    // clicked the underside, or the upper half of a side
+    let upper_half = face == BlockFace::Bottom || (face != BlockFace::Top && context.hit_y > 0.5);
+
+    if state.axis().is_some() {
+        state.set_axis(match face {
+            BlockFace::Top | BlockFace::Bottom => Axis::Y,
+            BlockFace::North | BlockFace::South => Axis::Z,
+            BlockFace::West | BlockFace::East => Axis::X,
+        });
+    }
+
+    if state.face().is_some() {
+        let (attach, facing) = match face {
+            BlockFace::Top => (Face::Floor, player_facing),
+            BlockFace::Bottom => (Face::Ceiling, player_facing),
+            _ => (Face::Wall, face),
+        };
+        state.set_face(attach);
+        state.set_facing(facing_property(facing));
+    } else if name.ends_with("trapdoor") {
+        if is_vertical(face) {
+            state.set_facing(facing_property(opposite(player_facing)));
+        } else {
+            state.set_facing(facing_property(face));
+        }
+        state.set_half(if upper_half { Half::Top } else { Half::Bottom });
+    } else if name.ends_with("stairs")
+        || name.ends_with("fence_gate")
+        || state.hinge().is_some()
+        || state.part().is_some()
+    {
+        state.set_facing(facing_property(player_facing));
+    } else if state.facing().is_some() {
+        let facing = if name.contains("wall_") {
+            face
+        } else if block == Block::Hopper {
+            if is_vertical(face) {
+                BlockFace::Bottom
+            } else {
+                opposite(face)
+            }
+        } else if block == Block::Observer {
+            looking
+        } else {
+            opposite(looking)
+        };
+        // blocks that only face sideways turn towards the player instead
+        if !state.set_facing(facing_property(facing)) {
+            state.set_facing(facing_property(opposite(player_facing)));
+        }
+    }
+
+    if name.ends_with("stairs") {
+        state.set_half(if upper_half { Half::Top } else { Half::Bottom });
+    }
+    if name.ends_with("_slab") {
+        state.set_kind(if upper_half { Kind::Top } else { Kind::Bottom });
+    }
+    if state.rotation().is_some() {
+        // heads face the player, signs and banners face away
+        let yaw = if name.ends_with("_skull") || name.ends_with("_head") {
+            context.rotation.yaw
+        } else {
+            context.rotation.yaw + 180.0
+        };
+        state.set_rotation(((yaw * 16.0 / 360.0 + 0.5).floor() as i32 & 15) as u8);
+    }
+    if state.hinge().is_some() {
+        state.set_hinge(door_hinge(player_facing, context));
+    }
+
+    let position = context.position;
+    let mut blocks = vec![(position, state)];
+    if state.half() == Some(Half::Lower) {
+        let mut upper = state;
+        upper.set_half(Half::Upper);
+        blocks.push((position.offset(BlockFace::Top), upper));
+    }
+    if state.part().is_some() {
+        let mut head = state;
+        head.set_part(Part::Head);
+        blocks.push((position.offset(player_facing), head));
+    }
+    blocks
+}
+
+// where the other half of a door, tall plant or bed is, it goes when this half does
+pub fn partner_position(position: Position, state: BlockState) -> Option<Position> {
+    match (state.half(), state.part()) {
+        (Some(Half::Lower), _) => Some(position.offset(BlockFace::Top)),
+        (Some(Half::Upper), _) => Some(position.offset(BlockFace::Bottom)),
+        (_, Some(part)) => {
+            let facing = block_face(state.facing()?);
+            let towards = if part == Part::Foot {
+                facing
+            } else {
+                opposite(facing)
+            };
+            Some(position.offset(towards))
+        }
+        _ => None,
+    }
+}
+
+// whether other is the rest of the block state belongs to, not just the same block
+pub fn is_partner(state: BlockState, other: BlockState) -> bool {
+    other.block() == state.block() && (other.half(), other.part()) != (state.half(), state.part())
+}
+
+// vanilla's fallback when no neighbouring door decides the hinge
+fn door_hinge(facing: BlockFace, context: &PlacementContext) -> Hinge {
+    let right = match facing {
+        BlockFace::West => context.hit_z < 0.5,
+        BlockFace::East => context.hit_z > 0.5,
+        BlockFace::North => context.hit_x > 0.5,
+        BlockFace::South => context.hit_x < 0.5,
+        _ => false,
+    };
+    if right {
+        Hinge::Right
+    } else {
+        Hinge::Left
+    }
+}
+
+fn horizontal_facing(yaw: f32) -> BlockFace {
+    match (yaw / 90.0 + 0.5).floor() as i32 & 3 {
+        0 => BlockFace::South,
+        1 => BlockFace::West,
+        2 => BlockFace::North,
+        _ => BlockFace::East,
+    }
+}
+
+fn looking_direction(rotation: Rotation) -> BlockFace {
+    if rotation.pitch > 45.0 {
+        BlockFace::Bottom
+    } else if rotation.pitch < -45.0 {
+        BlockFace::Top
+    } else {
+        horizontal_facing(rotation.yaw)
+    }
+}
+
+fn is_vertical(face: BlockFace) -> bool {
+    matches!(face, BlockFace::Top | BlockFace::Bottom)
+}
+
+fn opposite(face: BlockFace) -> BlockFace {
+    match face {
+        BlockFace::Bottom => BlockFace::Top,
+        BlockFace::Top => BlockFace::Bottom,
+        BlockFace::North => BlockFace::South,
+        BlockFace::South => BlockFace::North,
+        BlockFace::West => BlockFace::East,
+        BlockFace::East => BlockFace::West,
+    }
+}
+
+fn block_face(facing: Facing) -> BlockFace {
+    match facing {
+        Facing::Down => BlockFace::Bottom,
+        Facing::Up => BlockFace::Top,
+        Facing::North => BlockFace::North,
+        Facing::South => BlockFace::South,
+        Facing::West => BlockFace::West,
+        Facing::East => BlockFace::East,
+    }
+}
+
+fn facing_property(face: BlockFace) -> Facing {
+    match face {
+        BlockFace::Bottom => Facing::Down,
+        BlockFace::Top => Facing::Up,
+        BlockFace::North => Facing::North,
+        BlockFace::South => Facing::South,
+        BlockFace::West => Facing::West,
+        BlockFace::East => Facing::East,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGIN: Position = Position { x: 0, y: 64, z: 0 };
+
+    fn context(face: BlockFace, hit: (f64, f64, f64), yaw: f32) -> PlacementContext {
+        PlacementContext {
+            position: ORIGIN,
+            face,
+            hit_x: hit.0,
+            hit_y: hit.1,
+            hit_z: hit.2,
+            rotation: Rotation { yaw, pitch: 0.0 },
+        }
+    }
+
+    fn placed(block: Block, face: BlockFace, hit: (f64, f64, f64), yaw: f32) -> BlockState {
+        place(block, &context(face, hit, yaw))[0].1
+    }
+
+    #[test]
+    fn stairs_face_the_player_and_take_the_clicked_half() {
+        let cases = [
+            (BlockFace::Top, 1.0, 0.0, Facing::South, Half::Bottom),
+            (BlockFace::Bottom, 0.0, 90.0, Facing::West, Half::Top),
+            (BlockFace::North, 0.7, 180.0, Facing::North, Half::Top),
+            (BlockFace::East, 0.2, 270.0, Facing::East, Half::Bottom),
+            (BlockFace::South, 0.5, -90.0, Facing::East, Half::Bottom),
+        ];
+        for &(face, hit_y, yaw, facing, half) in cases.iter() {
+            let state = placed(Block::OakStairs, face, (0.5, hit_y, 0.5), yaw);
+            assert_eq!(state.facing(), Some(facing), "{:?} at {}", face, yaw);
+            assert_eq!(state.half(), Some(half), "{:?} at {}", face, hit_y);
+        }
+    }
+
+    #[test]
+    fn slabs_take_the_clicked_half() {
+        let cases = [
+            (BlockFace::Top, 1.0, Kind::Bottom),
+            (BlockFace::Bottom, 0.0, Kind::Top),
+            (BlockFace::West, 0.8, Kind::Top),
+            (BlockFace::West, 0.3, Kind::Bottom),
+        ];
+        for &(face, hit_y, kind) in cases.iter() {
+            let state = placed(Block::OakSlab, face, (0.5, hit_y, 0.5), 0.0);
+            assert_eq!(state.kind(), Some(kind), "{:?} at {}", face, hit_y);
+        }
+    }
+
+    #[test]
+    fn logs_follow_the_clicked_face() {
+        let cases = [
+            (BlockFace::Top, Axis::Y),
+            (BlockFace::Bottom, Axis::Y),
+            (BlockFace::North, Axis::Z),
+            (BlockFace::South, Axis::Z),
+            (BlockFace::West, Axis::X),
+            (BlockFace::East, Axis::X),
+        ];
+        for &(face, axis) in cases.iter() {
+            let state = placed(Block::OakLog, face, (0.5, 0.5, 0.5), 0.0);
+            assert_eq!(state.axis(), Some(axis), "{:?}", face);
+        }
+    }
+
+    #[test]
+    fn doors_place_both_halves_with_the_hinge_on_the_clicked_side() {
+        let blocks = place(
+            Block::OakDoor,
+            &context(BlockFace::Top, (0.7, 1.0, 0.5), 180.0),
+        );
+        assert_eq!(blocks.len(), 2);
+        let (lower, upper) = (blocks[0], blocks[1]);
+        assert_eq!(lower.0, ORIGIN);
+        assert_eq!(upper.0, ORIGIN.offset(BlockFace::Top));
+        assert_eq!(lower.1.half(), Some(Half::Lower));
+        assert_eq!(upper.1.half(), Some(Half::Upper));
+        assert_eq!(upper.1.facing(), Some(Facing::North));
+
+        let cases = [
+            (180.0, (0.7, 1.0, 0.5), Hinge::Right),
+            (180.0, (0.3, 1.0, 0.5), Hinge::Left),
+            (0.0, (0.3, 1.0, 0.5), Hinge::Right),
+            (90.0, (0.5, 1.0, 0.2), Hinge::Right),
+            (270.0, (0.5, 1.0, 0.2), Hinge::Left),
+        ];
+        for &(yaw, hit, hinge) in cases.iter() {
+            let blocks = place(Block::OakDoor, &context(BlockFace::Top, hit, yaw));
+            assert_eq!(blocks[0].1.hinge(), Some(hinge), "{} at {:?}", yaw, hit);
+            assert_eq!(blocks[1].1.hinge(), Some(hinge));
+        }
+    }
+
+    #[test]
+    fn beds_put_their_head_where_the_player_looks() {
+        let cases = [
+            (0.0, BlockFace::South),
+            (90.0, BlockFace::West),
+            (180.0, BlockFace::North),
+            (270.0, BlockFace::East),
+        ];
+        for &(yaw, towards) in cases.iter() {
+            let blocks = place(
+                Block::RedBed,
+                &context(BlockFace::Top, (0.5, 1.0, 0.5), yaw),
+            );
+            let (foot, head) = (blocks[0], blocks[1]);
+            assert_eq!(foot.1.part(), Some(Part::Foot));
+            assert_eq!(head.1.part(), Some(Part::Head));
+            assert_eq!(head.0, ORIGIN.offset(towards), "{}", yaw);
+
+            // each half finds the other
+            assert_eq!(partner_position(foot.0, foot.1), Some(head.0));
+            assert_eq!(partner_position(head.0, head.1), Some(foot.0));
+            assert!(is_partner(foot.1, head.1));
+            assert!(!is_partner(foot.1, foot.1));
+        }
+    }
+
+    #[test]
+    fn door_halves_find_each_other() {
+        let blocks = place(
+            Block::OakDoor,
+            &context(BlockFace::Top, (0.5, 1.0, 0.5), 0.0),
+        );
+        let (lower, upper) = (blocks[0], blocks[1]);
+        assert_eq!(partner_position(lower.0, lower.1), Some(upper.0));
+        assert_eq!(partner_position(upper.0, upper.1), Some(lower.0));
+        assert_eq!(
+            partner_position(ORIGIN, BlockState::new(Block::Stone)),
+            None
+        );
+    }
+}
@@ -1,23 +1,46 @@
 use std::io::Result;
 
+use kareki_data::{
+    item::Item,
+    recipe::{Recipe, RecipeKind, RECIPES},
+};
+
 use crate::client::Client;
-use crate::packet::client::{
-    AddPlayer, DeclareCommands, DeclareRecipes, EntityStatus, HeldItemChange, JoinGame, PlayPacket,
-    PlayerInfo, PlayerInfoAction, PlayerPositionAndLook, SpawnPosition, Tags, UnlockRecipes,
-    UpdateViewPosition, WorldBorder, WorldBorderAction,
+use crate::entity::{ItemEntity, ITEM_ENTITY_TYPE};
+use crate::level::Level;
+use crate::packet::{
+    self,
+    client::{
+        AddPlayer, BlockChange, ChangeGameState, ChatMessage, CloseWindow, CombatEvent,
+        CombatEventKind, ConfirmTransaction, CraftRecipeResponse, DeclareCommands, DeclareRecipes,
+        DestroyEntities, EntityHeadLook, EntityMetadata, EntityStatus, HeldItemChange, Ingredient,
+        JoinGame, MetadataValue, OpenSignEditor, PlayPacket, PlayerAbilities, PlayerInfo,
+        PlayerInfoAction, PlayerPositionAndLook, RecipeData, Respawn, SetSlot, SpawnObject,
+        SpawnPlayer, SpawnPosition, Tags, TimeUpdate, UnlockRecipes, UpdateHealth,
+        UpdateViewPosition, WindowItems, WindowProperty, WorldBorder, WorldBorderAction,
+    },
 };
+use crate::state::{GameMode, State};
+use crate::types::chat::{Chat, ChatPosition, ClickAction, HoverAction};
 
 use crate::types::position::Position;
+use crate::types::slot::Slot;
+use crate::window::{self, OpenWindow, PLAYER_WINDOW};
 
-pub fn join_game(client: &mut Client) -> Result<()> {
+pub fn join_game(
+    client: &mut Client,
+    level_type: &str,
+    max_players: u32,
+    view_distance: u8,
+) -> Result<()> {
     let packet = PlayPacket::JoinGame(JoinGame {
-        entity_id: 0,
-        game_mode: 1,
+        entity_id: client.state.entity_id,
+        game_mode: client.state.game_mode.id(),
         dimension: 0,
         hashed_seed: 0,
-        max_players: 3,
-        level_type: "flat".to_owned(),
-        view_distance: 4.into(),
+        max_players: max_players.min(u8::MAX as u32) as u8,
+        level_type: level_type.to_owned(),
+        view_distance: (view_distance as i32).into(),
         reduced_debug_info: false,
         enable_respawn_screen: true,
     });
@@ -35,7 +58,8 @@ pub fn held_item_change(client: &mut Client) -> Result<()> {
 }
 
 pub fn declare_recipes(client: &mut Client) -> Result<()> {
-    let packet = PlayPacket::DeclareRecipes(DeclareRecipes { recipes: vec![] });
+    let recipes = RECIPES.iter().map(recipe).collect();
+    let packet = PlayPacket::DeclareRecipes(DeclareRecipes { recipes });
     client.send_play_packet(packet)?;
 
     Ok(())
@@ -53,27 +77,73 @@ pub fn tags(client: &mut Client) -> Result<()> {
     Ok(())
 }
 
+// the player's op permission level, which the client needs for some commands
 pub fn entity_status(client: &mut Client) -> Result<()> {
     let packet = PlayPacket::EntityStatus(EntityStatus {
-        entity_id: 0,
-        entity_status: 2,
+        entity_id: client.state.entity_id,
+        entity_status: 24 + client.state.permission_level as i8,
     });
     client.send_play_packet(packet)?;
 
     Ok(())
 }
 
-#[allow(dead_code)]
-pub fn declare_commands(client: &mut Client) -> Result<()> {
-    let packet = PlayPacket::DeclareCommands(DeclareCommands {
-        nodes: vec![],
-        root_index: 0.into(),
+// the status of another entity, like hurt or dying
+pub fn entity_event(client: &Client, entity_id: i32, entity_status: i8) -> Result<()> {
+    let packet = PlayPacket::EntityStatus(EntityStatus {
+        entity_id,
+        entity_status,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn update_health(client: &Client) -> Result<()> {
+    let health = &client.state.health;
+    let packet = PlayPacket::UpdateHealth(UpdateHealth {
+        health: health.health,
+        food: health.food.into(),
+        food_saturation: health.saturation,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// shows the death screen with the death message
+pub fn combat_death(client: &Client, message: Chat) -> Result<()> {
+    let packet = PlayPacket::CombatEvent(CombatEvent {
+        event: CombatEventKind::EntityDead {
+            player_id: client.state.entity_id.into(),
+            entity_id: -1,
+            message,
+        },
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn respawn(client: &Client, level_type: &str) -> Result<()> {
+    let packet = PlayPacket::Respawn(Respawn {
+        dimension: 0,
+        hashed_seed: 0,
+        gamemode: client.state.game_mode.id(),
+        level_type: level_type.to_owned(),
     });
     client.send_play_packet(packet)?;
 
     Ok(())
 }
 
+pub fn declare_commands(client: &mut Client, commands: DeclareCommands) -> Result<()> {
+    let packet = PlayPacket::DeclareCommands(commands);
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
 pub fn unlock_recipes(client: &mut Client) -> Result<()> {
     let packet = PlayPacket::UnlockRecipes(UnlockRecipes {
         action: 0.into(),
@@ -81,7 +151,10 @@ pub fn unlock_recipes(client: &mut Client) -> Result<()> {
         crafting_recipe_book_filter_active: false,
         smelting_recipe_book_open: false,
         smelting_recipe_book_filter_active: false,
-        recipe_ids: vec![],
+        recipe_ids: RECIPES
+            .iter()
+            .map(|recipe| recipe.name.to_string())
+            .collect(),
         additional_recipe_ids: Some(vec![]),
     });
     client.send_play_packet(packet)?;
@@ -89,13 +162,66 @@ pub fn unlock_recipes(client: &mut Client) -> Result<()> {
     Ok(())
 }
 
+fn recipe(recipe: &Recipe) -> packet::client::Recipe {
+    let ingredient = |items: &&[Item]| {
+        Ingredient(
+            items
+                .iter()
+                .map(|item| window::stack_of(*item, 1))
+                .collect(),
+        )
+    };
+    let ingredients = recipe.ingredients.iter().map(ingredient).collect();
+    let result = window::stack_of(recipe.result, recipe.count);
+    let (recipe_type, data) = match recipe.kind {
+        RecipeKind::Shaped { width, height } => (
+            "minecraft:crafting_shaped",
+            RecipeData::Shaped {
+                width: width as i32,
+                height: height as i32,
+                group: String::new(),
+                ingredients,
+                result,
+            },
+        ),
+        RecipeKind::Shapeless => (
+            "minecraft:crafting_shapeless",
+            RecipeData::Shapeless {
+                group: String::new(),
+                ingredients,
+                result,
+            },
+        ),
+        RecipeKind::Smelting {
+            experience,
+            cooking_time,
+        } => (
+            "minecraft:smelting",
+            RecipeData::Smelting {
+                group: String::new(),
+                ingredient: ingredient(&recipe.ingredients[0]),
+                result,
+                experience,
+                cooking_time: cooking_time as i32,
+            },
+        ),
+    };
+    packet::client::Recipe {
+        recipe_type: recipe_type.to_string(),
+        recipe_id: recipe.name.to_string(),
+        data,
+    }
+}
+
 pub fn play_position_and_look(client: &mut Client) -> Result<()> {
+    let coordinate = client.state.coordinate;
+    let rotation = client.state.rotation;
     let packet = PlayPacket::PlayerPositionAndLook(PlayerPositionAndLook {
-        x: 0.0,
-        y: 64.0,
-        z: 0.0,
-        yaw: 0.0,
-        pitch: 0.0,
+        x: coordinate.x,
+        y: coordinate.y,
+        z: coordinate.z,
+        yaw: rotation.yaw,
+        pitch: rotation.pitch,
         flags: 0,
         teleport_id: 0.into(),
     });
@@ -104,24 +230,42 @@ pub fn play_position_and_look(client: &mut Client) -> Result<()> {
     Ok(())
 }
 
-pub fn player_info(client: &mut Client) -> Result<()> {
-    let packet = PlayPacket::PlayerInfo(PlayerInfo {
-        action: PlayerInfoAction::AddPlayer(vec![AddPlayer {
-            uuid: client.state.uuid.as_ref().unwrap().clone(),
-            name: client.state.name.as_ref().unwrap().to_string(),
-            props: vec![
-                // Properties {
-                //     name: "test".to_owned(),
-                //     value: "var".to_owned(),
-                //     is_signed: true,
-                //     signature: Some("yoyo".to_owned()),
-                // }
-            ],
-            gamemode: 0.into(),
-            ping: 1.into(),
-            has_display_name: false,
-            display_name: None,
-        }]),
+pub fn player_info(client: &Client, action: PlayerInfoAction) -> Result<()> {
+    let packet = PlayPacket::PlayerInfo(PlayerInfo { action });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn player_info_entry(state: &State) -> AddPlayer {
+    AddPlayer {
+        uuid: state.uuid.unwrap(),
+        name: state.name.as_ref().unwrap().to_string(),
+        props: state.properties.clone(),
+        gamemode: (state.game_mode.id() as i32).into(),
+        ping: 1.into(),
+        has_display_name: false,
+        display_name: None,
+    }
+}
+
+// player name as vanilla shows it in chat: shift-click to insert, click to whisper
+pub fn player_name(state: &State) -> Chat {
+    let name = state.name.as_ref().unwrap();
+    let uuid = state.uuid.unwrap();
+    Chat::text(name.as_str())
+        .insertion(name.as_str())
+        .click_event(ClickAction::SuggestCommand, format!("/tell {} ", name))
+        .hover_event(
+            HoverAction::ShowText,
+            Chat::text(format!("{}\n{}", name, uuid)),
+        )
+}
+
+pub fn chat_message(client: &Client, message: Chat, position: ChatPosition) -> Result<()> {
+    let packet = PlayPacket::ChatMessage(ChatMessage {
+        json_data: message,
+        position,
     });
     client.send_play_packet(packet)?;
 
@@ -130,8 +274,8 @@ pub fn player_info(client: &mut Client) -> Result<()> {
 
 pub fn update_view_position(client: &mut Client) -> Result<()> {
     let packet = PlayPacket::UpdateViewPosition(UpdateViewPosition {
-        chunk_x: 0.into(),
-        chunk_z: 1.into(),
+        chunk_x: client.state.last_chunk_x.into(),
+        chunk_z: client.state.last_chunk_z.into(),
     });
     client.send_play_packet(packet)?;
 
@@ -148,9 +292,264 @@ pub fn world_border(client: &mut Client) -> Result<()> {
     Ok(())
 }
 
-pub fn spawn_position(client: &mut Client) -> Result<()> {
-    let packet = PlayPacket::SpawnPosition(SpawnPosition {
-        location: Position { x: 0, y: 64, z: 0 },
+pub fn spawn_position(client: &mut Client, location: Position) -> Result<()> {
+    let packet = PlayPacket::SpawnPosition(SpawnPosition { location });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn time_update(client: &mut Client, level: &Level) -> Result<()> {
+    let time_of_day = if level.game_rule_bool("doDaylightCycle", true) {
+        level.day_time
+    } else {
+        -level.day_time.max(1)
+    };
+    let packet = PlayPacket::TimeUpdate(TimeUpdate {
+        world_age: level.time,
+        time_of_day,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn spawn_player(client: &Client, player: &State) -> Result<()> {
+    let packet = PlayPacket::SpawnPlayer(SpawnPlayer {
+        entity_id: player.entity_id.into(),
+        uuid: player.uuid.unwrap(),
+        x: player.coordinate.x,
+        y: player.coordinate.y,
+        z: player.coordinate.z,
+        yaw: angle(player.rotation.yaw),
+        pitch: angle(player.rotation.pitch),
+    });
+    client.send_play_packet(packet)?;
+    // Spawn Player carries no head rotation
+    entity_head_look(client, player)?;
+
+    Ok(())
+}
+
+pub fn spawn_item(client: &Client, item: &ItemEntity) -> Result<()> {
+    let packet = PlayPacket::SpawnObject(SpawnObject {
+        entity_id: item.entity_id.into(),
+        object_uuid: item.uuid,
+        object_type: ITEM_ENTITY_TYPE.into(),
+        x: item.position.x,
+        y: item.position.y,
+        z: item.position.z,
+        pitch: 0,
+        yaw: 0,
+        data: 1,
+        velocity_x: velocity(item.velocity.x),
+        velocity_y: velocity(item.velocity.y),
+        velocity_z: velocity(item.velocity.z),
+    });
+    client.send_play_packet(packet)?;
+    // the client shows nothing until it knows which item it is
+    item_metadata(client, item)?;
+
+    Ok(())
+}
+
+pub fn item_metadata(client: &Client, item: &ItemEntity) -> Result<()> {
+    let packet = PlayPacket::EntityMetadata(EntityMetadata {
+        entity_id: item.entity_id.into(),
+        metadata: vec![(7, MetadataValue::Slot(Some(item.stack.clone())))],
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn entity_head_look(client: &Client, player: &State) -> Result<()> {
+    let packet = PlayPacket::EntityHeadLook(EntityHeadLook {
+        entity_id: player.entity_id.into(),
+        head_yaw: angle(player.rotation.yaw),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn destroy_entities(client: &Client, entity_ids: Vec<i32>) -> Result<()> {
+    let packet = PlayPacket::DestroyEntities(DestroyEntities {
+        entity_ids: entity_ids.into_iter().map(|id| id.into()).collect(),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// blocks per tick to 1/8000ths, clamped like vanilla
+fn velocity(blocks_per_tick: f64) -> i16 {
+    (blocks_per_tick.clamp(-3.9, 3.9) * 8000.0) as i16
+}
+
+// degrees to 1/256ths of a full turn
+pub fn angle(degrees: f32) -> u8 {
+    (degrees * 256.0 / 360.0) as i32 as u8
+}
+
+// game mode is reason 3 of Change Game State
+pub fn game_mode(client: &Client) -> Result<()> {
+    let packet = PlayPacket::ChangeGameState(ChangeGameState {
+        reason: 3,
+        value: client.state.game_mode.id() as f32,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// what the game mode lets the player do, with vanilla flying and walking speeds
+pub fn player_abilities(client: &Client) -> Result<()> {
+    let state = &client.state;
+    let mut flags = 0;
+    if !state.game_mode.is_vulnerable() {
+        flags |= 0x01;
+    }
+    if state.flying {
+        flags |= 0x02;
+    }
+    if state.game_mode.may_fly() {
+        flags |= 0x04;
+    }
+    if state.game_mode == GameMode::Creative {
+        flags |= 0x08;
+    }
+    let packet = PlayPacket::PlayerAbilities(PlayerAbilities {
+        flags,
+        flying_speed: 0.05,
+        field_of_view_modifier: 0.1,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// correct a client that predicted a block change the server refused
+pub fn block_change(client: &Client, location: Position, block_id: u32) -> Result<()> {
+    let packet = PlayPacket::BlockChange(BlockChange {
+        location,
+        block_id: (block_id as i32).into(),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// slot index of the player inventory window
+pub fn set_slot(client: &Client, slot: usize) -> Result<()> {
+    let packet = PlayPacket::SetSlot(SetSlot {
+        window_id: PLAYER_WINDOW as i8,
+        slot: slot as i16,
+        slot_data: client.state.inventory.slots[slot].clone(),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// the whole open window and what the cursor holds, for when the client got out of sync
+pub fn window_items(client: &Client) -> Result<()> {
+    let inventory = &client.state.inventory;
+    let (window_id, slot_data) = match &client.state.window {
+        Some(window) => (
+            window.id,
+            window
+                .slots
+                .iter()
+                .chain(&inventory.slots[window::MAIN.start..window::HOTBAR.end])
+                .cloned()
+                .collect(),
+        ),
+        None => (PLAYER_WINDOW, inventory.slots.clone()),
+    };
+    let packet = PlayPacket::WindowItems(WindowItems {
+        window_id,
+        slot_data,
+    });
+    client.send_play_packet(packet)?;
+    let packet = PlayPacket::SetSlot(SetSlot {
+        window_id: -1,
+        slot: -1,
+        slot_data: inventory.cursor.clone(),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn confirm_transaction(
+    client: &Client,
+    window_id: u8,
+    action_number: i16,
+    accepted: bool,
+) -> Result<()> {
+    let packet = PlayPacket::ConfirmTransaction(ConfirmTransaction {
+        window_id: window_id as i8,
+        action_number,
+        accepted,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn window_slot(client: &Client, window_id: u8, slot: usize, data: Option<Slot>) -> Result<()> {
+    let packet = PlayPacket::SetSlot(SetSlot {
+        window_id: window_id as i8,
+        slot: slot as i16,
+        slot_data: data,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn open_window(client: &Client, window: &OpenWindow) -> Result<()> {
+    let packet = PlayPacket::OpenWindow(packet::client::OpenWindow {
+        window_id: (window.id as i32).into(),
+        window_type: window.kind.window_type().into(),
+        window_title: Chat::translate(window.kind.title(), vec![]),
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn window_property(client: &Client, window_id: u8, property: i16, value: i16) -> Result<()> {
+    let packet = PlayPacket::WindowProperty(WindowProperty {
+        window_id,
+        property,
+        value,
+    });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn open_sign_editor(client: &Client, location: Position) -> Result<()> {
+    let packet = PlayPacket::OpenSignEditor(OpenSignEditor { location });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+pub fn close_window(client: &Client, window_id: u8) -> Result<()> {
+    let packet = PlayPacket::CloseWindow(CloseWindow { window_id });
+    client.send_play_packet(packet)?;
+
+    Ok(())
+}
+
+// shows the recipe book's ghost items when the player lacks the ingredients
+pub fn craft_recipe_response(client: &Client, window_id: u8, recipe: &str) -> Result<()> {
+    let packet = PlayPacket::CraftRecipeResponse(CraftRecipeResponse {
+        window_id: window_id as i8,
+        recipe: recipe.to_string(),
     });
     client.send_play_packet(packet)?;
 
@@ -0,0 +1,665 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use kareki_data::block::Block;
+use nbt::{Blob, Map, Value};
+
+use crate::types::{block_entity::BlockEntity, chunk::Chunk, chunk_section::ChunkSection};
+
+const SECTOR_SIZE: u64 = 4096;
+const HEADER_SECTORS: usize = 2;
+const CHUNKS_PER_REGION: usize = 32 * 32;
+
+const COMPRESSION_GZIP: u8 = 1;
+const COMPRESSION_ZLIB: u8 = 2;
+const COMPRESSION_NONE: u8 = 3;
+
+// 1.15.2
+const DATA_VERSION: i32 = 2230;
+
+#[derive(Debug)]
+pub struct RegionFile {
+    file: File,
+    // (sector offset << 8) | sector count
+    locations: [u32; CHUNKS_PER_REGION],
+    timestamps: [u32; CHUNKS_PER_REGION],
+    used_sectors: Vec<bool>,
+}
+
+impl RegionFile {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        let header_size = HEADER_SECTORS as u64 * SECTOR_SIZE;
+        if file.metadata()?.len() < header_size {
+            file.set_len(header_size)?;
+        }
+
+        let mut locations = [0; CHUNKS_PER_REGION];
+        let mut timestamps = [0; CHUNKS_PER_REGION];
+        file.seek(SeekFrom::Start(0))?;
+        for location in locations.iter_mut() {
+            *location = file.read_u32::<BigEndian>()?;
+        }
+        for timestamp in timestamps.iter_mut() {
+            *timestamp = file.read_u32::<BigEndian>()?;
+        }
+
+        let total_sectors = file.metadata()?.len().div_ceil(SECTOR_SIZE) as usize;
+        let mut used_sectors = vec![false; total_sectors];
+        for used in used_sectors.iter_mut().take(HEADER_SECTORS) {
+            *used = true;
+        }
+        for &location in locations.iter() {
+            let (offset, count) = Self::split_location(location);
+            if offset < HEADER_SECTORS || offset + count > total_sectors {
+                continue;
+            }
+            for used in used_sectors.iter_mut().skip(offset).take(count) {
+                *used = true;
+            }
+        }
+
+        Ok(Self {
+            file,
+            locations,
+            timestamps,
+            used_sectors,
+        })
+    }
+
+    pub fn has_chunk(&self, chunk_x: i32, chunk_z: i32) -> bool {
+        self.locations[Self::chunk_index(chunk_x, chunk_z)] != 0
+    }
+
+    pub fn read_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<Option<Blob>> {
+        let location = self.locations[Self::chunk_index(chunk_x, chunk_z)];
+        if location == 0 {
+            return Ok(None);
+        }
+        let (offset, count) = Self::split_location(location);
+        if offset < HEADER_SECTORS || offset + count > self.used_sectors.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "chunk location is out of the region file",
+            ));
+        }
+
+        self.file
+            .seek(SeekFrom::Start(offset as u64 * SECTOR_SIZE))?;
+        let length = self.file.read_u32::<BigEndian>()? as usize;
+        if length == 0 || length + 4 > count * SECTOR_SIZE as usize {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid chunk length"));
+        }
+        let compression = self.file.read_u8()?;
+        let mut data = vec![0; length - 1];
+        self.file.read_exact(&mut data)?;
+
+        let mut data = Cursor::new(data);
+        let blob = match compression {
+            COMPRESSION_GZIP => Blob::from_gzip_reader(&mut data),
+            COMPRESSION_ZLIB => Blob::from_zlib_reader(&mut data),
+            COMPRESSION_NONE => Blob::from_reader(&mut data),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "unknown chunk compression type",
+                ))
+            }
+        }
+        .map_err(Error::from)?;
+
+        Ok(Some(blob))
+    }
+
+    pub fn write_chunk(&mut self, chunk_x: i32, chunk_z: i32, blob: &Blob) -> Result<()> {
+        let mut data = vec![];
+        blob.to_zlib_writer(&mut data).map_err(Error::from)?;
+
+        // length (4 bytes) + compression type (1 byte) + data
+        let sectors_needed = ((data.len() + 5) as u64).div_ceil(SECTOR_SIZE);
+        if sectors_needed > 255 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "chunk is too large for a region file",
+            ));
+        }
+        let sectors_needed = sectors_needed as usize;
+
+        let index = Self::chunk_index(chunk_x, chunk_z);
+        let (old_offset, old_count) = Self::split_location(self.locations[index]);
+        let offset = if old_offset >= HEADER_SECTORS && old_count == sectors_needed {
+            old_offset
+        } else {
+            if old_offset >= HEADER_SECTORS {
+                for used in self
+                    .used_sectors
+                    .iter_mut()
+                    .skip(old_offset)
+                    .take(old_count)
+                {
+                    *used = false;
+                }
+            }
+            self.allocate_sectors(sectors_needed)
+        };
+
+        self.file
+            .seek(SeekFrom::Start(offset as u64 * SECTOR_SIZE))?;
+        self.file.write_u32::<BigEndian>(data.len() as u32 + 1)?;
+        self.file.write_u8(COMPRESSION_ZLIB)?;
+        self.file.write_all(&data)?;
+        let padding = sectors_needed * SECTOR_SIZE as usize - (data.len() + 5);
+        self.file.write_all(&vec![0; padding])?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or(0);
+        self.locations[index] = (offset as u32) << 8 | sectors_needed as u32;
+        self.timestamps[index] = timestamp;
+
+        self.file.seek(SeekFrom::Start(index as u64 * 4))?;
+        self.file.write_u32::<BigEndian>(self.locations[index])?;
+        self.file
+            .seek(SeekFrom::Start(SECTOR_SIZE + index as u64 * 4))?;
+        self.file.write_u32::<BigEndian>(timestamp)?;
+        self.file.flush()?;
+
+        Ok(())
+    }
+
+    fn allocate_sectors(&mut self, count: usize) -> usize {
+        let mut run_start = 0;
+        let mut run_length = 0;
+        for (sector, &used) in self.used_sectors.iter().enumerate() {
+            if used {
+                run_length = 0;
+                continue;
+            }
+            if run_length == 0 {
+                run_start = sector;
+            }
+            run_length += 1;
+            if run_length == count {
+                break;
+            }
+        }
+
+        let offset = if run_length == count {
+            run_start
+        } else {
+            self.used_sectors.len()
+        };
+        if self.used_sectors.len() < offset + count {
+            self.used_sectors.resize(offset + count, false);
+        }
+        for used in self.used_sectors.iter_mut().skip(offset).take(count) {
+            *used = true;
+        }
+        offset
+    }
+
+    fn split_location(location: u32) -> (usize, usize) {
+        ((location >> 8) as usize, (location & 0xff) as usize)
+    }
+
+    fn chunk_index(chunk_x: i32, chunk_z: i32) -> usize {
+        ((chunk_x & 31) + (chunk_z & 31) * 32) as usize
+    }
+}
+
+#[derive(Debug)]
+pub struct RegionStorage {
+    directory: PathBuf,
+    regions: HashMap<(i32, i32), RegionFile>,
+}
+
+impl RegionStorage {
+    pub fn new<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+            regions: HashMap::new(),
+        })
+    }
+
+    pub fn load_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<Option<Chunk>> {
+        let region = match self.region(chunk_x >> 5, chunk_z >> 5, false)? {
+            Some(region) => region,
+            None => return Ok(None),
+        };
+        if !region.has_chunk(chunk_x, chunk_z) {
+            return Ok(None);
+        }
+
+        match region.read_chunk(chunk_x, chunk_z)? {
+            Some(blob) => chunk_from_nbt(&blob),
+            None => Ok(None),
+        }
+    }
+
+    pub fn save_chunk(
+        &mut self,
+        chunk_x: i32,
+        chunk_z: i32,
+        chunk: &Chunk,
+        game_time: i64,
+    ) -> Result<()> {
+        let region = self
+            .region(chunk_x >> 5, chunk_z >> 5, true)?
+            .expect("region file should be created");
+        // keep what kareki doesn't handle (entities, ticks, structures...) from the saved chunk
+        let previous = match region.read_chunk(chunk_x, chunk_z) {
+            Ok(blob) => blob,
+            Err(err) => {
+                println!(
+                    "discarding unreadable chunk ({}, {}): {:?}",
+                    chunk_x, chunk_z, err
+                );
+                None
+            }
+        };
+        let blob = chunk_to_nbt(chunk_x, chunk_z, chunk, game_time, previous)?;
+        region.write_chunk(chunk_x, chunk_z, &blob)
+    }
+
+    fn region(
+        &mut self,
+        region_x: i32,
+        region_z: i32,
+        create: bool,
+    ) -> Result<Option<&mut RegionFile>> {
+        if !self.regions.contains_key(&(region_x, region_z)) {
+            let path = self
+                .directory
+                .join(format!("r.{}.{}.mca", region_x, region_z));
+            if !create && !path.exists() {
+                return Ok(None);
+            }
+            let region = RegionFile::open(path)?;
+            self.regions.insert((region_x, region_z), region);
+        }
+        Ok(self.regions.get_mut(&(region_x, region_z)))
+    }
+}
+
+// None for chunks vanilla only half generated, the generator makes them again
+fn chunk_from_nbt(blob: &Blob) -> Result<Option<Chunk>> {
+    let level = match blob.get("Level") {
+        Some(Value::Compound(level)) => level,
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "chunk has no Level compound",
+            ))
+        }
+    };
+    match level.get("Status") {
+        Some(Value::String(status)) if status == "full" => {}
+        _ => return Ok(None),
+    }
+
+    let mut chunk = Chunk::empty();
+    if let Some(Value::IntArray(biomes)) = level.get("Biomes") {
+        if biomes.len() == chunk.biomes.len() {
+            chunk.biomes = biomes.clone();
+        }
+    }
+
+    if let Some(Value::List(entities)) = level.get("TileEntities") {
+        chunk.block_entities = entities
+            .iter()
+            .filter_map(|entity| match entity {
+                Value::Compound(entity) => BlockEntity::from_nbt(entity),
+                _ => None,
+            })
+            .collect();
+    }
+
+    let sections = match level.get("Sections") {
+        Some(Value::List(sections)) => sections,
+        _ => return Ok(Some(chunk)),
+    };
+    for section in sections {
+        let section = match section {
+            Value::Compound(section) => section,
+            _ => continue,
+        };
+        // vanilla also stores light-only sections at y = -1 and y = 16
+        let y = match section.get("Y") {
+            Some(Value::Byte(y)) if *y >= 0 && (*y as usize) < chunk.sections.len() => *y as usize,
+            _ => continue,
+        };
+        let (palette, block_states) = match (section.get("Palette"), section.get("BlockStates")) {
+            (Some(Value::List(palette)), Some(Value::LongArray(block_states))) => {
+                (palette, block_states)
+            }
+            _ => continue,
+        };
+        let palette = palette
+            .iter()
+            .map(palette_entry_to_state_id)
+            .collect::<Vec<_>>();
+        chunk.sections[y] = Some(ChunkSection::from_anvil(&palette, block_states)?);
+    }
+    chunk.recompute_heightmaps();
+
+    Ok(Some(chunk))
+}
+
+fn chunk_to_nbt(
+    chunk_x: i32,
+    chunk_z: i32,
+    chunk: &Chunk,
+    game_time: i64,
+    previous: Option<Blob>,
+) -> Result<Blob> {
+    let mut level = match previous.as_ref().and_then(|blob| blob.get("Level")) {
+        Some(Value::Compound(level)) => level.clone(),
+        _ => Map::new(),
+    };
+
+    let sections = chunk
+        .sections
+        .iter()
+        .enumerate()
+        .filter_map(|(y, section)| match section {
+            Some(section) if !section.is_empty() => Some((y, section)),
+            _ => None,
+        })
+        .map(|(y, section)| {
+            let (palette, block_states) = section.to_anvil();
+            let palette = palette.into_iter().map(state_id_to_palette_entry).collect();
+            let mut section = Map::new();
+            section.insert("Y".to_string(), Value::Byte(y as i8));
+            section.insert("Palette".to_string(), Value::List(palette));
+            section.insert("BlockStates".to_string(), Value::LongArray(block_states));
+            Value::Compound(section)
+        })
+        .collect();
+
+    level.insert("xPos".to_string(), Value::Int(chunk_x));
+    level.insert("zPos".to_string(), Value::Int(chunk_z));
+    level.insert("LastUpdate".to_string(), Value::Long(game_time));
+    level
+        .entry("InhabitedTime".to_string())
+        .or_insert(Value::Long(0));
+    // kareki generated the whole chunk, vanilla mustn't run its own stages over it
+    level.insert("Status".to_string(), Value::String("full".to_string()));
+    level.insert("Sections".to_string(), Value::List(sections));
+    level.insert("Biomes".to_string(), Value::IntArray(chunk.biomes.clone()));
+    level
+        .entry("Entities".to_string())
+        .or_insert_with(|| Value::List(vec![]));
+    // block entities of kinds kareki doesn't know about stay as vanilla left them
+    let mut block_entities = match level.remove("TileEntities") {
+        Some(Value::List(entities)) => entities
+            .into_iter()
+            .filter(|entity| match entity {
+                Value::Compound(entity) => BlockEntity::from_nbt(entity).is_none(),
+                _ => false,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    block_entities.extend(
+        chunk
+            .block_entities
+            .iter()
+            .map(|(position, entity)| Value::Compound(entity.to_nbt(*position))),
+    );
+    level.insert("TileEntities".to_string(), Value::List(block_entities));
+    // blocks may have changed, let vanilla recompute heightmaps and light
+    level.remove("Heightmaps");
+    level.insert("isLightOn".to_string(), Value::Byte(0));
+
+    let mut blob = Blob::new();
+    blob.insert("DataVersion", Value::Int(DATA_VERSION))
+        .map_err(Error::from)?;
+    blob.insert("Level", Value::Compound(level))
+        .map_err(Error::from)?;
+    Ok(blob)
+}
+
+fn palette_entry_to_state_id(entry: &Value) -> u16 {
+    let entry = match entry {
+        Value::Compound(entry) => entry,
+        _ => return 0,
+    };
+    let block = match entry.get("Name") {
+        Some(Value::String(name)) => Block::from_name(name.trim_start_matches("minecraft:")),
+        _ => None,
+    };
+    let block = match block {
+        Some(block) => block,
+        None => return 0,
+    };
+    let properties = match entry.get("Properties") {
+        Some(Value::Compound(properties)) => properties
+            .iter()
+            .filter_map(|(name, value)| match value {
+                Value::String(value) => Some((name.as_str(), value.as_str())),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    block.state_from_properties(&properties) as u16
+}
+
+fn state_id_to_palette_entry(state_id: u16) -> Value {
+    let block = Block::from_state_id(state_id as u32).unwrap_or(Block::Air);
+    let mut entry = Map::new();
+    entry.insert(
+        "Name".to_string(),
+        Value::String(format!("minecraft:{}", block.name())),
+    );
+    let properties = Block::state_properties(state_id as u32).unwrap_or_default();
+    if !properties.is_empty() {
+        let properties = properties
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), Value::String(value.to_string())))
+            .collect();
+        entry.insert("Properties".to_string(), Value::Compound(properties));
+    }
+    Value::Compound(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use kareki_data::block_state::BlockState;
+
+    use super::*;
+
+    fn temp_region(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("kareki-{}-{}.mca", name, process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn blob_with(value: i32, padding: usize) -> Blob {
+        let mut blob = Blob::new();
+        blob.insert("Value", Value::Int(value)).unwrap();
+        blob.insert("Padding", Value::ByteArray(vec![1; padding]))
+            .unwrap();
+        blob
+    }
+
+    #[test]
+    fn chunk_index_wraps_negative_coordinates() {
+        assert_eq!(RegionFile::chunk_index(0, 0), 0);
+        assert_eq!(RegionFile::chunk_index(31, 31), 1023);
+        assert_eq!(RegionFile::chunk_index(-1, 0), 31);
+        assert_eq!(RegionFile::chunk_index(0, -1), 31 * 32);
+    }
+
+    #[test]
+    fn write_and_read_back_chunks() {
+        let path = temp_region("round-trip");
+        let mut region = RegionFile::open(&path).unwrap();
+        assert!(!region.has_chunk(3, 4));
+        region.write_chunk(3, 4, &blob_with(1, 0)).unwrap();
+        region.write_chunk(5, 6, &blob_with(2, 0)).unwrap();
+
+        // reopened from the header on disk
+        let mut region = RegionFile::open(&path).unwrap();
+        assert_eq!(region.read_chunk(3, 4).unwrap(), Some(blob_with(1, 0)));
+        assert_eq!(region.read_chunk(5, 6).unwrap(), Some(blob_with(2, 0)));
+        assert_eq!(region.read_chunk(7, 8).unwrap(), None);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn growing_chunk_moves_and_frees_its_sectors() {
+        let path = temp_region("grow");
+        let mut region = RegionFile::open(&path).unwrap();
+        region.write_chunk(0, 0, &blob_with(1, 0)).unwrap();
+        let (offset, count) = RegionFile::split_location(region.locations[0]);
+        assert_eq!((offset, count), (HEADER_SECTORS, 1));
+
+        // xorshift bytes so zlib can't shrink it below two sectors
+        let mut seed = 0x2545_f491_u32;
+        let noise = (0..10_000)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed as i8
+            })
+            .collect();
+        let mut big = Blob::new();
+        big.insert("Noise", Value::ByteArray(noise)).unwrap();
+        region.write_chunk(0, 0, &big).unwrap();
+        let (offset, count) = RegionFile::split_location(region.locations[0]);
+        assert!(count >= 2);
+        assert_eq!(offset, HEADER_SECTORS + 1);
+
+        // the sector the small chunk left behind is reused
+        region.write_chunk(1, 0, &blob_with(2, 0)).unwrap();
+        let (offset, _) = RegionFile::split_location(region.locations[1]);
+        assert_eq!(offset, HEADER_SECTORS);
+        assert_eq!(region.read_chunk(0, 0).unwrap(), Some(big));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn palette_entries_keep_properties() {
+        let mut stairs = BlockState::new(Block::OakStairs);
+        assert!(stairs.set_property("facing", "east"));
+        assert!(stairs.set_property("half", "top"));
+        let entry = state_id_to_palette_entry(stairs.id() as u16);
+        let properties = match &entry {
+            Value::Compound(entry) => {
+                assert_eq!(
+                    entry.get("Name"),
+                    Some(&Value::String("minecraft:oak_stairs".to_string()))
+                );
+                entry.get("Properties").cloned()
+            }
+            _ => None,
+        };
+        match properties {
+            Some(Value::Compound(properties)) => {
+                assert_eq!(
+                    properties.get("facing"),
+                    Some(&Value::String("east".to_string()))
+                );
+            }
+            other => panic!("no properties: {:?}", other),
+        }
+        assert_eq!(palette_entry_to_state_id(&entry), stairs.id() as u16);
+    }
+
+    #[test]
+    fn unknown_palette_entries_load_as_air() {
+        let mut entry = Map::new();
+        entry.insert(
+            "Name".to_string(),
+            Value::String("othermod:thing".to_string()),
+        );
+        assert_eq!(palette_entry_to_state_id(&Value::Compound(entry)), 0);
+    }
+
+    #[test]
+    fn chunk_nbt_round_trip_keeps_unknown_data() {
+        let mut chunk = Chunk::empty();
+        let mut stairs = BlockState::new(Block::OakStairs);
+        stairs.set_property("facing", "south");
+        chunk.set_block_raw(1, 70, 2, stairs.id() as u16).unwrap();
+        chunk.set_block(15, 0, 15, Block::Bedrock).unwrap();
+
+        // what vanilla saved that kareki doesn't model
+        let mut level = Map::new();
+        level.insert("Entities".to_string(), Value::List(vec![Value::Int(7)]));
+        level.insert("Status".to_string(), Value::String("features".to_string()));
+        let mut previous = Blob::new();
+        previous.insert("Level", Value::Compound(level)).unwrap();
+
+        let blob = chunk_to_nbt(4, -3, &chunk, 100, Some(previous)).unwrap();
+        let level = match blob.get("Level") {
+            Some(Value::Compound(level)) => level,
+            _ => panic!("no Level"),
+        };
+        assert_eq!(level.get("xPos"), Some(&Value::Int(4)));
+        assert_eq!(level.get("zPos"), Some(&Value::Int(-3)));
+        assert_eq!(
+            level.get("Entities"),
+            Some(&Value::List(vec![Value::Int(7)]))
+        );
+        assert_eq!(
+            level.get("Status"),
+            Some(&Value::String("full".to_string()))
+        );
+        assert_eq!(blob.get("DataVersion"), Some(&Value::Int(DATA_VERSION)));
+
+        let loaded = chunk_from_nbt(&blob).unwrap().unwrap();
+        assert_eq!(loaded.get_block(1, 70, 2), Some(stairs.id() as u16));
+        assert_eq!(
+            loaded.get_block(15, 0, 15),
+            Some(BlockState::new(Block::Bedrock).id() as u16)
+        );
+        assert_eq!(loaded.get_block(0, 0, 0), Some(0));
+    }
+
+    #[test]
+    fn half_generated_chunks_load_as_absent() {
+        let blob = chunk_to_nbt(0, 0, &Chunk::empty(), 0, None).unwrap();
+        let mut level = match blob.get("Level") {
+            Some(Value::Compound(level)) => level.clone(),
+            _ => panic!("no Level"),
+        };
+        // the first stage, before vanilla has any sections
+        level.remove("Sections");
+        level.insert(
+            "Status".to_string(),
+            Value::String("structure_starts".to_string()),
+        );
+        let mut proto = Blob::new();
+        proto.insert("Level", Value::Compound(level)).unwrap();
+        assert!(chunk_from_nbt(&proto).unwrap().is_none());
+
+        let path = temp_region("proto");
+        let mut region = RegionFile::open(&path).unwrap();
+        region.write_chunk(0, 0, &proto).unwrap();
+        let directory = path.with_extension("d");
+        fs::create_dir_all(&directory).unwrap();
+        fs::rename(&path, directory.join("r.0.0.mca")).unwrap();
+        let mut storage = RegionStorage::new(&directory).unwrap();
+        assert!(storage.load_chunk(0, 0).unwrap().is_none());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
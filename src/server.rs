@@ -1,5 +1,12 @@
 use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
     io::{self, Cursor, ErrorKind, Read, Result, Write},
+    mem,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     thread::sleep,
     time::{Duration, Instant},
 };
@@ -12,7 +19,9 @@ use cfb8::{
 use flate2::{bufread::ZlibDecoder, write::ZlibEncoder, Compression};
 use flume::{Receiver, Sender, TryRecvError};
 use futures_lite::FutureExt;
-use kareki_data::{block::Block, item::Item};
+use kareki_data::{block::Block, block_state::BlockState, item::Item};
+use openssl::{pkey::Private, rsa::Rsa};
+use rand::Rng;
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::{
@@ -22,18 +31,44 @@ use tokio::{
     time::timeout,
 };
 
+mod commands;
+
 use crate::{
+    auth::{self, Authenticator},
     client::Client,
+    command::CommandDispatcher,
+    config::Config,
+    crafting, digging,
+    entity::{ItemEntity, DROPPED_PICKUP_DELAY, THROWN_PICKUP_DELAY},
+    forwarding::{self, Forwarding},
+    health::{self, DamageCause},
+    ops::Ops,
     packet::{
-        client::{BlockChange, PlayDisconnect, UnloadChunk, UpdateLight},
+        client::{
+            AcknowledgePlayerDigging, BlockBreakAnimation, BlockChange, BlockChangeRecord,
+            BlockEntityData, CollectItem, EntityPosition, EntityPositionAndRotation,
+            EntityRotation, EntityTeleport, MultiBlockChange, PlayDisconnect, PlayerInfoAction,
+            UnloadChunk,
+        },
         server::{
-            ClientSettings, CreativeInventoryAction, HeldItemChange, PlayerBlockPlacement,
-            PlayerDigging, PlayerPositionAndRotation, PlayerRotation,
+            ChatMessage, ClickWindow, ClientSettings, CraftRecipeRequest, CreativeInventoryAction,
+            HeldItemChange, PlayerBlockPlacement, PlayerDigging, PlayerPositionAndRotation,
+            PlayerRotation, UpdateSign,
         },
         PacketWriteEnum,
     },
-    state::{Coordinate, Rotation},
-    types::{digging_status::DiggingStatus, slot::Slot, Var},
+    placement::{self, PlacementContext},
+    state::{Coordinate, Digging, GameMode, Rotation},
+    types::{
+        block_entity::BlockEntity,
+        chat::{Chat, ChatPosition, Color},
+        digging_status::DiggingStatus,
+        nbt::Nbt,
+        position::Position,
+        slot::Slot,
+        Var,
+    },
+    window::{self, Click, OpenWindow, Window, WindowKind},
     world::World,
 };
 use crate::{
@@ -59,10 +94,27 @@ use crate::{slp::handle_slp_ping, HandshakePacket::Handshake};
 
 pub type AesCfb8 = Cfb8<Aes128>;
 
+const AUTOSAVE_INTERVAL_TICKS: u64 = 20 * 60;
+const TIME_UPDATE_INTERVAL_TICKS: u64 = 20;
+// what update sign allows for each line
+const SIGN_LINE_LENGTH: usize = 384;
+// vanilla gives up on a login after 600 ticks
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(30);
+// what vanilla reads of a chat message, commands included
+const MAX_CHAT_LENGTH: usize = 256;
+// vanilla's moved too quickly check, anything farther is a teleport and not a walk
+const MAX_MOVE_DISTANCE_SQUARED: f64 = 100.0;
+
 pub struct Worker {
     reader: Reader,
     writer: Writer,
     pub state: State,
+    pub config: Arc<Config>,
+    pub authenticator: Arc<dyn Authenticator>,
+    // one key pair for every login's encryption request
+    pub keypair: Arc<Rsa<Private>>,
+    // how many players the tick loop had at its last update
+    pub players_online: Arc<AtomicUsize>,
     packets_to_send_tx: Sender<client::PlayPacket>,
     received_packets_rx: Receiver<PlayPacket>,
 }
@@ -73,7 +125,13 @@ pub enum NextConnect {
 }
 
 impl Worker {
-    fn new(stream: TcpStream) -> Self {
+    pub fn new(
+        stream: TcpStream,
+        config: Arc<Config>,
+        authenticator: Arc<dyn Authenticator>,
+        keypair: Arc<Rsa<Private>>,
+        players_online: Arc<AtomicUsize>,
+    ) -> Self {
         let (reader, writer) = stream.into_split();
         let (received_packets_tx, received_packets_rx) = flume::bounded(32);
         let (packets_to_send_tx, packets_to_send_rx) = flume::unbounded();
@@ -84,6 +142,10 @@ impl Worker {
             reader,
             writer,
             state: State::default(),
+            config,
+            authenticator,
+            keypair,
+            players_online,
             packets_to_send_tx,
             received_packets_rx,
         }
@@ -112,10 +174,16 @@ impl Worker {
                     NextConnect::Disconnect
                 }
                 NextState::Login => {
-                    handle_login_handshake(self).await?;
-                    println!("gogo");
-
-                    NextConnect::Join
+                    let login = handle_login_handshake(self, &h.server_address);
+                    match timeout(LOGIN_TIMEOUT, login).await {
+                        Ok(next) => next?,
+                        Err(_) => {
+                            let reason =
+                                Chat::translate("multiplayer.disconnect.slow_login", vec![]);
+                            login::disconnect(self, reason).await?;
+                            NextConnect::Disconnect
+                        }
+                    }
                 }
             },
         };
@@ -154,13 +222,26 @@ impl Worker {
 }
 
 pub struct Server {
+    config: Arc<Config>,
+    players_online: Arc<AtomicUsize>,
     clients: Vec<Client>,
     receiver: Receiver<Client>,
     world: World,
+    ticks: u64,
+    commands: CommandDispatcher<Server>,
+    ops: Ops,
+    items: Vec<ItemEntity>,
 }
 
 impl Server {
-    pub async fn listen(bind_address: &str, sender: Sender<Client>) {
+    pub async fn listen(
+        bind_address: &str,
+        config: Arc<Config>,
+        authenticator: Arc<dyn Authenticator>,
+        keypair: Arc<Rsa<Private>>,
+        players_online: Arc<AtomicUsize>,
+        sender: Sender<Client>,
+    ) {
         let mut listener = TcpListener::bind(bind_address)
             .await
             .expect("Error. failed to bind.");
@@ -169,45 +250,82 @@ impl Server {
                 if let Ok((stream, addr)) = listener.accept().await {
                     println!("connection from {:?}", addr);
 
-                    let mut worker = Worker::new(stream);
-                    let handshake = worker.read_packet_exact::<HandshakePacket>().await.unwrap();
-                    let next = match worker.handshake(handshake).await {
-                        Ok(next) => next,
-                        Err(err) => {
-                            println!("{:?}", err);
-                            continue;
-                        }
-                    };
-
-                    match next {
-                        NextConnect::Disconnect => continue,
-                        NextConnect::Join => {
-                            let state = worker.state.clone();
-                            let client = Client::new(
-                                worker.packets_to_send(),
-                                worker.received_packets(),
-                                state,
-                            );
-                            sender.send_async(client).await.unwrap();
-                            worker.run()
+                    let mut worker = Worker::new(
+                        stream,
+                        config.clone(),
+                        authenticator.clone(),
+                        keypair.clone(),
+                        players_online.clone(),
+                    );
+                    worker.state.address = Some(addr.ip());
+                    let sender = sender.clone();
+                    // a slow login mustn't hold up everyone connecting after it
+                    tokio::task::spawn(async move {
+                        let next = match worker.read_packet_exact::<HandshakePacket>().await {
+                            Ok(handshake) => worker.handshake(handshake).await,
+                            Err(err) => Err(err),
+                        };
+                        let next = match next {
+                            Ok(next) => next,
+                            Err(err) => {
+                                println!("{:?}", err);
+                                return;
+                            }
+                        };
+
+                        match next {
+                            NextConnect::Disconnect => {}
+                            NextConnect::Join => {
+                                let state = worker.state.clone();
+                                let client = Client::new(
+                                    worker.packets_to_send(),
+                                    worker.received_packets(),
+                                    state,
+                                );
+                                if sender.send_async(client).await.is_ok() {
+                                    worker.run()
+                                }
+                            }
                         }
-                    }
+                    });
                 }
             }
         });
     }
 
-    pub async fn new(bind_string: String) -> Self {
+    pub async fn new(config: Config) -> Self {
+        let config = Arc::new(config);
+        let players_online = Arc::new(AtomicUsize::new(0));
         let (sender, receiver) = flume::bounded(4);
-        Self::listen(&bind_string, sender).await;
+        Self::listen(
+            &config.bind_address(),
+            config.clone(),
+            auth::create(&config).into(),
+            Arc::new(Rsa::generate(1024).expect("failed to generate the server key pair")),
+            players_online.clone(),
+            sender,
+        )
+        .await;
 
         Self {
+            world: World::new("world", &config.level_type, &config.generator_settings).unwrap(),
+            config,
+            players_online,
             clients: Vec::new(),
             receiver,
-            world: World::new().unwrap(),
+            ticks: 0,
+            commands: commands::dispatcher(),
+            ops: Ops::load("ops.json").expect("failed to read ops.json"),
+            items: Vec::new(),
         }
     }
 
+    pub fn shutdown(&mut self) -> Result<()> {
+        println!("saving world...");
+        self.world.flush()?;
+        Ok(())
+    }
+
     pub fn loop_once(&mut self) -> Result<()> {
         let start = Instant::now();
         self.update()?;
@@ -223,8 +341,15 @@ impl Server {
         loop {
             match self.receiver.try_recv() {
                 Ok(mut client) => {
+                    self.kick_duplicate_login(&client)?;
                     self.handle_login_handle(&mut client)?;
-                    self.clients.push(client)
+                    let message = Chat::translate(
+                        "multiplayer.player.joined",
+                        vec![play::player_name(&client.state)],
+                    )
+                    .color(Color::Yellow);
+                    self.clients.push(client);
+                    self.broadcast_chat(message, ChatPosition::System)?;
                 }
                 Err(TryRecvError::Empty) => break,
                 Err(TryRecvError::Disconnected) => return Ok(()),
@@ -252,8 +377,31 @@ impl Server {
                 will_removes.push(index);
             }
         }
-        for index in will_removes {
-            self.clients.remove(index);
+        for index in will_removes.into_iter().rev() {
+            let client = self.clients.remove(index);
+            self.handle_logout(&client)?;
+        }
+        self.players_online
+            .store(self.clients.len(), Ordering::Relaxed);
+        self.update_digging()?;
+        self.update_health()?;
+        self.update_items()?;
+        self.update_block_entities()?;
+        self.update_tracked_entities()?;
+        self.broadcast_block_changes()?;
+        self.send_pending_chunks()?;
+
+        self.ticks += 1;
+        self.world.tick();
+        if self.ticks.is_multiple_of(TIME_UPDATE_INTERVAL_TICKS) {
+            for client in self.clients.iter_mut() {
+                play::time_update(client, &self.world.level)?;
+            }
+        }
+        if self.ticks.is_multiple_of(AUTOSAVE_INTERVAL_TICKS) {
+            if let Err(err) = self.world.save() {
+                println!("failed to save world: {:?}", err);
+            }
         }
 
         Ok(())
@@ -274,14 +422,35 @@ impl Server {
 
     fn handle_packet(&mut self, client_index: usize, packet: PlayPacket) -> Result<()> {
         match packet {
+            PlayPacket::ChatMessage(chat_message) => {
+                self.handle_chat_message(client_index, &chat_message)?;
+            }
+            PlayPacket::TabComplete(tab_complete) => {
+                self.handle_tab_complete(client_index, &tab_complete)?;
+            }
+            PlayPacket::ClientStatus(client_status) => {
+                if client_status.action_id.0 == 0 {
+                    self.respawn(client_index)?;
+                }
+            }
             PlayPacket::ClientSettings(client_settings) => {
                 self.handle_client_settings(client_index, &client_settings)?;
             }
             PlayPacket::KeepAlive(_keep_alive) => {}
             PlayPacket::PlayerPosition(player_position) => {
-                let PlayerPosition { x, feet_y, z, .. } = player_position;
+                let PlayerPosition {
+                    x,
+                    feet_y,
+                    z,
+                    on_ground,
+                } = player_position;
                 // println!("player_position: {:?}", player_position);
+                let state = &mut self.clients[client_index].state;
+                let (previous, was_on_ground) = (state.coordinate, state.on_ground);
+                state.on_ground = on_ground;
                 self.set_position(client_index, x, feet_y, z)?;
+                self.player_moved(client_index, previous, was_on_ground)?;
+                self.broadcast_movement(client_index, Some(previous), false, on_ground)?;
             }
             PlayPacket::PlayerPositionAndRotation(player_position_and_rotation) => {
                 let PlayerPositionAndRotation {
@@ -290,14 +459,19 @@ impl Server {
                     z,
                     yaw,
                     pitch,
-                    ..
+                    on_ground,
                 } = player_position_and_rotation;
                 // println!(
                 //     "player_position_and_rotation: {:?}",
                 //     player_position_and_rotation
                 // );
+                let state = &mut self.clients[client_index].state;
+                let (previous, was_on_ground) = (state.coordinate, state.on_ground);
+                state.on_ground = on_ground;
                 self.set_position(client_index, x, feet_y, z)?;
+                self.player_moved(client_index, previous, was_on_ground)?;
                 self.set_rotation(client_index, yaw, pitch)?;
+                self.broadcast_movement(client_index, Some(previous), true, on_ground)?;
             }
             PlayPacket::PlayerBlockPlacement(placement) => {
                 self.handle_block_placement(client_index, &placement)?;
@@ -306,29 +480,59 @@ impl Server {
                 println!("teleport_confirm: {:?}", teleport_confirm);
             }
             PlayPacket::PlayerRotation(player_rotation) => {
-                let PlayerRotation { yaw, pitch, .. } = player_rotation;
+                let PlayerRotation {
+                    yaw,
+                    pitch,
+                    on_ground,
+                } = player_rotation;
+                let state = &mut self.clients[client_index].state;
+                state.on_ground = on_ground;
                 self.set_rotation(client_index, yaw, pitch)?;
+                self.broadcast_movement(client_index, None, true, on_ground)?;
             }
             PlayPacket::PlayerAbilities(player_abilities) => {
-                println!("player_abilities: {:?}", player_abilities);
+                // the client only says whether it is flying
+                let state = &mut self.clients[client_index].state;
+                state.flying = player_abilities.flags & 0x02 != 0 && state.game_mode.may_fly();
             }
             PlayPacket::PlayerDigging(player_digging) => {
                 self.handle_block_digging(client_index, &player_digging)?;
             }
-            PlayPacket::EntityAction(_entity_action) => {
-                // println!("entity_action: {:?}", _entity_action);
+            PlayPacket::EntityAction(entity_action) => {
+                // println!("entity_action: {:?}", entity_action);
+                let state = &mut self.clients[client_index].state;
+                match entity_action.action_id.0 {
+                    0 => state.sneaking = true,
+                    1 => state.sneaking = false,
+                    3 => state.sprinting = true,
+                    4 => state.sprinting = false,
+                    _ => {}
+                }
             }
             PlayPacket::CreativeInventoryAction(creative_inventory_action) => {
-                let CreativeInventoryAction {
-                    slot: slot_number,
-                    clicked_item,
-                } = creative_inventory_action;
-                self.set_inventory_item(client_index, slot_number as usize, clicked_item)?;
+                self.handle_creative_inventory_action(client_index, creative_inventory_action)?;
             }
             PlayPacket::HeldItemChange(held_item_change) => {
                 let HeldItemChange { slot } = held_item_change;
                 let client = self.clients.get_mut(client_index).unwrap();
-                client.state.inventory.selected = slot as usize;
+                if (0..9).contains(&slot) {
+                    client.state.inventory.selected = slot as usize;
+                }
+            }
+            PlayPacket::ClickWindow(click_window) => {
+                self.handle_click_window(client_index, &click_window)?;
+            }
+            PlayPacket::CloseWindow(close_window) => {
+                self.close_window(client_index, close_window.window_id)?;
+            }
+            PlayPacket::CraftRecipeRequest(craft_recipe_request) => {
+                self.handle_craft_recipe_request(client_index, &craft_recipe_request)?;
+            }
+            PlayPacket::UpdateSign(update_sign) => {
+                self.handle_update_sign(client_index, update_sign)?;
+            }
+            PlayPacket::ConfirmTransaction(_confirm_transaction) => {
+                // the client acknowledging a rejected click, the resync was already sent
             }
         }
 
@@ -336,78 +540,437 @@ impl Server {
     }
 
     pub fn set_position(&mut self, client_index: usize, x: f64, y: f64, z: f64) -> Result<()> {
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            return self.reject_movement(client_index);
+        }
         let client = self.clients.get_mut(client_index).unwrap();
         client.state.coordinate = Coordinate { x, y, z };
 
-        if y < -16.0 {
-            let packet = client::PlayPacket::Disconnect(PlayDisconnect {
-                reason: r#"{"text": "you are dead ( ; _ ; )"}"#.to_string(),
-            });
+        let chunk_x = x as i32 >> 4;
+        let chunk_z = z as i32 >> 4;
+        if client.state.last_chunk_x != chunk_x || client.state.last_chunk_z != chunk_z {
+            client.state.last_chunk_x = chunk_x;
+            client.state.last_chunk_z = chunk_z;
+            self.update_view(client_index)?;
+        }
+        Ok(())
+    }
+
+    // fall distance, landing and the exhaustion of moving, from what the client reports
+    fn player_moved(
+        &mut self,
+        client_index: usize,
+        previous: Coordinate,
+        was_on_ground: bool,
+    ) -> Result<()> {
+        let state = &self.clients[client_index].state;
+        if state.health.is_dead() {
+            return Ok(());
+        }
+        let (sprinting, on_ground, flying) = (state.sprinting, state.on_ground, state.flying);
+        let vulnerable = state.game_mode.is_vulnerable();
+        let Coordinate { x, y, z } = state.coordinate;
+        let (dx, dy, dz) = (x - previous.x, y - previous.y, z - previous.z);
+        let distance_squared = dx * dx + dy * dy + dz * dz;
+        if !distance_squared.is_finite() || distance_squared > MAX_MOVE_DISTANCE_SQUARED {
+            return Ok(());
+        }
+        let feet = self.block_state_at(Position {
+            x: x.floor() as i32,
+            y: y.floor() as i16,
+            z: z.floor() as i32,
+        })?;
+
+        let distance = (dx * dx + dz * dz).sqrt() as f32;
+        let delta_y = dy as f32;
+        let breaks_fall = flying || feet.is_some_and(health::breaks_fall);
+        let mut exhaustion = 0.0;
+        if feet.is_some_and(health::is_water) {
+            exhaustion += health::SWIM_EXHAUSTION * distance;
+        } else if on_ground && sprinting {
+            exhaustion += health::SPRINT_EXHAUSTION * distance;
+        }
+        if was_on_ground && !on_ground && delta_y > 0.0 {
+            exhaustion += if sprinting {
+                health::SPRINT_JUMP_EXHAUSTION
+            } else {
+                health::JUMP_EXHAUSTION
+            };
+        }
+
+        let health = &mut self.clients[client_index].state.health;
+        if vulnerable {
+            health.exhaust(exhaustion);
+        }
+        if breaks_fall {
+            health.fall_distance = 0.0;
+        } else if on_ground {
+            let damage = health.land();
+            self.damage_player(client_index, DamageCause::Fall, damage)?;
+        } else if delta_y < 0.0 {
+            health.fall_distance -= delta_y;
+        }
+        Ok(())
+    }
+
+    // hunger, breath and the void for every living player, and their health bar for everyone
+    fn update_health(&mut self) -> Result<()> {
+        let natural_regeneration = self.world.level.game_rule_bool("naturalRegeneration", true);
+        for client_index in 0..self.clients.len() {
+            let state = &self.clients[client_index].state;
+            if !state.health.is_dead() {
+                let Coordinate { x, y, z } = state.coordinate;
+                let eye_height = if state.sneaking { 1.27 } else { 1.62 };
+                let eyes = Position {
+                    x: x.floor() as i32,
+                    y: (y + eye_height).floor() as i16,
+                    z: z.floor() as i32,
+                };
+                let vulnerable = state.game_mode.is_vulnerable();
+
+                if y < health::VOID_Y {
+                    self.damage_player(client_index, DamageCause::Void, 4.0)?;
+                }
+                let under_water =
+                    vulnerable && self.block_state_at(eyes)?.is_some_and(health::is_water);
+                let health = &mut self.clients[client_index].state.health;
+                if let Some(damage) = health.breathe(under_water) {
+                    self.damage_player(client_index, DamageCause::Drown, damage)?;
+                }
+                let health = &mut self.clients[client_index].state.health;
+                if let Some(damage) = health.tick(natural_regeneration) {
+                    self.damage_player(client_index, DamageCause::Starve, damage)?;
+                }
+            }
+
+            let client = &mut self.clients[client_index];
+            if client.state.health.changed() {
+                play::update_health(client)?;
+            }
+        }
+        Ok(())
+    }
+
+    // hurt a player, killing them when it takes the last of their health
+    fn damage_player(
+        &mut self,
+        client_index: usize,
+        cause: DamageCause,
+        amount: f32,
+    ) -> Result<()> {
+        let state = &mut self.clients[client_index].state;
+        if !state.game_mode.is_vulnerable() && !cause.bypasses_invulnerability() {
+            return Ok(());
+        }
+        if !state.health.damage(cause, amount) {
+            return Ok(());
+        }
+        let entity_id = state.entity_id;
+        let dead = state.health.is_dead();
+        let status = if dead { 3 } else { cause.hurt_status() };
+        for (index, viewer) in self.clients.iter().enumerate() {
+            if index == client_index || viewer.state.tracked_entities.contains(&entity_id) {
+                play::entity_event(viewer, entity_id, status)?;
+            }
+        }
+        if dead {
+            self.kill_player(client_index, cause)?;
+        }
+        Ok(())
+    }
+
+    // the death screen for the player, the death message for everyone
+    // and what they carried on the ground
+    fn kill_player(&mut self, client_index: usize, cause: DamageCause) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        client.state.digging = None;
+        client.state.sprinting = false;
+        let message = Chat::translate(
+            cause.death_message(),
+            vec![play::player_name(&client.state)],
+        );
+        play::combat_death(client, message.clone())?;
+
+        let window_id = client
+            .state
+            .window
+            .as_ref()
+            .map_or(window::PLAYER_WINDOW, |window| window.id);
+        self.close_window(client_index, window_id)?;
+        if window_id != window::PLAYER_WINDOW {
+            play::close_window(&self.clients[client_index], window_id)?;
+        }
+        if !self.world.level.game_rule_bool("keepInventory", false) {
+            let slots = &mut self.clients[client_index].state.inventory.slots;
+            let stacks = slots[window::ARMOR.start..]
+                .iter_mut()
+                .filter_map(Option::take)
+                .collect::<Vec<_>>();
+            for stack in stacks {
+                self.drop_around(client_index, stack);
+            }
+        }
+
+        if self.world.level.game_rule_bool("showDeathMessages", true) {
+            self.broadcast_chat(message, ChatPosition::System)?;
+        }
+        Ok(())
+    }
+
+    // back to the world spawn with full health once the player leaves the death screen
+    fn respawn(&mut self, client_index: usize) -> Result<()> {
+        if !self.clients[client_index].state.health.is_dead() {
+            return Ok(());
+        }
+        let spawn = self.world.level.spawn;
+        let coordinate = self.spawn_coordinate();
+        let client = &mut self.clients[client_index];
+        let state = &mut client.state;
+        let entity_id = state.entity_id;
+        // full health, food and air, and no fall distance left over from dying
+        state.health = Default::default();
+        state.coordinate = coordinate;
+        state.rotation = Default::default();
+        state.last_chunk_x = spawn.x >> 4;
+        state.last_chunk_z = spawn.z >> 4;
+        // the client keeps its chunks but forgets every entity
+        state.tracked_entities.clear();
+
+        play::respawn(client, self.world.level_type())?;
+        play::player_abilities(client)?;
+        play::update_view_position(client)?;
+        play::spawn_position(client, spawn)?;
+        play::play_position_and_look(client)?;
+        play::window_items(client)?;
+        play::time_update(client, &self.world.level)?;
+        self.update_view(client_index)?;
+
+        // the others spawn the player again where they respawned
+        for other in self.clients.iter_mut() {
+            if other.state.tracked_entities.remove(&entity_id) {
+                play::destroy_entities(other, vec![entity_id])?;
+            }
+        }
+        Ok(())
+    }
+
+    // unload the chunks that left view distance and queue the ones that came into it
+    fn update_view(&mut self, client_index: usize) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        let state = &client.state;
+        let (center_x, center_z) = (state.last_chunk_x, state.last_chunk_z);
+        let view_distance = state.view_distance as u32;
+        let in_view = |&(x, z): &(i32, i32)| {
+            Self::get_chunk_distance(x, z, center_x, center_z) <= view_distance
+        };
+
+        let mut unloaded = state
+            .loaded_chunks
+            .iter()
+            .copied()
+            .filter(|chunk| !in_view(chunk))
+            .collect::<Vec<_>>();
+        unloaded.sort_unstable();
+        for (chunk_x, chunk_z) in unloaded {
+            client.state.loaded_chunks.remove(&(chunk_x, chunk_z));
+            let packet = client::PlayPacket::UnloadChunk(UnloadChunk { chunk_x, chunk_z });
             client.send_play_packet(packet)?;
-            client.is_disconnected.set(true);
         }
 
-        let view_distance = client.state.view_distance as i32;
-        let chunk_x = x as i32 >> 4;
-        let chunk_z = z as i32 >> 4;
-        let last_chunk_x = client.state.last_chunk_x;
-        let last_chunk_z = client.state.last_chunk_z;
-
-        client.state.last_chunk_x = chunk_x;
-        client.state.last_chunk_z = chunk_z;
-
-        if last_chunk_x != chunk_x || last_chunk_z != chunk_z {
-            let nx = last_chunk_x.min(chunk_x) - 2 * view_distance;
-            let nz = last_chunk_z.min(chunk_z) - 2 * view_distance;
-            let px = last_chunk_x.max(chunk_x) + 2 * view_distance;
-            let pz = last_chunk_z.max(chunk_z) + 2 * view_distance;
-
-            for x in nx..=px {
-                for z in nz..=pz {
-                    let was_loaded = Self::get_chunk_distance(x, z, last_chunk_x, last_chunk_z)
-                        <= view_distance as u32;
-                    let should_be_loaded =
-                        Self::get_chunk_distance(x, z, chunk_x, chunk_z) <= view_distance as u32;
-
-                    if !was_loaded && should_be_loaded {
-                        let chunk = self.world.fetch_chunk(x, z)?;
-
-                        let packet = chunk.clone().to_packet(x, z)?;
-
-                        client.send_play_packet(packet)?;
-
-                        let sky_light_mask = 0b111111111111111111;
-                        let block_light_mask = 0b111111111111111111;
-                        let packet = client::PlayPacket::UpdateLight(UpdateLight {
-                            chunk_x: x.into(),
-                            chunk_z: z.into(),
-                            sky_light_mask: sky_light_mask.into(),
-                            block_light_mask: block_light_mask.into(),
-                            empty_sky_light_mask: Var(!sky_light_mask),
-                            empty_block_light_mask: Var(!block_light_mask),
-                            sky_lights: vec![vec![0xff; 2048]; 18],
-                            block_lights: vec![vec![0xff; 2048]; 18],
-                        });
-                        client.send_play_packet(packet)?;
-                    } else if was_loaded && !should_be_loaded {
-                        let packet = client::PlayPacket::UnloadChunk(UnloadChunk {
-                            chunk_x: x,
-                            chunk_z: z,
-                        });
-                        client.send_play_packet(packet)?;
+        let state = &mut client.state;
+        let radius = view_distance as i32;
+        let mut pending = vec![];
+        for x in center_x - radius..=center_x + radius {
+            for z in center_z - radius..=center_z + radius {
+                if !state.loaded_chunks.contains(&(x, z)) {
+                    pending.push((x, z));
+                }
+            }
+        }
+        pending.sort_by_key(|&(x, z)| (x - center_x).pow(2) + (z - center_z).pow(2));
+        for &(chunk_x, chunk_z) in pending.iter() {
+            self.world.request_chunk(chunk_x, chunk_z);
+        }
+        state.pending_chunks = pending;
+        Ok(())
+    }
+
+    // send every pending chunk that is ready, in each client's nearest-first order
+    fn send_pending_chunks(&mut self) -> Result<()> {
+        self.world.poll_workers();
+        let mut waiting = HashSet::new();
+        for client in self.clients.iter_mut() {
+            let pending = mem::take(&mut client.state.pending_chunks);
+            let mut still_pending = vec![];
+            for (chunk_x, chunk_z) in pending {
+                match self.world.encoded_chunk(chunk_x, chunk_z) {
+                    Some(packets) => {
+                        client.send_play_packet(packets.light)?;
+                        client.send_play_packet(packets.data)?;
+                        client.state.loaded_chunks.insert((chunk_x, chunk_z));
+                    }
+                    None => {
+                        waiting.insert((chunk_x, chunk_z));
+                        still_pending.push((chunk_x, chunk_z));
                     }
                 }
             }
+            client.state.pending_chunks = still_pending;
         }
+        self.world
+            .retain_encoded(|chunk_x, chunk_z| waiting.contains(&(chunk_x, chunk_z)));
         Ok(())
     }
 
     pub fn set_rotation(&mut self, client_index: usize, yaw: f32, pitch: f32) -> Result<()> {
+        if !(yaw.is_finite() && pitch.is_finite()) {
+            return self.reject_movement(client_index);
+        }
         let client = self.clients.get_mut(client_index).unwrap();
         client.state.rotation = Rotation { yaw, pitch };
         Ok(())
     }
 
+    // kicked like vanilla does for coordinates or angles that are not numbers
+    fn reject_movement(&self, client_index: usize) -> Result<()> {
+        let packet = client::PlayPacket::Disconnect(PlayDisconnect {
+            reason: Chat::translate("multiplayer.disconnect.invalid_player_movement", vec![]),
+        });
+        self.clients[client_index].send_play_packet(packet)?;
+        Err(io::Error::new(
+            ErrorKind::InvalidData,
+            "invalid player movement",
+        ))
+    }
+
+    // send the movement of a player to everyone who can see them
+    fn broadcast_movement(
+        &self,
+        client_index: usize,
+        previous: Option<Coordinate>,
+        rotated: bool,
+        on_ground: bool,
+    ) -> Result<()> {
+        let state = &self.clients[client_index].state;
+        let entity_id = state.entity_id.into();
+        let Coordinate { x, y, z } = state.coordinate;
+        let yaw = play::angle(state.rotation.yaw);
+        let pitch = play::angle(state.rotation.pitch);
+
+        let packet = match previous {
+            Some(previous) => {
+                let delta = (
+                    position_delta(previous.x, x),
+                    position_delta(previous.y, y),
+                    position_delta(previous.z, z),
+                );
+                match delta {
+                    (Some(delta_x), Some(delta_y), Some(delta_z)) if rotated => {
+                        client::PlayPacket::EntityPositionAndRotation(EntityPositionAndRotation {
+                            entity_id,
+                            delta_x,
+                            delta_y,
+                            delta_z,
+                            yaw,
+                            pitch,
+                            on_ground,
+                        })
+                    }
+                    (Some(delta_x), Some(delta_y), Some(delta_z)) => {
+                        client::PlayPacket::EntityPosition(EntityPosition {
+                            entity_id,
+                            delta_x,
+                            delta_y,
+                            delta_z,
+                            on_ground,
+                        })
+                    }
+                    // moved more than 8 blocks at once
+                    _ => client::PlayPacket::EntityTeleport(EntityTeleport {
+                        entity_id,
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        on_ground,
+                    }),
+                }
+            }
+            None => client::PlayPacket::EntityRotation(EntityRotation {
+                entity_id,
+                yaw,
+                pitch,
+                on_ground,
+            }),
+        };
+
+        for client in self.clients.iter() {
+            if client.state.tracked_entities.contains(&state.entity_id) {
+                client.send_play_packet(packet.clone())?;
+                if rotated {
+                    play::entity_head_look(client, state)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // spawn players and items that came into view distance and destroy those that left it
+    fn update_tracked_entities(&mut self) -> Result<()> {
+        for viewer_index in 0..self.clients.len() {
+            let mut spawned = vec![];
+            let mut destroyed = vec![];
+            let viewer = &self.clients[viewer_index];
+            for (player_index, player) in self.clients.iter().enumerate() {
+                if player_index == viewer_index {
+                    continue;
+                }
+                let entity_id = player.state.entity_id;
+                let in_range = Self::get_chunk_distance(
+                    player.state.last_chunk_x,
+                    player.state.last_chunk_z,
+                    viewer.state.last_chunk_x,
+                    viewer.state.last_chunk_z,
+                ) <= viewer.state.view_distance as u32;
+                let is_tracked = viewer.state.tracked_entities.contains(&entity_id);
+
+                if in_range && !is_tracked {
+                    play::spawn_player(viewer, &player.state)?;
+                    spawned.push(entity_id);
+                } else if !in_range && is_tracked {
+                    destroyed.push(entity_id);
+                }
+            }
+            for item in self.items.iter() {
+                let in_range = Self::get_chunk_distance(
+                    item.position.x as i32 >> 4,
+                    item.position.z as i32 >> 4,
+                    viewer.state.last_chunk_x,
+                    viewer.state.last_chunk_z,
+                ) <= viewer.state.view_distance as u32;
+                let is_tracked = viewer.state.tracked_entities.contains(&item.entity_id);
+
+                if in_range && !is_tracked {
+                    play::spawn_item(viewer, item)?;
+                    spawned.push(item.entity_id);
+                } else if !in_range && is_tracked {
+                    destroyed.push(item.entity_id);
+                }
+            }
+            if !destroyed.is_empty() {
+                play::destroy_entities(viewer, destroyed.clone())?;
+            }
+
+            let tracked_entities = &mut self.clients[viewer_index].state.tracked_entities;
+            tracked_entities.extend(spawned);
+            for entity_id in destroyed {
+                tracked_entities.remove(&entity_id);
+            }
+        }
+        Ok(())
+    }
+
     pub fn set_inventory_item(
         &mut self,
         client_index: usize,
@@ -415,7 +978,323 @@ impl Server {
         item: Option<Slot>,
     ) -> Result<()> {
         let client = self.clients.get_mut(client_index).unwrap();
-        client.state.inventory.slots[slot_number] = item;
+        if let Some(slot) = client.state.inventory.slots.get_mut(slot_number) {
+            *slot = item;
+        }
+        Ok(())
+    }
+
+    // creative players set slots outright, slot -1 throws the item
+    fn handle_creative_inventory_action(
+        &mut self,
+        client_index: usize,
+        action: CreativeInventoryAction,
+    ) -> Result<()> {
+        let CreativeInventoryAction { slot, clicked_item } = action;
+        let creative = self.clients[client_index].state.game_mode == GameMode::Creative;
+        let valid = creative
+            && clicked_item.as_ref().is_none_or(|stack| {
+                (1..=64).contains(&stack.item_count)
+                    && Item::from_id(stack.item_id.0 as u32).is_some()
+            });
+        if (1..=window::OFFHAND as i16).contains(&slot) {
+            if valid {
+                self.set_inventory_item(client_index, slot as usize, clicked_item)?;
+            } else {
+                play::set_slot(&self.clients[client_index], slot as usize)?;
+            }
+        } else if slot == -1 && valid {
+            if let Some(stack) = clicked_item {
+                self.throw_stack(client_index, stack);
+            }
+        }
+        Ok(())
+    }
+
+    // run a click on the open window, rejecting it and sending the whole window again
+    // when the client predicted something else
+    fn handle_click_window(&mut self, client_index: usize, packet: &ClickWindow) -> Result<()> {
+        if !self.window_still_valid(client_index)? {
+            return Ok(());
+        }
+        let client = &mut self.clients[client_index];
+        // spectators may look into containers but not take anything out
+        if client.state.game_mode == GameMode::Spectator
+            && packet.window_id != window::PLAYER_WINDOW
+        {
+            play::confirm_transaction(client, packet.window_id, packet.action_number, false)?;
+            return play::window_items(client);
+        }
+        let creative = client.state.game_mode == GameMode::Creative;
+        let click = Click {
+            slot: packet.slot,
+            button: packet.button,
+            mode: packet.mode.0,
+        };
+        let state = &mut client.state;
+        let inventory = &mut state.inventory;
+        let mut window =
+            match Window::with_id(packet.window_id, &mut state.window, &mut inventory.slots) {
+                Some(window) => window,
+                None => return Ok(()),
+            };
+        let result = window.click(&mut inventory.cursor, &mut inventory.drag, &click, creative);
+        // the client never works out crafting results itself
+        let crafted = window
+            .kind
+            .grid()
+            .map(|_| (*window.slots[window::CRAFTING_RESULT]).clone());
+        let accepted = result
+            .as_ref()
+            .is_some_and(|result| result.clicked == packet.clicked_item);
+        play::confirm_transaction(client, packet.window_id, packet.action_number, accepted)?;
+        if !accepted {
+            play::window_items(client)?;
+        } else if let Some(crafted) = crafted {
+            play::window_slot(client, packet.window_id, window::CRAFTING_RESULT, crafted)?;
+        }
+
+        self.store_container(client_index)?;
+
+        for stack in result.map_or(vec![], |result| result.dropped) {
+            self.throw_stack(client_index, stack);
+        }
+        Ok(())
+    }
+
+    // fill the crafting grid with a recipe picked in the recipe book
+    fn handle_craft_recipe_request(
+        &mut self,
+        client_index: usize,
+        request: &CraftRecipeRequest,
+    ) -> Result<()> {
+        let recipe = match crafting::recipe_by_name(&request.recipe) {
+            Some(recipe) => recipe,
+            None => return Ok(()),
+        };
+        if !self.window_still_valid(client_index)? {
+            return Ok(());
+        }
+        let window_id = request.window_id as u8;
+        let client = &mut self.clients[client_index];
+        let state = &mut client.state;
+        let mut window =
+            match Window::with_id(window_id, &mut state.window, &mut state.inventory.slots) {
+                Some(window) => window,
+                None => return Ok(()),
+            };
+        if !window.fill_grid(recipe, request.make_all) {
+            play::craft_recipe_response(client, window_id, recipe.name)?;
+        }
+        play::window_items(client)
+    }
+
+    fn open_window(
+        &mut self,
+        client_index: usize,
+        kind: WindowKind,
+        position: Position,
+    ) -> Result<()> {
+        if let Some(open) = &self.clients[client_index].state.window {
+            let id = open.id;
+            self.close_window(client_index, id)?;
+        }
+        let block = self.block_state_at(position)?.map(|state| state.block());
+        let items = match (self.world.block_entity(position), block) {
+            (Some(entity), _) => entity.items().map(<[_]>::to_vec),
+            // a container from before block entities were kept
+            (None, Some(block)) => BlockEntity::for_block(block).and_then(|entity| {
+                let items = entity.items().map(<[_]>::to_vec);
+                self.world.set_block_entity(position, entity);
+                items
+            }),
+            (None, None) => None,
+        };
+
+        let client = &mut self.clients[client_index];
+        let id = client.state.next_window_id;
+        client.state.next_window_id = id % 100 + 1;
+        let window = OpenWindow {
+            id,
+            kind,
+            slots: items.unwrap_or_else(|| vec![None; kind.size()]),
+            properties: vec![],
+            position,
+        };
+        play::open_window(client, &window)?;
+        client.state.window = Some(window);
+        play::window_items(client)?;
+        self.sync_container(position)
+    }
+
+    // write a container window's slots back to its block entity and show the change to
+    // everyone else looking into it
+    fn store_container(&mut self, client_index: usize) -> Result<()> {
+        let (position, slots) = match &self.clients[client_index].state.window {
+            Some(window) => (window.position, window.slots.clone()),
+            None => return Ok(()),
+        };
+        match self.world.block_entity_mut(position) {
+            Some(entity) if entity.items().is_some() => entity.set_items(slots),
+            _ => return Ok(()),
+        }
+        self.sync_container(position)
+    }
+
+    // send the slots and properties of a container that changed to the windows open on it
+    fn sync_container(&mut self, position: Position) -> Result<()> {
+        let entity = match self.world.block_entity(position) {
+            Some(entity) => entity,
+            None => return Ok(()),
+        };
+        let items = match entity.items() {
+            Some(items) => items,
+            None => return Ok(()),
+        };
+        let properties = entity.window_properties();
+        for client in self.clients.iter_mut() {
+            let window = match &mut client.state.window {
+                Some(window) if window.position == position => window,
+                _ => continue,
+            };
+            let id = window.id;
+            let mut changed_slots = vec![];
+            for (index, (slot, item)) in window.slots.iter_mut().zip(items).enumerate() {
+                if slot != item {
+                    slot.clone_from(item);
+                    changed_slots.push((index, item.clone()));
+                }
+            }
+            let mut changed_properties = vec![];
+            window.properties.resize(properties.len(), -1);
+            for (index, (shown, value)) in window.properties.iter_mut().zip(&properties).enumerate()
+            {
+                if shown != value {
+                    *shown = *value;
+                    changed_properties.push((index as i16, *value));
+                }
+            }
+
+            for (index, item) in changed_slots {
+                play::window_slot(client, id, index, item)?;
+            }
+            for (property, value) in changed_properties {
+                play::window_property(client, id, property, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    // close every window open on a block that went away
+    fn close_windows_at(&mut self, position: Position) -> Result<()> {
+        for client_index in 0..self.clients.len() {
+            let id = match &self.clients[client_index].state.window {
+                Some(window) if window.position == position => window.id,
+                _ => continue,
+            };
+            self.close_window(client_index, id)?;
+            play::close_window(&self.clients[client_index], id)?;
+        }
+        Ok(())
+    }
+
+    // close the open window once its block is gone or the player walked away from it
+    fn window_still_valid(&mut self, client_index: usize) -> Result<bool> {
+        let (id, kind, position) = match &self.clients[client_index].state.window {
+            Some(window) => (window.id, window.kind, window.position),
+            None => return Ok(true),
+        };
+        let block = self.block_state_at(position)?.map(|state| state.block());
+        let Coordinate { x, y, z } = self.clients[client_index].state.coordinate;
+        let dx = x - (position.x as f64 + 0.5);
+        let dy = y - (position.y as f64 + 0.5);
+        let dz = z - (position.z as f64 + 0.5);
+        if block.and_then(WindowKind::for_block) == Some(kind)
+            && dx * dx + dy * dy + dz * dz <= 64.0
+        {
+            return Ok(true);
+        }
+        self.close_window(client_index, id)?;
+        play::close_window(&self.clients[client_index], id)?;
+        Ok(false)
+    }
+
+    // closing a window puts its crafting grid back and throws what the cursor held
+    fn close_window(&mut self, client_index: usize, window_id: u8) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        let state = &mut client.state;
+        let (mut window, player) = match state.window.take() {
+            Some(window) if window.id == window_id => (Some(window), false),
+            None if window_id == window::PLAYER_WINDOW => (None, true),
+            other => {
+                state.window = other;
+                return Ok(());
+            }
+        };
+        let inventory = &mut state.inventory;
+        inventory.drag = None;
+        let cursor = inventory.cursor.take();
+        let kind = window
+            .as_ref()
+            .map_or(WindowKind::Player, |window| window.kind);
+        let slots = match &mut window {
+            Some(window) => &mut window.slots,
+            None => &mut inventory.slots,
+        };
+        let grid = match kind.grid() {
+            Some((grid, _)) => {
+                slots[window::CRAFTING_RESULT] = None;
+                grid.filter_map(|index| slots[index].take()).collect()
+            }
+            None => vec![],
+        };
+        if player {
+            play::set_slot(client, window::CRAFTING_RESULT)?;
+            for index in window::CRAFTING_GRID {
+                play::set_slot(client, index)?;
+            }
+        }
+
+        for mut stack in grid {
+            stack.item_count -= self.add_to_inventory(client_index, &stack)?;
+            if stack.item_count > 0 {
+                self.throw_stack(client_index, stack);
+            }
+        }
+        if let Some(stack) = cursor {
+            self.throw_stack(client_index, stack);
+        }
+        Ok(())
+    }
+
+    // the text of a sign the player just placed
+    fn handle_update_sign(&mut self, client_index: usize, update_sign: UpdateSign) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        if client.state.editing_sign.take() != Some(update_sign.location)
+            || !Self::can_reach(client, update_sign.location)
+        {
+            return Ok(());
+        }
+        if !matches!(
+            self.world.block_entity(update_sign.location),
+            Some(BlockEntity::Sign { .. })
+        ) {
+            return Ok(());
+        }
+        let UpdateSign {
+            location,
+            line_1,
+            line_2,
+            line_3,
+            line_4,
+        } = update_sign;
+        let lines = [line_1, line_2, line_3, line_4].map(|line| {
+            // the client keeps lines short, stop anyone who doesn't
+            let line = line.chars().take(SIGN_LINE_LENGTH).collect::<String>();
+            serde_json::to_string(&Chat::text(line)).expect("chat serializes")
+        });
+        self.world
+            .set_block_entity(location, BlockEntity::Sign { lines });
         Ok(())
     }
 
@@ -426,28 +1305,50 @@ impl Server {
     ) -> Result<()> {
         println!("settings: {:?}", settings);
         let client = self.clients.get_mut(client_index).unwrap();
-        client.state.view_distance = settings.view_distance as usize;
-        let view_distance = client.state.view_distance as i32;
-        let diff = view_distance * 2;
-        let center_chunk_x = (client.state.coordinate.x as i32) >> 4;
-        let center_chunk_z = (client.state.coordinate.x as i32) >> 4;
-
-        let chunk = self.world.fetch_chunk(center_chunk_x, center_chunk_z)?;
-        let packet = chunk.clone().to_packet(center_chunk_x, center_chunk_z)?;
-        client.send_play_packet(packet)?;
-        for x in center_chunk_x - diff..=center_chunk_x + diff {
-            for z in center_chunk_z - diff..=center_chunk_z + diff {
-                if x == center_chunk_x && z == center_chunk_z {
-                    continue;
-                }
-                let chunk = self.world.fetch_chunk(x, z)?;
-
-                let packet = chunk.clone().to_packet(x, z)?;
+        // never more than the server is willing to send
+        client.state.view_distance =
+            (settings.view_distance as usize).min(self.config.view_distance as usize);
+        self.update_view(client_index)
+    }
 
-                client.send_play_packet(packet)?;
+    pub fn handle_chat_message(
+        &mut self,
+        client_index: usize,
+        chat_message: &ChatMessage,
+    ) -> Result<()> {
+        let message = match chat_text(&chat_message.message) {
+            Some(message) => message,
+            None => {
+                let packet = client::PlayPacket::Disconnect(PlayDisconnect {
+                    reason: Chat::translate("multiplayer.disconnect.illegal_characters", vec![]),
+                });
+                self.clients[client_index].send_play_packet(packet)?;
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    "illegal characters in chat",
+                ));
             }
+        };
+        if message.is_empty() {
+            return Ok(());
+        }
+        if let Some(command) = message.strip_prefix('/') {
+            return self.execute_command(client_index, command);
         }
+        let client = &self.clients[client_index];
+
+        println!("<{}> {}", client.state.name.as_ref().unwrap(), message);
+        let message = Chat::translate(
+            "chat.type.text",
+            vec![play::player_name(&client.state), Chat::text(message)],
+        );
+        self.broadcast_chat(message, ChatPosition::Chat)
+    }
 
+    pub fn broadcast_chat(&self, message: Chat, position: ChatPosition) -> Result<()> {
+        for client in self.clients.iter() {
+            play::chat_message(client, message.clone(), position)?;
+        }
         Ok(())
     }
 
@@ -456,84 +1357,859 @@ impl Server {
         client_index: usize,
         placement: &PlayerBlockPlacement,
     ) -> Result<()> {
-        let client = self.clients.get_mut(client_index).unwrap();
-        let selected = if placement.hand.0 == 0 {
+        let client = &self.clients[client_index];
+        let rotation = client.state.rotation;
+        let in_reach = Self::can_reach(client, placement.location);
+        let can_build = client.state.game_mode.can_build();
+        let slot_index = if placement.hand.0 == 0 {
             client.state.inventory.selected + 36
         } else {
             45
         };
-        let item = client.state.inventory.slots[selected].clone();
-
-        if let Some(slot) = item {
-            println!("placement: {:?}", placement);
-            let block_pos = placement.location.offset(placement.face);
-            let item = Item::from_id(slot.item_id.0 as _).expect("Unknown item id");
-            println!("block_pos: {:?}, item: {:?}", block_pos, item);
-            let block = Block::from_name(&item.name()).expect("Unknown block name");
-            self.world.set_block(
-                block_pos.x as usize,
-                block_pos.y as usize,
-                block_pos.z as usize,
-                block,
+        let item = client.state.inventory.slots[slot_index]
+            .as_ref()
+            .and_then(|slot| Item::from_id(slot.item_id.0 as u32));
+        // sneaking with something in hand places it against the block instead
+        let uses_block = !client.state.sneaking || item.is_none();
+        let clicked_state = self.block_state_at(placement.location)?;
+        let window = clicked_state.and_then(|state| WindowKind::for_block(state.block()));
+        if let Some(kind) = window.filter(|_| in_reach && uses_block) {
+            return self.open_window(client_index, kind, placement.location);
+        }
+        // nothing in hand, or an item that isn't a block
+        let block = match item.and_then(|item| placement::block_for_item(item, placement.face)) {
+            Some(block) => block,
+            None => return Ok(()),
+        };
+
+        let clicked = placement.location;
+        let replaces_clicked = clicked_state.is_some_and(|state| {
+            placement::is_replaceable(state)
+                || placement::double_slab(state, block, placement.face, true).is_some()
+        });
+        let position = if replaces_clicked {
+            clicked
+        } else {
+            clicked.offset(placement.face)
+        };
+        let context = PlacementContext {
+            position,
+            face: placement.face,
+            hit_x: (clicked.x - position.x) as f64 + placement.cursor_point_x as f64,
+            hit_y: (clicked.y - position.y) as f64 + placement.cursor_point_y as f64,
+            hit_z: (clicked.z - position.z) as f64 + placement.cursor_point_z as f64,
+            rotation,
+        };
+
+        let existing = self.block_state_at(position)?;
+        let double = existing.and_then(|state| {
+            placement::double_slab(state, block, placement.face, position == clicked)
+        });
+        let blocks = match double {
+            Some(state) => vec![(position, state)],
+            None => placement::place(block, &context),
+        };
+
+        let mut allowed = in_reach && can_build;
+        for (target, state) in blocks.iter() {
+            if !allowed {
+                break;
+            }
+            allowed = match self.block_state_at(*target)? {
+                Some(_) if double.is_some() => true,
+                Some(existing) => placement::is_replaceable(existing),
+                None => false,
+            } && !(state.block().solid() && self.is_occupied(*target));
+        }
+
+        if !allowed {
+            // undo what the client already predicted
+            for (target, _) in blocks.iter() {
+                if let Some(state) = self.block_state_at(*target)? {
+                    play::block_change(&self.clients[client_index], *target, state.id())?;
+                }
+            }
+            play::set_slot(&self.clients[client_index], slot_index)?;
+            return Ok(());
+        }
+
+        for (target, state) in blocks {
+            self.world.set_block_state(
+                target.x as usize,
+                target.y as usize,
+                target.z as usize,
+                state,
             )?;
+            if let Some(BlockEntity::Sign { .. }) = self.world.block_entity(target) {
+                let client = &mut self.clients[client_index];
+                client.state.editing_sign = Some(target);
+                play::open_sign_editor(client, target)?;
+            }
+        }
 
-            let packet = client::PlayPacket::BlockChange(BlockChange {
-                location: block_pos,
-                block_id: Var(block.default_state() as i32),
-            });
-            client.send_play_packet(packet)?;
+        let client = &mut self.clients[client_index];
+        if client.state.game_mode != GameMode::Creative {
+            let slot = &mut client.state.inventory.slots[slot_index];
+            if let Some(stack) = slot {
+                stack.item_count -= 1;
+                if stack.item_count == 0 {
+                    *slot = None;
+                }
+            }
+            play::set_slot(client, slot_index)?;
         }
 
         Ok(())
     }
 
+    // None outside the world height or in chunks that aren't there
+    fn block_state_at(&mut self, position: Position) -> Result<Option<BlockState>> {
+        if !(0..256).contains(&position.y) {
+            return Ok(None);
+        }
+        self.world.get_block_state(
+            position.x as usize,
+            position.y as usize,
+            position.z as usize,
+        )
+    }
+
+    // whether a block at position would overlap a player's bounding box
+    fn is_occupied(&self, position: Position) -> bool {
+        self.clients.iter().any(|client| {
+            let state = &client.state;
+            if state.game_mode == GameMode::Spectator || client.is_disconnected() {
+                return false;
+            }
+            let Coordinate { x, y, z } = state.coordinate;
+            let (block_x, block_y, block_z) =
+                (position.x as f64, position.y as f64, position.z as f64);
+            x + 0.3 > block_x
+                && x - 0.3 < block_x + 1.0
+                && y + 1.8 > block_y
+                && y < block_y + 1.0
+                && z + 0.3 > block_z
+                && z - 0.3 < block_z + 1.0
+        })
+    }
+
     pub fn handle_block_digging(
         &mut self,
         client_index: usize,
         digging: &PlayerDigging,
     ) -> Result<()> {
-        let client = self.clients.get_mut(client_index).unwrap();
         println!("digging: {:?}", digging);
-        if let DiggingStatus::StartedDigging = digging.status {
+        let location = digging.location;
+        let progress = self.break_progress(client_index, location)?;
+        let client = &mut self.clients[client_index];
+
+        let successful = match digging.status {
+            DiggingStatus::StartedDigging => {
+                if !Self::can_reach(client, location) || !client.state.game_mode.can_build() {
+                    false
+                } else if client.state.game_mode == GameMode::Creative || progress >= 1.0 {
+                    self.break_block(client_index, location)?;
+                    true
+                } else {
+                    client.state.digging = Some(Digging {
+                        location,
+                        started_tick: self.ticks,
+                        stage: -1,
+                    });
+                    true
+                }
+            }
+            DiggingStatus::CancelledDigging => {
+                client.state.digging = None;
+                self.broadcast_break_animation(client_index, location, -1)?;
+                true
+            }
+            DiggingStatus::FinishedDigging => {
+                // vanilla lets the client finish a little early to make up for latency
+                let finished = match client.state.digging.take() {
+                    // the game mode may have changed since digging started
+                    Some(started)
+                        if started.location == location && client.state.game_mode.can_build() =>
+                    {
+                        let ticks = (self.ticks - started.started_tick + 1) as f32;
+                        progress * ticks >= 0.7
+                    }
+                    _ => false,
+                };
+                if finished {
+                    self.break_block(client_index, location)?;
+                }
+                self.broadcast_break_animation(client_index, location, -1)?;
+                finished
+            }
+            DiggingStatus::DropItem => return self.drop_held_item(client_index, false),
+            DiggingStatus::DropItemStack => return self.drop_held_item(client_index, true),
+            _ => return Ok(()),
+        };
+
+        let block = self.world.get_block(
+            location.x as usize,
+            location.y as usize,
+            location.z as usize,
+        )?;
+        let packet = client::PlayPacket::AcknowledgePlayerDigging(AcknowledgePlayerDigging {
+            location,
+            block: Var(block.unwrap_or(0) as i32),
+            status: digging.status,
+            successful,
+        });
+        self.clients[client_index].send_play_packet(packet)?;
+
+        Ok(())
+    }
+
+    // per tick progress of the client breaking the block at location
+    fn break_progress(&mut self, client_index: usize, location: Position) -> Result<f32> {
+        let state = self.world.get_block_state(
+            location.x as usize,
+            location.y as usize,
+            location.z as usize,
+        )?;
+        let block = match state.map(|state| state.block()) {
+            Some(block) if block.diggable() => block,
+            _ => return Ok(0.0),
+        };
+
+        let tool = self.held_item(client_index);
+        let on_ground = self.clients[client_index].state.on_ground;
+        Ok(digging::break_progress(block, tool, on_ground))
+    }
+
+    fn held_item(&self, client_index: usize) -> Option<Item> {
+        let inventory = &self.clients[client_index].state.inventory;
+        inventory.slots[inventory.selected + 36]
+            .as_ref()
+            .and_then(|slot| Item::from_id(slot.item_id.0 as u32))
+    }
+
+    fn can_reach(client: &Client, location: Position) -> bool {
+        let Coordinate { x, y, z } = client.state.coordinate;
+        let dx = x - (location.x as f64 + 0.5);
+        let dy = y - (location.y as f64 + 0.5) + 1.5;
+        let dz = z - (location.z as f64 + 0.5);
+        dx * dx + dy * dy + dz * dz <= 36.0
+    }
+
+    fn break_block(&mut self, client_index: usize, location: Position) -> Result<()> {
+        let state = self.world.get_block_state(
+            location.x as usize,
+            location.y as usize,
+            location.z as usize,
+        )?;
+        let player = &mut self.clients[client_index].state;
+        if state.is_some() && player.game_mode.is_vulnerable() {
+            player.health.exhaust(health::BREAK_EXHAUSTION);
+        }
+        let is_creative = player.game_mode == GameMode::Creative;
+        let held = self.held_item(client_index);
+        let harvested =
+            !is_creative && state.is_some_and(|state| digging::can_harvest(state.block(), held));
+        self.destroy_block(location, harvested)
+    }
+
+    // take a block out of the world the way breaking it does, dropping what it drops
+    // when harvested. what a container held is spilled either way
+    fn destroy_block(&mut self, location: Position, harvested: bool) -> Result<()> {
+        let (x, y, z) = (
+            location.x as usize,
+            location.y as usize,
+            location.z as usize,
+        );
+        let state = self.world.get_block_state(x, y, z)?;
+        // the other half of a door, tall plant or bed goes with it
+        let mut partner = None;
+        if let Some(position) = state.and_then(|state| placement::partner_position(location, state))
+        {
+            if (0..256).contains(&position.y) {
+                let other = self.world.get_block_state(
+                    position.x as usize,
+                    position.y as usize,
+                    position.z as usize,
+                )?;
+                partner = other
+                    .filter(|other| placement::is_partner(state.unwrap(), *other))
+                    .map(|other| (position, other));
+            }
+        }
+        let contents = self
+            .world
+            .block_entity(location)
+            .and_then(|entity| entity.items())
+            .map_or(vec![], |items| items.iter().flatten().cloned().collect());
+        self.close_windows_at(location)?;
+        self.world.set_block(x, y, z, Block::Air)?;
+        if let Some((position, _)) = partner {
             self.world.set_block(
-                digging.location.x as usize,
-                digging.location.y as usize,
-                digging.location.z as usize,
+                position.x as usize,
+                position.y as usize,
+                position.z as usize,
                 Block::Air,
             )?;
-            let packet = client::PlayPacket::BlockChange(BlockChange {
-                location: digging.location,
-                block_id: Var(0),
+        }
+
+        // containers spill what they held, even in creative
+        let mut drops = contents;
+        let state = match state {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+        let mut rng = rand::thread_rng();
+        if harvested {
+            drops.extend(digging::drops(state, &mut rng));
+            if let Some((_, other)) = partner {
+                drops.extend(digging::drops(other, &mut rng));
+            }
+        }
+        for stack in drops {
+            let position = Coordinate {
+                x: location.x as f64 + rng.gen_range(0.25, 0.75),
+                y: location.y as f64 + rng.gen_range(0.25, 0.75),
+                z: location.z as f64 + rng.gen_range(0.25, 0.75),
+            };
+            let velocity = Coordinate {
+                x: rng.gen_range(-0.1, 0.1),
+                y: 0.2,
+                z: rng.gen_range(-0.1, 0.1),
+            };
+            self.spawn_item(position, velocity, stack, DROPPED_PICKUP_DELAY);
+        }
+        Ok(())
+    }
+
+    // advance the crack texture other players see on blocks being broken
+    fn update_digging(&mut self) -> Result<()> {
+        for client_index in 0..self.clients.len() {
+            let digging = match self.clients[client_index].state.digging {
+                Some(digging) => digging,
+                None => continue,
+            };
+            let progress = self.break_progress(client_index, digging.location)?;
+            let ticks = (self.ticks - digging.started_tick + 1) as f32;
+            let stage = ((progress * ticks * 10.0) as i8).min(9);
+            if stage != digging.stage {
+                self.broadcast_break_animation(client_index, digging.location, stage)?;
+                if let Some(digging) = &mut self.clients[client_index].state.digging {
+                    digging.stage = stage;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn broadcast_break_animation(
+        &self,
+        client_index: usize,
+        location: Position,
+        destroy_stage: i8,
+    ) -> Result<()> {
+        let packet = client::PlayPacket::BlockBreakAnimation(BlockBreakAnimation {
+            entity_id: Var(self.clients[client_index].state.entity_id),
+            location,
+            destroy_stage,
+        });
+        for (index, other) in self.clients.iter().enumerate() {
+            let Coordinate { x, y, z } = other.state.coordinate;
+            let dx = x - location.x as f64;
+            let dy = y - location.y as f64;
+            let dz = z - location.z as f64;
+            if index != client_index && dx * dx + dy * dy + dz * dz < 32.0 * 32.0 {
+                other.send_play_packet(packet.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn spawn_item(
+        &mut self,
+        position: Coordinate,
+        velocity: Coordinate,
+        stack: Slot,
+        pickup_delay: u32,
+    ) {
+        let entity_id = self.world.next_entity_id();
+        let item = ItemEntity::new(entity_id, position, velocity, stack, pickup_delay);
+        self.items.push(item);
+    }
+
+    // throw one item or the whole held stack
+    fn drop_held_item(&mut self, client_index: usize, whole_stack: bool) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        let slot = client.state.inventory.selected + 36;
+        let held = match &mut client.state.inventory.slots[slot] {
+            Some(held) => held,
+            None => return Ok(()),
+        };
+        let count = if whole_stack { held.item_count } else { 1 };
+        let mut stack = held.clone();
+        stack.item_count = count;
+        held.item_count -= count;
+        if held.item_count == 0 {
+            client.state.inventory.slots[slot] = None;
+        }
+        play::set_slot(client, slot)?;
+        self.throw_stack(client_index, stack);
+        Ok(())
+    }
+
+    // throw a stack where the player is looking
+    fn throw_stack(&mut self, client_index: usize, stack: Slot) {
+        let client = &self.clients[client_index];
+        let Coordinate { x, y, z } = client.state.coordinate;
+        let Rotation { yaw, pitch } = client.state.rotation;
+        let (yaw, pitch) = (yaw.to_radians() as f64, pitch.to_radians() as f64);
+        let mut rng = rand::thread_rng();
+        let spread = rng.gen_range(0.0, std::f64::consts::PI * 2.0);
+        let spread_power = rng.gen_range(0.0, 0.02);
+        let position = Coordinate {
+            x,
+            y: y + 1.62 - 0.3,
+            z,
+        };
+        let velocity = Coordinate {
+            x: -yaw.sin() * pitch.cos() * 0.3 + spread.cos() * spread_power,
+            y: -pitch.sin() * 0.3 + 0.1 + rng.gen_range(-0.1, 0.1) * 0.1,
+            z: yaw.cos() * pitch.cos() * 0.3 + spread.sin() * spread_power,
+        };
+        self.spawn_item(position, velocity, stack, THROWN_PICKUP_DELAY);
+    }
+
+    // drop a stack in a random direction around the player, as a dying player does
+    fn drop_around(&mut self, client_index: usize, stack: Slot) {
+        let Coordinate { x, y, z } = self.clients[client_index].state.coordinate;
+        let mut rng = rand::thread_rng();
+        let power = rng.gen_range(0.0, 0.5);
+        let angle: f64 = rng.gen_range(0.0, std::f64::consts::PI * 2.0);
+        let position = Coordinate {
+            x,
+            y: y + 1.62 - 0.3,
+            z,
+        };
+        let velocity = Coordinate {
+            x: -angle.sin() * power,
+            y: 0.2,
+            z: angle.cos() * power,
+        };
+        self.spawn_item(position, velocity, stack, THROWN_PICKUP_DELAY);
+    }
+
+    // add as much of the stack as fits, matching stacks first, then empty slots,
+    // hotbar before the main inventory. returns how many items were added
+    fn add_to_inventory(&mut self, client_index: usize, stack: &Slot) -> Result<u8> {
+        let client = &mut self.clients[client_index];
+        let stack_size =
+            Item::from_id(stack.item_id.0 as u32).map_or(64, |item| item.stack_size()) as u8;
+        let slot_order = (36..45).chain(9..36).collect::<Vec<_>>();
+        let mut remaining = stack.item_count;
+        let mut changed = vec![];
+
+        for slot in slot_order.iter() {
+            if let Some(existing) = &mut client.state.inventory.slots[*slot] {
+                let space = stack_size.saturating_sub(existing.item_count);
+                if existing.item_id.0 == stack.item_id.0
+                    && existing.meta.is_none()
+                    && stack.meta.is_none()
+                    && space > 0
+                    && remaining > 0
+                {
+                    let added = space.min(remaining);
+                    existing.item_count += added;
+                    remaining -= added;
+                    changed.push(*slot);
+                }
+            }
+        }
+        for slot in slot_order.iter() {
+            if remaining > 0 && client.state.inventory.slots[*slot].is_none() {
+                let added = stack_size.min(remaining);
+                let mut new_stack = stack.clone();
+                new_stack.item_count = added;
+                client.state.inventory.slots[*slot] = Some(new_stack);
+                remaining -= added;
+                changed.push(*slot);
+            }
+        }
+
+        for slot in changed {
+            play::set_slot(client, slot)?;
+        }
+        Ok(stack.item_count - remaining)
+    }
+
+    fn update_items(&mut self) -> Result<()> {
+        for index in 0..self.items.len() {
+            // items wait in chunks that aren't loaded, nothing loads a chunk for them
+            let (chunk_x, chunk_z) = self.items[index].chunk();
+            if self.world.get_chunk(chunk_x, chunk_z)?.is_none() {
+                continue;
+            }
+            let previous = self.items[index].position;
+            self.items[index].tick(&mut self.world)?;
+            let item = &self.items[index];
+            let Coordinate { x, y, z } = item.position;
+            if x == previous.x && y == previous.y && z == previous.z {
+                continue;
+            }
+
+            let entity_id = item.entity_id.into();
+            let on_ground = item.on_ground;
+            let packet = match (
+                position_delta(previous.x, x),
+                position_delta(previous.y, y),
+                position_delta(previous.z, z),
+            ) {
+                (Some(delta_x), Some(delta_y), Some(delta_z)) => {
+                    client::PlayPacket::EntityPosition(EntityPosition {
+                        entity_id,
+                        delta_x,
+                        delta_y,
+                        delta_z,
+                        on_ground,
+                    })
+                }
+                _ => client::PlayPacket::EntityTeleport(EntityTeleport {
+                    entity_id,
+                    x,
+                    y,
+                    z,
+                    yaw: 0,
+                    pitch: 0,
+                    on_ground,
+                }),
+            };
+            for client in self.clients.iter() {
+                if client.state.tracked_entities.contains(&item.entity_id) {
+                    client.send_play_packet(packet.clone())?;
+                }
+            }
+        }
+
+        self.merge_items()?;
+        self.pick_up_items()?;
+
+        for index in (0..self.items.len()).rev() {
+            if self.items[index].is_despawned() {
+                self.remove_item(index)?;
+            }
+        }
+        Ok(())
+    }
+
+    // the bigger stack absorbs the smaller one. items only touch others in the
+    // same or a neighbouring chunk, so only those are compared
+    fn merge_items(&mut self) -> Result<()> {
+        let mut by_chunk: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, item) in self.items.iter().enumerate() {
+            by_chunk.entry(item.chunk()).or_default().push(index);
+        }
+        let mut merged = HashSet::new();
+        let mut changed = vec![];
+        for i in 0..self.items.len() {
+            let (chunk_x, chunk_z) = self.items[i].chunk();
+            let nearby = (-1..=1)
+                .flat_map(|dx| (-1..=1).map(move |dz| (chunk_x + dx, chunk_z + dz)))
+                .filter_map(|chunk| by_chunk.get(&chunk))
+                .flatten()
+                .copied()
+                .filter(|j| *j > i)
+                .collect::<Vec<_>>();
+            for j in nearby {
+                if merged.contains(&i) {
+                    break;
+                }
+                if merged.contains(&j) || !self.items[i].can_merge(&self.items[j]) {
+                    continue;
+                }
+                let (keep, absorbed) =
+                    if self.items[i].stack.item_count >= self.items[j].stack.item_count {
+                        (i, j)
+                    } else {
+                        (j, i)
+                    };
+                let other = self.items[absorbed].clone();
+                self.items[keep].merge(&other);
+                merged.insert(absorbed);
+                changed.push(keep);
+            }
+        }
+
+        for index in changed {
+            if merged.contains(&index) {
+                continue;
+            }
+            let item = &self.items[index];
+            for client in self.clients.iter() {
+                if client.state.tracked_entities.contains(&item.entity_id) {
+                    play::item_metadata(client, item)?;
+                }
+            }
+        }
+        let mut merged = merged.into_iter().collect::<Vec<_>>();
+        merged.sort_unstable();
+        for index in merged.into_iter().rev() {
+            self.remove_item(index)?;
+        }
+        Ok(())
+    }
+
+    fn pick_up_items(&mut self) -> Result<()> {
+        for item_index in (0..self.items.len()).rev() {
+            if self.items[item_index].pickup_delay > 0 {
+                continue;
+            }
+            for client_index in 0..self.clients.len() {
+                let item = &self.items[item_index];
+                let state = &self.clients[client_index].state;
+                if state.game_mode == GameMode::Spectator
+                    || self.clients[client_index].is_disconnected()
+                    || !Self::is_in_pickup_range(state.coordinate, item.position)
+                {
+                    continue;
+                }
+
+                let stack = item.stack.clone();
+                let picked_up = self.add_to_inventory(client_index, &stack)?;
+                if picked_up == 0 {
+                    continue;
+                }
+                let item = &self.items[item_index];
+                let collector = &self.clients[client_index];
+                let packet = client::PlayPacket::CollectItem(CollectItem {
+                    collected_entity_id: item.entity_id.into(),
+                    collector_entity_id: collector.state.entity_id.into(),
+                    pickup_item_count: (picked_up as i32).into(),
+                });
+                for (index, client) in self.clients.iter().enumerate() {
+                    if index == client_index
+                        || client.state.tracked_entities.contains(&item.entity_id)
+                    {
+                        client.send_play_packet(packet.clone())?;
+                    }
+                }
+
+                if picked_up == stack.item_count {
+                    self.remove_item(item_index)?;
+                    break;
+                }
+                let item = &mut self.items[item_index];
+                item.stack.item_count -= picked_up;
+                let item = &self.items[item_index];
+                for client in self.clients.iter() {
+                    if client.state.tracked_entities.contains(&item.entity_id) {
+                        play::item_metadata(client, item)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // the player's hitbox grown by a block sideways and half a block vertically
+    fn is_in_pickup_range(player: Coordinate, item: Coordinate) -> bool {
+        (player.x - item.x).abs() <= 0.3 + 1.0 + 0.125
+            && (player.z - item.z).abs() <= 0.3 + 1.0 + 0.125
+            && item.y + 0.25 >= player.y - 0.5
+            && item.y <= player.y + 1.8 + 0.5
+    }
+
+    fn remove_item(&mut self, index: usize) -> Result<()> {
+        let item = self.items.remove(index);
+        for client in self.clients.iter_mut() {
+            if client.state.tracked_entities.remove(&item.entity_id) {
+                play::destroy_entities(client, vec![item.entity_id])?;
+            }
+        }
+        Ok(())
+    }
+
+    // send this tick's block changes to every client that has the chunk loaded
+    fn broadcast_block_changes(&mut self) -> Result<()> {
+        for (chunk_x, chunk_z, changes) in self.world.take_block_changes()? {
+            let packet = match changes[..] {
+                [] => continue,
+                [(location, block_id)] => client::PlayPacket::BlockChange(BlockChange {
+                    location,
+                    block_id: Var(block_id as i32),
+                }),
+                _ => client::PlayPacket::MultiBlockChange(MultiBlockChange {
+                    chunk_x,
+                    chunk_z,
+                    records: changes
+                        .iter()
+                        .map(|(location, block_id)| BlockChangeRecord {
+                            horizontal_position: ((location.x & 0xF) << 4 | (location.z & 0xF))
+                                as u8,
+                            y: location.y as u8,
+                            block_id: Var(*block_id as i32),
+                        })
+                        .collect(),
+                }),
+            };
+            for client in self.clients.iter() {
+                if Self::is_chunk_loaded(client, chunk_x, chunk_z) {
+                    client.send_play_packet(packet.clone())?;
+                }
+            }
+        }
+
+        for (location, entity) in self.world.take_block_entity_changes() {
+            let action = match entity.update_action() {
+                Some(action) => action,
+                None => continue,
+            };
+            let packet = client::PlayPacket::BlockEntityData(BlockEntityData {
+                location,
+                action,
+                nbt: Nbt(entity.update_tag(location)),
             });
-            client.send_play_packet(packet)?;
+            for client in self.clients.iter() {
+                if Self::is_chunk_loaded(client, location.x >> 4, location.z >> 4) {
+                    client.send_play_packet(packet.clone())?;
+                }
+            }
         }
 
+        for (chunk_x, chunk_z, sections) in self.world.take_light_changes() {
+            let packet = match self.world.get_chunk(chunk_x, chunk_z)? {
+                Some(chunk) => chunk.light_packet(chunk_x, chunk_z, sections),
+                None => continue,
+            };
+            for client in self.clients.iter() {
+                if Self::is_chunk_loaded(client, chunk_x, chunk_z) {
+                    client.send_play_packet(packet.clone())?;
+                }
+            }
+        }
         Ok(())
     }
 
+    // tick furnaces and show their progress to whoever has them open
+    fn update_block_entities(&mut self) -> Result<()> {
+        for position in self.world.tick_block_entities()? {
+            self.sync_container(position)?;
+        }
+        Ok(())
+    }
+
+    fn is_chunk_loaded(client: &Client, chunk_x: i32, chunk_z: i32) -> bool {
+        client.state.loaded_chunks.contains(&(chunk_x, chunk_z))
+    }
+
     fn get_chunk_distance(x1: i32, z1: i32, x2: i32, z2: i32) -> u32 {
         let x = x1 - x2;
         let z = z1 - z2;
         x.abs().max(z.abs()) as u32
     }
 
+    // players join and respawn in the middle of the spawn block
+    fn spawn_coordinate(&self) -> Coordinate {
+        let spawn = self.world.level.spawn;
+        Coordinate {
+            x: spawn.x as f64 + 0.5,
+            y: spawn.y as f64,
+            z: spawn.z as f64 + 0.5,
+        }
+    }
+
     fn handle_login_handle(&mut self, client: &mut Client) -> Result<()> {
-        play::join_game(client)?;
+        client.state.entity_id = self.world.next_entity_id();
+        client.state.game_mode = self.config.gamemode;
+        client.state.flying = self.config.gamemode == GameMode::Spectator;
+        if let Some(uuid) = client.state.uuid {
+            client.state.permission_level = self.ops.permission_level(uuid);
+        }
+        let spawn = self.world.level.spawn;
+        client.state.coordinate = self.spawn_coordinate();
+        client.state.last_chunk_x = spawn.x >> 4;
+        client.state.last_chunk_z = spawn.z >> 4;
+
+        play::join_game(
+            client,
+            self.world.level_type(),
+            self.config.max_players,
+            self.config.view_distance,
+        )?;
+        play::player_abilities(client)?;
         play::held_item_change(client)?;
+        play::window_items(client)?;
         play::declare_recipes(client)?;
         play::tags(client)?;
         play::entity_status(client)?;
-        // play::decrale_commands(&mut stream)?;
+        let permission_level = client.state.permission_level;
+        play::declare_commands(client, self.commands.declare_commands(permission_level))?;
         play::unlock_recipes(client)?;
         play::play_position_and_look(client)?;
-        play::player_info(client)?;
+        let mut players = vec![play::player_info_entry(&client.state)];
+        for other in self.clients.iter() {
+            play::player_info(
+                other,
+                PlayerInfoAction::AddPlayer(vec![play::player_info_entry(&client.state)]),
+            )?;
+            players.push(play::player_info_entry(&other.state));
+        }
+        play::player_info(client, PlayerInfoAction::AddPlayer(players))?;
         play::update_view_position(client)?;
         // play::world_border(client)?;
-        play::spawn_position(client)?;
+        play::spawn_position(client, spawn)?;
+        play::time_update(client, &self.world.level)?;
         play::play_position_and_look(client)?;
 
         Ok(())
     }
+
+    // logging in again from somewhere else ends the old session
+    fn kick_duplicate_login(&mut self, client: &Client) -> Result<()> {
+        let uuid = match client.state.uuid {
+            Some(uuid) => uuid,
+            None => return Ok(()),
+        };
+        let index = match self
+            .clients
+            .iter()
+            .position(|other| other.state.uuid == Some(uuid))
+        {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+        let old = self.clients.remove(index);
+        let packet = client::PlayPacket::Disconnect(PlayDisconnect {
+            reason: Chat::translate("multiplayer.disconnect.duplicate_login", vec![]),
+        });
+        // the old connection may already be gone
+        let _ = old.send_play_packet(packet);
+        old.is_disconnected.set(true);
+        self.handle_logout(&old)
+    }
+
+    fn handle_logout(&mut self, client: &Client) -> Result<()> {
+        let message = Chat::translate(
+            "multiplayer.player.left",
+            vec![play::player_name(&client.state)],
+        )
+        .color(Color::Yellow);
+        self.broadcast_chat(message, ChatPosition::System)?;
+
+        let entity_id = client.state.entity_id;
+        for other in self.clients.iter_mut() {
+            if let Some(uuid) = client.state.uuid {
+                play::player_info(other, PlayerInfoAction::RemovePlayer(vec![uuid]))?;
+            }
+            if other.state.tracked_entities.remove(&entity_id) {
+                play::destroy_entities(other, vec![entity_id])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// relative move in 1/4096ths of a block, None when it doesn't fit in a short
+fn position_delta(previous: f64, current: f64) -> Option<i16> {
+    let delta = (current * 4096.0).floor() as i64 - (previous * 4096.0).floor() as i64;
+    i16::try_from(delta).ok()
 }
 
 pub struct Reader {
@@ -722,22 +2398,120 @@ pub async fn handle_status_handshake(worker: &mut Worker) -> Result<()> {
     Ok(())
 }
 
-pub async fn handle_login_handshake(worker: &mut Worker) -> Result<()> {
-    if let LoginPacket::LoginStart(start) = worker.read_packet_exact().await? {
-        let crack = false;
-        if crack {
-            login::crack_login_start(worker, start).await?;
-        } else {
-            login::login_start(worker, start).await?;
+pub async fn handle_login_handshake(
+    worker: &mut Worker,
+    server_address: &str,
+) -> Result<NextConnect> {
+    let forwarding = worker.config.proxy_forwarding;
+    let bungeecord_player = if forwarding == Forwarding::BungeeCord {
+        match forwarding::parse_bungeecord(server_address) {
+            Ok(player) => Some(player),
+            Err(err) => {
+                println!("bungeecord forwarding failed: {:?}", err);
+                let reason = Chat::text(
+                    "If you wish to use IP forwarding, please enable it in your BungeeCord config as well!",
+                );
+                login::disconnect(worker, reason).await?;
+                return Ok(NextConnect::Disconnect);
+            }
         }
+    } else {
+        None
+    };
+
+    let start = match worker.read_packet_exact().await? {
+        LoginPacket::LoginStart(start) => start,
+        _ => return unexpected_login_packet(worker).await,
+    };
+    let players_online = worker.players_online.load(Ordering::Relaxed);
+    if players_online >= worker.config.max_players as usize {
+        let reason = Chat::translate("multiplayer.disconnect.server_full", vec![]);
+        login::disconnect(worker, reason).await?;
+        return Ok(NextConnect::Disconnect);
+    }
+    if !login::is_valid_username(&start.name) {
+        println!("invalid username: {:?}", start.name);
+        let reason = Chat::translate("multiplayer.disconnect.invalid_player_data", vec![]);
+        login::disconnect(worker, reason).await?;
+        return Ok(NextConnect::Disconnect);
+    }
+    if let Some(player) = bungeecord_player {
+        login::bungeecord_login_start(worker, start, player).await?;
+    } else if forwarding == Forwarding::Velocity {
+        if !login::velocity_login_start(worker, start).await? {
+            return Ok(NextConnect::Disconnect);
+        }
+    } else {
+        login::login_start(worker, start).await?;
     }
     if !worker.state.crack {
-        if let LoginPacket::EncryptionResponse(encryption_response) =
-            worker.read_packet_exact().await?
-        {
-            login::encryption_response(worker, encryption_response).await?;
+        let encryption_response = match worker.read_packet_exact().await? {
+            LoginPacket::EncryptionResponse(encryption_response) => encryption_response,
+            _ => return unexpected_login_packet(worker).await,
+        };
+        if !login::encryption_response(worker, encryption_response).await? {
+            return Ok(NextConnect::Disconnect);
         }
     }
 
-    Ok(())
+    Ok(NextConnect::Join)
+}
+
+async fn unexpected_login_packet(worker: &mut Worker) -> Result<NextConnect> {
+    println!("unexpected packet during login");
+    login::disconnect(worker, Chat::text("Unexpected packet during login")).await?;
+    Ok(NextConnect::Disconnect)
+}
+
+// the message cut to what vanilla accepts, None when it has formatting codes or
+// control characters
+fn chat_text(message: &str) -> Option<&str> {
+    if message
+        .chars()
+        .any(|c| c == '\u{a7}' || c < ' ' || c == '\u{7f}')
+    {
+        return None;
+    }
+    let end = message
+        .char_indices()
+        .nth(MAX_CHAT_LENGTH)
+        .map_or(message.len(), |(index, _)| index);
+    Some(message[..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chat_keeps_plain_text() {
+        assert_eq!(chat_text("  hello there "), Some("hello there"));
+        assert_eq!(chat_text("/tp 0 64 0"), Some("/tp 0 64 0"));
+        assert_eq!(chat_text("héllo 日本"), Some("héllo 日本"));
+    }
+
+    #[test]
+    fn chat_rejects_formatting_codes() {
+        assert_eq!(chat_text("\u{a7}cServer restarting"), None);
+    }
+
+    #[test]
+    fn chat_rejects_control_characters() {
+        assert_eq!(chat_text("line\nbreak"), None);
+        assert_eq!(chat_text("tab\t"), None);
+        assert_eq!(chat_text("\u{0}"), None);
+    }
+
+    #[test]
+    fn chat_rejects_delete() {
+        assert_eq!(chat_text("oops\u{7f}"), None);
+    }
+
+    #[test]
+    fn chat_is_cut_to_256_characters() {
+        let long = "é".repeat(300);
+        assert_eq!(chat_text(&long).unwrap().chars().count(), MAX_CHAT_LENGTH);
+        let exact = "a".repeat(MAX_CHAT_LENGTH);
+        assert_eq!(chat_text(&exact), Some(exact.as_str()));
+    }
 }
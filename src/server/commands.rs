@@ -0,0 +1,561 @@
+use std::{io::Result, time::SystemTime};
+
+use kareki_data::{block::Block, block_state::BlockState, item::Item};
+
+use crate::{
+    command::{
+        argument, literal, Argument, CommandContext, CommandDispatcher, CommandError,
+        CommandResult, EntitySelector, Parser,
+    },
+    packet::{
+        client::{self, PlayDisconnect, PlayerInfoAction, TabComplete, TabCompleteMatch},
+        server::TabComplete as TabCompleteRequest,
+    },
+    play,
+    state::{Coordinate, GameMode},
+    types::{
+        chat::{Chat, ChatPosition, Color},
+        slot::Slot,
+    },
+};
+
+use super::Server;
+
+// a hundred stacks of 64
+const MAX_GIVE_COUNT: i32 = 6400;
+
+pub fn dispatcher() -> CommandDispatcher<Server> {
+    let mut dispatcher = CommandDispatcher::default();
+
+    let single_entity = Parser::Entity {
+        single: true,
+        players_only: false,
+    };
+    let entities = Parser::Entity {
+        single: false,
+        players_only: false,
+    };
+    let players = Parser::Entity {
+        single: false,
+        players_only: true,
+    };
+
+    dispatcher.register(
+        literal("tp")
+            .requires(2)
+            .then(argument("location", Parser::Vec3).executes(tp_location))
+            .then(argument("destination", single_entity.clone()).executes(tp_entity))
+            .then(
+                argument("targets", entities)
+                    .then(argument("location", Parser::Vec3).executes(tp_location))
+                    .then(argument("destination", single_entity).executes(tp_entity)),
+            ),
+    );
+
+    let mut gamemode = literal("gamemode").requires(2);
+    for game_mode in [
+        GameMode::Survival,
+        GameMode::Creative,
+        GameMode::Adventure,
+        GameMode::Spectator,
+    ]
+    .iter()
+    {
+        gamemode = gamemode.then(
+            literal(game_mode.name())
+                .executes(gamemode_command)
+                .then(argument("target", players.clone()).executes(gamemode_command)),
+        );
+    }
+    dispatcher.register(gamemode);
+
+    dispatcher.register(
+        literal("give").requires(2).then(
+            argument("targets", players.clone()).then(
+                argument("item", Parser::ItemStack).executes(give).then(
+                    argument(
+                        "count",
+                        Parser::Integer {
+                            min: Some(1),
+                            max: Some(MAX_GIVE_COUNT),
+                        },
+                    )
+                    .executes(give),
+                ),
+            ),
+        ),
+    );
+
+    let mut time_set = literal("set");
+    for name in ["day", "noon", "night", "midnight"].iter() {
+        time_set = time_set.then(literal(name).executes(time_set_command));
+    }
+    let mut time_query = literal("query");
+    for name in ["daytime", "gametime", "day"].iter() {
+        time_query = time_query.then(literal(name).executes(time_query_command));
+    }
+    dispatcher.register(
+        literal("time")
+            .requires(2)
+            .then(time_set.then(argument("time", Parser::Time).executes(time_set_command)))
+            .then(literal("add").then(argument("time", Parser::Time).executes(time_add)))
+            .then(time_query),
+    );
+
+    dispatcher.register(
+        literal("kick").requires(3).then(
+            argument("targets", players)
+                .executes(kick)
+                .then(argument("reason", Parser::Message).executes(kick)),
+        ),
+    );
+
+    dispatcher.register(literal("list").executes(list));
+
+    let mut setblock = argument("block", Parser::BlockState).executes(setblock_command);
+    for mode in ["destroy", "keep", "replace"].iter() {
+        setblock = setblock.then(literal(mode).executes(setblock_command));
+    }
+    dispatcher.register(
+        literal("setblock")
+            .requires(2)
+            .then(argument("pos", Parser::BlockPos).then(setblock)),
+    );
+
+    dispatcher
+}
+
+impl Server {
+    pub fn execute_command(&mut self, sender: usize, input: &str) -> Result<()> {
+        let name = self.clients[sender].state.name.clone().unwrap_or_default();
+        println!("{} issued server command: /{}", name, input);
+
+        let permission_level = self.clients[sender].state.permission_level;
+        let result = match self.commands.parse(sender, permission_level, input) {
+            Ok((handler, context)) => handler(self, &context),
+            Err(err) => {
+                let client = &self.clients[sender];
+                let message = err.message.clone().color(Color::Red);
+                play::chat_message(client, message, ChatPosition::System)?;
+                play::chat_message(client, err.context(), ChatPosition::System)?;
+                return Ok(());
+            }
+        };
+        match result {
+            Ok(()) => Ok(()),
+            Err(CommandError::Message(message)) => {
+                let message = message.color(Color::Red);
+                play::chat_message(&self.clients[sender], message, ChatPosition::System)
+            }
+            Err(CommandError::Io(err)) => Err(err),
+        }
+    }
+
+    pub fn handle_tab_complete(
+        &mut self,
+        client_index: usize,
+        request: &TabCompleteRequest,
+    ) -> Result<()> {
+        let input = request
+            .text
+            .strip_prefix('/')
+            .unwrap_or(request.text.as_str());
+        let offset = request.text.len() - input.len();
+        let names = self
+            .clients
+            .iter()
+            .filter_map(|client| client.state.name.clone())
+            .collect::<Vec<_>>();
+        let permission_level = self.clients[client_index].state.permission_level;
+        let (start, suggestions) = self.commands.suggest(input, permission_level, &names);
+
+        let packet = client::PlayPacket::TabComplete(TabComplete {
+            id: request.transaction_id,
+            start: ((start + offset) as i32).into(),
+            length: ((input.len() - start) as i32).into(),
+            matches: suggestions
+                .into_iter()
+                .map(|text| TabCompleteMatch {
+                    text,
+                    tooltip: None,
+                })
+                .collect(),
+        });
+        self.clients[client_index].send_play_packet(packet)?;
+        Ok(())
+    }
+
+    fn send_feedback(&self, sender: usize, message: Chat) -> CommandResult {
+        play::chat_message(&self.clients[sender], message, ChatPosition::System)?;
+        Ok(())
+    }
+
+    fn select_players(
+        &self,
+        sender: usize,
+        selector: &EntitySelector,
+    ) -> std::result::Result<Vec<usize>, CommandError> {
+        let online = (0..self.clients.len())
+            .filter(|index| !self.clients[*index].is_disconnected())
+            .collect::<Vec<_>>();
+        let origin = self.clients[sender].state.coordinate;
+        let selected = match selector {
+            EntitySelector::Player(name) => online
+                .into_iter()
+                .filter(|index| {
+                    let state = &self.clients[*index].state;
+                    state
+                        .name
+                        .as_ref()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                })
+                .collect(),
+            EntitySelector::Sender => vec![sender],
+            EntitySelector::NearestPlayer => online
+                .into_iter()
+                .map(|index| {
+                    (
+                        index,
+                        distance(origin, self.clients[index].state.coordinate),
+                    )
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(index, _)| index)
+                .into_iter()
+                .collect(),
+            EntitySelector::RandomPlayer => {
+                let nanos = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap()
+                    .subsec_nanos() as usize;
+                online
+                    .get(nanos % online.len().max(1))
+                    .copied()
+                    .into_iter()
+                    .collect()
+            }
+            EntitySelector::AllPlayers | EntitySelector::AllEntities => online,
+        };
+        if selected.is_empty() {
+            return Err(Chat::translate("argument.entity.notfound.player", vec![]).into());
+        }
+        Ok(selected)
+    }
+
+    fn player_name(&self, index: usize) -> Chat {
+        play::player_name(&self.clients[index].state)
+    }
+
+    fn teleport(&mut self, client_index: usize, destination: Coordinate) -> Result<()> {
+        let state = &mut self.clients[client_index].state;
+        let previous = state.coordinate;
+        state.health.fall_distance = 0.0;
+        self.set_position(client_index, destination.x, destination.y, destination.z)?;
+        play::play_position_and_look(&mut self.clients[client_index])?;
+        self.broadcast_movement(client_index, Some(previous), false, false)
+    }
+
+    fn set_game_mode(&mut self, client_index: usize, game_mode: GameMode) -> Result<()> {
+        let client = &mut self.clients[client_index];
+        let state = &mut client.state;
+        state.game_mode = game_mode;
+        // spectators always fly, survival and adventure players never do
+        state.flying = game_mode == GameMode::Spectator || state.flying && game_mode.may_fly();
+        play::game_mode(client)?;
+        play::player_abilities(client)?;
+
+        let uuid = client.state.uuid.unwrap();
+        let update = vec![(uuid, (game_mode.id() as i32).into())];
+        for client in self.clients.iter() {
+            play::player_info(client, PlayerInfoAction::UpdateGamemode(update.clone()))?;
+        }
+        Ok(())
+    }
+
+    // whatever does not fit is dropped at the player's feet
+    fn give_item(&mut self, client_index: usize, item: Item, count: i32) -> Result<()> {
+        let stack_size = item.stack_size() as i32;
+        let mut remaining = count;
+        while remaining > 0 {
+            let stack = Slot {
+                item_id: (item.id() as i32).into(),
+                item_count: stack_size.min(remaining) as u8,
+                meta: None,
+            };
+            remaining -= stack.item_count as i32;
+
+            let added = self.add_to_inventory(client_index, &stack)?;
+            if added < stack.item_count {
+                let leftover = Slot {
+                    item_count: stack.item_count - added,
+                    ..stack
+                };
+                let position = self.clients[client_index].state.coordinate;
+                self.spawn_item(position, Coordinate::default(), leftover, 0);
+            }
+        }
+        Ok(())
+    }
+
+    fn broadcast_time(&mut self) -> Result<()> {
+        for client in self.clients.iter_mut() {
+            play::time_update(client, &self.world.level)?;
+        }
+        Ok(())
+    }
+}
+
+fn distance(a: Coordinate, b: Coordinate) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+}
+
+// where an entity may be, which also keeps relative coordinates from overflowing
+fn in_spawnable_bounds(coordinate: Coordinate) -> bool {
+    coordinate.x.abs() < 3.0e7 && coordinate.z.abs() < 3.0e7 && coordinate.y.abs() < 2.0e7
+}
+
+fn number<T: ToString>(value: T) -> Chat {
+    Chat::text(value.to_string())
+}
+
+// the selected players, or the sender when the argument was left out
+fn targets(
+    server: &Server,
+    context: &CommandContext,
+    name: &str,
+) -> std::result::Result<Vec<usize>, CommandError> {
+    match context.entity(name) {
+        Some(selector) => server.select_players(context.sender, selector),
+        None => Ok(vec![context.sender]),
+    }
+}
+
+fn tp_location(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let targets = targets(server, context, "targets")?;
+    let sender = &server.clients[context.sender].state;
+    let destination = context
+        .coordinates("location")
+        .unwrap()
+        .resolve(sender.coordinate, sender.rotation);
+    if !in_spawnable_bounds(destination) {
+        return Err(Chat::translate("commands.teleport.invalidPosition", vec![]).into());
+    }
+
+    for target in targets.iter() {
+        server.teleport(*target, destination)?;
+    }
+    let Coordinate { x, y, z } = destination;
+    let message = match targets[..] {
+        [target] => Chat::translate(
+            "commands.teleport.success.location.single",
+            vec![server.player_name(target), number(x), number(y), number(z)],
+        ),
+        _ => Chat::translate(
+            "commands.teleport.success.location.multiple",
+            vec![number(targets.len()), number(x), number(y), number(z)],
+        ),
+    };
+    server.send_feedback(context.sender, message)
+}
+
+fn tp_entity(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let targets = targets(server, context, "targets")?;
+    let destination =
+        server.select_players(context.sender, context.entity("destination").unwrap())?[0];
+    let coordinate = server.clients[destination].state.coordinate;
+
+    for target in targets.iter() {
+        server.teleport(*target, coordinate)?;
+    }
+    let message = match targets[..] {
+        [target] => Chat::translate(
+            "commands.teleport.success.entity.single",
+            vec![server.player_name(target), server.player_name(destination)],
+        ),
+        _ => Chat::translate(
+            "commands.teleport.success.entity.multiple",
+            vec![number(targets.len()), server.player_name(destination)],
+        ),
+    };
+    server.send_feedback(context.sender, message)
+}
+
+fn gamemode_command(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let game_mode = GameMode::from_name(&context.literals()[1]).unwrap();
+    let mode_name = Chat::translate(format!("gameMode.{}", game_mode.name()), vec![]);
+
+    for target in targets(server, context, "target")? {
+        if server.clients[target].state.game_mode == game_mode {
+            continue;
+        }
+        server.set_game_mode(target, game_mode)?;
+        if target == context.sender {
+            let message =
+                Chat::translate("commands.gamemode.success.self", vec![mode_name.clone()]);
+            server.send_feedback(context.sender, message)?;
+        } else {
+            let message = Chat::translate("gameMode.changed", vec![mode_name.clone()]);
+            server.send_feedback(target, message)?;
+            let message = Chat::translate(
+                "commands.gamemode.success.other",
+                vec![server.player_name(target), mode_name.clone()],
+            );
+            server.send_feedback(context.sender, message)?;
+        }
+    }
+    Ok(())
+}
+
+fn give(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let targets = server.select_players(context.sender, context.entity("targets").unwrap())?;
+    let item = match context.argument("item") {
+        Some(Argument::ItemStack(item)) => *item,
+        _ => unreachable!(),
+    };
+    let count = context.integer("count").unwrap_or(1);
+
+    for target in targets.iter() {
+        server.give_item(*target, item, count)?;
+    }
+    let item_name = Chat::text(item.display_name());
+    let message = match targets[..] {
+        [target] => Chat::translate(
+            "commands.give.success.single",
+            vec![number(count), item_name, server.player_name(target)],
+        ),
+        _ => Chat::translate(
+            "commands.give.success.multiple",
+            vec![number(count), item_name, number(targets.len())],
+        ),
+    };
+    server.send_feedback(context.sender, message)
+}
+
+fn time_set_command(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let time = match context.literals().get(2).map(|literal| literal.as_str()) {
+        Some("day") => 1000,
+        Some("noon") => 6000,
+        Some("night") => 13000,
+        Some("midnight") => 18000,
+        _ => context.time("time").unwrap(),
+    };
+    server.world.level.day_time = time as i64;
+    server.broadcast_time()?;
+
+    let message = Chat::translate("commands.time.set", vec![number(time)]);
+    server.send_feedback(context.sender, message)
+}
+
+fn time_add(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let level = &mut server.world.level;
+    level.day_time += context.time("time").unwrap() as i64;
+    let time = level.day_time % 24000;
+    server.broadcast_time()?;
+
+    let message = Chat::translate("commands.time.set", vec![number(time)]);
+    server.send_feedback(context.sender, message)
+}
+
+fn time_query_command(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let level = &server.world.level;
+    let value = match context.literals()[2].as_str() {
+        "daytime" => level.day_time % 24000,
+        "gametime" => level.time % i32::MAX as i64,
+        _ => level.day_time / 24000 % i32::MAX as i64,
+    };
+    let message = Chat::translate("commands.time.query", vec![number(value)]);
+    server.send_feedback(context.sender, message)
+}
+
+fn kick(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let targets = server.select_players(context.sender, context.entity("targets").unwrap())?;
+    let reason = match context.string("reason") {
+        Some(reason) => Chat::text(reason),
+        None => Chat::translate("multiplayer.disconnect.kicked", vec![]),
+    };
+
+    for target in targets {
+        let client = &server.clients[target];
+        let packet = client::PlayPacket::Disconnect(PlayDisconnect {
+            reason: reason.clone(),
+        });
+        client.send_play_packet(packet)?;
+        client.is_disconnected.set(true);
+
+        let message = Chat::translate(
+            "commands.kick.success",
+            vec![server.player_name(target), reason.clone()],
+        );
+        server.send_feedback(context.sender, message)?;
+    }
+    Ok(())
+}
+
+fn list(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let names = server
+        .clients
+        .iter()
+        .filter_map(|client| client.state.name.clone())
+        .collect::<Vec<_>>();
+    let message = Chat::translate(
+        "commands.list.players",
+        vec![
+            number(names.len()),
+            number(server.config.max_players),
+            Chat::text(names.join(", ")),
+        ],
+    );
+    server.send_feedback(context.sender, message)
+}
+
+fn setblock_command(server: &mut Server, context: &CommandContext) -> CommandResult {
+    let sender = &server.clients[context.sender].state;
+    let position = context
+        .coordinates("pos")
+        .unwrap()
+        .resolve_block(sender.coordinate, sender.rotation);
+    let state = match context.argument("block") {
+        Some(Argument::BlockState(state)) => *state,
+        _ => unreachable!(),
+    };
+    let mode = context.literals().get(1).map(|literal| literal.as_str());
+
+    if position.y < 0 || position.y > 255 {
+        return Err(Chat::translate("argument.pos.outofworld", vec![]).into());
+    }
+    let (x, y, z) = (
+        position.x as usize,
+        position.y as usize,
+        position.z as usize,
+    );
+    if server
+        .world
+        .get_chunk(position.x >> 4, position.z >> 4)?
+        .is_none()
+    {
+        return Err(Chat::translate("argument.pos.unloaded", vec![]).into());
+    }
+
+    let current = server
+        .world
+        .get_block_state(x, y, z)?
+        .unwrap_or_else(|| BlockState::new(Block::Air));
+    let is_air = matches!(
+        current.block(),
+        Block::Air | Block::CaveAir | Block::VoidAir
+    );
+    if current == state || (mode == Some("keep") && !is_air) {
+        return Err(Chat::translate("commands.setblock.failed", vec![]).into());
+    }
+    // broken like a player with any tool would, then replaced
+    if mode == Some("destroy") {
+        server.destroy_block(position, true)?;
+    }
+    server.world.set_block_state(x, y, z, state)?;
+
+    let message = Chat::translate(
+        "commands.setblock.success",
+        vec![number(position.x), number(position.y), number(position.z)],
+    );
+    server.send_feedback(context.sender, message)
+}
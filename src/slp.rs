@@ -1,4 +1,5 @@
 use std::io::Error;
+use std::sync::atomic::Ordering;
 
 use serde::Serialize;
 
@@ -44,11 +45,11 @@ pub async fn handle_slp_status(worker: &mut Worker, _request: Request) -> Result
 
     let status_response = StatusResponse {
         description: Description {
-            text: "Yo this was implemented by naari3 @naari_ @_naari_".to_string(),
+            text: worker.config.motd.clone(),
         },
         players: Players {
-            max: 12345,
-            online: 126534640, // Japan population
+            max: worker.config.max_players as i32,
+            online: worker.players_online.load(Ordering::Relaxed) as i32,
             sample: Some(vec![]),
         },
         favicon: None,
@@ -1,43 +1,165 @@
-use std::time::Instant;
+use std::{collections::HashSet, net::IpAddr, str::FromStr, time::Instant};
 
-use openssl::{pkey::Private, rsa::Rsa};
 use uuid::Uuid;
 
-use crate::types::slot::Slot;
+use crate::{
+    health::Health,
+    packet::client::Properties,
+    types::{position::Position, slot::Slot},
+    window::{Drag, OpenWindow},
+};
 
 #[derive(Debug, Clone)]
 pub struct State {
     pub name: Option<String>,
-    pub rsa: Option<Rsa<Private>>,
+    // the token sent in the encryption request, the response has to echo it
+    pub verify_token: Vec<u8>,
     pub uuid: Option<Uuid>,
+    // where the player connects from, as forwarded by a proxy if there is one
+    pub address: Option<IpAddr>,
+    // skin and cape textures
+    pub properties: Vec<Properties>,
     pub crack: bool,
+    pub entity_id: i32,
+    pub game_mode: GameMode,
     pub coordinate: Coordinate,
     pub rotation: Rotation,
+    pub on_ground: bool,
+    pub sneaking: bool,
+    pub sprinting: bool,
+    // only while the game mode allows flying
+    pub flying: bool,
+    // from ops.json, which commands the player may run
+    pub permission_level: u8,
+    pub health: Health,
     pub inventory: Inventory,
+    // a window opened on a block, the inventory is open otherwise
+    pub window: Option<OpenWindow>,
+    pub next_window_id: u8,
+    // the sign the player placed last, the only one they may write on
+    pub editing_sign: Option<Position>,
     pub last_keep_alive: Instant,
     pub last_chunk_x: i32,
     pub last_chunk_z: i32,
     pub view_distance: usize,
+    // chunks this client has been sent
+    pub loaded_chunks: HashSet<(i32, i32)>,
+    // chunks in view that are waiting to be sent, nearest first
+    pub pending_chunks: Vec<(i32, i32)>,
+    // entity ids of the other players and items this client has been sent a spawn packet for
+    pub tracked_entities: HashSet<i32>,
+    pub digging: Option<Digging>,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             name: Default::default(),
-            rsa: Default::default(),
+            verify_token: vec![],
             uuid: Default::default(),
+            address: None,
+            properties: vec![],
             crack: false,
+            entity_id: 0,
+            game_mode: GameMode::Creative,
             coordinate: Default::default(),
             rotation: Default::default(),
+            on_ground: false,
+            sneaking: false,
+            sprinting: false,
+            flying: false,
+            permission_level: 0,
+            health: Default::default(),
             inventory: Default::default(),
+            window: None,
+            next_window_id: 1,
+            editing_sign: None,
             last_keep_alive: Instant::now(),
             last_chunk_x: 0,
             last_chunk_z: 0,
             view_distance: 0,
+            loaded_chunks: HashSet::new(),
+            pending_chunks: vec![],
+            tracked_entities: HashSet::new(),
+            digging: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+impl GameMode {
+    pub fn id(self) -> u8 {
+        match self {
+            GameMode::Survival => 0,
+            GameMode::Creative => 1,
+            GameMode::Adventure => 2,
+            GameMode::Spectator => 3,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Survival => "survival",
+            GameMode::Creative => "creative",
+            GameMode::Adventure => "adventure",
+            GameMode::Spectator => "spectator",
+        }
+    }
+
+    // whether the player can be hurt, creative and spectator players only by the void
+    pub fn is_vulnerable(self) -> bool {
+        matches!(self, GameMode::Survival | GameMode::Adventure)
+    }
+
+    // creative and spectator players fly
+    pub fn may_fly(self) -> bool {
+        matches!(self, GameMode::Creative | GameMode::Spectator)
+    }
+
+    // adventure and spectator players can't break or place blocks
+    pub fn can_build(self) -> bool {
+        matches!(self, GameMode::Survival | GameMode::Creative)
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(GameMode::Survival),
+            1 => Some(GameMode::Creative),
+            2 => Some(GameMode::Adventure),
+            3 => Some(GameMode::Spectator),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "survival" => Some(GameMode::Survival),
+            "creative" => Some(GameMode::Creative),
+            "adventure" => Some(GameMode::Adventure),
+            "spectator" => Some(GameMode::Spectator),
+            _ => None,
+        }
+    }
+}
+
+// server.properties takes the name or the id
+impl FromStr for GameMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+            .or_else(|| s.parse().ok().and_then(Self::from_id))
+            .ok_or(())
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Coordinate {
     pub x: f64,
@@ -51,10 +173,22 @@ pub struct Rotation {
     pub pitch: f32,
 }
 
+// block being broken in survival
+#[derive(Debug, Clone, Copy)]
+pub struct Digging {
+    pub location: Position,
+    pub started_tick: u64,
+    // last destroy stage sent to the other players
+    pub stage: i8,
+}
+
 #[derive(Debug, Clone)]
 pub struct Inventory {
     pub slots: Vec<Option<Slot>>,
     pub selected: usize,
+    // the stack held by the mouse while a window is open
+    pub cursor: Option<Slot>,
+    pub drag: Option<Drag>,
 }
 
 impl Default for Inventory {
@@ -62,6 +196,8 @@ impl Default for Inventory {
         Self {
             slots: vec![Default::default(); 46],
             selected: 0,
+            cursor: None,
+            drag: None,
         }
     }
 }
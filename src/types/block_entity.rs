@@ -1,5 +1,578 @@
-use serde::Serialize;
+use kareki_data::{
+    block::Block,
+    item::Item,
+    recipe::{Recipe, RecipeKind},
+};
+use nbt::{Blob, Map, Value};
 
-// TODO: I dont know how do this need to behave
-#[derive(Debug, Clone, Serialize)]
-pub struct BlockEntity {}
+use super::{position::Position, slot::Slot};
+use crate::crafting;
+
+pub const CHEST_SLOTS: usize = 27;
+
+// furnace slots
+pub const FURNACE_INPUT: usize = 0;
+pub const FURNACE_FUEL: usize = 1;
+pub const FURNACE_RESULT: usize = 2;
+// ticks an item takes to smelt when there is no recipe to say otherwise
+pub const DEFAULT_COOK_TIME: u16 = 200;
+
+// what update block entity's action says about the tag
+const ACTION_BANNER: u8 = 6;
+const ACTION_SIGN: u8 = 9;
+const ACTION_BED: u8 = 11;
+
+// the extra data a block keeps beside its state
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlockEntity {
+    Chest { items: Vec<Option<Slot>> },
+    Furnace(Furnace),
+    // each line is a json text component
+    Sign { lines: [String; 4] },
+    // pattern code and dye color of each layer
+    Banner { patterns: Vec<(String, i32)> },
+    Bed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Furnace {
+    pub items: Vec<Option<Slot>>,
+    // ticks of fuel left and how long the current fuel burns in total
+    pub burn_time: u16,
+    pub burn_total: u16,
+    pub cook_time: u16,
+    pub cook_total: u16,
+}
+
+impl Furnace {
+    pub fn is_lit(&self) -> bool {
+        self.burn_time > 0
+    }
+
+    // one tick of burning and smelting, true when anything changed
+    pub fn tick(&mut self) -> bool {
+        let before = self.clone();
+        if self.is_lit() {
+            self.burn_time -= 1;
+        }
+        let recipe = item_in(&self.items[FURNACE_INPUT]).and_then(crafting::smelting_recipe);
+        if let Some(Recipe {
+            kind: RecipeKind::Smelting { cooking_time, .. },
+            ..
+        }) = recipe
+        {
+            self.cook_total = *cooking_time as u16;
+        }
+        let fuel = item_in(&self.items[FURNACE_FUEL]);
+
+        if self.is_lit() || (fuel.is_some() && recipe.is_some()) {
+            let can_smelt = recipe.is_some_and(|recipe| self.has_room_for(recipe));
+            if !self.is_lit() && can_smelt {
+                if let Some(burn_time) = fuel.and_then(crafting::burn_time) {
+                    self.burn_time = burn_time;
+                    self.burn_total = burn_time;
+                    self.use_fuel();
+                }
+            }
+            match recipe {
+                Some(recipe) if self.is_lit() && can_smelt => {
+                    self.cook_time += 1;
+                    if self.cook_time >= self.cook_total {
+                        self.cook_time = 0;
+                        self.smelt(recipe);
+                    }
+                }
+                _ => self.cook_time = 0,
+            }
+        } else if self.cook_time > 0 {
+            // cools down twice as fast as it cooks
+            self.cook_time = self.cook_time.saturating_sub(2);
+        }
+        *self != before
+    }
+
+    // a different input starts cooking from scratch
+    pub fn set_items(&mut self, items: Vec<Option<Slot>>) {
+        if item_in(&items[FURNACE_INPUT]) != item_in(&self.items[FURNACE_INPUT]) {
+            self.cook_time = 0;
+        }
+        self.items = items;
+    }
+
+    fn has_room_for(&self, recipe: &Recipe) -> bool {
+        match &self.items[FURNACE_RESULT] {
+            None => true,
+            Some(stack) => {
+                item_in(&self.items[FURNACE_RESULT]) == Some(recipe.result)
+                    && (stack.item_count + recipe.count) as u32 <= recipe.result.stack_size()
+            }
+        }
+    }
+
+    fn use_fuel(&mut self) {
+        let fuel = &mut self.items[FURNACE_FUEL];
+        if let Some(stack) = fuel {
+            stack.item_count -= 1;
+            if stack.item_count == 0 {
+                // lava leaves its bucket behind
+                *fuel = item_in(fuel)
+                    .and_then(crafting::remainder)
+                    .map(|item| stack_of(item, 1));
+            }
+        }
+    }
+
+    fn smelt(&mut self, recipe: &Recipe) {
+        if let Some(input) = &mut self.items[FURNACE_INPUT] {
+            input.item_count -= 1;
+            if input.item_count == 0 {
+                self.items[FURNACE_INPUT] = None;
+            }
+        }
+        match &mut self.items[FURNACE_RESULT] {
+            Some(stack) => stack.item_count += recipe.count,
+            None => self.items[FURNACE_RESULT] = Some(stack_of(recipe.result, recipe.count)),
+        }
+    }
+}
+
+impl BlockEntity {
+    // the empty block entity of a block that was just placed
+    pub fn for_block(block: Block) -> Option<Self> {
+        let name = block.name();
+        let entity = match block {
+            Block::Chest => BlockEntity::Chest {
+                items: vec![None; CHEST_SLOTS],
+            },
+            Block::Furnace => BlockEntity::Furnace(Furnace {
+                items: vec![None; 3],
+                burn_time: 0,
+                burn_total: 0,
+                cook_time: 0,
+                cook_total: DEFAULT_COOK_TIME,
+            }),
+            _ if name.ends_with("_sign") => BlockEntity::Sign {
+                lines: Default::default(),
+            },
+            _ if name.ends_with("_banner") => BlockEntity::Banner { patterns: vec![] },
+            _ if name.ends_with("_bed") => BlockEntity::Bed,
+            _ => return None,
+        };
+        Some(entity)
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            BlockEntity::Chest { .. } => "minecraft:chest",
+            BlockEntity::Furnace(_) => "minecraft:furnace",
+            BlockEntity::Sign { .. } => "minecraft:sign",
+            BlockEntity::Banner { .. } => "minecraft:banner",
+            BlockEntity::Bed => "minecraft:bed",
+        }
+    }
+
+    // the slots a container shows in its window
+    pub fn items(&self) -> Option<&[Option<Slot>]> {
+        match self {
+            BlockEntity::Chest { items } => Some(items),
+            BlockEntity::Furnace(furnace) => Some(&furnace.items),
+            _ => None,
+        }
+    }
+
+    pub fn set_items(&mut self, new_items: Vec<Option<Slot>>) {
+        match self {
+            BlockEntity::Chest { items } => *items = new_items,
+            BlockEntity::Furnace(furnace) => furnace.set_items(new_items),
+            _ => {}
+        }
+    }
+
+    // window properties of the container, for furnaces the fuel left, how long the fuel
+    // burns, the cooking progress and how long cooking takes
+    pub fn window_properties(&self) -> Vec<i16> {
+        match self {
+            BlockEntity::Furnace(furnace) => vec![
+                furnace.burn_time as i16,
+                furnace.burn_total as i16,
+                furnace.cook_time as i16,
+                furnace.cook_total as i16,
+            ],
+            _ => vec![],
+        }
+    }
+
+    // the action update block entity tells clients with, None for block entities clients
+    // don't need to hear about
+    pub fn update_action(&self) -> Option<u8> {
+        match self {
+            BlockEntity::Sign { .. } => Some(ACTION_SIGN),
+            BlockEntity::Banner { .. } => Some(ACTION_BANNER),
+            BlockEntity::Bed => Some(ACTION_BED),
+            _ => None,
+        }
+    }
+
+    // what clients are sent, containers keep their items to themselves
+    pub fn update_tag(&self, position: Position) -> Blob {
+        let mut tag = Blob::new();
+        for (name, value) in self.header(position) {
+            tag.insert(name, value).expect("tag names are strings");
+        }
+        for (name, value) in self.data(false) {
+            tag.insert(name, value).expect("tag names are strings");
+        }
+        tag
+    }
+
+    // the compound kept in the chunk's TileEntities
+    pub fn to_nbt(&self, position: Position) -> Map<String, Value> {
+        let mut tag = self.header(position);
+        tag.extend(self.data(true));
+        tag
+    }
+
+    pub fn from_nbt(tag: &Map<String, Value>) -> Option<(Position, Self)> {
+        let int = |name: &str| match tag.get(name) {
+            Some(Value::Int(value)) => Some(*value),
+            _ => None,
+        };
+        let short = |name: &str| match tag.get(name) {
+            Some(Value::Short(value)) => *value.max(&0) as u16,
+            _ => 0,
+        };
+        let position = Position {
+            x: int("x")?,
+            y: int("y")? as i16,
+            z: int("z")?,
+        };
+        let id = match tag.get("id") {
+            Some(Value::String(id)) => id.as_str(),
+            _ => return None,
+        };
+        let entity = match id {
+            "minecraft:chest" => BlockEntity::Chest {
+                items: items_from_nbt(tag, CHEST_SLOTS),
+            },
+            "minecraft:furnace" => BlockEntity::Furnace(Furnace {
+                items: items_from_nbt(tag, 3),
+                burn_time: short("BurnTime"),
+                // not saved, the flame starts out full after a load
+                burn_total: short("BurnTime"),
+                cook_time: short("CookTime"),
+                cook_total: match short("CookTimeTotal") {
+                    0 => DEFAULT_COOK_TIME,
+                    total => total,
+                },
+            }),
+            "minecraft:sign" => {
+                let mut lines: [String; 4] = Default::default();
+                for (index, line) in lines.iter_mut().enumerate() {
+                    if let Some(Value::String(text)) = tag.get(&format!("Text{}", index + 1)) {
+                        *line = text.clone();
+                    }
+                }
+                BlockEntity::Sign { lines }
+            }
+            "minecraft:banner" => {
+                let patterns = match tag.get("Patterns") {
+                    Some(Value::List(patterns)) => patterns
+                        .iter()
+                        .filter_map(|pattern| match pattern {
+                            Value::Compound(pattern) => {
+                                match (pattern.get("Pattern"), pattern.get("Color")) {
+                                    (Some(Value::String(code)), Some(Value::Int(color))) => {
+                                        Some((code.clone(), *color))
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
+                BlockEntity::Banner { patterns }
+            }
+            "minecraft:bed" => BlockEntity::Bed,
+            _ => return None,
+        };
+        Some((position, entity))
+    }
+
+    fn header(&self, position: Position) -> Map<String, Value> {
+        let mut tag = Map::new();
+        tag.insert("id".to_string(), Value::String(self.id().to_string()));
+        tag.insert("x".to_string(), Value::Int(position.x));
+        tag.insert("y".to_string(), Value::Int(position.y as i32));
+        tag.insert("z".to_string(), Value::Int(position.z));
+        tag
+    }
+
+    // everything beside the id and position, items only when saving
+    fn data(&self, with_items: bool) -> Map<String, Value> {
+        let mut tag = Map::new();
+        match self {
+            BlockEntity::Chest { items } if with_items => {
+                tag.insert("Items".to_string(), items_to_nbt(items));
+            }
+            BlockEntity::Furnace(furnace) if with_items => {
+                tag.insert("Items".to_string(), items_to_nbt(&furnace.items));
+                tag.insert(
+                    "BurnTime".to_string(),
+                    Value::Short(furnace.burn_time as i16),
+                );
+                tag.insert(
+                    "CookTime".to_string(),
+                    Value::Short(furnace.cook_time as i16),
+                );
+                tag.insert(
+                    "CookTimeTotal".to_string(),
+                    Value::Short(furnace.cook_total as i16),
+                );
+            }
+            BlockEntity::Sign { lines } => {
+                for (index, line) in lines.iter().enumerate() {
+                    tag.insert(format!("Text{}", index + 1), Value::String(line.clone()));
+                }
+                tag.insert("Color".to_string(), Value::String("black".to_string()));
+            }
+            BlockEntity::Banner { patterns } => {
+                let patterns = patterns
+                    .iter()
+                    .map(|(code, color)| {
+                        let mut pattern = Map::new();
+                        pattern.insert("Pattern".to_string(), Value::String(code.clone()));
+                        pattern.insert("Color".to_string(), Value::Int(*color));
+                        Value::Compound(pattern)
+                    })
+                    .collect();
+                tag.insert("Patterns".to_string(), Value::List(patterns));
+            }
+            _ => {}
+        }
+        tag
+    }
+}
+
+fn item_in(slot: &Option<Slot>) -> Option<Item> {
+    slot.as_ref()
+        .and_then(|stack| Item::from_id(stack.item_id.0 as u32))
+}
+
+fn stack_of(item: Item, count: u8) -> Slot {
+    Slot {
+        item_id: (item.id() as i32).into(),
+        item_count: count,
+        meta: None,
+    }
+}
+
+// only the non-empty slots are stored, each with its index. item nbt isn't kept yet
+fn items_to_nbt(items: &[Option<Slot>]) -> Value {
+    let items = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, slot)| {
+            let stack = slot.as_ref()?;
+            let item = Item::from_id(stack.item_id.0 as u32)?;
+            let mut tag = Map::new();
+            tag.insert("Slot".to_string(), Value::Byte(index as i8));
+            tag.insert(
+                "id".to_string(),
+                Value::String(format!("minecraft:{}", item.name())),
+            );
+            tag.insert("Count".to_string(), Value::Byte(stack.item_count as i8));
+            Some(Value::Compound(tag))
+        })
+        .collect();
+    Value::List(items)
+}
+
+fn items_from_nbt(tag: &Map<String, Value>, size: usize) -> Vec<Option<Slot>> {
+    let mut items = vec![None; size];
+    let list = match tag.get("Items") {
+        Some(Value::List(list)) => list,
+        _ => return items,
+    };
+    for item in list {
+        let item = match item {
+            Value::Compound(item) => item,
+            _ => continue,
+        };
+        let (index, id, count) = match (item.get("Slot"), item.get("id"), item.get("Count")) {
+            (Some(Value::Byte(index)), Some(Value::String(id)), Some(Value::Byte(count))) => {
+                (*index as usize, id, *count)
+            }
+            _ => continue,
+        };
+        let stack = Item::from_name(id.trim_start_matches("minecraft:"))
+            .map(|item| stack_of(item, count as u8));
+        if index < size && count > 0 {
+            items[index] = stack;
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn furnace(input: Option<Slot>, fuel: Option<Slot>, result: Option<Slot>) -> Furnace {
+        Furnace {
+            items: vec![input, fuel, result],
+            burn_time: 0,
+            burn_total: 0,
+            cook_time: 0,
+            cook_total: DEFAULT_COOK_TIME,
+        }
+    }
+
+    fn count(slot: &Option<Slot>) -> u8 {
+        slot.as_ref().map_or(0, |stack| stack.item_count)
+    }
+
+    #[test]
+    fn smelts_with_fuel() {
+        let mut furnace = furnace(
+            Some(stack_of(Item::IronOre, 2)),
+            Some(stack_of(Item::Coal, 3)),
+            None,
+        );
+        assert!(furnace.tick());
+        assert!(furnace.is_lit());
+        assert_eq!((furnace.burn_time, furnace.burn_total), (1600, 1600));
+        assert_eq!(count(&furnace.items[FURNACE_FUEL]), 2);
+        for _ in 1..200 {
+            furnace.tick();
+        }
+        assert_eq!(
+            furnace.items[FURNACE_RESULT],
+            Some(stack_of(Item::IronIngot, 1))
+        );
+        assert_eq!(count(&furnace.items[FURNACE_INPUT]), 1);
+        assert_eq!(furnace.cook_time, 0);
+        assert_eq!(furnace.burn_time, 1401);
+        // the lit coal carries on without taking another
+        assert_eq!(count(&furnace.items[FURNACE_FUEL]), 2);
+    }
+
+    #[test]
+    fn lava_leaves_its_bucket() {
+        let mut furnace = furnace(
+            Some(stack_of(Item::IronOre, 1)),
+            Some(stack_of(Item::LavaBucket, 1)),
+            None,
+        );
+        furnace.tick();
+        assert_eq!(furnace.burn_time, 20000);
+        assert_eq!(furnace.items[FURNACE_FUEL], Some(stack_of(Item::Bucket, 1)));
+    }
+
+    #[test]
+    fn waits_for_room_in_the_result() {
+        let full = furnace(
+            Some(stack_of(Item::IronOre, 1)),
+            Some(stack_of(Item::Coal, 1)),
+            Some(stack_of(Item::IronIngot, 64)),
+        );
+        let mut furnace = full.clone();
+        assert!(!furnace.tick());
+        assert_eq!(furnace, full);
+
+        // nor onto something else
+        let mut furnace = full.clone();
+        furnace.items[FURNACE_RESULT] = Some(stack_of(Item::Stone, 1));
+        furnace.tick();
+        assert!(!furnace.is_lit());
+        assert_eq!(count(&furnace.items[FURNACE_FUEL]), 1);
+
+        let mut furnace = full;
+        furnace.items[FURNACE_RESULT] = Some(stack_of(Item::IronIngot, 63));
+        furnace.tick();
+        assert!(furnace.is_lit());
+    }
+
+    #[test]
+    fn cools_down_twice_as_fast() {
+        let mut furnace = furnace(Some(stack_of(Item::IronOre, 1)), None, None);
+        furnace.cook_time = 5;
+        assert!(furnace.tick());
+        assert_eq!(furnace.cook_time, 3);
+        furnace.tick();
+        furnace.tick();
+        assert_eq!(furnace.cook_time, 0);
+        assert!(!furnace.tick());
+    }
+
+    #[test]
+    fn a_new_input_starts_over() {
+        let mut furnace = furnace(Some(stack_of(Item::IronOre, 2)), None, None);
+        furnace.cook_time = 50;
+        furnace.set_items(vec![Some(stack_of(Item::IronOre, 5)), None, None]);
+        assert_eq!(furnace.cook_time, 50);
+        furnace.set_items(vec![Some(stack_of(Item::Sand, 5)), None, None]);
+        assert_eq!(furnace.cook_time, 0);
+    }
+
+    fn round_trip(entity: BlockEntity) {
+        let position = Position {
+            x: -5,
+            y: 70,
+            z: 12,
+        };
+        let tag = entity.to_nbt(position);
+        assert_eq!(BlockEntity::from_nbt(&tag), Some((position, entity)));
+    }
+
+    #[test]
+    fn chests_round_trip() {
+        let mut items = vec![None; CHEST_SLOTS];
+        items[0] = Some(stack_of(Item::Diamond, 3));
+        items[26] = Some(stack_of(Item::Cobblestone, 64));
+        round_trip(BlockEntity::Chest { items });
+    }
+
+    #[test]
+    fn furnaces_round_trip() {
+        let mut furnace = furnace(
+            Some(stack_of(Item::IronOre, 4)),
+            Some(stack_of(Item::Coal, 1)),
+            Some(stack_of(Item::IronIngot, 2)),
+        );
+        furnace.burn_time = 900;
+        // not saved, so the same as what's left after a load
+        furnace.burn_total = 900;
+        furnace.cook_time = 120;
+        round_trip(BlockEntity::Furnace(furnace));
+    }
+
+    #[test]
+    fn signs_and_banners_round_trip() {
+        round_trip(BlockEntity::Sign {
+            lines: [
+                r#"{"text":"hello"}"#.to_string(),
+                r#"{"text":""}"#.to_string(),
+                r#"{"text":"there"}"#.to_string(),
+                r#"{"text":""}"#.to_string(),
+            ],
+        });
+        round_trip(BlockEntity::Banner {
+            patterns: vec![("bs".to_string(), 14), ("cre".to_string(), 0)],
+        });
+        round_trip(BlockEntity::Bed);
+    }
+
+    #[test]
+    fn update_tags_leave_out_items() {
+        let mut items = vec![None; CHEST_SLOTS];
+        items[0] = Some(stack_of(Item::Diamond, 3));
+        let tag = BlockEntity::Chest { items }.update_tag(Position { x: 0, y: 0, z: 0 });
+        assert!(tag.get("Items").is_none());
+        assert_eq!(
+            tag.get("id"),
+            Some(&Value::String("minecraft:chest".to_string()))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::io::{self, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::protocol::{ProtocolRead, ProtocolWrite};
+
+// chat component, sent over the wire as json
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Chat {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translate: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub with: Vec<Chat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub underlined: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub obfuscated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insertion: Option<String>,
+    #[serde(rename = "clickEvent", skip_serializing_if = "Option::is_none")]
+    pub click_event: Option<ClickEvent>,
+    #[serde(rename = "hoverEvent", skip_serializing_if = "Option::is_none")]
+    pub hover_event: Option<HoverEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra: Vec<Chat>,
+}
+
+impl Chat {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn translate<S: Into<String>>(key: S, with: Vec<Chat>) -> Self {
+        Self {
+            translate: Some(key.into()),
+            with,
+            ..Default::default()
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn insertion<S: Into<String>>(mut self, insertion: S) -> Self {
+        self.insertion = Some(insertion.into());
+        self
+    }
+
+    pub fn click_event(mut self, action: ClickAction, value: String) -> Self {
+        self.click_event = Some(ClickEvent { action, value });
+        self
+    }
+
+    pub fn hover_event(mut self, action: HoverAction, value: Chat) -> Self {
+        self.hover_event = Some(HoverEvent {
+            action,
+            value: Box::new(value),
+        });
+        self
+    }
+}
+
+impl From<&str> for Chat {
+    fn from(text: &str) -> Self {
+        Chat::text(text)
+    }
+}
+
+impl From<String> for Chat {
+    fn from(text: String) -> Self {
+        Chat::text(text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Color {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    Reset,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClickEvent {
+    pub action: ClickAction,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClickAction {
+    OpenUrl,
+    RunCommand,
+    SuggestCommand,
+    ChangePage,
+    CopyToClipboard,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoverEvent {
+    pub action: HoverAction,
+    pub value: Box<Chat>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+pub enum HoverAction {
+    ShowText,
+    ShowItem,
+    ShowEntity,
+}
+
+impl ProtocolWrite for Chat {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        let json = serde_json::to_string(value)?;
+        String::proto_encode(&json, dst)
+    }
+}
+
+impl ProtocolRead for Chat {
+    fn proto_decode<S: Read>(src: &mut S) -> io::Result<Self> {
+        let json = String::proto_decode(src)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatPosition {
+    Chat,
+    System,
+    GameInfo,
+}
+
+impl ProtocolWrite for ChatPosition {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> io::Result<()> {
+        let position: i8 = match value {
+            ChatPosition::Chat => 0,
+            ChatPosition::System => 1,
+            ChatPosition::GameInfo => 2,
+        };
+        i8::proto_encode(&position, dst)
+    }
+}
@@ -1,26 +1,48 @@
+use std::collections::HashMap;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
 
-use kareki_data::block::Block;
+use kareki_data::{block::Block, block_state::BlockState};
 
 use crate::packet::client;
-use crate::packet::client::ChunkData;
+use crate::packet::client::{ChunkData, UpdateLight};
 use crate::protocol::ProtocolWrite;
-use crate::types::heightmap::Heightmaps;
+use crate::types::block_entity::BlockEntity;
+use crate::types::heightmap::{HeightmapKind, Heightmaps};
 use crate::types::nbt::Nbt;
+use crate::types::position::Position;
 
 use super::chunk_section::ChunkSection;
+use super::light::{LightArray, LightKind};
+use super::Var;
+
+// light masks cover the 16 sections plus one below and one above the world
+pub const ALL_LIGHT_SECTIONS: u32 = (1 << 18) - 1;
 
 #[derive(Debug, Clone)]
 pub struct Chunk {
     pub sections: Vec<Option<ChunkSection>>,
+    // 4x4x4 cells, 1024 biome ids
+    pub biomes: Vec<i32>,
+    // keyed by the block's position in the world
+    pub block_entities: HashMap<Position, BlockEntity>,
+    // one per section, filled in by the world's light engine
+    sky_light: Vec<LightArray>,
+    block_light: Vec<LightArray>,
+    // indexed by HeightmapKind, then x + z * 16
+    heightmaps: [[u16; 256]; 4],
 }
 
 impl Chunk {
     pub fn empty() -> Chunk {
         Self {
             sections: vec![None; 16],
+            biomes: vec![127; 1024],
+            block_entities: HashMap::new(),
+            sky_light: vec![LightArray::new(0); 16],
+            block_light: vec![LightArray::new(0); 16],
+            heightmaps: [[0; 256]; 4],
         }
     }
 
@@ -45,28 +67,136 @@ impl Chunk {
                 Some(section) => section,
                 None => self.create_section(y >> 4),
             }
-            .set_block(x, y % 16, z, block_id),
-            None => Err(Error::new(ErrorKind::InvalidInput, "out of index")),
+            .set_block(x, y % 16, z, block_id)?,
+            None => return Err(Error::new(ErrorKind::InvalidInput, "out of index")),
+        }
+        self.update_heights(x, y, z, state_of(block_id));
+        Ok(())
+    }
+
+    pub fn get_light(&self, kind: LightKind, x: usize, y: usize, z: usize) -> u8 {
+        self.light_arrays(kind)[y >> 4].get(x, y & 0xf, z)
+    }
+
+    pub fn set_light(&mut self, kind: LightKind, x: usize, y: usize, z: usize, level: u8) {
+        let arrays = match kind {
+            LightKind::Sky => &mut self.sky_light,
+            LightKind::Block => &mut self.block_light,
+        };
+        arrays[y >> 4].set(x, y & 0xf, z, level);
+    }
+
+    pub fn clear_light(&mut self) {
+        self.sky_light = vec![LightArray::new(0); 16];
+        self.block_light = vec![LightArray::new(0); 16];
+    }
+
+    fn light_arrays(&self, kind: LightKind) -> &[LightArray] {
+        match kind {
+            LightKind::Sky => &self.sky_light,
+            LightKind::Block => &self.block_light,
+        }
+    }
+
+    // sections is a light mask, bit 0 being the section below the world
+    pub fn light_packet(&self, chunk_x: i32, chunk_z: i32, sections: u32) -> client::PlayPacket {
+        let (sky_light_mask, empty_sky_light_mask, sky_lights) =
+            self.light_data(LightKind::Sky, sections);
+        let (block_light_mask, empty_block_light_mask, block_lights) =
+            self.light_data(LightKind::Block, sections);
+
+        client::PlayPacket::UpdateLight(UpdateLight {
+            chunk_x: chunk_x.into(),
+            chunk_z: chunk_z.into(),
+            sky_light_mask: Var(sky_light_mask as i32),
+            block_light_mask: Var(block_light_mask as i32),
+            empty_sky_light_mask: Var(empty_sky_light_mask as i32),
+            empty_block_light_mask: Var(empty_block_light_mask as i32),
+            sky_lights,
+            block_lights,
+        })
+    }
+
+    // (mask, empty mask, arrays) for the requested sections. the sky is fully lit
+    // above the world and everything is dark below it
+    fn light_data(&self, kind: LightKind, sections: u32) -> (u32, u32, Vec<Vec<u8>>) {
+        let above_world = LightArray::new(match kind {
+            LightKind::Sky => 15,
+            LightKind::Block => 0,
+        });
+        let below_world = LightArray::new(0);
+
+        let mut mask = 0;
+        let mut empty_mask = 0;
+        let mut arrays = vec![];
+        for index in 0..18 {
+            if sections & (1 << index) == 0 {
+                continue;
+            }
+            let light = match index {
+                0 => &below_world,
+                17 => &above_world,
+                index => &self.light_arrays(kind)[index - 1],
+            };
+            if light.is_empty() {
+                empty_mask |= 1 << index;
+            } else {
+                mask |= 1 << index;
+                arrays.push(light.as_bytes().to_vec());
+            }
+        }
+        (mask, empty_mask, arrays)
+    }
+
+    pub fn height(&self, kind: HeightmapKind, x: usize, z: usize) -> u16 {
+        self.heightmaps[kind as usize][x + z * 16]
+    }
+
+    // after sections were filled in directly, like when loading from disk
+    pub fn recompute_heightmaps(&mut self) {
+        for kind in HeightmapKind::ALL.iter().copied() {
+            for x in 0..16 {
+                for z in 0..16 {
+                    self.heightmaps[kind as usize][x + z * 16] = self.find_height(kind, x, z, 256);
+                }
+            }
         }
     }
 
-    pub fn get_heighest_position(&self, x: usize, z: usize) -> Result<u16> {
-        let mut heighest = 0;
-        for section in self.sections.iter() {
-            for y in 0..16 {
-                let section_y = match section {
-                    Some(s) => match s.get_block(x, y, z) {
-                        Some(y) => y,
-                        None => 0,
-                    },
-                    None => 0,
-                };
-                if heighest < section_y {
-                    heighest = section_y;
+    fn update_heights(&mut self, x: usize, y: usize, z: usize, state: BlockState) {
+        for kind in HeightmapKind::ALL.iter().copied() {
+            let height = self.height(kind, x, z) as usize;
+            let height = if kind.counts(state) {
+                height.max(y + 1)
+            } else if y + 1 == height {
+                // the top block went away, look for the next one down
+                self.find_height(kind, x, z, y) as usize
+            } else {
+                height
+            };
+            self.heightmaps[kind as usize][x + z * 16] = height as u16;
+        }
+    }
+
+    // one above the highest counting block under y, 0 for none
+    fn find_height(&self, kind: HeightmapKind, x: usize, z: usize, below: usize) -> u16 {
+        let mut y = below;
+        while y > 0 {
+            let section = match &self.sections[(y - 1) >> 4] {
+                Some(section) => section,
+                None => {
+                    y = (y - 1) & !0xf;
+                    continue;
                 }
+            };
+            if kind.counts(state_of(
+                section.get_block(x, (y - 1) & 0xf, z).unwrap_or(0),
+            )) {
+                return y as u16;
             }
+            y -= 1;
         }
-        Ok(heighest)
+        0
     }
 
     fn create_section(&mut self, index: usize) -> &mut ChunkSection {
@@ -75,7 +205,7 @@ impl Chunk {
         a.expect("should valid").as_mut().expect("should valid")
     }
 
-    pub fn to_packet(self, chunk_x: i32, chunk_z: i32) -> Result<client::PlayPacket> {
+    pub fn to_packet(&self, chunk_x: i32, chunk_z: i32) -> Result<client::PlayPacket> {
         let mut data = vec![];
         let mut primary_bit_mask = 0;
         for (section_y, section) in self.sections.iter().enumerate() {
@@ -84,24 +214,28 @@ impl Chunk {
                 ChunkSection::proto_encode(section, &mut data)?;
             }
         }
-        let mut height_map = [0; 256];
-        for x in 0..16 {
-            for z in 0..16 {
-                height_map[(x * 16) + z] = self.get_heighest_position(x, z)?;
-            }
-        }
-
         let packet = client::PlayPacket::ChunkData(ChunkData {
             chunk_x,
             chunk_z,
             full_chunk: true,
             primary_bit_mask: primary_bit_mask.into(),
-            heightmaps: Nbt(Heightmaps::from_array(&height_map)),
-            biomes: Some(vec![127.into(); 1024]),
+            heightmaps: Nbt(Heightmaps::new(
+                &self.heightmaps[HeightmapKind::MotionBlocking as usize],
+                &self.heightmaps[HeightmapKind::WorldSurface as usize],
+            )),
+            biomes: Some(self.biomes.clone()),
             data,
-            block_entities: vec![],
+            block_entities: self
+                .block_entities
+                .iter()
+                .map(|(position, entity)| Nbt(entity.update_tag(*position)))
+                .collect(),
         });
 
         Ok(packet)
     }
 }
+
+fn state_of(block_id: u16) -> BlockState {
+    BlockState::from_id(block_id as u32).unwrap_or_else(|| BlockState::new(Block::Air))
+}
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
@@ -9,14 +8,14 @@ use crate::protocol::ProtocolWrite;
 
 use super::{Arr, Var};
 
+const MAX_INDIRECT_BITS_PER_BLOCK: usize = 8;
+const GLOBAL_BITS_PER_BLOCK: usize = 14;
+
 #[derive(Debug, Clone)]
 pub struct ChunkSection {
     data: Vec<u16>,
 }
-// block_count: i16,
-// bits_per_block: u8,
-// palette: Option<Vec<Var<i32>>>, // Some => indirect, None => direct
-// data: Vec<u64>,
+
 impl ChunkSection {
     pub fn empty() -> ChunkSection {
         Self {
@@ -43,79 +42,177 @@ impl ChunkSection {
         }
     }
 
-    fn block_index(x: usize, y: usize, z: usize) -> Option<usize> {
-        if x >= 16 || y >= 16 || z >= 16 {
-            None
-        } else {
-            Some((y << 8) | (z << 4) | x)
-        }
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&n| n == 0)
     }
 
-    pub fn make_palette(&self) -> Vec<Var<i32>> {
-        let mut palette_set = self.data.iter().map(|&n| n).collect::<HashSet<_>>();
-        palette_set.insert(0);
-        // must have Air
+    // palette in first-seen order and indices packed into longs, where an
+    // index may span two longs (the pre-1.16 layout, both on disk and network)
+    pub fn to_anvil(&self) -> (Vec<u16>, Vec<i64>) {
+        let (palette, indices) = self.palette_and_indices();
+        let bits_per_block = Self::bits_per_block(palette.len());
+        let states = Self::pack(&indices, bits_per_block);
 
-        let palette = palette_set
-            .into_iter()
-            .map(|n| Var(n as i32))
-            .collect::<Vec<_>>();
+        (palette, states.into_iter().map(|n| n as i64).collect())
+    }
 
-        palette
+    pub fn from_anvil(palette: &[u16], states: &[i64]) -> Result<ChunkSection> {
+        let bits_per_block = Self::bits_per_block(palette.len());
+        if states.len() * 64 < 4096 * bits_per_block {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "block states are too short for palette",
+            ));
+        }
+        let mask = (1u64 << bits_per_block) - 1;
+        let mut data = vec![0; 4096];
+        for (index, block) in data.iter_mut().enumerate() {
+            let bit_index = index * bits_per_block;
+            let u64_index = bit_index / 64;
+            let offset = bit_index % 64;
+            let mut value = states[u64_index] as u64 >> offset;
+            if offset + bits_per_block > 64 {
+                value |= (states[u64_index + 1] as u64) << (64 - offset);
+            }
+            *block = *palette
+                .get((value & mask) as usize)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "palette index out of range"))?;
+        }
+        Ok(Self { data })
     }
 
-    fn from_array_and_palette(
-        array: &[u16],
-        palette: Vec<Var<i32>>,
-    ) -> (i16, u8, Option<Vec<Var<i32>>>, Vec<u64>) {
-        assert!(array.len() == 4096);
-        let invert_palette: HashMap<u16, usize> = palette
+    fn palette_and_indices(&self) -> (Vec<u16>, Vec<u64>) {
+        let mut palette = vec![];
+        let mut invert_palette = HashMap::new();
+        let indices = self
+            .data
             .iter()
-            .enumerate()
-            .map(|(i, &n)| (n.0 as u16, i))
-            .collect();
-
-        let array2 = array
-            .into_iter()
-            .map(|n| *invert_palette.get(n).expect("must") as u16)
+            .map(|&n| {
+                *invert_palette.entry(n).or_insert_with(|| {
+                    palette.push(n);
+                    palette.len() - 1
+                }) as u64
+            })
             .collect::<Vec<_>>();
+        (palette, indices)
+    }
 
-        let mut bits_per_block = (palette.len() as f64).log2().ceil() as u8;
-        if bits_per_block < 4 {
-            bits_per_block = 4;
-        }
-        let block_count = array2.iter().filter(|&x| *x != 0).count() as i16;
-        let values_per_u64 = 64 / bits_per_block as usize;
-        let length = (array2.len() + values_per_u64 - 1) / values_per_u64;
-        let mask = (1 << bits_per_block) - 1;
-        let mut bits = vec![0u64; length];
-
-        for (index, &value) in array2.iter().enumerate() {
-            let u64_index = index / values_per_u64;
-            let bit_index = (index % values_per_u64) * (bits_per_block as usize);
-            let u64 = &mut bits[u64_index];
-            *u64 &= !(mask << bit_index);
-            *u64 |= (value as u64) << bit_index;
+    fn bits_per_block(palette_len: usize) -> usize {
+        let bits = (palette_len as f64).log2().ceil() as usize;
+        bits.max(4)
+    }
+
+    fn pack(values: &[u64], bits_per_block: usize) -> Vec<u64> {
+        let mut bits = vec![0u64; (values.len() * bits_per_block).div_ceil(64)];
+        for (index, &value) in values.iter().enumerate() {
+            let bit_index = index * bits_per_block;
+            let u64_index = bit_index / 64;
+            let offset = bit_index % 64;
+            bits[u64_index] |= value << offset;
+            if offset + bits_per_block > 64 {
+                bits[u64_index + 1] |= value >> (64 - offset);
+            }
         }
+        bits
+    }
 
-        (block_count, bits_per_block, Some(palette), bits)
+    fn block_index(x: usize, y: usize, z: usize) -> Option<usize> {
+        if x >= 16 || y >= 16 || z >= 16 {
+            None
+        } else {
+            Some((y << 8) | (z << 4) | x)
+        }
     }
 }
 
 impl ProtocolWrite for ChunkSection {
     fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> std::io::Result<()> {
-        let palette = value.make_palette();
-        let (block_count, bits_per_block, palette, data) =
-            Self::from_array_and_palette(&value.data, palette);
+        let block_count = value.data.iter().filter(|&x| *x != 0).count() as i16;
+        let (palette, indices) = value.palette_and_indices();
+        let bits_per_block = Self::bits_per_block(palette.len());
 
         i16::proto_encode(&block_count, dst)?;
-        u8::proto_encode(&bits_per_block, dst)?;
-        // TODO: support Option<Arr>
-        match &palette {
-            Some(palette) => <Arr<Var<i32>, Var<i32>>>::proto_encode(palette, dst)?,
-            None => {}
+        if bits_per_block <= MAX_INDIRECT_BITS_PER_BLOCK {
+            u8::proto_encode(&(bits_per_block as u8), dst)?;
+            let palette = palette
+                .into_iter()
+                .map(|n| Var(n as i32))
+                .collect::<Vec<_>>();
+            <Arr<Var<i32>, Var<i32>>>::proto_encode(&palette, dst)?;
+            let data = Self::pack(&indices, bits_per_block);
+            <Arr<Var<i32>, u64>>::proto_encode(&data, dst)?;
+        } else {
+            // too many states for a palette, so use global state ids directly
+            u8::proto_encode(&(GLOBAL_BITS_PER_BLOCK as u8), dst)?;
+            let values = value.data.iter().map(|&n| n as u64).collect::<Vec<_>>();
+            let data = Self::pack(&values, GLOBAL_BITS_PER_BLOCK);
+            <Arr<Var<i32>, u64>>::proto_encode(&data, dst)?;
         }
-        <Arr<Var<i32>, u64>>::proto_encode(&data, dst)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_four_bit_indices_in_order() {
+        let mut section = ChunkSection::empty();
+        for x in 0..16 {
+            section.set_block(x, 0, 0, x as u16 * 10).unwrap();
+        }
+        let (palette, states) = section.to_anvil();
+        assert_eq!(palette, (0..16).map(|n| n * 10).collect::<Vec<_>>());
+        assert_eq!(states.len(), 4096 * 4 / 64);
+        assert_eq!(states[0] as u64, 0xFEDC_BA98_7654_3210);
+        assert!(states[1..].iter().all(|&n| n == 0));
+    }
+
+    #[test]
+    fn indices_span_two_longs() {
+        // 27 states need 5 bits, so index 25 starts at bit 61 of the second long
+        // and its top two bits end up in the third
+        let mut section = ChunkSection::empty();
+        for index in 0..26 {
+            section
+                .set_block(index % 16, 0, index / 16, index as u16 + 1)
+                .unwrap();
+        }
+        let (palette, states) = section.to_anvil();
+        assert_eq!(palette.len(), 27);
+        assert_eq!(states.len(), 4096 * 5 / 64);
+        assert_eq!(states[1] as u64 >> 61, 25 & 0b111);
+        assert_eq!(states[2] as u64 & 0b11, 25 >> 3);
+        let loaded = ChunkSection::from_anvil(&palette, &states).unwrap();
+        assert_eq!(loaded.get_block(9, 0, 1), Some(26));
+    }
+
+    #[test]
+    fn anvil_round_trip() {
+        let mut section = ChunkSection::empty();
+        for (index, (x, y, z)) in [(0, 0, 0), (15, 15, 15), (3, 7, 11), (12, 0, 0)]
+            .iter()
+            .enumerate()
+        {
+            section
+                .set_block(*x, *y, *z, 100 + index as u16 * 37)
+                .unwrap();
+        }
+        let (palette, states) = section.to_anvil();
+        let loaded = ChunkSection::from_anvil(&palette, &states).unwrap();
+        assert_eq!(loaded.data, section.data);
+    }
+
+    #[test]
+    fn rejects_short_block_states() {
+        assert!(ChunkSection::from_anvil(&[0, 1], &[0; 10]).is_err());
+        assert!(ChunkSection::from_anvil(&[0], &[0; 256]).is_ok());
+    }
+
+    #[test]
+    fn rejects_indices_past_the_palette() {
+        let states = vec![-1; 256];
+        assert!(ChunkSection::from_anvil(&[0, 1], &states).is_err());
+    }
+}
@@ -1,52 +1,92 @@
 use std::{fmt, marker::PhantomData};
 
+use kareki_data::{block::Block, block_state::BlockState};
 use serde::{
     de::{self, SeqAccess, Visitor},
     Deserialize, Deserializer, Serialize,
 };
 
+// the heightmaps kept for every chunk, a column's height is one above the top
+// block that counts for the kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightmapKind {
+    WorldSurface,
+    MotionBlocking,
+    MotionBlockingNoLeaves,
+    OceanFloor,
+}
+
+impl HeightmapKind {
+    pub const ALL: [HeightmapKind; 4] = [
+        HeightmapKind::WorldSurface,
+        HeightmapKind::MotionBlocking,
+        HeightmapKind::MotionBlockingNoLeaves,
+        HeightmapKind::OceanFloor,
+    ];
+
+    pub fn counts(self, state: BlockState) -> bool {
+        let block = state.block();
+        let blocks_motion = block.solid() || is_fluid(state);
+        match self {
+            HeightmapKind::WorldSurface => {
+                !matches!(block, Block::Air | Block::CaveAir | Block::VoidAir)
+            }
+            HeightmapKind::MotionBlocking => blocks_motion,
+            HeightmapKind::MotionBlockingNoLeaves => blocks_motion && block.material() != "leaves",
+            HeightmapKind::OceanFloor => block.solid(),
+        }
+    }
+}
+
+fn is_fluid(state: BlockState) -> bool {
+    match state.block() {
+        Block::Water
+        | Block::Lava
+        | Block::BubbleColumn
+        | Block::Seagrass
+        | Block::TallSeagrass
+        | Block::Kelp
+        | Block::KelpPlant => true,
+        _ => state.waterlogged() == Some(true),
+    }
+}
+
+// the heightmaps the client gets with ChunkData
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Heightmaps {
     #[serde(rename = "MOTION_BLOCKING")]
     #[serde(serialize_with = "nbt::i64_array")]
     #[serde(deserialize_with = "deserialize_i64_36")]
     pub motion_blocking: [i64; 36],
+    #[serde(rename = "WORLD_SURFACE")]
+    #[serde(serialize_with = "nbt::i64_array")]
+    #[serde(deserialize_with = "deserialize_i64_36")]
+    pub world_surface: [i64; 36],
 }
 
-// TODO: 絶対もっといい書き方があると思うんだけど
 impl Heightmaps {
-    pub fn from_array(array: &[u16; 256]) -> Self {
-        let mut yoyobits = [0i64; 36];
-        let bits_per_byte = 9;
-        // let length = (array.len() + values_per_u64 - 1) / values_per_u64;
-        let mask = (1 << bits_per_byte) - 1;
-        let mut bits = [0u64; 36];
+    pub fn new(motion_blocking: &[u16; 256], world_surface: &[u16; 256]) -> Self {
+        Self {
+            motion_blocking: Self::pack(motion_blocking),
+            world_surface: Self::pack(world_surface),
+        }
+    }
 
+    // nine bits per column, a value may span two longs
+    fn pack(array: &[u16; 256]) -> [i64; 36] {
+        let bits_per_value = 9;
+        let mut bits = [0u64; 36];
         for (index, &value) in array.iter().enumerate() {
-            let all_bit_index = index * bits_per_byte;
-            let bit_index = all_bit_index % 64;
-            let u64_index = all_bit_index / 64;
-            let is_over = (bit_index + bits_per_byte) % 64 < bits_per_byte
-                && (bit_index + bits_per_byte) % 64 != 0;
-            let u64 = &mut bits[u64_index];
-            *u64 &= !(mask << bit_index);
-            *u64 |= (value as u64) << bit_index;
-            if is_over {
-                let u64_index = u64_index + 1;
-                let bit_index = (bit_index + bits_per_byte) % 64;
-                let next_u64 = &mut bits[u64_index];
-                *next_u64 &= !(mask << bit_index);
-                *next_u64 |= (value as u64) << bit_index;
+            let value = value as u64 & ((1 << bits_per_value) - 1);
+            let bit_index = index * bits_per_value;
+            let u64_index = bit_index / 64;
+            let offset = bit_index % 64;
+            bits[u64_index] |= value << offset;
+            if offset + bits_per_value > 64 {
+                bits[u64_index + 1] |= value >> (64 - offset);
             }
         }
-
-        for (index, &value) in bits.iter().enumerate() {
-            yoyobits[index] = value as i64;
-        }
-
-        Self {
-            motion_blocking: yoyobits,
-        }
+        bits.map(|n| n as i64)
     }
 }
 
@@ -92,3 +132,53 @@ where
     let visitor = MaxVisitor(PhantomData);
     deserializer.deserialize_seq(visitor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unpack(bits: &[i64; 36], index: usize) -> u16 {
+        let bit_index = index * 9;
+        let (u64_index, offset) = (bit_index / 64, bit_index % 64);
+        let mut value = bits[u64_index] as u64 >> offset;
+        if offset + 9 > 64 {
+            value |= (bits[u64_index + 1] as u64) << (64 - offset);
+        }
+        (value & 0x1ff) as u16
+    }
+
+    #[test]
+    fn packs_nine_bits_per_column() {
+        let mut heights = [0; 256];
+        heights[0] = 256;
+        heights[1] = 1;
+        let packed = Heightmaps::pack(&heights);
+        assert_eq!(packed[0], 256 | 1 << 9);
+        assert!(packed[1..].iter().all(|&n| n == 0));
+    }
+
+    #[test]
+    fn columns_span_two_longs() {
+        // column 7 starts at bit 63, so only its lowest bit is in the first long
+        let mut heights = [0; 256];
+        heights[7] = 0b1_0110_1011;
+        let packed = Heightmaps::pack(&heights);
+        assert_eq!(packed[0] as u64, 1 << 63);
+        assert_eq!(packed[1], 0b1011_0101);
+        assert_eq!(unpack(&packed, 7), heights[7]);
+    }
+
+    #[test]
+    fn every_column_round_trips() {
+        let mut heights = [0; 256];
+        for (index, height) in heights.iter_mut().enumerate() {
+            *height = (index * 37 % 257) as u16;
+        }
+        let packed = Heightmaps::pack(&heights);
+        for (index, height) in heights.iter().enumerate() {
+            assert_eq!(unpack(&packed, index), *height, "column {}", index);
+        }
+        // the last column ends exactly at the end of the last long
+        assert_eq!(packed[35] as u64 >> 55, heights[255] as u64);
+    }
+}
@@ -0,0 +1,44 @@
+// one light level per block of a 16x16x16 section, two blocks to a byte in the
+// order the client expects them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LightArray {
+    data: Vec<u8>,
+}
+
+impl LightArray {
+    pub fn new(level: u8) -> LightArray {
+        Self {
+            data: vec![level << 4 | level; 2048],
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize, z: usize) -> u8 {
+        let index = Self::index(x, y, z);
+        self.data[index >> 1] >> ((index & 1) * 4) & 0xf
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, z: usize, level: u8) {
+        let index = Self::index(x, y, z);
+        let shift = (index & 1) * 4;
+        let byte = &mut self.data[index >> 1];
+        *byte = *byte & !(0xf << shift) | (level & 0xf) << shift;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&n| n == 0)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn index(x: usize, y: usize, z: usize) -> usize {
+        (y << 8) | (z << 4) | x
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightKind {
+    Sky,
+    Block,
+}
@@ -5,7 +5,7 @@ use serde::{de::DeserializeOwned, Serialize};
 
 use crate::protocol::{ProtocolRead, ProtocolWrite};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Nbt<T>(pub T);
 
 impl<T> ProtocolWrite for Nbt<T>
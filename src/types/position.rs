@@ -4,7 +4,7 @@ use crate::protocol::{ProtocolLen, ProtocolRead, ProtocolWrite};
 
 use super::block_face::BlockFace;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Position {
     pub x: i32,
     pub y: i16,
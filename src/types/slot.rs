@@ -1,10 +1,10 @@
-use std::io::{Read, Result};
+use std::io::{Read, Result, Write};
 
-use crate::protocol::ProtocolRead;
+use crate::protocol::{ProtocolRead, ProtocolWrite};
 
 use super::{item_stack_meta::ItemStackMeta, nbt::Nbt, Var};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Slot {
     pub item_id: Var<i32>,
     pub item_count: u8,
@@ -28,3 +28,16 @@ impl ProtocolRead for Slot {
         })
     }
 }
+
+impl ProtocolWrite for Slot {
+    fn proto_encode<D: Write>(value: &Self, dst: &mut D) -> Result<()> {
+        <Var<i32>>::proto_encode(&value.item_id, dst)?;
+        u8::proto_encode(&value.item_count, dst)?;
+        match &value.meta {
+            Some(meta) => <Nbt<_>>::proto_encode(meta, dst)?,
+            // TAG_End, no nbt
+            None => u8::proto_encode(&0, dst)?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,1076 @@
+use std::ops::Range;
+
+use kareki_data::{
+    block::Block,
+    item::Item,
+    recipe::{Recipe, RecipeKind},
+};
+
+use crate::{
+    crafting,
+    types::{
+        block_entity::{CHEST_SLOTS, FURNACE_FUEL, FURNACE_INPUT, FURNACE_RESULT},
+        position::Position,
+        slot::Slot,
+    },
+};
+
+// the window id of the player's own inventory
+pub const PLAYER_WINDOW: u8 = 0;
+// clicks outside of the window drop the cursor
+pub const OUTSIDE: i16 = -999;
+
+// slots of the player inventory window
+pub const CRAFTING_RESULT: usize = 0;
+pub const CRAFTING_GRID: Range<usize> = 1..5;
+pub const ARMOR: Range<usize> = 5..9;
+pub const MAIN: Range<usize> = 9..36;
+pub const HOTBAR: Range<usize> = 36..45;
+pub const OFFHAND: usize = 45;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowKind {
+    Player,
+    Crafting,
+    Chest,
+    Furnace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotKind {
+    Normal,
+    // crafts when taken from
+    Result,
+    // can only be taken from
+    Output,
+    // takes only what burns
+    Fuel,
+    // takes a single piece of armor, 0 is the head and 3 the feet
+    Armor(u8),
+}
+
+impl WindowKind {
+    // the window right-clicking a block opens
+    pub fn for_block(block: Block) -> Option<Self> {
+        match block {
+            Block::CraftingTable => Some(WindowKind::Crafting),
+            Block::Chest => Some(WindowKind::Chest),
+            Block::Furnace => Some(WindowKind::Furnace),
+            _ => None,
+        }
+    }
+
+    // the id of the window type in open window
+    pub fn window_type(self) -> i32 {
+        match self {
+            WindowKind::Player => -1,
+            WindowKind::Crafting => 11,
+            // generic_9x3
+            WindowKind::Chest => 2,
+            WindowKind::Furnace => 13,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            WindowKind::Player => "container.inventory",
+            WindowKind::Crafting => "container.crafting",
+            WindowKind::Chest => "container.chest",
+            WindowKind::Furnace => "container.furnace",
+        }
+    }
+
+    // how many slots the window has before the player's main inventory
+    pub fn size(self) -> usize {
+        match self {
+            WindowKind::Player => MAIN.start,
+            WindowKind::Crafting => 10,
+            WindowKind::Chest => CHEST_SLOTS,
+            WindowKind::Furnace => 3,
+        }
+    }
+
+    // the crafting grid and its width
+    pub fn grid(self) -> Option<(Range<usize>, usize)> {
+        match self {
+            WindowKind::Player => Some((CRAFTING_GRID, 2)),
+            WindowKind::Crafting => Some((1..10, 3)),
+            _ => None,
+        }
+    }
+
+    pub fn slot_kind(self, index: usize) -> SlotKind {
+        match self {
+            WindowKind::Player if ARMOR.contains(&index) => {
+                SlotKind::Armor((index - ARMOR.start) as u8)
+            }
+            WindowKind::Furnace if index == FURNACE_FUEL => SlotKind::Fuel,
+            WindowKind::Furnace if index == FURNACE_RESULT => SlotKind::Output,
+            _ if index == CRAFTING_RESULT && self.grid().is_some() => SlotKind::Result,
+            _ => SlotKind::Normal,
+        }
+    }
+
+    // the player's main inventory, followed by the hotbar
+    fn main(self) -> Range<usize> {
+        self.size()..self.size() + 27
+    }
+
+    fn hotbar(self) -> Range<usize> {
+        self.size() + 27..self.size() + 36
+    }
+
+    fn inventory(self) -> Range<usize> {
+        self.size()..self.size() + 36
+    }
+
+    // where shift-clicking a stack in index sends it, tried in order, the flag walks
+    // the range backwards
+    fn quick_move_targets(self, index: usize, stack: &Slot) -> Vec<(Range<usize>, bool)> {
+        if let SlotKind::Result | SlotKind::Output = self.slot_kind(index) {
+            return vec![(self.inventory(), true)];
+        }
+        if !self.inventory().contains(&index) {
+            return vec![(self.inventory(), self == WindowKind::Chest)];
+        }
+        let mut targets = vec![];
+        let item = Item::from_id(stack.item_id.0 as u32);
+        match self {
+            WindowKind::Player => {
+                if let Some(armor) = armor_slot(stack) {
+                    let armor = ARMOR.start + armor as usize;
+                    targets.push((armor..armor + 1, false));
+                }
+            }
+            WindowKind::Chest => return vec![(0..CHEST_SLOTS, false)],
+            // what smelts or burns goes into the furnace and nowhere else
+            WindowKind::Furnace if item.and_then(crafting::smelting_recipe).is_some() => {
+                return vec![(FURNACE_INPUT..FURNACE_INPUT + 1, false)];
+            }
+            WindowKind::Furnace if item.and_then(crafting::burn_time).is_some() => {
+                return vec![(FURNACE_FUEL..FURNACE_FUEL + 1, false)];
+            }
+            _ => {}
+        }
+        if self.main().contains(&index) {
+            targets.push((self.hotbar(), false));
+        } else {
+            targets.push((self.main(), false));
+        }
+        targets
+    }
+}
+
+// the armor slot an item goes in
+pub fn armor_slot(stack: &Slot) -> Option<u8> {
+    let name = Item::from_id(stack.item_id.0 as u32)?.name();
+    if name.ends_with("_helmet")
+        || name.ends_with("_head")
+        || name.ends_with("_skull")
+        || name == "carved_pumpkin"
+    {
+        Some(0)
+    } else if name.ends_with("_chestplate") || name == "elytra" {
+        Some(1)
+    } else if name.ends_with("_leggings") {
+        Some(2)
+    } else if name.ends_with("_boots") {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+pub fn stack_size(stack: &Slot) -> u8 {
+    Item::from_id(stack.item_id.0 as u32).map_or(64, |item| item.stack_size()) as u8
+}
+
+// whether two stacks are the same item and can be merged
+pub fn same_item(a: &Slot, b: &Slot) -> bool {
+    a.item_id == b.item_id && a.meta == b.meta
+}
+
+// split count items off a stack, emptying it when nothing is left
+pub fn take(slot: &mut Option<Slot>, count: u8) -> Option<Slot> {
+    let stack = slot.as_mut()?;
+    let count = count.min(stack.item_count);
+    if count == 0 {
+        return None;
+    }
+    let mut taken = stack.clone();
+    taken.item_count = count;
+    stack.item_count -= count;
+    if stack.item_count == 0 {
+        *slot = None;
+    }
+    Some(taken)
+}
+
+pub fn stack_of(item: Item, count: u8) -> Slot {
+    Slot {
+        item_id: (item.id() as i32).into(),
+        item_count: count,
+        meta: None,
+    }
+}
+
+// a drag painting items over slots while the mouse is held
+#[derive(Debug, Clone)]
+pub struct Drag {
+    // 0 spreads the cursor evenly, 1 places one item per slot, 2 fills the slots in creative
+    pub button: u8,
+    pub slots: Vec<usize>,
+}
+
+// a window opened on a block, with the slots that belong to it
+#[derive(Debug, Clone)]
+pub struct OpenWindow {
+    pub id: u8,
+    pub kind: WindowKind,
+    // the crafting grid, or what the client was last sent of a container's slots
+    pub slots: Vec<Option<Slot>>,
+    // window properties as the client was last sent them
+    pub properties: Vec<i16>,
+    pub position: Position,
+}
+
+pub struct Click {
+    pub slot: i16,
+    pub button: i8,
+    pub mode: i32,
+}
+
+pub struct ClickResult {
+    // stacks the player threw out of the window
+    pub dropped: Vec<Slot>,
+    // what vanilla reports for the click, the client predicts the same and sends it
+    // along, a mismatch means the two disagree on the window
+    pub clicked: Option<Slot>,
+}
+
+// an open window over slots of the player and maybe a block
+pub struct Window<'a> {
+    pub kind: WindowKind,
+    pub slots: Vec<&'a mut Option<Slot>>,
+    // crafting leftovers with no room in the inventory
+    dropped: Vec<Slot>,
+}
+
+impl<'a> Window<'a> {
+    pub fn player(slots: &'a mut [Option<Slot>]) -> Self {
+        Self {
+            kind: WindowKind::Player,
+            slots: slots.iter_mut().collect(),
+            dropped: vec![],
+        }
+    }
+
+    // the window's own slots followed by the player's main inventory and hotbar
+    pub fn open(window: &'a mut OpenWindow, inventory: &'a mut [Option<Slot>]) -> Self {
+        Self {
+            kind: window.kind,
+            slots: window
+                .slots
+                .iter_mut()
+                .chain(inventory[MAIN.start..HOTBAR.end].iter_mut())
+                .collect(),
+            dropped: vec![],
+        }
+    }
+
+    // the window a packet is about, None when the client names one that isn't open
+    pub fn with_id(
+        id: u8,
+        window: &'a mut Option<OpenWindow>,
+        inventory: &'a mut [Option<Slot>],
+    ) -> Option<Self> {
+        match window {
+            Some(window) if window.id == id => Some(Self::open(window, inventory)),
+            None if id == PLAYER_WINDOW => Some(Self::player(inventory)),
+            _ => None,
+        }
+    }
+
+    fn may_place(&self, index: usize, stack: &Slot) -> bool {
+        match self.kind.slot_kind(index) {
+            SlotKind::Normal => true,
+            SlotKind::Result | SlotKind::Output => false,
+            SlotKind::Fuel => Item::from_id(stack.item_id.0 as u32)
+                .is_some_and(|item| item == Item::Bucket || crafting::burn_time(item).is_some()),
+            SlotKind::Armor(armor) => armor_slot(stack) == Some(armor),
+        }
+    }
+
+    // how many of the stack's item the slot holds
+    fn limit(&self, index: usize, stack: &Slot) -> u8 {
+        match self.kind.slot_kind(index) {
+            SlotKind::Armor(_) => 1,
+            _ => stack_size(stack),
+        }
+    }
+
+    // apply a click the way vanilla does, None when the click makes no sense
+    pub fn click(
+        &mut self,
+        cursor: &mut Option<Slot>,
+        drag: &mut Option<Drag>,
+        click: &Click,
+        creative: bool,
+    ) -> Option<ClickResult> {
+        let mut result = ClickResult {
+            dropped: vec![],
+            clicked: None,
+        };
+        if click.mode != 5 {
+            *drag = None;
+        }
+        let slot = if click.slot == OUTSIDE || click.slot == -1 {
+            None
+        } else if click.slot >= 0 && (click.slot as usize) < self.slots.len() {
+            Some(click.slot as usize)
+        } else {
+            return None;
+        };
+
+        match (click.mode, slot) {
+            // left and right click
+            (0, None) if click.slot == OUTSIDE => {
+                let count = if click.button == 0 { u8::MAX } else { 1 };
+                result.dropped.extend(take(cursor, count));
+            }
+            (0, Some(index)) => {
+                result.clicked = self.slots[index].clone();
+                self.pickup(index, click.button == 0, cursor);
+            }
+            // shift click
+            (1, Some(index)) => {
+                result.clicked = self.quick_move(index);
+            }
+            // number keys
+            (2, Some(index)) if (0..9).contains(&click.button) => {
+                self.swap(index, self.kind.hotbar().start + click.button as usize);
+            }
+            // middle click takes a full stack in creative
+            (3, Some(index)) => {
+                if creative && cursor.is_none() {
+                    if let Some(stack) = &*self.slots[index] {
+                        let mut stack = stack.clone();
+                        stack.item_count = stack_size(&stack);
+                        *cursor = Some(stack);
+                    }
+                }
+            }
+            // q drops one, ctrl-q the whole stack, results always go whole
+            (4, Some(index)) => {
+                if cursor.is_none() {
+                    if self.kind.slot_kind(index) == SlotKind::Result {
+                        result.dropped.extend(self.slots[index].take());
+                        self.craft();
+                    } else {
+                        let count = if click.button == 0 { 1 } else { u8::MAX };
+                        result.dropped.extend(take(self.slots[index], count));
+                    }
+                }
+            }
+            (5, slot) => self.drag(slot, click.button as u8, cursor, drag, creative)?,
+            // double click gathers matching items onto the cursor
+            (6, Some(index)) => {
+                if self.slots[index].is_none() {
+                    self.collect(cursor, click.button == 0);
+                }
+            }
+            (0..=6, _) => {}
+            _ => return None,
+        }
+        self.update_result();
+        result.dropped.append(&mut self.dropped);
+        Some(result)
+    }
+
+    fn pickup(&mut self, index: usize, left: bool, cursor: &mut Option<Slot>) {
+        let kind = self.kind.slot_kind(index);
+        match (self.slots[index].take(), cursor.take()) {
+            (None, None) => {}
+            (Some(stack), None) => {
+                if kind == SlotKind::Result {
+                    *cursor = Some(stack);
+                    self.craft();
+                    return;
+                }
+                let count = if left {
+                    stack.item_count
+                } else {
+                    stack.item_count.div_ceil(2)
+                };
+                let mut slot = Some(stack);
+                *cursor = take(&mut slot, count);
+                *self.slots[index] = slot;
+            }
+            (None, Some(held)) => {
+                let mut held = Some(held);
+                if let Some(stack) = &held {
+                    if self.may_place(index, stack) {
+                        let count = if left { stack.item_count } else { 1 };
+                        let count = count.min(self.limit(index, stack));
+                        *self.slots[index] = take(&mut held, count);
+                    }
+                }
+                *cursor = held;
+            }
+            (Some(mut stack), Some(mut held)) => {
+                if kind == SlotKind::Result
+                    || (kind == SlotKind::Output && same_item(&stack, &held))
+                {
+                    // taking a result adds onto a matching cursor if it all fits
+                    if same_item(&stack, &held)
+                        && held.item_count + stack.item_count <= stack_size(&held)
+                    {
+                        held.item_count += stack.item_count;
+                        *cursor = Some(held);
+                        self.craft();
+                    } else {
+                        *self.slots[index] = Some(stack);
+                        *cursor = Some(held);
+                    }
+                } else if same_item(&stack, &held) {
+                    if self.may_place(index, &held) {
+                        let space = self.limit(index, &held).saturating_sub(stack.item_count);
+                        let count = if left { held.item_count } else { 1 }.min(space);
+                        stack.item_count += count;
+                        held.item_count -= count;
+                    }
+                    *self.slots[index] = Some(stack);
+                    *cursor = Some(held).filter(|held| held.item_count > 0);
+                } else if self.may_place(index, &held)
+                    && held.item_count <= self.limit(index, &held)
+                {
+                    *self.slots[index] = Some(held);
+                    *cursor = Some(stack);
+                } else {
+                    *self.slots[index] = Some(stack);
+                    *cursor = Some(held);
+                }
+            }
+        }
+    }
+
+    // returns the stack as it was when anything moved, results are crafted over and
+    // over while they fit
+    fn quick_move(&mut self, index: usize) -> Option<Slot> {
+        let targets = self.quick_move_indices(index);
+        if self.kind.slot_kind(index) == SlotKind::Result {
+            let mut moved = None;
+            while let Some(mut stack) = self.slots[index].clone() {
+                if moved
+                    .as_ref()
+                    .is_some_and(|moved| !same_item(moved, &stack))
+                    || self.space_for(&stack, &targets) < stack.item_count
+                {
+                    break;
+                }
+                moved.get_or_insert_with(|| stack.clone());
+                self.insert(&mut stack, &targets);
+                *self.slots[index] = None;
+                self.craft();
+            }
+            return moved;
+        }
+
+        let original = self.slots[index].clone()?;
+        let mut stack = original.clone();
+        self.insert(&mut stack, &targets);
+        if stack.item_count == original.item_count {
+            return None;
+        }
+        *self.slots[index] = Some(stack).filter(|stack| stack.item_count > 0);
+        Some(original)
+    }
+
+    fn quick_move_indices(&self, index: usize) -> Vec<usize> {
+        let mut targets = vec![];
+        let stack = match &*self.slots[index] {
+            Some(stack) => stack,
+            None => return targets,
+        };
+        for (range, reverse) in self.kind.quick_move_targets(index, stack) {
+            let mut indices = range.filter(|&target| target != index).collect::<Vec<_>>();
+            if reverse {
+                indices.reverse();
+            }
+            targets.extend(indices);
+        }
+        targets
+    }
+
+    // merge into matching stacks first, then fill empty slots
+    fn insert(&mut self, stack: &mut Slot, targets: &[usize]) {
+        for &target in targets {
+            let limit = self.limit(target, stack);
+            if let Some(existing) = self.slots[target].as_mut() {
+                if same_item(existing, stack) {
+                    let count = limit
+                        .saturating_sub(existing.item_count)
+                        .min(stack.item_count);
+                    existing.item_count += count;
+                    stack.item_count -= count;
+                }
+            }
+        }
+        for &target in targets {
+            if stack.item_count > 0 && self.slots[target].is_none() && self.may_place(target, stack)
+            {
+                let count = self.limit(target, stack).min(stack.item_count);
+                let mut placed = stack.clone();
+                placed.item_count = count;
+                *self.slots[target] = Some(placed);
+                stack.item_count -= count;
+            }
+        }
+    }
+
+    fn space_for(&self, stack: &Slot, targets: &[usize]) -> u8 {
+        let mut space = 0u32;
+        for &target in targets {
+            space += match &*self.slots[target] {
+                Some(existing) if same_item(existing, stack) => {
+                    self.limit(target, stack)
+                        .saturating_sub(existing.item_count) as u32
+                }
+                Some(_) => 0,
+                None if self.may_place(target, stack) => self.limit(target, stack) as u32,
+                None => 0,
+            };
+        }
+        space.min(u8::MAX as u32) as u8
+    }
+
+    fn swap(&mut self, index: usize, hotbar: usize) {
+        if index == hotbar {
+            return;
+        }
+        let kind = self.kind.slot_kind(index);
+        match (self.slots[index].take(), self.slots[hotbar].take()) {
+            (stack, None) => {
+                let crafted = stack.is_some() && kind == SlotKind::Result;
+                *self.slots[hotbar] = stack;
+                if crafted {
+                    self.craft();
+                }
+            }
+            (None, Some(held)) => {
+                let mut held = Some(held);
+                if let Some(stack) = &held {
+                    if self.may_place(index, stack) {
+                        let count = self.limit(index, stack);
+                        *self.slots[index] = take(&mut held, count);
+                    }
+                }
+                *self.slots[hotbar] = held;
+            }
+            (Some(stack), Some(held)) => {
+                if kind != SlotKind::Result
+                    && self.may_place(index, &held)
+                    && held.item_count <= self.limit(index, &held)
+                {
+                    *self.slots[index] = Some(held);
+                    *self.slots[hotbar] = Some(stack);
+                } else {
+                    *self.slots[index] = Some(stack);
+                    *self.slots[hotbar] = Some(held);
+                }
+            }
+        }
+    }
+
+    // the button packs the stage in the low bits and the drag button above them
+    fn drag(
+        &mut self,
+        slot: Option<usize>,
+        button: u8,
+        cursor: &mut Option<Slot>,
+        drag: &mut Option<Drag>,
+        creative: bool,
+    ) -> Option<()> {
+        let stage = button & 3;
+        let drag_button = (button >> 2) & 3;
+        if (drag_button == 2 && !creative) || drag_button > 2 {
+            *drag = None;
+            return None;
+        }
+        match stage {
+            0 => {
+                *drag = cursor.as_ref().map(|_| Drag {
+                    button: drag_button,
+                    slots: vec![],
+                });
+            }
+            1 => {
+                let (held, index) = match (cursor.as_ref(), slot) {
+                    (Some(held), Some(index)) => (held, index),
+                    _ => return Some(()),
+                };
+                let eligible = self.can_drag_into(index, held);
+                if let Some(drag) = drag.as_mut().filter(|drag| drag.button == drag_button) {
+                    if eligible
+                        && !drag.slots.contains(&index)
+                        && (drag.button == 2 || (held.item_count as usize) > drag.slots.len())
+                    {
+                        drag.slots.push(index);
+                    }
+                }
+            }
+            2 => {
+                let drag = match drag.take().filter(|drag| drag.button == drag_button) {
+                    Some(drag) => drag,
+                    None => return Some(()),
+                };
+                if drag.slots.len() == 1 && drag.button != 2 {
+                    self.pickup(drag.slots[0], drag.button == 0, cursor);
+                } else if !drag.slots.is_empty() {
+                    self.spread(&drag, cursor);
+                }
+            }
+            _ => *drag = None,
+        }
+        Some(())
+    }
+
+    fn can_drag_into(&self, index: usize, held: &Slot) -> bool {
+        let fits = match &*self.slots[index] {
+            Some(stack) => same_item(stack, held),
+            None => true,
+        };
+        fits && self.may_place(index, held)
+    }
+
+    fn spread(&mut self, drag: &Drag, cursor: &mut Option<Slot>) {
+        let held = match cursor.as_mut() {
+            Some(held) => held,
+            None => return,
+        };
+        let each = match drag.button {
+            0 => held.item_count / drag.slots.len() as u8,
+            1 => 1,
+            _ => stack_size(held),
+        };
+        let mut remaining = held.item_count;
+        for &index in &drag.slots {
+            if !self.can_drag_into(index, held) {
+                continue;
+            }
+            let existing = self.slots[index]
+                .as_ref()
+                .map_or(0, |stack| stack.item_count);
+            let each = if drag.button == 2 {
+                each
+            } else {
+                each.min(remaining)
+            };
+            let count = (existing + each).min(self.limit(index, held));
+            let mut stack = held.clone();
+            stack.item_count = count;
+            *self.slots[index] = Some(stack);
+            if drag.button != 2 {
+                remaining -= count.saturating_sub(existing);
+            }
+        }
+        held.item_count = remaining;
+        if remaining == 0 {
+            *cursor = None;
+        }
+    }
+
+    // takes non-full stacks before full ones, never from results
+    fn collect(&mut self, cursor: &mut Option<Slot>, forward: bool) {
+        let held = match cursor.as_mut() {
+            Some(held) => held,
+            None => return,
+        };
+        let max = stack_size(held);
+        let mut indices = (0..self.slots.len()).collect::<Vec<_>>();
+        if !forward {
+            indices.reverse();
+        }
+        for pass in 0..2 {
+            for &index in &indices {
+                if held.item_count >= max {
+                    return;
+                }
+                if self.kind.slot_kind(index) == SlotKind::Result {
+                    continue;
+                }
+                let count = match &*self.slots[index] {
+                    Some(stack) if same_item(stack, held) => {
+                        if pass == 0 && stack.item_count >= stack_size(stack) {
+                            continue;
+                        }
+                        stack.item_count
+                    }
+                    _ => continue,
+                };
+                let count = count.min(max - held.item_count);
+                take(self.slots[index], count);
+                held.item_count += count;
+            }
+        }
+    }
+
+    pub fn recipe(&self) -> Option<&'static Recipe> {
+        let (grid, size) = self.kind.grid()?;
+        let items = grid
+            .map(|index| (*self.slots[index]).clone())
+            .collect::<Vec<_>>();
+        crafting::find_recipe(&items, size)
+    }
+
+    // put what the grid makes in the result slot
+    pub fn update_result(&mut self) {
+        if self.kind.grid().is_none() {
+            return;
+        }
+        *self.slots[CRAFTING_RESULT] = self
+            .recipe()
+            .map(|recipe| stack_of(recipe.result, recipe.count));
+    }
+
+    // use up one of every ingredient once the result was taken
+    fn craft(&mut self) {
+        let (grid, _) = match self.kind.grid() {
+            Some(grid) => grid,
+            None => return,
+        };
+        for index in grid {
+            let item = self.slots[index]
+                .as_ref()
+                .and_then(|stack| Item::from_id(stack.item_id.0 as u32));
+            take(self.slots[index], 1);
+            let mut left = match item.and_then(crafting::remainder) {
+                Some(left) => stack_of(left, 1),
+                None => continue,
+            };
+            if self.slots[index].is_none() {
+                *self.slots[index] = Some(left);
+                continue;
+            }
+            let inventory = self.kind.inventory().collect::<Vec<_>>();
+            self.insert(&mut left, &inventory);
+            if left.item_count > 0 {
+                self.dropped.push(left);
+            }
+        }
+        self.update_result();
+    }
+
+    // move the grid back into the inventory, false when some of it didn't fit
+    pub fn clear_grid(&mut self) -> bool {
+        let (grid, _) = match self.kind.grid() {
+            Some(grid) => grid,
+            None => return true,
+        };
+        let inventory = self.kind.inventory().collect::<Vec<_>>();
+        let mut cleared = true;
+        for index in grid {
+            if let Some(mut stack) = self.slots[index].take() {
+                self.insert(&mut stack, &inventory);
+                if stack.item_count > 0 {
+                    *self.slots[index] = Some(stack);
+                    cleared = false;
+                }
+            }
+        }
+        self.update_result();
+        cleared
+    }
+
+    // what the recipe book does: lay out the recipe's ingredients from the inventory,
+    // once or as many times as possible. false when there weren't enough ingredients
+    pub fn fill_grid(&mut self, recipe: &Recipe, all: bool) -> bool {
+        let (grid, size) = match self.kind.grid() {
+            Some(grid) => grid,
+            None => return false,
+        };
+        let cells = match recipe.kind {
+            RecipeKind::Shaped { width, height }
+                if width as usize <= size && height as usize <= size =>
+            {
+                let width = width as usize;
+                (0..recipe.ingredients.len())
+                    .map(|index| grid.start + index / width * size + index % width)
+                    .collect::<Vec<_>>()
+            }
+            RecipeKind::Shapeless if recipe.ingredients.len() <= size * size => {
+                grid.clone().take(recipe.ingredients.len()).collect()
+            }
+            _ => return false,
+        };
+        // another click on the recipe adds to what is already laid out
+        if self.recipe() != Some(recipe) && !self.clear_grid() {
+            return false;
+        }
+
+        let inventory = self.kind.inventory().collect::<Vec<_>>();
+        let mut slots = self
+            .slots
+            .iter()
+            .map(|slot| (**slot).clone())
+            .collect::<Vec<_>>();
+        let mut rounds = 0;
+        while rounds < if all { 64 } else { 1 } {
+            let mut next = slots.clone();
+            let placed = cells
+                .iter()
+                .zip(recipe.ingredients)
+                .all(|(&cell, ingredient)| {
+                    ingredient.is_empty()
+                        || place_ingredient(&mut next, cell, ingredient, &inventory)
+                });
+            if !placed {
+                break;
+            }
+            slots = next;
+            rounds += 1;
+        }
+        for (slot, stack) in self.slots.iter_mut().zip(slots) {
+            **slot = stack;
+        }
+        self.update_result();
+        rounds > 0
+    }
+}
+
+// move one matching item from the inventory onto the cell
+fn place_ingredient(
+    slots: &mut [Option<Slot>],
+    cell: usize,
+    ingredient: &[Item],
+    inventory: &[usize],
+) -> bool {
+    let source = inventory.iter().copied().find(|&index| {
+        let stack = match &slots[index] {
+            Some(stack) => stack,
+            None => return false,
+        };
+        let matches =
+            Item::from_id(stack.item_id.0 as u32).is_some_and(|item| ingredient.contains(&item));
+        matches
+            && match &slots[cell] {
+                Some(placed) => same_item(placed, stack) && placed.item_count < stack_size(placed),
+                None => true,
+            }
+    });
+    let source = match source {
+        Some(source) => source,
+        None => return false,
+    };
+    let taken = take(&mut slots[source], 1);
+    match (&mut slots[cell], taken) {
+        (Some(placed), Some(_)) => placed.item_count += 1,
+        (cell, taken) => *cell = taken,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inventory() -> Vec<Option<Slot>> {
+        vec![None; OFFHAND + 1]
+    }
+
+    fn click(slot: i16, button: i8, mode: i32) -> Click {
+        Click { slot, button, mode }
+    }
+
+    fn count(slot: &Option<Slot>) -> u8 {
+        slot.as_ref().map_or(0, |stack| stack.item_count)
+    }
+
+    #[test]
+    fn left_and_right_clicks_pick_up_and_place() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 5));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        // right click takes the larger half
+        let result = window
+            .click(&mut cursor, &mut drag, &click(9, 1, 0), false)
+            .unwrap();
+        assert_eq!(result.clicked, Some(stack_of(Item::Stone, 5)));
+        assert_eq!(count(&cursor), 3);
+        assert_eq!(count(window.slots[9]), 2);
+
+        // right click on an empty slot places one, left click places the rest
+        window.click(&mut cursor, &mut drag, &click(10, 1, 0), false);
+        assert_eq!(count(window.slots[10]), 1);
+        window.click(&mut cursor, &mut drag, &click(9, 0, 0), false);
+        assert_eq!(count(window.slots[9]), 4);
+        assert_eq!(cursor, None);
+    }
+
+    #[test]
+    fn clicking_outside_drops_the_cursor() {
+        let mut slots = inventory();
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (Some(stack_of(Item::Stone, 5)), None);
+
+        let result = window
+            .click(&mut cursor, &mut drag, &click(OUTSIDE, 1, 0), false)
+            .unwrap();
+        assert_eq!(result.dropped, vec![stack_of(Item::Stone, 1)]);
+        let result = window
+            .click(&mut cursor, &mut drag, &click(OUTSIDE, 0, 0), false)
+            .unwrap();
+        assert_eq!(result.dropped, vec![stack_of(Item::Stone, 4)]);
+        assert_eq!(cursor, None);
+    }
+
+    #[test]
+    fn shift_clicks_merge_before_filling_empty_slots() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 10));
+        slots[HOTBAR.start + 4] = Some(stack_of(Item::Stone, 60));
+        slots[MAIN.start + 1] = Some(stack_of(Item::IronHelmet, 1));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        let result = window
+            .click(&mut cursor, &mut drag, &click(9, 0, 1), false)
+            .unwrap();
+        assert_eq!(result.clicked, Some(stack_of(Item::Stone, 10)));
+        assert_eq!(count(window.slots[HOTBAR.start + 4]), 64);
+        assert_eq!(count(window.slots[HOTBAR.start]), 6);
+        assert_eq!(*window.slots[9], None);
+
+        // armor goes on first
+        window.click(&mut cursor, &mut drag, &click(10, 0, 1), false);
+        assert_eq!(
+            *window.slots[ARMOR.start],
+            Some(stack_of(Item::IronHelmet, 1))
+        );
+    }
+
+    #[test]
+    fn number_keys_swap_with_the_hotbar() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 1));
+        slots[HOTBAR.start + 2] = Some(stack_of(Item::Dirt, 2));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        window.click(&mut cursor, &mut drag, &click(9, 2, 2), false);
+        assert_eq!(*window.slots[9], Some(stack_of(Item::Dirt, 2)));
+        assert_eq!(
+            *window.slots[HOTBAR.start + 2],
+            Some(stack_of(Item::Stone, 1))
+        );
+        // a helmet can't be swapped into the chest slot
+        *window.slots[HOTBAR.start] = Some(stack_of(Item::IronHelmet, 1));
+        window.click(&mut cursor, &mut drag, &click(6, 0, 2), false);
+        assert_eq!(*window.slots[6], None);
+    }
+
+    #[test]
+    fn middle_clicks_only_clone_in_creative() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 1));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        window.click(&mut cursor, &mut drag, &click(9, 2, 3), false);
+        assert_eq!(cursor, None);
+        window.click(&mut cursor, &mut drag, &click(9, 2, 3), true);
+        assert_eq!(cursor, Some(stack_of(Item::Stone, 64)));
+        assert_eq!(count(window.slots[9]), 1);
+    }
+
+    #[test]
+    fn q_drops_one_and_ctrl_q_the_stack() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 5));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        let result = window
+            .click(&mut cursor, &mut drag, &click(9, 0, 4), false)
+            .unwrap();
+        assert_eq!(result.dropped, vec![stack_of(Item::Stone, 1)]);
+        let result = window
+            .click(&mut cursor, &mut drag, &click(9, 1, 4), false)
+            .unwrap();
+        assert_eq!(result.dropped, vec![stack_of(Item::Stone, 4)]);
+        assert_eq!(*window.slots[9], None);
+    }
+
+    #[test]
+    fn dragging_spreads_the_cursor() {
+        let mut slots = inventory();
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (Some(stack_of(Item::Stone, 10)), None);
+
+        // start, add three slots, end
+        window.click(&mut cursor, &mut drag, &click(OUTSIDE, 0, 5), false);
+        for slot in 9..12 {
+            window.click(&mut cursor, &mut drag, &click(slot, 1, 5), false);
+        }
+        window.click(&mut cursor, &mut drag, &click(OUTSIDE, 2, 5), false);
+        for slot in 9..12 {
+            assert_eq!(count(window.slots[slot]), 3);
+        }
+        assert_eq!(count(&cursor), 1);
+        assert!(drag.is_none());
+
+        // the creative drag button is rejected outside creative
+        assert!(window
+            .click(&mut cursor, &mut drag, &click(OUTSIDE, 8, 5), false)
+            .is_none());
+    }
+
+    #[test]
+    fn double_clicks_collect_partial_stacks_first() {
+        let mut slots = inventory();
+        slots[MAIN.start] = Some(stack_of(Item::Stone, 64));
+        slots[MAIN.start + 1] = Some(stack_of(Item::Stone, 20));
+        slots[HOTBAR.start] = Some(stack_of(Item::Stone, 30));
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (Some(stack_of(Item::Stone, 10)), None);
+
+        // the partial stacks empty before the full one tops the cursor up
+        window.click(&mut cursor, &mut drag, &click(20, 0, 6), false);
+        assert_eq!(count(&cursor), 64);
+        assert_eq!(count(window.slots[9]), 60);
+        assert_eq!(*window.slots[10], None);
+        assert_eq!(*window.slots[HOTBAR.start], None);
+    }
+
+    #[test]
+    fn taking_the_result_crafts() {
+        let mut slots = inventory();
+        for slot in CRAFTING_GRID {
+            slots[slot] = Some(stack_of(Item::OakPlanks, 2));
+        }
+        let mut window = Window::player(&mut slots);
+        window.update_result();
+        let (mut cursor, mut drag) = (None, None);
+
+        window.click(&mut cursor, &mut drag, &click(0, 0, 0), false);
+        assert_eq!(cursor, Some(stack_of(Item::CraftingTable, 1)));
+        assert_eq!(count(window.slots[1]), 1);
+        assert_eq!(*window.slots[0], Some(stack_of(Item::CraftingTable, 1)));
+    }
+
+    #[test]
+    fn rejects_unknown_slots_and_modes() {
+        let mut slots = inventory();
+        let mut window = Window::player(&mut slots);
+        let (mut cursor, mut drag) = (None, None);
+
+        assert!(window
+            .click(&mut cursor, &mut drag, &click(46, 0, 0), false)
+            .is_none());
+        assert!(window
+            .click(&mut cursor, &mut drag, &click(9, 0, 7), false)
+            .is_none());
+    }
+}
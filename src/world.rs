@@ -1,71 +1,372 @@
-use std::{collections::HashMap, io::Result};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Result,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
-use kareki_data::block::Block;
+use kareki_data::{block::Block, block_state::BlockState};
 
-use crate::types::chunk::Chunk;
+mod light;
+mod workers;
 
-#[derive(Debug, Clone, Default)]
+pub use workers::EncodedChunk;
+
+use self::workers::{Done, Job, Workers};
+use crate::{
+    generator::{self, ChunkGenerator, SEA_LEVEL},
+    level::Level,
+    region::RegionStorage,
+    types::{
+        block_entity::BlockEntity, chunk::Chunk, heightmap::HeightmapKind, position::Position,
+    },
+};
+
+// chunk_x, chunk_z and the new state of each changed block in one chunk section
+pub type SectionChanges = (i32, i32, Vec<(Position, u16)>);
+
+#[derive(Debug)]
 pub struct World {
-    chunks: HashMap<(i32, i32), Chunk>,
+    pub level: Level,
+    level_path: PathBuf,
+    // shared with the workers encoding them, changing one while it's being
+    // encoded copies it first
+    chunks: HashMap<(i32, i32), Arc<Chunk>>,
+    dirty_chunks: HashSet<(i32, i32)>,
+    // chunks the workers are writing to disk, they're dirty again if that fails
+    saving: HashSet<(i32, i32)>,
+    // blocks set since the last take_block_changes, keyed by chunk section
+    changed_blocks: HashMap<(i32, i32, i32), HashSet<Position>>,
+    // block entities clients are told about that changed since the last
+    // take_block_entity_changes
+    changed_block_entities: HashSet<Position>,
+    // light mask of the sections whose light changed since the last take_light_changes
+    changed_light: HashMap<(i32, i32), u32>,
+    // bumped whenever a chunk's blocks or light change, so stale encodes can be told apart
+    versions: HashMap<(i32, i32), u64>,
+    // chunks being loaded or generated by the workers
+    loading: HashSet<(i32, i32)>,
+    // chunks being encoded and the version they were shared at
+    encoding: HashMap<(i32, i32), u64>,
+    encoded: HashMap<(i32, i32), (u64, EncodedChunk)>,
+    storage: Arc<Mutex<RegionStorage>>,
+    generator: Arc<dyn ChunkGenerator>,
+    workers: Workers,
+    next_entity_id: i32,
 }
 
 impl World {
-    pub fn new() -> Result<Self> {
-        let mut world = Self::default();
-        let mut default_chunk = Chunk::empty();
-        for x in 0..16 {
-            for z in 0..16 {
-                for y in 0..16 {
-                    let block = if y == 15 {
-                        Block::GrassBlock
-                    } else {
-                        Block::Dirt
-                    };
-                    default_chunk.set_block(x, y, z, block)?;
-                }
-            }
+    pub fn new<P: AsRef<Path>>(
+        directory: P,
+        level_type: &str,
+        generator_settings: &str,
+    ) -> Result<Self> {
+        let directory = directory.as_ref();
+        let level_path = directory.join("level.dat");
+        let level = Level::load(&level_path)?;
+        let is_new_world = level.is_none();
+        if let Some(level) = &level {
+            println!(
+                "loaded world {:?} (spawn: {:?}, seed: {})",
+                level.name, level.spawn, level.seed
+            );
+        }
+
+        let mut level = level.unwrap_or_default();
+        if is_new_world {
+            level.seed = rand::random();
         }
+        // server.properties only decides how new worlds are generated
+        let generator: Arc<dyn ChunkGenerator> = if is_new_world {
+            let generator = generator::create(level_type, generator_settings, level.seed)?;
+            level.generator_name = generator.level_type().to_string();
+            level.generator_options = generator.options();
+            generator.into()
+        } else {
+            generator::load(&level.generator_name, &level.generator_options, level.seed)?.into()
+        };
+        let storage = Arc::new(Mutex::new(RegionStorage::new(directory.join("region"))?));
+        let workers = Workers::spawn(storage.clone(), generator.clone());
+
+        let mut world = Self {
+            level,
+            level_path,
+            chunks: HashMap::new(),
+            dirty_chunks: HashSet::new(),
+            saving: HashSet::new(),
+            changed_blocks: HashMap::new(),
+            changed_block_entities: HashSet::new(),
+            changed_light: HashMap::new(),
+            versions: HashMap::new(),
+            loading: HashSet::new(),
+            encoding: HashMap::new(),
+            encoded: HashMap::new(),
+            storage,
+            generator,
+            workers,
+            next_entity_id: 1,
+        };
+        if !is_new_world {
+            return Ok(world);
+        }
+
         for x in -2..2 {
             for z in -2..2 {
-                world.chunks.insert((x, z), default_chunk.clone());
+                world.fetch_chunk(x, z)?;
             }
         }
+        world.level.spawn = world.find_spawn()?;
 
         Ok(world)
     }
 
-    // get chunk or chunk generate and return
-    pub fn fetch_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<&mut Chunk> {
-        let chunk = self
+    // get chunk or load chunk from disk or chunk generate and return, blocking the caller.
+    // the tick uses request_chunk instead
+    pub fn fetch_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<&Chunk> {
+        if !self.chunks.contains_key(&(chunk_x, chunk_z)) {
+            let (chunk, generated) =
+                workers::load(chunk_x, chunk_z, &self.storage, &*self.generator)?;
+            self.insert_chunk(chunk_x, chunk_z, chunk, generated);
+        }
+
+        Ok(self
             .chunks
-            .entry((chunk_x, chunk_z))
-            .or_insert(Self::generate_chunk(chunk_x, chunk_z)?);
+            .get(&(chunk_x, chunk_z))
+            .expect("chunk should be loaded"))
+    }
 
-        Ok(chunk)
+    fn insert_chunk(&mut self, chunk_x: i32, chunk_z: i32, chunk: Chunk, generated: bool) {
+        if generated {
+            self.dirty_chunks.insert((chunk_x, chunk_z));
+        }
+        self.chunks.insert((chunk_x, chunk_z), Arc::new(chunk));
+        self.light_chunk(chunk_x, chunk_z);
+    }
+
+    // have the workers load or generate a chunk, poll_workers picks it up once it's done
+    pub fn request_chunk(&mut self, chunk_x: i32, chunk_z: i32) {
+        let key = (chunk_x, chunk_z);
+        if self.chunks.contains_key(&key) || !self.loading.insert(key) {
+            return;
+        }
+        self.workers.submit(Job::Load { chunk_x, chunk_z });
+    }
+
+    // take in everything the workers finished since the last call
+    pub fn poll_workers(&mut self) {
+        for done in self.workers.completed() {
+            self.finish_job(done);
+        }
     }
 
-    fn generate_chunk(_chunk_x: i32, _chunk_z: i32) -> Result<Chunk> {
-        let mut chunk = Chunk::empty();
-        for x in 0..16 {
-            for z in 0..16 {
-                for y in 0..16 {
-                    if (x + y + z) / 4 % 3 != 0 {
-                        let block = Block::from_id((((x + y + z) % 13) + 1) as u32)
-                            .expect("Unknown block id");
-                        chunk.set_block(x, y, z, block)?;
+    fn finish_job(&mut self, done: Done) {
+        match done {
+            Done::Loaded {
+                chunk_x,
+                chunk_z,
+                chunk,
+            } => {
+                let key = (chunk_x, chunk_z);
+                self.loading.remove(&key);
+                match chunk {
+                    // fetch_chunk may have got to it first
+                    Ok(_) if self.chunks.contains_key(&key) => {}
+                    Ok((chunk, generated)) => {
+                        self.insert_chunk(chunk_x, chunk_z, *chunk, generated)
+                    }
+                    Err(err) => {
+                        println!("failed to load chunk {:?}: {:?}", key, err);
+                    }
+                }
+            }
+            Done::Encoded {
+                chunk_x,
+                chunk_z,
+                version,
+                packets,
+            } => {
+                let key = (chunk_x, chunk_z);
+                if self.encoding.get(&key) == Some(&version) {
+                    self.encoding.remove(&key);
+                }
+                match packets {
+                    Ok(packets) if version == self.version(chunk_x, chunk_z) => {
+                        self.encoded.insert(key, (version, *packets));
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        println!("failed to encode chunk {:?}: {:?}", key, err);
                     }
                 }
             }
+            Done::Saved {
+                chunk_x,
+                chunk_z,
+                result,
+            } => {
+                let key = (chunk_x, chunk_z);
+                self.saving.remove(&key);
+                if let Err(err) = result {
+                    println!("failed to save chunk {:?}: {:?}", key, err);
+                    self.dirty_chunks.insert(key);
+                }
+            }
         }
-        Ok(chunk)
     }
 
-    pub fn get_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Result<Option<&mut Chunk>> {
-        let chunk = self.chunks.get_mut(&(chunk_x, chunk_z));
+    // the packets for a loaded chunk as it is now. None while they are still being encoded,
+    // in which case an encode is started if there isn't one already
+    pub fn encoded_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Option<EncodedChunk> {
+        let key = (chunk_x, chunk_z);
+        let version = self.version(chunk_x, chunk_z);
+        if let Some((encoded_version, packets)) = self.encoded.get(&key) {
+            if *encoded_version == version {
+                return Some(packets.clone());
+            }
+            self.encoded.remove(&key);
+        }
+        let chunk = self.chunks.get(&key)?;
+        if self.encoding.get(&key) != Some(&version) {
+            self.encoding.insert(key, version);
+            self.workers.submit(Job::Encode {
+                chunk_x,
+                chunk_z,
+                version,
+                chunk: chunk.clone(),
+            });
+        }
+        None
+    }
+
+    // drop the encoded packets of chunks nobody is waiting on anymore
+    pub fn retain_encoded<F: FnMut(i32, i32) -> bool>(&mut self, mut keep: F) {
+        self.encoded
+            .retain(|(chunk_x, chunk_z), _| keep(*chunk_x, *chunk_z));
+    }
+
+    fn version(&self, chunk_x: i32, chunk_z: i32) -> u64 {
+        self.versions
+            .get(&(chunk_x, chunk_z))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn bump_version(&mut self, chunk_x: i32, chunk_z: i32) {
+        *self.versions.entry((chunk_x, chunk_z)).or_default() += 1;
+    }
+
+    pub fn level_type(&self) -> &'static str {
+        self.generator.level_type()
+    }
+
+    pub fn next_entity_id(&mut self) -> i32 {
+        let entity_id = self.next_entity_id;
+        self.next_entity_id += 1;
+        entity_id
+    }
+
+    pub fn tick(&mut self) {
+        self.level.time += 1;
+        if self.level.game_rule_bool("doDaylightCycle", true) {
+            self.level.day_time += 1;
+        }
+    }
+
+    // write level.dat and have the workers write every modified chunk back to its
+    // region file. a chunk still being saved from last time waits for the next save
+    pub fn save(&mut self) -> Result<()> {
+        let dirty_chunks = self
+            .dirty_chunks
+            .iter()
+            .filter(|key| !self.saving.contains(key))
+            .copied()
+            .collect::<Vec<_>>();
+        for key in dirty_chunks {
+            self.dirty_chunks.remove(&key);
+            if let Some(chunk) = self.chunks.get(&key) {
+                self.saving.insert(key);
+                self.workers.submit(Job::Save {
+                    chunk_x: key.0,
+                    chunk_z: key.1,
+                    chunk: chunk.clone(),
+                    game_time: self.level.time,
+                });
+            }
+        }
+        self.level.save(&self.level_path)
+    }
+
+    // save everything and wait for it to be on disk, for shutting down
+    pub fn flush(&mut self) -> Result<()> {
+        // an older copy still being written mustn't land after the one written here
+        while !self.saving.is_empty() {
+            match self.workers.wait() {
+                Some(done) => self.finish_job(done),
+                None => break,
+            }
+        }
+        let mut dirty_chunks = self.dirty_chunks.drain().collect::<Vec<_>>();
+        dirty_chunks.sort_unstable();
+        for (chunk_x, chunk_z) in dirty_chunks {
+            if let Some(chunk) = self.chunks.get(&(chunk_x, chunk_z)) {
+                self.storage.lock().unwrap().save_chunk(
+                    chunk_x,
+                    chunk_z,
+                    chunk,
+                    self.level.time,
+                )?;
+            }
+        }
+        self.level.save(&self.level_path)
+    }
+
+    // the dry column closest to the origin within the chunks generated around it
+    fn find_spawn(&mut self) -> Result<Position> {
+        let mut spawn = None;
+        for x in -32..32 {
+            for z in -32..32 {
+                let (x, z) = (x as usize, z as usize);
+                let height = match self.height(HeightmapKind::MotionBlocking, x, z)? {
+                    Some(height) if height as usize > SEA_LEVEL => height,
+                    _ => continue,
+                };
+                let top = self.get_block_state(x, height as usize - 1, z)?;
+                if top.is_none_or(|top| top.block() == Block::Water || top.block() == Block::Lava) {
+                    continue;
+                }
+                let position = Position {
+                    x: x as i32,
+                    y: height as i16,
+                    z: z as i32,
+                };
+                let distance = |p: &Position| p.x * p.x + p.z * p.z;
+                if spawn
+                    .as_ref()
+                    .is_none_or(|spawn| distance(&position) < distance(spawn))
+                {
+                    spawn = Some(position);
+                }
+            }
+        }
+        let spawn = match spawn {
+            Some(spawn) => spawn,
+            // all water, spawn on the surface at the origin
+            None => Position {
+                x: 0,
+                y: self
+                    .height(HeightmapKind::MotionBlocking, 0, 0)?
+                    .unwrap_or(64) as i16,
+                z: 0,
+            },
+        };
+        Ok(spawn)
+    }
+
+    pub fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<Option<&Chunk>> {
+        let chunk = self.chunks.get(&(chunk_x, chunk_z)).map(Arc::as_ref);
         Ok(chunk)
     }
 
-    #[allow(dead_code)]
     pub fn get_block(&mut self, x: usize, y: usize, z: usize) -> Result<Option<u16>> {
         let chunk_x = (x >> 4) as i32;
         let chunk_z = (z >> 4) as i32;
@@ -78,15 +379,182 @@ impl World {
         }
     }
 
+    pub fn height(&mut self, kind: HeightmapKind, x: usize, z: usize) -> Result<Option<u16>> {
+        let chunk = self.get_chunk((x >> 4) as i32, (z >> 4) as i32)?;
+        Ok(chunk.map(|chunk| chunk.height(kind, x & 0b1111, z & 0b1111)))
+    }
+
+    pub fn get_block_state(&mut self, x: usize, y: usize, z: usize) -> Result<Option<BlockState>> {
+        let state = self.get_block(x, y, z)?;
+        Ok(state.and_then(|state| BlockState::from_id(state as u32)))
+    }
+
     pub fn set_block(&mut self, x: usize, y: usize, z: usize, block: Block) -> Result<()> {
+        self.set_block_state(x, y, z, BlockState::new(block))
+    }
+
+    pub fn set_block_state(
+        &mut self,
+        x: usize,
+        y: usize,
+        z: usize,
+        state: BlockState,
+    ) -> Result<()> {
         let chunk_x = (x >> 4) as i32;
         let chunk_z = (z >> 4) as i32;
 
-        let chunk = self.get_chunk(chunk_x, chunk_z)?;
+        let old = match self.get_block_state(x, y, z)? {
+            Some(old) => old,
+            None => BlockState::new(Block::Air),
+        };
+        let chunk = self.chunks.get_mut(&(chunk_x, chunk_z)).map(Arc::make_mut);
 
         match chunk {
-            Some(chunk) => chunk.set_block(x & 0b1111, y, z & 0b1111, block),
+            Some(chunk) => chunk.set_block_raw(x & 0b1111, y, z & 0b1111, state.id() as u16)?,
             None => return Ok(()),
         }
+        let position = Position {
+            x: x as i32,
+            y: y as i16,
+            z: z as i32,
+        };
+        if old.block() != state.block() {
+            let chunk = Arc::make_mut(
+                self.chunks
+                    .get_mut(&(chunk_x, chunk_z))
+                    .expect("chunk is loaded"),
+            );
+            chunk.block_entities.remove(&position);
+            if let Some(entity) = BlockEntity::for_block(state.block()) {
+                if entity.update_action().is_some() {
+                    self.changed_block_entities.insert(position);
+                }
+                chunk.block_entities.insert(position, entity);
+            }
+        }
+        self.update_light(x as i32, y as i32, z as i32, old, state);
+        self.bump_version(chunk_x, chunk_z);
+        self.dirty_chunks.insert((chunk_x, chunk_z));
+        self.changed_blocks
+            .entry((chunk_x, (y >> 4) as i32, chunk_z))
+            .or_default()
+            .insert(position);
+        Ok(())
+    }
+
+    pub fn block_entity(&self, position: Position) -> Option<&BlockEntity> {
+        let chunk = self.chunks.get(&(position.x >> 4, position.z >> 4))?;
+        chunk.block_entities.get(&position)
+    }
+
+    // for changes clients don't see, like a container's items
+    pub fn block_entity_mut(&mut self, position: Position) -> Option<&mut BlockEntity> {
+        let key = (position.x >> 4, position.z >> 4);
+        let chunk = self.chunks.get_mut(&key)?;
+        if !chunk.block_entities.contains_key(&position) {
+            return None;
+        }
+        let entity = Arc::make_mut(chunk).block_entities.get_mut(&position)?;
+        self.dirty_chunks.insert(key);
+        Some(entity)
+    }
+
+    // replace a block entity and tell clients about it
+    pub fn set_block_entity(&mut self, position: Position, entity: BlockEntity) {
+        let key = (position.x >> 4, position.z >> 4);
+        let chunk = match self.chunks.get_mut(&key) {
+            Some(chunk) => Arc::make_mut(chunk),
+            None => return,
+        };
+        chunk.block_entities.insert(position, entity);
+        self.changed_block_entities.insert(position);
+        self.dirty_chunks.insert(key);
+        self.bump_version(key.0, key.1);
+    }
+
+    // burn and smelt in every loaded furnace, returns the furnaces that changed
+    pub fn tick_block_entities(&mut self) -> Result<Vec<Position>> {
+        let mut changed = vec![];
+        let mut toggled = vec![];
+        for (key, chunk) in self.chunks.iter_mut() {
+            let has_furnace = chunk
+                .block_entities
+                .values()
+                .any(|entity| matches!(entity, BlockEntity::Furnace(_)));
+            if !has_furnace {
+                continue;
+            }
+            for (position, entity) in Arc::make_mut(chunk).block_entities.iter_mut() {
+                let furnace = match entity {
+                    BlockEntity::Furnace(furnace) => furnace,
+                    _ => continue,
+                };
+                let was_lit = furnace.is_lit();
+                if furnace.tick() {
+                    changed.push(*position);
+                    self.dirty_chunks.insert(*key);
+                }
+                if furnace.is_lit() != was_lit {
+                    toggled.push((*position, furnace.is_lit()));
+                }
+            }
+        }
+        for (position, lit) in toggled {
+            let (x, y, z) = (
+                position.x as usize,
+                position.y as usize,
+                position.z as usize,
+            );
+            if let Some(mut state) = self.get_block_state(x, y, z)? {
+                state.set_lit(lit);
+                self.set_block_state(x, y, z, state)?;
+            }
+        }
+        changed.sort_unstable_by_key(|position| (position.x, position.y, position.z));
+        Ok(changed)
+    }
+
+    // current state of every block changed since the last call
+    pub fn take_block_changes(&mut self) -> Result<Vec<SectionChanges>> {
+        let mut changed_blocks = self.changed_blocks.drain().collect::<Vec<_>>();
+        changed_blocks.sort_unstable_by_key(|(section, _)| *section);
+
+        let mut changes = vec![];
+        for ((chunk_x, _, chunk_z), positions) in changed_blocks {
+            let mut blocks = vec![];
+            for position in positions {
+                let block = self.get_block(
+                    position.x as usize,
+                    position.y as usize,
+                    position.z as usize,
+                )?;
+                if let Some(block) = block {
+                    blocks.push((position, block));
+                }
+            }
+            changes.push((chunk_x, chunk_z, blocks));
+        }
+        Ok(changes)
+    }
+
+    // every block entity clients need to hear about that changed since the last call
+    pub fn take_block_entity_changes(&mut self) -> Vec<(Position, BlockEntity)> {
+        let mut positions = self.changed_block_entities.drain().collect::<Vec<_>>();
+        positions.sort_unstable_by_key(|position| (position.x, position.y, position.z));
+        positions
+            .into_iter()
+            .filter_map(|position| Some((position, self.block_entity(position)?.clone())))
+            .collect()
+    }
+
+    // light mask of every chunk whose light changed since the last call
+    pub fn take_light_changes(&mut self) -> Vec<(i32, i32, u32)> {
+        let mut changes = self
+            .changed_light
+            .drain()
+            .map(|((chunk_x, chunk_z), sections)| (chunk_x, chunk_z, sections))
+            .collect::<Vec<_>>();
+        changes.sort_unstable();
+        changes
     }
 }
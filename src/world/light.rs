@@ -0,0 +1,357 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use kareki_data::{block::Block, block_state::BlockState};
+
+use crate::types::light::LightKind;
+
+use super::World;
+
+// down first, sky light keeps its full level only when going straight down
+const DIRECTIONS: [(i32, i32, i32); 6] = [
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+    (-1, 0, 0),
+    (1, 0, 0),
+];
+
+type LightQueue = VecDeque<(i32, i32, i32)>;
+
+// how much light the state gives off
+pub fn emission(state: BlockState) -> u8 {
+    if state.lit() == Some(false) {
+        return 0;
+    }
+    match state.block() {
+        Block::SeaPickle => match state.waterlogged() {
+            Some(true) => (state.pickles().unwrap_or(1) + 1) * 3,
+            _ => 0,
+        },
+        block => block.emit_light(),
+    }
+}
+
+impl World {
+    // light a chunk that was just loaded, then let light flow across its borders
+    // with the loaded neighbours
+    pub(super) fn light_chunk(&mut self, chunk_x: i32, chunk_z: i32) {
+        let (base_x, base_z) = (chunk_x * 16, chunk_z * 16);
+        let mut sky = LightQueue::new();
+        let mut block = LightQueue::new();
+
+        let chunk = match self.chunks.get_mut(&(chunk_x, chunk_z)) {
+            Some(chunk) => Arc::make_mut(chunk),
+            None => return,
+        };
+        chunk.clear_light();
+
+        // the lowest y each column still sees the sky from
+        let mut heights = [[256; 16]; 16];
+        for (x, column) in heights.iter_mut().enumerate() {
+            for (z, height) in column.iter_mut().enumerate() {
+                let mut y = 256;
+                while y > 0 && filter(state_of(chunk.get_block(x, y - 1, z))) == 0 {
+                    y -= 1;
+                }
+                *height = y;
+                for y in y..256 {
+                    chunk.set_light(LightKind::Sky, x, y, z, 15);
+                }
+
+                for y in 0..256 {
+                    let emission = emission(state_of(chunk.get_block(x, y, z)));
+                    if emission > 0 {
+                        chunk.set_light(LightKind::Block, x, y, z, emission);
+                        block.push_back((base_x + x as i32, y as i32, base_z + z as i32));
+                    }
+                }
+            }
+        }
+
+        // west, east, north and south
+        let neighbours = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .map(|(dx, dz)| self.chunks.contains_key(&(chunk_x + dx, chunk_z + dz)));
+
+        // only sky cells next to something darker have anywhere to spread
+        for (x, column) in heights.iter().enumerate() {
+            for (z, &height) in column.iter().enumerate() {
+                let shaded_side = DIRECTIONS[2..]
+                    .iter()
+                    .map(|(dx, _, dz)| (x as i32 + dx, z as i32 + dz))
+                    .filter(|(x, z)| (0..16).contains(x) && (0..16).contains(z))
+                    .map(|(x, z)| heights[x as usize][z as usize])
+                    .max()
+                    .unwrap_or(height);
+                let on_border = (x == 0 && neighbours[0])
+                    || (x == 15 && neighbours[1])
+                    || (z == 0 && neighbours[2])
+                    || (z == 15 && neighbours[3]);
+                let top = if on_border {
+                    256
+                } else {
+                    shaded_side.max(height + 1).min(256)
+                };
+                for y in height..top {
+                    sky.push_back((base_x + x as i32, y as i32, base_z + z as i32));
+                }
+            }
+        }
+
+        // light already in the neighbours flows into this chunk
+        for (dx, _, dz) in DIRECTIONS[2..].iter() {
+            if !self.chunks.contains_key(&(chunk_x + dx, chunk_z + dz)) {
+                continue;
+            }
+            for i in 0..16 {
+                let (x, z) = match (dx, dz) {
+                    (-1, _) => (base_x - 1, base_z + i),
+                    (1, _) => (base_x + 16, base_z + i),
+                    (_, -1) => (base_x + i, base_z - 1),
+                    _ => (base_x + i, base_z + 16),
+                };
+                for y in 0..256 {
+                    if self.light(LightKind::Sky, x, y, z).unwrap_or(0) > 1 {
+                        sky.push_back((x, y, z));
+                    }
+                    if self.light(LightKind::Block, x, y, z).unwrap_or(0) > 1 {
+                        block.push_back((x, y, z));
+                    }
+                }
+            }
+        }
+
+        self.propagate_light(LightKind::Sky, &mut sky);
+        self.propagate_light(LightKind::Block, &mut block);
+        // whoever gets this chunk gets its light with it
+        self.changed_light.remove(&(chunk_x, chunk_z));
+    }
+
+    // relight around a block that changed from old to new
+    pub(super) fn update_light(
+        &mut self,
+        x: i32,
+        y: i32,
+        z: i32,
+        old: BlockState,
+        new: BlockState,
+    ) {
+        if filter(old) == filter(new) && emission(old) == emission(new) {
+            return;
+        }
+        for kind in [LightKind::Sky, LightKind::Block].iter().copied() {
+            let mut removal = VecDeque::new();
+            let mut queue = LightQueue::new();
+
+            let level = self.light(kind, x, y, z).unwrap_or(0);
+            if level > 0 {
+                self.set_light(kind, x, y, z, 0);
+                removal.push_back((x, y, z, level));
+            }
+            if kind == LightKind::Block && emission(new) > 0 {
+                self.set_light(kind, x, y, z, emission(new));
+                queue.push_back((x, y, z));
+            }
+            // light may now pass where it was blocked before
+            for (dx, dy, dz) in DIRECTIONS.iter() {
+                queue.push_back((x + dx, y + dy, z + dz));
+            }
+
+            self.remove_light(kind, &mut removal, &mut queue);
+            self.propagate_light(kind, &mut queue);
+        }
+    }
+
+    // darken everything lit through the removed cells, queueing the brighter cells
+    // around them to fill the gap back in
+    fn remove_light(
+        &mut self,
+        kind: LightKind,
+        removal: &mut VecDeque<(i32, i32, i32, u8)>,
+        queue: &mut LightQueue,
+    ) {
+        while let Some((x, y, z, level)) = removal.pop_front() {
+            for (direction, (dx, dy, dz)) in DIRECTIONS.iter().enumerate() {
+                let (x, y, z) = (x + dx, y + dy, z + dz);
+                let neighbour = match self.light(kind, x, y, z) {
+                    Some(neighbour) if neighbour > 0 => neighbour,
+                    _ => continue,
+                };
+                let straight_down = kind == LightKind::Sky && direction == 0 && level == 15;
+                if y < 256 && (neighbour < level || (straight_down && neighbour == 15)) {
+                    self.set_light(kind, x, y, z, 0);
+                    removal.push_back((x, y, z, neighbour));
+                    if kind == LightKind::Block {
+                        let emission = self.block_state(x, y, z).map_or(0, emission);
+                        if emission > 0 {
+                            self.set_light(kind, x, y, z, emission);
+                            queue.push_back((x, y, z));
+                        }
+                    }
+                } else {
+                    queue.push_back((x, y, z));
+                }
+            }
+        }
+    }
+
+    fn propagate_light(&mut self, kind: LightKind, queue: &mut LightQueue) {
+        while let Some((x, y, z)) = queue.pop_front() {
+            let level = match self.light(kind, x, y, z) {
+                Some(level) if level > 1 => level,
+                _ => continue,
+            };
+            for (direction, (dx, dy, dz)) in DIRECTIONS.iter().enumerate() {
+                let (x, y, z) = (x + dx, y + dy, z + dz);
+                let state = match self.block_state(x, y, z) {
+                    Some(state) => state,
+                    None => continue,
+                };
+                let filter = filter(state);
+                let level =
+                    if kind == LightKind::Sky && direction == 0 && level == 15 && filter == 0 {
+                        15
+                    } else {
+                        level.saturating_sub(filter.max(1))
+                    };
+                if level > self.light(kind, x, y, z).unwrap_or(15) {
+                    self.set_light(kind, x, y, z, level);
+                    queue.push_back((x, y, z));
+                }
+            }
+        }
+    }
+
+    // None for unloaded chunks and below the world, above it is open sky
+    fn light(&self, kind: LightKind, x: i32, y: i32, z: i32) -> Option<u8> {
+        if y >= 256 {
+            return Some(match kind {
+                LightKind::Sky => 15,
+                LightKind::Block => 0,
+            });
+        }
+        if y < 0 {
+            return None;
+        }
+        let chunk = self.chunks.get(&(x >> 4, z >> 4))?;
+        Some(chunk.get_light(kind, (x & 0xf) as usize, y as usize, (z & 0xf) as usize))
+    }
+
+    fn set_light(&mut self, kind: LightKind, x: i32, y: i32, z: i32, level: u8) {
+        if !(0..256).contains(&y) {
+            return;
+        }
+        let (chunk_x, chunk_z) = (x >> 4, z >> 4);
+        if let Some(chunk) = self.chunks.get_mut(&(chunk_x, chunk_z)) {
+            Arc::make_mut(chunk).set_light(
+                kind,
+                (x & 0xf) as usize,
+                y as usize,
+                (z & 0xf) as usize,
+                level,
+            );
+            *self.changed_light.entry((chunk_x, chunk_z)).or_default() |= 1 << ((y >> 4) + 1);
+            self.bump_version(chunk_x, chunk_z);
+        }
+    }
+
+    // only within the world's height and loaded chunks
+    fn block_state(&self, x: i32, y: i32, z: i32) -> Option<BlockState> {
+        if !(0..256).contains(&y) {
+            return None;
+        }
+        let chunk = self.chunks.get(&(x >> 4, z >> 4))?;
+        Some(state_of(chunk.get_block(
+            (x & 0xf) as usize,
+            y as usize,
+            (z & 0xf) as usize,
+        )))
+    }
+}
+
+// blocks in sections that don't exist yet are air
+fn state_of(block_id: Option<u16>) -> BlockState {
+    block_id
+        .and_then(|block_id| BlockState::from_id(block_id as u32))
+        .unwrap_or_else(|| BlockState::new(Block::Air))
+}
+
+fn filter(state: BlockState) -> u8 {
+    state.block().filter_light()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::*;
+
+    // the default flat preset, grass at y 3
+    const GROUND: i32 = 4;
+
+    fn flat_world(name: &str) -> (World, PathBuf) {
+        let directory = env::temp_dir().join(format!("kareki-light-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        let world = World::new(&directory, "flat", "").unwrap();
+        (world, directory)
+    }
+
+    fn set_block(world: &mut World, x: i32, y: i32, z: i32, block: Block) {
+        world
+            .set_block(x as usize, y as usize, z as usize, block)
+            .unwrap();
+    }
+
+    #[test]
+    fn sky_light_reaches_under_an_overhang() {
+        let (mut world, directory) = flat_world("overhang");
+        assert_eq!(world.light(LightKind::Sky, 8, GROUND, 8), Some(15));
+        assert_eq!(world.light(LightKind::Sky, 8, GROUND - 1, 8), Some(0));
+
+        set_block(&mut world, 8, GROUND + 1, 8, Block::Stone);
+        assert_eq!(world.light(LightKind::Sky, 8, GROUND + 2, 8), Some(15));
+        assert_eq!(world.light(LightKind::Sky, 8, GROUND + 1, 8), Some(0));
+        // lit from the open column beside it
+        assert_eq!(world.light(LightKind::Sky, 8, GROUND, 8), Some(14));
+        assert_eq!(world.light(LightKind::Sky, 7, GROUND, 8), Some(15));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn removing_a_torch_leaves_no_block_light() {
+        let (mut world, directory) = flat_world("torch");
+        set_block(&mut world, 20, GROUND, 20, Block::Torch);
+        assert_eq!(world.light(LightKind::Block, 20, GROUND, 20), Some(14));
+        assert_eq!(world.light(LightKind::Block, 23, GROUND, 20), Some(11));
+        assert_eq!(world.light(LightKind::Block, 20, GROUND - 1, 20), Some(0));
+
+        set_block(&mut world, 20, GROUND, 20, Block::Air);
+        for chunk in world.chunks.values() {
+            for x in 0..16 {
+                for y in 0..256 {
+                    for z in 0..16 {
+                        assert_eq!(chunk.get_light(LightKind::Block, x, y, z), 0);
+                    }
+                }
+            }
+        }
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn light_crosses_into_chunks_loaded_later() {
+        let (mut world, directory) = flat_world("border");
+        assert!(world.get_chunk(2, 0).unwrap().is_none());
+        set_block(&mut world, 31, GROUND, 8, Block::Torch);
+        assert_eq!(world.light(LightKind::Block, 32, GROUND, 8), None);
+
+        world.fetch_chunk(2, 0).unwrap();
+        assert_eq!(world.light(LightKind::Block, 31, GROUND, 8), Some(14));
+        assert_eq!(world.light(LightKind::Block, 32, GROUND, 8), Some(13));
+        assert_eq!(world.light(LightKind::Block, 35, GROUND, 9), Some(9));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    io::Result,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use flume::{Receiver, Sender};
+
+use crate::{
+    generator::ChunkGenerator,
+    packet::client::PlayPacket,
+    region::RegionStorage,
+    types::chunk::{Chunk, ALL_LIGHT_SECTIONS},
+};
+
+pub enum Job {
+    Load {
+        chunk_x: i32,
+        chunk_z: i32,
+    },
+    // version is what the world's chunk was at when it was shared
+    Encode {
+        chunk_x: i32,
+        chunk_z: i32,
+        version: u64,
+        chunk: Arc<Chunk>,
+    },
+    Save {
+        chunk_x: i32,
+        chunk_z: i32,
+        chunk: Arc<Chunk>,
+        game_time: i64,
+    },
+}
+
+pub enum Done {
+    // the chunk, and whether it was generated rather than read from disk
+    Loaded {
+        chunk_x: i32,
+        chunk_z: i32,
+        chunk: Result<(Box<Chunk>, bool)>,
+    },
+    Encoded {
+        chunk_x: i32,
+        chunk_z: i32,
+        version: u64,
+        packets: Result<Box<EncodedChunk>>,
+    },
+    Saved {
+        chunk_x: i32,
+        chunk_z: i32,
+        result: Result<()>,
+    },
+}
+
+// the packets that send one chunk to a client, light first
+#[derive(Clone)]
+pub struct EncodedChunk {
+    pub light: PlayPacket,
+    pub data: PlayPacket,
+}
+
+// play packets don't implement Debug
+impl Debug for EncodedChunk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodedChunk").finish_non_exhaustive()
+    }
+}
+
+// threads that read, generate, encode and save chunks so the tick doesn't wait on them.
+// jobs run in the order they were submitted
+#[derive(Debug)]
+pub struct Workers {
+    jobs: Sender<Job>,
+    done: Receiver<Done>,
+}
+
+impl Workers {
+    pub fn spawn(storage: Arc<Mutex<RegionStorage>>, generator: Arc<dyn ChunkGenerator>) -> Self {
+        let (jobs, job_receiver) = flume::unbounded::<Job>();
+        let (done_sender, done) = flume::unbounded();
+        let count = thread::available_parallelism().map_or(2, |count| count.get().clamp(1, 4));
+        for index in 0..count {
+            let jobs = job_receiver.clone();
+            let done = done_sender.clone();
+            let storage = storage.clone();
+            let generator = generator.clone();
+            thread::Builder::new()
+                .name(format!("chunk worker {}", index))
+                .spawn(move || {
+                    for job in jobs.iter() {
+                        if done.send(run(job, &storage, &*generator)).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .expect("failed to spawn chunk worker");
+        }
+        Self { jobs, done }
+    }
+
+    pub fn submit(&self, job: Job) {
+        // the workers only stop once the world is dropped
+        let _ = self.jobs.send(job);
+    }
+
+    pub fn completed(&self) -> Vec<Done> {
+        self.done.try_iter().collect()
+    }
+
+    // block until the next job finishes
+    pub fn wait(&self) -> Option<Done> {
+        self.done.recv().ok()
+    }
+}
+
+fn run(job: Job, storage: &Mutex<RegionStorage>, generator: &dyn ChunkGenerator) -> Done {
+    match job {
+        Job::Load { chunk_x, chunk_z } => {
+            let chunk = load(chunk_x, chunk_z, storage, generator)
+                .map(|(chunk, generated)| (Box::new(chunk), generated));
+            Done::Loaded {
+                chunk_x,
+                chunk_z,
+                chunk,
+            }
+        }
+        Job::Encode {
+            chunk_x,
+            chunk_z,
+            version,
+            chunk,
+        } => {
+            let light = chunk.light_packet(chunk_x, chunk_z, ALL_LIGHT_SECTIONS);
+            let packets = chunk
+                .to_packet(chunk_x, chunk_z)
+                .map(|data| Box::new(EncodedChunk { light, data }));
+            Done::Encoded {
+                chunk_x,
+                chunk_z,
+                version,
+                packets,
+            }
+        }
+        Job::Save {
+            chunk_x,
+            chunk_z,
+            chunk,
+            game_time,
+        } => {
+            let result = storage
+                .lock()
+                .unwrap()
+                .save_chunk(chunk_x, chunk_z, &chunk, game_time);
+            Done::Saved {
+                chunk_x,
+                chunk_z,
+                result,
+            }
+        }
+    }
+}
+
+pub fn load(
+    chunk_x: i32,
+    chunk_z: i32,
+    storage: &Mutex<RegionStorage>,
+    generator: &dyn ChunkGenerator,
+) -> Result<(Chunk, bool)> {
+    let stored = storage.lock().unwrap().load_chunk(chunk_x, chunk_z)?;
+    match stored {
+        Some(chunk) => Ok((chunk, false)),
+        None => Ok((generator.generate(chunk_x, chunk_z)?, true)),
+    }
+}